@@ -0,0 +1,108 @@
+//! `bybit-cli account ...` — wallet, fees and account settings.
+
+use super::output::{self, OutputFormat};
+use super::{parse_enum, Context};
+use bybit_rust_api::dto::{
+    DemoApplyMoney, DemoApplyMoneyParams, GetTransactionLogParams, GetWalletBalanceParams,
+};
+use bybit_rust_api::rest::enums::account_type::AccountType;
+use bybit_rust_api::rest::AccountClient;
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum AccountCommand {
+    /// Wallet balance (one row per coin)
+    Balance {
+        /// UNIFIED, CONTRACT, SPOT, ...
+        #[arg(long, default_value = "UNIFIED", value_parser = parse_enum::<AccountType>)]
+        account_type: AccountType,
+        #[arg(long)]
+        coin: Option<String>,
+    },
+    /// Trading fee rates
+    FeeRate {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        base_coin: Option<String>,
+    },
+    /// Account info (margin mode, UTA status, ...)
+    Info,
+    /// Transaction log
+    Transactions {
+        #[arg(long)]
+        coin: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Collateral info
+    Collateral {
+        #[arg(long)]
+        coin: Option<String>,
+    },
+    /// Set account margin mode: REGULAR_MARGIN, PORTFOLIO_MARGIN, ISOLATED_MARGIN
+    SetMarginMode { mode: String },
+    /// Request demo trading funds (demo environment only)
+    DemoFunds { coin: String, amount: String },
+}
+
+pub async fn run(cmd: AccountCommand, ctx: &Context) -> anyhow::Result<()> {
+    let out = ctx.output();
+    let account = AccountClient::new(ctx.private_client()?);
+
+    match cmd {
+        AccountCommand::Balance { account_type, coin } => {
+            let r = account
+                .get_wallet_balance(GetWalletBalanceParams { account_type, coin })
+                .await?;
+            if out == OutputFormat::Json {
+                return output::print(&r.result, out);
+            }
+            let coins: Vec<_> = r
+                .result
+                .list
+                .iter()
+                .flat_map(|wallet| wallet.coin.iter())
+                .collect();
+            output::print(&coins, out)
+        }
+        AccountCommand::FeeRate { symbol, base_coin } => {
+            let r = account
+                .get_fee_rate(
+                    &ctx.category().to_string(),
+                    symbol.as_deref(),
+                    base_coin.as_deref(),
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+        AccountCommand::Info => {
+            let r = account.get_account_info().await?;
+            output::print(&r.result, out)
+        }
+        AccountCommand::Transactions { coin, limit } => {
+            let params = GetTransactionLogParams {
+                currency: coin,
+                limit,
+                ..Default::default()
+            };
+            let r = account.get_transaction_log(params).await?;
+            output::print(&r.result, out)
+        }
+        AccountCommand::Collateral { coin } => {
+            let r = account.get_collateral_info(coin.as_deref()).await?;
+            output::print(&r.result, out)
+        }
+        AccountCommand::SetMarginMode { mode } => {
+            ctx.confirm(&format!("set account margin mode to {}", mode))?;
+            let r = account.set_margin_mode(&mode).await?;
+            output::print(&r.result, out)
+        }
+        AccountCommand::DemoFunds { coin, amount } => {
+            ctx.confirm(&format!("request {} {} demo funds", amount, coin))?;
+            let params = DemoApplyMoneyParams::add(vec![DemoApplyMoney::new(coin, amount)]);
+            let r = account.request_demo_funds(params).await?;
+            output::print(&r.result, out)
+        }
+    }
+}
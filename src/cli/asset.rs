@@ -0,0 +1,149 @@
+//! `bybit-cli asset ...` — balances, deposits, withdrawals and transfers.
+
+use super::output;
+use super::Context;
+use bybit_rust_api::rest::asset::dto::WithdrawRequest;
+use bybit_rust_api::rest::AssetClient;
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum AssetCommand {
+    /// Coin / chain information
+    Coins {
+        #[arg(long)]
+        coin: Option<String>,
+    },
+    /// Balances of an account type (FUND, UNIFIED, CONTRACT, ...)
+    Balance {
+        #[arg(long, default_value = "FUND")]
+        account_type: String,
+        #[arg(long)]
+        coin: Option<String>,
+    },
+    /// Deposit records
+    Deposits {
+        #[arg(long)]
+        coin: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Deposit address
+    DepositAddress {
+        coin: String,
+        #[arg(long)]
+        chain: Option<String>,
+    },
+    /// Withdrawal records
+    Withdrawals {
+        #[arg(long)]
+        coin: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Withdrawable amount
+    Withdrawable { coin: String },
+    /// Withdraw to an external address
+    Withdraw {
+        coin: String,
+        chain: String,
+        address: String,
+        amount: String,
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// Transfer between your own account types
+    Transfer {
+        coin: String,
+        amount: String,
+        from: String,
+        to: String,
+    },
+    /// Internal transfer records
+    Transfers {
+        #[arg(long)]
+        coin: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+}
+
+pub async fn run(cmd: AssetCommand, ctx: &Context) -> anyhow::Result<()> {
+    let out = ctx.output();
+    let asset = AssetClient::new(ctx.private_client()?);
+
+    match cmd {
+        AssetCommand::Coins { coin } => {
+            let r = asset.get_coin_info(coin.as_deref()).await?;
+            output::print(&r.result["rows"], out)
+        }
+        AssetCommand::Balance { account_type, coin } => {
+            let r = asset
+                .query_account_coins_balance(None, &account_type, coin.as_deref(), None)
+                .await?;
+            output::print(&r.result["balance"], out)
+        }
+        AssetCommand::Deposits { coin, limit } => {
+            let r = asset
+                .get_deposit_records(coin.as_deref(), None, None, limit, None)
+                .await?;
+            output::print(&r.result["rows"], out)
+        }
+        AssetCommand::DepositAddress { coin, chain } => {
+            let r = asset
+                .get_master_deposit_address(&coin, chain.as_deref())
+                .await?;
+            output::print(&r.result["chains"], out)
+        }
+        AssetCommand::Withdrawals { coin, limit } => {
+            let r = asset
+                .get_withdrawal_records(None, coin.as_deref(), None, None, None, limit, None)
+                .await?;
+            output::print(&r.result["rows"], out)
+        }
+        AssetCommand::Withdrawable { coin } => {
+            let r = asset.get_withdrawable_amount(&coin).await?;
+            output::print(&r.result, out)
+        }
+        AssetCommand::Withdraw {
+            coin,
+            chain,
+            address,
+            amount,
+            tag,
+        } => {
+            ctx.confirm(&format!(
+                "withdraw {} {} via {} to {}",
+                amount, coin, chain, address
+            ))?;
+            let r = asset
+                .withdraw(WithdrawRequest {
+                    tag,
+                    ..WithdrawRequest::new(&coin, &chain, &address, &amount)
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        AssetCommand::Transfer {
+            coin,
+            amount,
+            from,
+            to,
+        } => {
+            ctx.confirm(&format!(
+                "transfer {} {} from {} to {}",
+                amount, coin, from, to
+            ))?;
+            let transfer_id = uuid::Uuid::new_v4().to_string();
+            let r = asset
+                .create_internal_transfer(&transfer_id, &coin, &amount, &from, &to)
+                .await?;
+            output::print(&r.result, out)
+        }
+        AssetCommand::Transfers { coin, limit } => {
+            let r = asset
+                .get_internal_transfer_records(None, coin.as_deref(), None, None, None, limit, None)
+                .await?;
+            output::print(&r.result, out)
+        }
+    }
+}
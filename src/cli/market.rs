@@ -0,0 +1,263 @@
+//! `bybit-cli market ...` — public market data.
+
+use super::output::{self, OutputFormat};
+use super::{parse_enum, Context};
+use bybit_rust_api::rest::enums::interval_time::IntervalTime;
+use bybit_rust_api::rest::MarketClient;
+use bybit_rust_api::Interval;
+use clap::Subcommand;
+use serde_json::json;
+
+#[derive(Subcommand, Debug)]
+pub enum MarketCommand {
+    /// Server time
+    Time,
+    /// Ticker for one symbol, or all symbols of the category
+    Ticker { symbol: Option<String> },
+    /// Candlesticks
+    Kline {
+        symbol: String,
+        /// 1,3,5,15,30,1h,2h,4h,6h,12h,1d,1w,1M
+        #[arg(default_value = "1h")]
+        interval: String,
+        #[arg(default_value_t = 5)]
+        limit: i32,
+    },
+    /// Order book snapshot
+    Orderbook {
+        symbol: String,
+        #[arg(default_value_t = 5)]
+        depth: i32,
+    },
+    /// Instrument specifications
+    Instruments {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        base_coin: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Recent public trades
+    Trades {
+        symbol: String,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Funding rate history
+    Funding {
+        symbol: String,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Open interest
+    OpenInterest {
+        symbol: String,
+        /// 5min, 15min, 30min, 1h, 4h, 1d
+        #[arg(long, default_value = "1h", value_parser = parse_enum::<IntervalTime>)]
+        interval: IntervalTime,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Insurance fund
+    Insurance {
+        #[arg(long)]
+        coin: Option<String>,
+    },
+    /// Risk limit tiers
+    RiskLimit {
+        #[arg(long)]
+        symbol: Option<String>,
+    },
+    /// Delivery price of expired contracts
+    DeliveryPrice {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        base_coin: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Long/short account ratio
+    LongShort {
+        symbol: String,
+        /// 5min, 15min, 30min, 1h, 4h, 1d
+        #[arg(long, default_value = "1h")]
+        period: String,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Option historical volatility
+    Volatility {
+        #[arg(long)]
+        base_coin: Option<String>,
+        #[arg(long)]
+        period: Option<i32>,
+    },
+}
+
+pub async fn run(cmd: MarketCommand, ctx: &Context) -> anyhow::Result<()> {
+    let market = MarketClient::new(ctx.public_client());
+    let category = ctx.category();
+    let out = ctx.output();
+
+    match cmd {
+        MarketCommand::Time => {
+            let r = market.get_server_time().await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::Ticker { symbol } => {
+            let r = market
+                .get_tickers(category, symbol.as_deref(), None, None)
+                .await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::Kline {
+            symbol,
+            interval,
+            limit,
+        } => {
+            let r = market
+                .get_kline(
+                    category,
+                    &symbol,
+                    parse_interval(&interval),
+                    None,
+                    None,
+                    Some(limit),
+                )
+                .await?;
+            if out == OutputFormat::Json {
+                return output::print(&r.result, out);
+            }
+            let rows: Vec<_> = r
+                .result
+                .list
+                .iter()
+                .map(|c| {
+                    json!({
+                        "start": c[0], "open": c[1], "high": c[2], "low": c[3],
+                        "close": c[4], "volume": c[5], "turnover": c[6],
+                    })
+                })
+                .collect();
+            output::print(&rows, out)
+        }
+        MarketCommand::Orderbook { symbol, depth } => {
+            let r = market.get_orderbook(category, &symbol, Some(depth)).await?;
+            if out == OutputFormat::Json {
+                return output::print(&r.result, out);
+            }
+            // Asks best-last above bids best-first, like a depth ladder
+            let asks = r
+                .result
+                .a
+                .iter()
+                .rev()
+                .map(|l| json!({ "side": "ask", "price": l[0], "size": l[1] }));
+            let bids = r
+                .result
+                .b
+                .iter()
+                .map(|l| json!({ "side": "bid", "price": l[0], "size": l[1] }));
+            output::print(&asks.chain(bids).collect::<Vec<_>>(), out)
+        }
+        MarketCommand::Instruments {
+            symbol,
+            base_coin,
+            limit,
+        } => {
+            let r = market
+                .get_instruments_info(
+                    category,
+                    symbol.as_deref(),
+                    base_coin.as_deref(),
+                    limit,
+                    None,
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::Trades { symbol, limit } => {
+            let r = market
+                .get_recent_trade(category, Some(&symbol), None, None, limit)
+                .await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::Funding { symbol, limit } => {
+            let r = market
+                .get_funding_history(category, &symbol, None, None, limit)
+                .await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::OpenInterest {
+            symbol,
+            interval,
+            limit,
+        } => {
+            let r = market
+                .get_open_interest(category, &symbol, interval, None, None, limit, None)
+                .await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::Insurance { coin } => {
+            let r = market.get_insurance(coin.as_deref()).await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::RiskLimit { symbol } => {
+            let r = market.get_risk_limit(category, symbol.as_deref()).await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::DeliveryPrice {
+            symbol,
+            base_coin,
+            limit,
+        } => {
+            let r = market
+                .get_delivery_price(
+                    category,
+                    symbol.as_deref(),
+                    base_coin.as_deref(),
+                    limit,
+                    None,
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::LongShort {
+            symbol,
+            period,
+            limit,
+        } => {
+            let r = market
+                .get_long_short_ratio(category, &symbol, &period, limit)
+                .await?;
+            output::print(&r.result, out)
+        }
+        MarketCommand::Volatility { base_coin, period } => {
+            let r = market
+                .get_historical_volatility(category, base_coin.as_deref(), period)
+                .await?;
+            output::print(&r.result, out)
+        }
+    }
+}
+
+pub fn parse_interval(s: &str) -> Interval {
+    match s {
+        "1" | "1m" => Interval::OneMinute,
+        "3" | "3m" => Interval::ThreeMinute,
+        "5" | "5m" => Interval::FiveMinute,
+        "15" | "15m" => Interval::FifteenMinute,
+        "30" | "30m" => Interval::ThirtyMinute,
+        "1h" | "60" => Interval::OneHour,
+        "2h" | "120" => Interval::TwoHour,
+        "4h" | "240" => Interval::FourHour,
+        "6h" | "360" => Interval::SixHour,
+        "12h" | "720" => Interval::TwelveHour,
+        "1d" | "D" => Interval::OneDay,
+        "1w" | "W" => Interval::OneWeek,
+        "1M" | "M" => Interval::OneMonth,
+        _ => Interval::OneHour,
+    }
+}
@@ -0,0 +1,357 @@
+//! Command-line definitions and shared plumbing for `bybit-cli`.
+//!
+//! Each REST client has a matching subcommand group (`market`, `order`,
+//! `position`, `account`, `asset`, `user`), plus `stream` for live
+//! WebSocket topics, `watch` for a terminal dashboard and `record` /
+//! `replay` for offline captures. Global flags select the
+//! credentials profile, environment, default category and output format.
+
+pub mod account;
+pub mod asset;
+pub mod market;
+pub mod order;
+pub mod output;
+pub mod position;
+pub mod record;
+pub mod stream;
+pub mod user;
+pub mod watch;
+
+use anyhow::{bail, Context as _};
+use bybit_rust_api::rest::{ApiKeyPair, RestClient};
+use bybit_rust_api::{BybitEnv, Category, Profile, ProfileStore};
+use clap::{Parser, Subcommand};
+use output::OutputFormat;
+use serde::de::DeserializeOwned;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "bybit-cli",
+    version = bybit_rust_api::consts::VERSION,
+    about = "Command-line interface for the Bybit V5 API"
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct GlobalArgs {
+    /// Credentials profile (see `ProfileStore`)
+    #[arg(long, global = true, env = "BYBIT_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Profile file (default: $BYBIT_PROFILES or ~/.config/bybit/profiles.toml)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub profiles_file: Option<PathBuf>,
+
+    /// Use testnet (shorthand for `--env testnet`)
+    #[arg(long, global = true, conflicts_with = "env")]
+    pub testnet: bool,
+
+    /// Environment: mainnet, mainnet-backup, testnet, demo, tr, kz, ge, ae, nl
+    #[arg(long, global = true, env = "BYBIT_ENV")]
+    pub env: Option<BybitEnv>,
+
+    /// Product category
+    #[arg(long, short = 'c', global = true, default_value = "spot", value_parser = parse_enum::<Category>)]
+    pub category: Category,
+
+    /// Output format
+    #[arg(long, short = 'o', global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    /// Do not ask for confirmation before mutating commands
+    #[arg(long, short = 'y', global = true)]
+    pub yes: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Market data (public)
+    #[command(subcommand)]
+    Market(market::MarketCommand),
+    /// Place, amend, cancel and query orders
+    #[command(subcommand)]
+    Order(order::OrderCommand),
+    /// Positions, leverage and TP/SL
+    #[command(subcommand)]
+    Position(position::PositionCommand),
+    /// Wallet balance, fees and account settings
+    #[command(subcommand)]
+    Account(account::AccountCommand),
+    /// Deposits, withdrawals and transfers
+    #[command(subcommand)]
+    Asset(asset::AssetCommand),
+    /// API key and sub-account management
+    #[command(subcommand)]
+    User(user::UserCommand),
+    /// Live WebSocket topics
+    Stream(stream::StreamArgs),
+    /// Terminal dashboard for one symbol: book, trades, orders, positions
+    Watch(watch::WatchArgs),
+    /// Record raw public WebSocket frames to compressed, rotated NDJSON
+    Record(record::RecordArgs),
+    /// Replay recorded frames offline
+    Replay(record::ReplayArgs),
+
+    // Shortcuts kept from the original CLI
+    #[command(hide = true)]
+    Time,
+    #[command(hide = true)]
+    Ticker { symbol: Option<String> },
+    #[command(hide = true)]
+    Kline {
+        symbol: Option<String>,
+        interval: Option<String>,
+        limit: Option<i32>,
+    },
+    #[command(hide = true)]
+    Orderbook {
+        symbol: Option<String>,
+        depth: Option<i32>,
+    },
+}
+
+impl Command {
+    /// Rewrite legacy top-level shortcuts into `market` subcommands.
+    pub fn normalize(self) -> Command {
+        match self {
+            Command::Time => Command::Market(market::MarketCommand::Time),
+            Command::Ticker { symbol } => Command::Market(market::MarketCommand::Ticker { symbol }),
+            Command::Kline {
+                symbol,
+                interval,
+                limit,
+            } => Command::Market(market::MarketCommand::Kline {
+                symbol: symbol.unwrap_or_else(|| "BTCUSDT".to_string()),
+                interval: interval.unwrap_or_else(|| "1h".to_string()),
+                limit: limit.unwrap_or(5),
+            }),
+            Command::Orderbook { symbol, depth } => {
+                Command::Market(market::MarketCommand::Orderbook {
+                    symbol: symbol.unwrap_or_else(|| "BTCUSDT".to_string()),
+                    depth: depth.unwrap_or(5),
+                })
+            }
+            other => other,
+        }
+    }
+}
+
+/// Resolved global settings shared by every command.
+pub struct Context {
+    pub global: GlobalArgs,
+    pub env: BybitEnv,
+    store: Option<ProfileStore>,
+}
+
+impl Context {
+    pub fn new(global: GlobalArgs) -> anyhow::Result<Self> {
+        let path = global
+            .profiles_file
+            .clone()
+            .or_else(ProfileStore::default_path);
+        let store = match path {
+            Some(ref path) if path.exists() => Some(ProfileStore::load(path)?),
+            Some(ref path) if global.profiles_file.is_some() => {
+                bail!("Profile file {} does not exist", path.display())
+            }
+            _ => None,
+        };
+        let profile_env = select_profile(store.as_ref(), global.profile.as_deref())?.map(|p| p.env);
+        let env = if global.testnet {
+            BybitEnv::Testnet
+        } else {
+            global.env.or(profile_env).unwrap_or_default()
+        };
+
+        Ok(Context { global, env, store })
+    }
+
+    pub fn category(&self) -> Category {
+        self.global.category
+    }
+
+    pub fn output(&self) -> OutputFormat {
+        self.global.output
+    }
+
+    /// Client for public endpoints (no credentials).
+    pub fn public_client(&self) -> RestClient {
+        let keys = ApiKeyPair::new("cli".to_string(), String::new(), String::new());
+        RestClient::for_env(keys, self.env)
+    }
+
+    /// Client for private endpoints.
+    ///
+    /// Credentials come from the selected (or default) profile, falling back
+    /// to `BYBIT_API_KEY` / `BYBIT_API_SECRET` (or the testnet variants) only
+    /// when no `--profile` was given.
+    pub fn private_client(&self) -> anyhow::Result<RestClient> {
+        Ok(RestClient::for_env(self.key_pair()?, self.env))
+    }
+
+    pub fn key_pair(&self) -> anyhow::Result<ApiKeyPair> {
+        if let Some(profile) = select_profile(self.store.as_ref(), self.global.profile.as_deref())?
+        {
+            let passphrase = if profile.is_encrypted() {
+                Some(read_passphrase(&profile.name)?)
+            } else {
+                None
+            };
+            return Ok(profile.key_pair(passphrase.as_deref())?);
+        }
+        if self.env.is_testnet() {
+            ApiKeyPair::from_env_testnet()
+        } else {
+            ApiKeyPair::from_env()
+        }
+    }
+
+    /// Credentials, if any are configured.
+    ///
+    /// `None` when there is no profile and no API key in the environment.
+    /// Configured but unusable credentials (unknown profile, wrong
+    /// passphrase, key without secret) are an error rather than `None`.
+    pub fn configured_key_pair(&self) -> anyhow::Result<Option<ApiKeyPair>> {
+        let profile = select_profile(self.store.as_ref(), self.global.profile.as_deref())?;
+        let env_vars: &[&str] = if self.env.is_testnet() {
+            &[
+                "BYBIT_API_KEY",
+                "BYBIT_API_SECRET",
+                "BYBIT_TESTNET_API_KEY",
+                "BYBIT_TESTNET_API_SECRET",
+            ]
+        } else {
+            &["BYBIT_API_KEY", "BYBIT_API_SECRET"]
+        };
+        if profile.is_none() && !env_vars.iter().any(|v| std::env::var_os(v).is_some()) {
+            return Ok(None);
+        }
+        self.key_pair().map(Some)
+    }
+
+    /// Ask before running a mutating command. `--yes` skips the prompt.
+    pub fn confirm(&self, action: &str) -> anyhow::Result<()> {
+        if self.global.yes {
+            return Ok(());
+        }
+        let stdin = std::io::stdin();
+        if !stdin.is_terminal() {
+            bail!("Refusing to {} without confirmation; pass --yes", action);
+        }
+        eprint!("{} on {}? [y/N] ", capitalize(action), self.env);
+        std::io::stderr().flush()?;
+        let mut answer = String::new();
+        stdin.lock().read_line(&mut answer)?;
+        match answer.trim().to_ascii_lowercase().as_str() {
+            "y" | "yes" => Ok(()),
+            _ => bail!("Aborted"),
+        }
+    }
+}
+
+/// The profile named by `--profile`, or the store's default one.
+///
+/// An explicitly requested profile must exist: silently falling back to the
+/// environment keys would send commands to the wrong account.
+fn select_profile<'a>(
+    store: Option<&'a ProfileStore>,
+    name: Option<&str>,
+) -> anyhow::Result<Option<&'a Profile>> {
+    match (store, name) {
+        (Some(store), Some(name)) => Ok(Some(store.resolve(Some(name))?)),
+        (Some(store), None) => Ok(store.default_profile()),
+        (None, Some(_)) => bail!("--profile given but no profile file was found"),
+        (None, None) => Ok(None),
+    }
+}
+
+fn read_passphrase(profile: &str) -> anyhow::Result<String> {
+    if let Ok(passphrase) = std::env::var("BYBIT_PROFILE_PASSPHRASE") {
+        return Ok(passphrase);
+    }
+    rpassword::prompt_password(format!("Passphrase for profile {}: ", profile))
+        .context("Failed to read passphrase")
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Parse a crate enum from its wire name, case-insensitively
+/// (`linear`, `buy` → `Buy`, `gtc` → `GTC`, ...).
+pub fn parse_enum<T: DeserializeOwned>(s: &str) -> Result<T, String> {
+    let candidates = [
+        s.to_string(),
+        s.to_ascii_lowercase(),
+        s.to_ascii_uppercase(),
+        capitalize(&s.to_ascii_lowercase()),
+    ];
+    candidates
+        .iter()
+        .find_map(|c| serde_json::from_value(serde_json::Value::String(c.clone())).ok())
+        .ok_or_else(|| format!("invalid value: {}", s))
+}
+
+pub async fn run(cli: Cli) -> anyhow::Result<()> {
+    let ctx = Context::new(cli.global)?;
+    match cli.command.normalize() {
+        Command::Market(cmd) => market::run(cmd, &ctx).await,
+        Command::Order(cmd) => order::run(cmd, &ctx).await,
+        Command::Position(cmd) => position::run(cmd, &ctx).await,
+        Command::Account(cmd) => account::run(cmd, &ctx).await,
+        Command::Asset(cmd) => asset::run(cmd, &ctx).await,
+        Command::User(cmd) => user::run(cmd, &ctx).await,
+        Command::Stream(args) => stream::run(args, &ctx).await,
+        Command::Watch(args) => watch::run(args, &ctx).await,
+        Command::Record(args) => record::record(args, &ctx).await,
+        Command::Replay(args) => record::replay(args, &ctx).await,
+        Command::Time
+        | Command::Ticker { .. }
+        | Command::Kline { .. }
+        | Command::Orderbook { .. } => {
+            unreachable!("normalized above")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r#"
+default = "main"
+
+[profiles.main]
+key = "main-key"
+secret = "main-secret"
+env = "testnet"
+"#;
+
+    #[test]
+    fn test_unknown_profile_is_error() {
+        let store = ProfileStore::from_toml_str(TOML).unwrap();
+        let err = select_profile(Some(&store), Some("mian")).unwrap_err();
+        assert!(err.to_string().contains("Unknown profile: mian"));
+        assert!(select_profile(None, Some("main")).is_err());
+    }
+
+    #[test]
+    fn test_default_profile_without_flag() {
+        let store = ProfileStore::from_toml_str(TOML).unwrap();
+        let profile = select_profile(Some(&store), None).unwrap().unwrap();
+        assert_eq!(profile.name, "main");
+        assert!(select_profile(None, None).unwrap().is_none());
+    }
+}
@@ -0,0 +1,295 @@
+//! `bybit-cli order ...` — order entry and queries.
+
+use super::output;
+use super::{parse_enum, Context};
+use anyhow::bail;
+use bybit_rust_api::rest::order::dto::{AmendOrderRequest, CancelOrderRequest, PlaceOrderRequest};
+use bybit_rust_api::rest::OrderClient;
+use bybit_rust_api::{OrderType, Side, TimeInForce};
+use clap::{Args, Subcommand};
+
+#[derive(Subcommand, Debug)]
+pub enum OrderCommand {
+    /// Place an order (market if no --price is given)
+    Place {
+        symbol: String,
+        /// buy / sell
+        #[arg(value_parser = parse_enum::<Side>)]
+        side: Side,
+        qty: String,
+        /// Limit price; omit for a market order
+        #[arg(long)]
+        price: Option<String>,
+        /// GTC, IOC, FOK, PostOnly
+        #[arg(long, value_parser = parse_enum::<TimeInForce>)]
+        tif: Option<TimeInForce>,
+        #[arg(long)]
+        reduce_only: bool,
+        #[arg(long)]
+        take_profit: Option<String>,
+        #[arg(long)]
+        stop_loss: Option<String>,
+        /// Client order id
+        #[arg(long)]
+        link_id: Option<String>,
+        /// 0: one-way, 1: hedge buy side, 2: hedge sell side
+        #[arg(long)]
+        position_idx: Option<i32>,
+    },
+    /// Amend an open order
+    Amend {
+        symbol: String,
+        #[command(flatten)]
+        id: OrderId,
+        #[arg(long)]
+        qty: Option<String>,
+        #[arg(long)]
+        price: Option<String>,
+        #[arg(long)]
+        take_profit: Option<String>,
+        #[arg(long)]
+        stop_loss: Option<String>,
+    },
+    /// Cancel an open order
+    Cancel {
+        symbol: String,
+        #[command(flatten)]
+        id: OrderId,
+    },
+    /// Cancel all open orders of the category (optionally one symbol)
+    CancelAll {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        settle_coin: Option<String>,
+    },
+    /// Open orders
+    Open {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        settle_coin: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Order history
+    History {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Execution (fill) history
+    Trades {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+}
+
+#[derive(Args, Debug)]
+#[group(required = true, multiple = false)]
+pub struct OrderId {
+    #[arg(long)]
+    pub order_id: Option<String>,
+    /// Client order id
+    #[arg(long)]
+    pub link_id: Option<String>,
+}
+
+impl OrderId {
+    fn describe(&self) -> String {
+        match (&self.order_id, &self.link_id) {
+            (Some(id), _) => id.clone(),
+            (None, Some(link)) => format!("link id {}", link),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+pub async fn run(cmd: OrderCommand, ctx: &Context) -> anyhow::Result<()> {
+    let category = ctx.category();
+    let out = ctx.output();
+
+    match cmd {
+        OrderCommand::Place {
+            symbol,
+            side,
+            qty,
+            price,
+            tif,
+            reduce_only,
+            take_profit,
+            stop_loss,
+            link_id,
+            position_idx,
+        } => {
+            let order_type = if price.is_some() {
+                OrderType::Limit
+            } else {
+                OrderType::Market
+            };
+            ctx.confirm(&format!(
+                "place {} {} {} {} {}{}",
+                category,
+                order_type,
+                side,
+                qty,
+                symbol,
+                price
+                    .as_ref()
+                    .map(|p| format!(" @ {}", p))
+                    .unwrap_or_default()
+            ))?;
+            let request = PlaceOrderRequest {
+                category,
+                symbol,
+                side,
+                order_type,
+                qty,
+                price,
+                time_in_force: tif,
+                reduce_only: reduce_only.then_some(true),
+                take_profit,
+                stop_loss,
+                order_link_id: link_id,
+                position_idx,
+                ..Default::default()
+            };
+            let r = OrderClient::new(ctx.private_client()?)
+                .place_order(request)
+                .await?;
+            output::print(&r.result, out)
+        }
+        OrderCommand::Amend {
+            symbol,
+            id,
+            qty,
+            price,
+            take_profit,
+            stop_loss,
+        } => {
+            if qty.is_none() && price.is_none() && take_profit.is_none() && stop_loss.is_none() {
+                bail!("Nothing to amend: pass --qty, --price, --take-profit or --stop-loss");
+            }
+            ctx.confirm(&format!("amend order {} on {}", id.describe(), symbol))?;
+            let request = AmendOrderRequest {
+                category,
+                symbol,
+                order_id: id.order_id,
+                order_link_id: id.link_id,
+                order_iv: None,
+                trigger_price: None,
+                qty,
+                price,
+                tpsl_mode: None,
+                take_profit,
+                stop_loss,
+                tp_trigger_by: None,
+                sl_trigger_by: None,
+                trigger_by: None,
+                tp_limit_price: None,
+                sl_limit_price: None,
+            };
+            let r = OrderClient::new(ctx.private_client()?)
+                .amend_order(request)
+                .await?;
+            output::print(&r.result, out)
+        }
+        OrderCommand::Cancel { symbol, id } => {
+            ctx.confirm(&format!("cancel order {} on {}", id.describe(), symbol))?;
+            let request = CancelOrderRequest {
+                category,
+                symbol,
+                order_id: id.order_id,
+                order_link_id: id.link_id,
+                order_filter: None,
+            };
+            let r = OrderClient::new(ctx.private_client()?)
+                .cancel_order(request)
+                .await?;
+            output::print(&r.result, out)
+        }
+        OrderCommand::CancelAll {
+            symbol,
+            settle_coin,
+        } => {
+            ctx.confirm(&format!(
+                "cancel all {} orders{}",
+                category,
+                symbol
+                    .as_ref()
+                    .map(|s| format!(" on {}", s))
+                    .unwrap_or_default()
+            ))?;
+            let r = OrderClient::new(ctx.private_client()?)
+                .cancel_all_orders(
+                    category,
+                    symbol.as_deref(),
+                    None,
+                    settle_coin.as_deref(),
+                    None,
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+        OrderCommand::Open {
+            symbol,
+            settle_coin,
+            limit,
+        } => {
+            let r = OrderClient::new(ctx.private_client()?)
+                .get_open_orders(
+                    category,
+                    symbol.as_deref(),
+                    None,
+                    settle_coin.as_deref(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    limit,
+                    None,
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+        OrderCommand::History { symbol, limit } => {
+            let r = OrderClient::new(ctx.private_client()?)
+                .get_order_history(
+                    category,
+                    symbol.as_deref(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    limit,
+                    None,
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+        OrderCommand::Trades { symbol, limit } => {
+            let r = OrderClient::new(ctx.private_client()?)
+                .get_trade_history(
+                    category,
+                    symbol.as_deref(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    limit,
+                    None,
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Rendering of API results as aligned tables, JSON or CSV.
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+/// Print any serializable result in the requested format.
+pub fn print<T: Serialize>(result: &T, format: OutputFormat) -> anyhow::Result<()> {
+    let value = serde_json::to_value(result)?;
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&value)?),
+        OutputFormat::Table => print!("{}", render_table(&rows(&value))),
+        OutputFormat::Csv => print!("{}", render_csv(&rows(&value))),
+    }
+    Ok(())
+}
+
+/// Header + rows extracted from a result.
+#[derive(Debug, PartialEq)]
+pub struct Rows {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Turn a result into rows.
+///
+/// Arrays (or a `list` field, as most Bybit results have) become one row
+/// per element; any other object is shown as `key | value` pairs.
+pub fn rows(value: &Value) -> Rows {
+    let items = match value {
+        Value::Array(items) => Some(items),
+        Value::Object(map) => map.get("list").and_then(Value::as_array),
+        _ => None,
+    };
+
+    match items {
+        Some(items) if items.iter().all(Value::is_object) => {
+            let mut columns: Vec<String> = Vec::new();
+            for item in items {
+                for key in item.as_object().into_iter().flat_map(|m| m.keys()) {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                }
+            }
+            let rows = items
+                .iter()
+                .map(|item| columns.iter().map(|c| cell(&item[c])).collect())
+                .collect();
+            Rows { columns, rows }
+        }
+        Some(items) => {
+            // Positional arrays, e.g. klines: ["start", "open", ...]
+            let width = items
+                .iter()
+                .map(|i| i.as_array().map_or(1, Vec::len))
+                .max()
+                .unwrap_or(0);
+            let rows = items
+                .iter()
+                .map(|item| match item.as_array() {
+                    Some(fields) => fields.iter().map(cell).collect(),
+                    None => vec![cell(item)],
+                })
+                .collect();
+            Rows {
+                columns: (0..width).map(|i| i.to_string()).collect(),
+                rows,
+            }
+        }
+        None => match value {
+            Value::Object(map) => Rows {
+                columns: vec!["field".to_string(), "value".to_string()],
+                rows: map.iter().map(|(k, v)| vec![k.clone(), cell(v)]).collect(),
+            },
+            other => Rows {
+                columns: vec!["value".to_string()],
+                rows: vec![vec![cell(other)]],
+            },
+        },
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub fn render_table(rows: &Rows) -> String {
+    if rows.rows.is_empty() {
+        return "(no results)\n".to_string();
+    }
+    let mut widths: Vec<usize> = rows.columns.iter().map(|c| c.chars().count()).collect();
+    for row in &rows.rows {
+        for (i, field) in row.iter().enumerate() {
+            widths[i] = widths[i].max(field.chars().count());
+        }
+    }
+
+    let line = |fields: &[String]| {
+        let cells: Vec<String> = fields
+            .iter()
+            .zip(&widths)
+            .map(|(f, w)| format!("{:<width$}", f, width = *w))
+            .collect();
+        format!("{}\n", cells.join("  ").trim_end())
+    };
+
+    let mut out = line(&rows.columns);
+    let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    out.push_str(&line(&rule));
+    for row in &rows.rows {
+        out.push_str(&line(row));
+    }
+    out
+}
+
+pub fn render_csv(rows: &Rows) -> String {
+    let line = |fields: &[String]| {
+        let cells: Vec<String> = fields.iter().map(|f| csv_escape(f)).collect();
+        format!("{}\n", cells.join(","))
+    };
+    let mut out = line(&rows.columns);
+    for row in &rows.rows {
+        out.push_str(&line(row));
+    }
+    out
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_rows_from_list_field() {
+        let value = json!({
+            "category": "linear",
+            "list": [
+                {"symbol": "BTCUSDT", "lastPrice": "65000"},
+                {"symbol": "ETHUSDT", "lastPrice": "3500", "volume24h": "10"}
+            ]
+        });
+        let rows = rows(&value);
+        assert_eq!(rows.columns, vec!["lastPrice", "symbol", "volume24h"]);
+        assert_eq!(rows.rows[0], vec!["65000", "BTCUSDT", ""]);
+        assert_eq!(rows.rows[1], vec!["3500", "ETHUSDT", "10"]);
+    }
+
+    #[test]
+    fn test_rows_from_plain_object() {
+        let rows = rows(&json!({"timeSecond": "1700000000"}));
+        assert_eq!(rows.columns, vec!["field", "value"]);
+        assert_eq!(rows.rows, vec![vec!["timeSecond", "1700000000"]]);
+    }
+
+    #[test]
+    fn test_render_csv_escapes_fields() {
+        let rows = Rows {
+            columns: vec!["a".to_string(), "b".to_string()],
+            rows: vec![vec!["x,y".to_string(), "say \"hi\"".to_string()]],
+        };
+        assert_eq!(render_csv(&rows), "a,b\n\"x,y\",\"say \"\"hi\"\"\"\n");
+    }
+
+    #[test]
+    fn test_render_table_aligns_columns() {
+        let rows = Rows {
+            columns: vec!["symbol".to_string(), "qty".to_string()],
+            rows: vec![vec!["BTCUSDT".to_string(), "1".to_string()]],
+        };
+        assert_eq!(
+            render_table(&rows),
+            "symbol   qty\n-------  ---\nBTCUSDT  1\n"
+        );
+    }
+}
@@ -0,0 +1,243 @@
+//! `bybit-cli position ...` — positions, leverage and TP/SL.
+
+use super::output;
+use super::Context;
+use anyhow::bail;
+use bybit_rust_api::enums::{PositionIdx, TradeMode};
+use bybit_rust_api::rest::position::dto::{
+    AddMarginRequest, GetClosedPnlParams, GetPositionInfoParams, SetLeverageRequest,
+    SetTradingStopRequest, SwitchIsolatedRequest, SwitchPositionModeRequest,
+};
+use bybit_rust_api::rest::PositionClient;
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Subcommand, Debug)]
+pub enum PositionCommand {
+    /// Open positions
+    List {
+        #[arg(long)]
+        symbol: Option<String>,
+        /// Required for linear/inverse when no symbol is given (e.g. USDT)
+        #[arg(long)]
+        settle_coin: Option<String>,
+    },
+    /// Set leverage (sell leverage defaults to buy leverage)
+    Leverage {
+        symbol: String,
+        buy: String,
+        sell: Option<String>,
+    },
+    /// Switch cross / isolated margin for a symbol
+    MarginMode {
+        symbol: String,
+        #[arg(value_enum)]
+        mode: MarginMode,
+        leverage: String,
+    },
+    /// Switch one-way / hedge position mode
+    Mode {
+        #[arg(value_enum)]
+        mode: PositionMode,
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        coin: Option<String>,
+    },
+    /// Set take profit / stop loss / trailing stop
+    TradingStop {
+        symbol: String,
+        #[arg(long)]
+        take_profit: Option<String>,
+        #[arg(long)]
+        stop_loss: Option<String>,
+        #[arg(long)]
+        trailing_stop: Option<String>,
+        /// 0: one-way, 1: hedge buy side, 2: hedge sell side
+        #[arg(long, default_value = "0", value_parser = parse_position_idx)]
+        position_idx: PositionIdx,
+    },
+    /// Add (positive) or reduce (negative) isolated margin
+    Margin {
+        symbol: String,
+        #[arg(allow_hyphen_values = true)]
+        amount: String,
+        #[arg(long, value_parser = parse_position_idx)]
+        position_idx: Option<PositionIdx>,
+    },
+    /// Closed profit and loss
+    ClosedPnl {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Executions
+    Executions {
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum MarginMode {
+    Cross,
+    Isolated,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum PositionMode {
+    /// Merged single (one-way)
+    OneWay,
+    /// Both sides
+    Hedge,
+}
+
+fn parse_position_idx(s: &str) -> Result<PositionIdx, String> {
+    serde_json::from_value(serde_json::Value::String(s.to_string())).map_err(|e| e.to_string())
+}
+
+pub async fn run(cmd: PositionCommand, ctx: &Context) -> anyhow::Result<()> {
+    let category = ctx.category();
+    let out = ctx.output();
+    let position = PositionClient::new(ctx.private_client()?);
+
+    match cmd {
+        PositionCommand::List {
+            symbol,
+            settle_coin,
+        } => {
+            let r = position
+                .get_position_info(GetPositionInfoParams {
+                    category,
+                    symbol,
+                    settle_coin,
+                    ..Default::default()
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        PositionCommand::Leverage { symbol, buy, sell } => {
+            let sell = sell.unwrap_or_else(|| buy.clone());
+            ctx.confirm(&format!("set {} leverage to {}/{}", symbol, buy, sell))?;
+            let r = position
+                .set_leverage(SetLeverageRequest {
+                    category,
+                    symbol,
+                    buy_leverage: buy,
+                    sell_leverage: sell,
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        PositionCommand::MarginMode {
+            symbol,
+            mode,
+            leverage,
+        } => {
+            let trade_mode = match mode {
+                MarginMode::Cross => TradeMode::CrossMargin,
+                MarginMode::Isolated => TradeMode::IsolatedMargin,
+            };
+            ctx.confirm(&format!("switch {} to {:?} margin", symbol, mode))?;
+            let r = position
+                .switch_margin_mode(SwitchIsolatedRequest {
+                    category,
+                    symbol,
+                    trade_mode,
+                    buy_leverage: leverage.clone(),
+                    sell_leverage: leverage,
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        PositionCommand::Mode { mode, symbol, coin } => {
+            if symbol.is_none() && coin.is_none() {
+                bail!("Pass --symbol or --coin");
+            }
+            let position_mode = match mode {
+                PositionMode::OneWay => bybit_rust_api::enums::PositionMode::MergedSingle,
+                PositionMode::Hedge => bybit_rust_api::enums::PositionMode::BothSides,
+            };
+            ctx.confirm(&format!("switch position mode to {:?}", mode))?;
+            let r = position
+                .switch_position_mode(SwitchPositionModeRequest {
+                    category,
+                    symbol,
+                    coin,
+                    mode: position_mode,
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        PositionCommand::TradingStop {
+            symbol,
+            take_profit,
+            stop_loss,
+            trailing_stop,
+            position_idx,
+        } => {
+            if take_profit.is_none() && stop_loss.is_none() && trailing_stop.is_none() {
+                bail!("Pass --take-profit, --stop-loss or --trailing-stop");
+            }
+            ctx.confirm(&format!("set trading stop on {}", symbol))?;
+            let r = position
+                .set_trading_stop(SetTradingStopRequest {
+                    category,
+                    symbol,
+                    position_idx,
+                    take_profit,
+                    stop_loss,
+                    trailing_stop,
+                    ..Default::default()
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        PositionCommand::Margin {
+            symbol,
+            amount,
+            position_idx,
+        } => {
+            ctx.confirm(&format!("change {} position margin by {}", symbol, amount))?;
+            let r = position
+                .update_margin(AddMarginRequest {
+                    category,
+                    symbol,
+                    margin: amount,
+                    position_idx,
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        PositionCommand::ClosedPnl { symbol, limit } => {
+            let r = position
+                .get_closed_pnl(GetClosedPnlParams {
+                    category,
+                    symbol,
+                    limit,
+                    ..Default::default()
+                })
+                .await?;
+            output::print(&r.result, out)
+        }
+        PositionCommand::Executions { symbol, limit } => {
+            let r = position
+                .get_execution(
+                    category,
+                    symbol.as_deref(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    limit,
+                    None,
+                )
+                .await?;
+            output::print(&r.result, out)
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! `bybit-cli record` / `bybit-cli replay` — capture raw WebSocket frames
+//! and play them back offline.
+
+use super::output::OutputFormat;
+use super::stream::print_pretty;
+use super::Context;
+use anyhow::bail;
+use bybit_rust_api::ws::{
+    RecorderConfig, ReplaySpeed, ReplayWsSource, WsClient, WsMessage, WsRecorder,
+};
+use clap::Args;
+use futures_util::StreamExt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Args, Debug)]
+pub struct RecordArgs {
+    /// Raw topics, e.g. orderbook.50.BTCUSDT publicTrade.BTCUSDT
+    #[arg(required = true)]
+    pub topics: Vec<String>,
+
+    /// Output directory
+    #[arg(long, default_value = "recordings")]
+    pub dir: PathBuf,
+
+    /// File name prefix
+    #[arg(long, default_value = "bybit")]
+    pub prefix: String,
+
+    /// Write plain .ndjson instead of .ndjson.gz
+    #[arg(long)]
+    pub no_compress: bool,
+
+    /// Start a new file after this many MiB (uncompressed)
+    #[arg(long, default_value_t = 256)]
+    pub rotate_mb: u64,
+
+    /// Start a new file after this many minutes
+    #[arg(long, default_value_t = 60)]
+    pub rotate_minutes: u64,
+}
+
+#[derive(Args, Debug)]
+pub struct ReplayArgs {
+    /// Recording directory or files
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+
+    /// real, max, or an acceleration factor such as 10
+    #[arg(long, default_value = "real", value_parser = parse_speed)]
+    pub speed: ReplaySpeed,
+
+    /// Only replay these topics
+    #[arg(long = "topic")]
+    pub topics: Vec<String>,
+}
+
+pub async fn record(args: RecordArgs, ctx: &Context) -> anyhow::Result<()> {
+    let config = RecorderConfig::new(&args.dir)
+        .with_prefix(args.prefix)
+        .with_compression(!args.no_compress)
+        .with_max_file_bytes(Some(args.rotate_mb * 1024 * 1024))
+        .with_max_file_age(Some(Duration::from_secs(args.rotate_minutes * 60)));
+    let recorder = WsRecorder::start(config)?;
+
+    let mut client = WsClient::public(ctx.env, ctx.category()).await?;
+    recorder.attach(&client);
+    eprintln!(
+        "Recording {} from {} into {} (Ctrl-C to stop)",
+        args.topics.join(", "),
+        client.url(),
+        args.dir.display()
+    );
+    client.subscribe(args.topics).await?;
+
+    let mut frames = 0u64;
+    loop {
+        tokio::select! {
+            msg = client.next() => match msg {
+                Some(WsMessage::Data(_)) => frames += 1,
+                Some(WsMessage::Op(op)) if op.success == Some(false) => {
+                    eprintln!(
+                        "{} failed: {}",
+                        op.op.unwrap_or_default(),
+                        op.ret_msg.unwrap_or_default()
+                    );
+                }
+                Some(WsMessage::Op(_)) => {}
+                None => break,
+            },
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    client.close();
+    let files = recorder.finish()?;
+    eprintln!(
+        "Recorded {} data messages into {} file(s)",
+        frames,
+        files.len()
+    );
+    for file in files {
+        println!("{}", file.display());
+    }
+    Ok(())
+}
+
+pub async fn replay(args: ReplayArgs, ctx: &Context) -> anyhow::Result<()> {
+    let ndjson = match ctx.output() {
+        OutputFormat::Table => false,
+        OutputFormat::Json => true,
+        OutputFormat::Csv => bail!("replay supports table (pretty) or json (NDJSON) output"),
+    };
+
+    let mut files = Vec::new();
+    for path in args.paths {
+        if path.is_dir() {
+            files.extend(bybit_rust_api::ws::recorder::recording_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    let mut source = ReplayWsSource::from_files(files).with_speed(args.speed);
+    if !args.topics.is_empty() {
+        source = source.with_topics(args.topics);
+    }
+
+    let mut stream = source.start()?;
+    while let Some(msg) = stream.next().await {
+        if let WsMessage::Data(ref data) = msg {
+            if ndjson {
+                println!("{}", serde_json::to_string(&msg)?);
+            } else {
+                print_pretty(data);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_speed(s: &str) -> Result<ReplaySpeed, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "real" | "realtime" | "1" | "1x" => Ok(ReplaySpeed::RealTime),
+        "max" => Ok(ReplaySpeed::Max),
+        other => other
+            .trim_end_matches('x')
+            .parse::<f64>()
+            .ok()
+            .filter(|f| *f > 0.0)
+            .map(ReplaySpeed::Accelerated)
+            .ok_or_else(|| format!("invalid speed: {} (use real, max or a factor)", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_speed() {
+        assert_eq!(parse_speed("real"), Ok(ReplaySpeed::RealTime));
+        assert_eq!(parse_speed("MAX"), Ok(ReplaySpeed::Max));
+        assert_eq!(parse_speed("10x"), Ok(ReplaySpeed::Accelerated(10.0)));
+        assert!(parse_speed("0").is_err());
+        assert!(parse_speed("fast").is_err());
+    }
+}
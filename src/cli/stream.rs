@@ -0,0 +1,430 @@
+//! `bybit-cli stream ...` — live WebSocket topics.
+//!
+//! Messages are pretty-printed one per line, or emitted as NDJSON with
+//! `-o json`. `--record FILE` appends every data message to FILE as NDJSON,
+//! whatever the terminal output.
+
+use super::output::OutputFormat;
+use super::Context;
+use anyhow::bail;
+use bybit_rust_api::ws::{generate_auth_params, topics, WsClient, WsMessage, WsResponse};
+use bybit_rust_api::Category;
+use chrono::{TimeZone, Utc};
+use clap::{Args, Subcommand};
+use futures_util::StreamExt;
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::{LineWriter, Write};
+use std::path::PathBuf;
+
+#[derive(Args, Debug)]
+pub struct StreamArgs {
+    #[command(subcommand)]
+    pub topic: StreamTopic,
+
+    /// Append every data message to this file as NDJSON
+    #[arg(long, global = true, value_name = "PATH")]
+    pub record: Option<PathBuf>,
+
+    /// Exit after this many data messages
+    #[arg(long, global = true)]
+    pub count: Option<usize>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StreamTopic {
+    /// Order book snapshots and deltas
+    Orderbook {
+        #[arg(required = true)]
+        symbols: Vec<String>,
+        /// 1, 50, 200 or 1000 (option: 25, 100)
+        #[arg(long, default_value_t = 50)]
+        depth: u16,
+    },
+    /// Public trades
+    Trades {
+        #[arg(required = true)]
+        symbols: Vec<String>,
+    },
+    /// Tickers
+    Tickers {
+        #[arg(required = true)]
+        symbols: Vec<String>,
+    },
+    /// Liquidations (every event, via allLiquidation)
+    Liquidations {
+        #[arg(required = true)]
+        symbols: Vec<String>,
+    },
+    /// Own order updates (authenticated)
+    Orders,
+    /// Own position updates (authenticated)
+    Positions,
+    /// Wallet balance updates (authenticated)
+    Wallet,
+}
+
+impl StreamTopic {
+    fn is_private(&self) -> bool {
+        matches!(
+            self,
+            StreamTopic::Orders | StreamTopic::Positions | StreamTopic::Wallet
+        )
+    }
+
+    fn topics(&self, category: Category) -> Vec<String> {
+        match self {
+            StreamTopic::Orderbook { symbols, depth } => symbols
+                .iter()
+                .map(|s| topics::orderbook(*depth, s))
+                .collect(),
+            StreamTopic::Trades { symbols } => symbols.iter().map(|s| topics::trade(s)).collect(),
+            StreamTopic::Tickers { symbols } => {
+                let ticker = match category {
+                    Category::Spot => topics::ticker::spot,
+                    Category::Linear => topics::ticker::linear,
+                    Category::Inverse => topics::ticker::inverse,
+                    Category::Option => topics::ticker::option,
+                };
+                symbols.iter().map(|s| ticker(s)).collect()
+            }
+            StreamTopic::Liquidations { symbols } => {
+                symbols.iter().map(|s| topics::all_liquidation(s)).collect()
+            }
+            StreamTopic::Orders => vec![topics::order::all()],
+            StreamTopic::Positions => vec![topics::position::all()],
+            StreamTopic::Wallet => vec![topics::wallet::all()],
+        }
+    }
+}
+
+pub async fn run(args: StreamArgs, ctx: &Context) -> anyhow::Result<()> {
+    let ndjson = match ctx.output() {
+        OutputFormat::Table => false,
+        OutputFormat::Json => true,
+        OutputFormat::Csv => bail!("stream supports table (pretty) or json (NDJSON) output"),
+    };
+
+    let mut record = match args.record {
+        Some(ref path) => Some(LineWriter::new(
+            OpenOptions::new().create(true).append(true).open(path)?,
+        )),
+        None => None,
+    };
+
+    let mut client = if args.topic.is_private() {
+        let keys = ctx.key_pair()?;
+        let client = WsClient::private(ctx.env).await?;
+        let (expires, signature) = generate_auth_params(keys.secret());
+        client.authenticate(keys.key(), expires, &signature).await?;
+        client
+    } else {
+        WsClient::public(ctx.env, ctx.category()).await?
+    };
+    let topics = args.topic.topics(ctx.category());
+    eprintln!("Streaming {} from {}", topics.join(", "), client.url());
+    client.subscribe(topics).await?;
+
+    let mut received = 0;
+    loop {
+        let msg = tokio::select! {
+            msg = client.next() => msg,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        let Some(msg) = msg else {
+            bail!("WebSocket stream ended");
+        };
+
+        match msg {
+            WsMessage::Op(ref op) => {
+                if op.success == Some(false) {
+                    eprintln!(
+                        "{} failed: {}",
+                        op.op.as_deref().unwrap_or("request"),
+                        op.ret_msg.as_deref().unwrap_or("")
+                    );
+                    if op.op.as_deref() == Some("auth") {
+                        bail!("WebSocket authentication failed");
+                    }
+                }
+            }
+            WsMessage::Data(ref data) => {
+                let line = serde_json::to_string(&msg)?;
+                if let Some(ref mut file) = record {
+                    writeln!(file, "{}", line)?;
+                }
+                if ndjson {
+                    println!("{}", line);
+                } else {
+                    print_pretty(data);
+                }
+                received += 1;
+                if args.count.is_some_and(|n| received >= n) {
+                    break;
+                }
+            }
+        }
+    }
+
+    client.close();
+    Ok(())
+}
+
+/// Pretty-print one data message to stdout.
+pub fn print_pretty(data: &WsResponse) {
+    for line in pretty(
+        data.topic.as_deref().unwrap_or(""),
+        data.msg_type.as_deref(),
+        data.ts,
+        data.data.as_ref().unwrap_or(&Value::Null),
+    ) {
+        println!("{}", line);
+    }
+}
+
+/// Human-readable lines for one data message.
+fn pretty(topic: &str, msg_type: Option<&str>, ts: Option<i64>, data: &Value) -> Vec<String> {
+    let time = &format_ts(ts);
+    let kind = topic.split('.').next().unwrap_or("");
+    let items: Vec<&Value> = match data {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    };
+
+    match kind {
+        "orderbook" => {
+            let levels = |side: &str| {
+                let levels: Vec<String> = data[side]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .take(5)
+                    .map(|l| format!("{} x {}", s(&l[0]), s(&l[1])))
+                    .collect();
+                levels.join(", ")
+            };
+            vec![format!(
+                "{} {} {:<8} u={} bids [{}] asks [{}]",
+                time,
+                s(&data["s"]),
+                msg_type.unwrap_or(""),
+                data["u"],
+                levels("b"),
+                levels("a"),
+            )]
+        }
+        "publicTrade" => items
+            .iter()
+            .map(|t| {
+                format!(
+                    "{} {} {:<4} {} @ {}",
+                    format_ts(t["T"].as_i64()),
+                    s(&t["s"]),
+                    s(&t["S"]),
+                    s(&t["v"]),
+                    s(&t["p"]),
+                )
+            })
+            .collect(),
+        "liquidation" => items
+            .iter()
+            .map(|l| {
+                format!(
+                    "{} {} {:<4} {} @ {} liquidated",
+                    format_ts(l["updatedTime"].as_i64().or(ts)),
+                    s(&l["symbol"]),
+                    s(&l["side"]),
+                    s(&l["size"]),
+                    s(&l["price"]),
+                )
+            })
+            .collect(),
+        "allLiquidation" => items
+            .iter()
+            .map(|l| {
+                format!(
+                    "{} {} {:<4} {} @ {} liquidated",
+                    format_ts(l["T"].as_i64().or(ts)),
+                    s(&l["s"]),
+                    s(&l["S"]),
+                    s(&l["v"]),
+                    s(&l["p"]),
+                )
+            })
+            .collect(),
+        "order" => items
+            .iter()
+            .map(|o| {
+                format!(
+                    "{} {} {} {} {} {} @ {} {} (filled {}) {}",
+                    time,
+                    s(&o["category"]),
+                    s(&o["symbol"]),
+                    s(&o["side"]),
+                    s(&o["orderType"]),
+                    s(&o["qty"]),
+                    s(&o["price"]),
+                    s(&o["orderStatus"]),
+                    s(&o["cumExecQty"]),
+                    s(&o["orderId"]),
+                )
+            })
+            .collect(),
+        "position" => items
+            .iter()
+            .map(|p| {
+                format!(
+                    "{} {} {} {} size={} entry={} mark={} uPnL={}",
+                    time,
+                    s(&p["category"]),
+                    s(&p["symbol"]),
+                    s(&p["side"]),
+                    s(&p["size"]),
+                    s(&p["entryPrice"]),
+                    s(&p["markPrice"]),
+                    s(&p["unrealisedPnl"]),
+                )
+            })
+            .collect(),
+        "wallet" => items
+            .iter()
+            .flat_map(|account| {
+                account["coin"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(move |c| {
+                        format!(
+                            "{} {} {} balance={} equity={} uPnL={}",
+                            time,
+                            s(&account["accountType"]),
+                            s(&c["coin"]),
+                            s(&c["walletBalance"]),
+                            s(&c["equity"]),
+                            s(&c["unrealisedPnl"]),
+                        )
+                    })
+            })
+            .collect(),
+        // Tickers and anything else: the fields present in the push
+        _ => items
+            .iter()
+            .map(|item| {
+                let fields: Vec<String> = item
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .filter(|(k, _)| k.as_str() != "symbol")
+                    .map(|(k, v)| format!("{}={}", k, s(v)))
+                    .collect();
+                format!(
+                    "{} {} {}",
+                    time,
+                    item["symbol"].as_str().unwrap_or(topic),
+                    fields.join(" ")
+                )
+            })
+            .collect(),
+    }
+}
+
+/// A JSON value without the quotes around strings.
+fn s(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn format_ts(ts: Option<i64>) -> String {
+    ts.and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+        .map(|t| t.format("%H:%M:%S%.3f").to_string())
+        .unwrap_or_else(|| "--:--:--.---".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_pretty_trades() {
+        let data = json!([
+            {"T": 1672304486865_i64, "s": "BTCUSDT", "S": "Buy", "v": "0.001", "p": "16578.50"}
+        ]);
+        let lines = pretty("publicTrade.BTCUSDT", Some("snapshot"), None, &data);
+        assert_eq!(lines, vec!["09:01:26.865 BTCUSDT Buy  0.001 @ 16578.50"]);
+    }
+
+    #[test]
+    fn test_pretty_orderbook_shows_top_levels() {
+        let data = json!({
+            "s": "BTCUSDT",
+            "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+            "a": [["16611.00", "0.029"]],
+            "u": 18521288
+        });
+        let lines = pretty(
+            "orderbook.50.BTCUSDT",
+            Some("snapshot"),
+            Some(1672304484978),
+            &data,
+        );
+        assert_eq!(
+            lines,
+            vec![
+                "09:01:24.978 BTCUSDT snapshot u=18521288 bids [16493.50 x 0.006, 16493.00 x 0.100] asks [16611.00 x 0.029]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_topics_for_private_streams() {
+        assert!(StreamTopic::Wallet.is_private());
+        assert_eq!(StreamTopic::Orders.topics(Category::Linear), vec!["order"]);
+        let book = StreamTopic::Orderbook {
+            symbols: vec!["BTCUSDT".into(), "ETHUSDT".into()],
+            depth: 50,
+        };
+        assert!(!book.is_private());
+        assert_eq!(
+            book.topics(Category::Linear),
+            vec!["orderbook.50.BTCUSDT", "orderbook.50.ETHUSDT"]
+        );
+    }
+
+    #[test]
+    fn test_liquidations_use_all_liquidation() {
+        let liquidations = StreamTopic::Liquidations {
+            symbols: vec!["BTCUSDT".into()],
+        };
+        assert_eq!(
+            liquidations.topics(Category::Linear),
+            vec!["allLiquidation.BTCUSDT"]
+        );
+    }
+
+    #[test]
+    fn test_pretty_all_liquidation() {
+        let data = json!([
+            {"T": 1672304486865_i64, "s": "BTCUSDT", "S": "Sell", "v": "0.003", "p": "16578.50"}
+        ]);
+        let lines = pretty("allLiquidation.BTCUSDT", Some("snapshot"), None, &data);
+        assert_eq!(
+            lines,
+            vec!["09:01:26.865 BTCUSDT Sell 0.003 @ 16578.50 liquidated"]
+        );
+    }
+
+    #[test]
+    fn test_ticker_topics_follow_category() {
+        let tickers = StreamTopic::Tickers {
+            symbols: vec!["BTC-27DEC24-80000-C".into()],
+        };
+        assert_eq!(
+            tickers.topics(Category::Option),
+            vec!["tickers.BTC-27DEC24-80000-C"]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! `bybit-cli user ...` — API keys and sub-accounts.
+
+use super::output;
+use super::Context;
+use bybit_rust_api::rest::UserClient;
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum UserCommand {
+    /// Information about the current API key
+    ApiKey,
+    /// Account member type
+    MemberType,
+    /// Sub-accounts
+    SubMembers {
+        #[arg(long)]
+        page_size: Option<i32>,
+    },
+    /// API keys of a sub-account
+    SubApiKeys {
+        sub_member_id: String,
+        #[arg(long)]
+        limit: Option<i32>,
+    },
+    /// Freeze or unfreeze a sub-account
+    FreezeSub {
+        sub_uid: i64,
+        #[arg(long)]
+        unfreeze: bool,
+    },
+    /// Create (or look up) the demo trading account (mainnet only)
+    CreateDemoAccount,
+}
+
+pub async fn run(cmd: UserCommand, ctx: &Context) -> anyhow::Result<()> {
+    let out = ctx.output();
+    let user = UserClient::new(ctx.private_client()?);
+
+    match cmd {
+        UserCommand::ApiKey => {
+            let r = user.query_api().await?;
+            output::print(&r.result, out)
+        }
+        UserCommand::MemberType => {
+            let r = user.get_member_type().await?;
+            output::print(&r.result["accounts"], out)
+        }
+        UserCommand::SubMembers { page_size } => {
+            let r = user.query_sub_members(page_size, None).await?;
+            output::print(&r.result["subMembers"], out)
+        }
+        UserCommand::SubApiKeys {
+            sub_member_id,
+            limit,
+        } => {
+            let r = user.get_sub_api_keys(&sub_member_id, limit, None).await?;
+            output::print(&r.result["result"], out)
+        }
+        UserCommand::FreezeSub { sub_uid, unfreeze } => {
+            let action = if unfreeze { "unfreeze" } else { "freeze" };
+            ctx.confirm(&format!("{} sub-account {}", action, sub_uid))?;
+            let r = user
+                .freeze_sub_member(sub_uid, if unfreeze { 0 } else { 1 })
+                .await?;
+            output::print(&r.result, out)
+        }
+        UserCommand::CreateDemoAccount => {
+            ctx.confirm("create a demo trading account")?;
+            let r = user.create_demo_account().await?;
+            output::print(&r.result, out)
+        }
+    }
+}
@@ -0,0 +1,839 @@
+//! `bybit-cli watch SYMBOL` — live terminal dashboard.
+//!
+//! Shows a depth ladder, the recent trades tape and the ticker from the
+//! public stream. With credentials it also shows open orders and positions
+//! (REST snapshot, then kept current from the private stream) and lets the
+//! user cancel orders from the keyboard.
+
+use super::Context;
+use anyhow::{bail, Context as _};
+use bybit_rust_api::rest::order::dto::CancelOrderRequest;
+use bybit_rust_api::rest::position::dto::GetPositionInfoParams;
+use bybit_rust_api::rest::{OrderClient, PositionClient};
+use bybit_rust_api::ws::public::OrderBookStream;
+use bybit_rust_api::ws::{generate_auth_params, topics, WsClient, WsMessage, WsResponse};
+use bybit_rust_api::Category;
+use chrono::{TimeZone, Utc};
+use clap::Args;
+use futures_util::StreamExt;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Trades kept on the tape.
+const TAPE_LEN: usize = 100;
+/// Order statuses after which an order leaves the open-orders table.
+const CLOSED_STATUSES: [&str; 5] = [
+    "Filled",
+    "Cancelled",
+    "Rejected",
+    "Deactivated",
+    "PartiallyFilledCanceled",
+];
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    pub symbol: String,
+
+    /// Order book depth to subscribe to: 1, 50, 200 or 1000 (option: 25,
+    /// 100; default 50, or 25 for options)
+    #[arg(long)]
+    pub depth: Option<u16>,
+}
+
+/// The requested depth, or the category's default, checked against what
+/// Bybit serves for the category.
+fn book_depth(depth: Option<u16>, category: Category) -> anyhow::Result<u16> {
+    let valid = OrderBookStream::depths(category);
+    let depth = depth.unwrap_or(if valid.contains(&50) { 50 } else { valid[0] });
+    if !valid.contains(&depth) {
+        bail!(
+            "Invalid --depth {} for {}; expected one of {:?}",
+            depth,
+            category,
+            valid
+        );
+    }
+    Ok(depth)
+}
+
+/// Price key ordered numerically; the original string is kept for display.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Price(f64);
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Local order book rebuilt from `orderbook.*` snapshots and deltas.
+#[derive(Debug, Default)]
+struct Book {
+    bids: BTreeMap<Price, (String, String)>,
+    asks: BTreeMap<Price, (String, String)>,
+}
+
+impl Book {
+    fn apply(&mut self, msg_type: Option<&str>, data: &Value) {
+        if msg_type == Some("snapshot") {
+            self.bids.clear();
+            self.asks.clear();
+        }
+        Self::apply_side(&mut self.bids, &data["b"]);
+        Self::apply_side(&mut self.asks, &data["a"]);
+    }
+
+    fn apply_side(side: &mut BTreeMap<Price, (String, String)>, levels: &Value) {
+        for level in levels.as_array().into_iter().flatten() {
+            let (Some(price), Some(size)) = (level[0].as_str(), level[1].as_str()) else {
+                continue;
+            };
+            let Ok(key) = price.parse::<f64>() else {
+                continue;
+            };
+            if size.parse::<f64>().map_or(true, |s| s == 0.0) {
+                side.remove(&Price(key));
+            } else {
+                side.insert(Price(key), (price.to_string(), size.to_string()));
+            }
+        }
+    }
+
+    /// Best-first bids.
+    fn bids(&self, n: usize) -> impl Iterator<Item = &(String, String)> {
+        self.bids.values().rev().take(n)
+    }
+
+    /// Best-first asks.
+    fn asks(&self, n: usize) -> impl Iterator<Item = &(String, String)> {
+        self.asks.values().take(n)
+    }
+}
+
+/// Everything the dashboard shows, updated from stream messages.
+#[derive(Debug, Default)]
+struct Dashboard {
+    symbol: String,
+    book: Book,
+    trades: VecDeque<Value>,
+    ticker: Map<String, Value>,
+    orders: Vec<Value>,
+    positions: Vec<Value>,
+    private: bool,
+    selected: usize,
+    pending: Option<Action>,
+    status: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Action {
+    Cancel(String),
+    CancelAll,
+}
+
+impl Dashboard {
+    fn new(symbol: &str) -> Self {
+        Dashboard {
+            symbol: symbol.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn apply(&mut self, msg: &WsResponse) {
+        let data = msg.data.as_ref().unwrap_or(&Value::Null);
+        match msg.topic.as_deref().and_then(|t| t.split('.').next()) {
+            Some("orderbook") => self.book.apply(msg.msg_type.as_deref(), data),
+            Some("publicTrade") => self.apply_trades(data),
+            Some("tickers") => self.apply_ticker(msg.msg_type.as_deref(), data),
+            Some("order") => self.apply_orders(data),
+            Some("position") => self.apply_positions(data),
+            _ => {}
+        }
+    }
+
+    fn apply_trades(&mut self, data: &Value) {
+        for trade in data.as_array().into_iter().flatten() {
+            self.trades.push_front(trade.clone());
+        }
+        self.trades.truncate(TAPE_LEN);
+    }
+
+    fn apply_ticker(&mut self, msg_type: Option<&str>, data: &Value) {
+        if msg_type == Some("snapshot") {
+            self.ticker.clear();
+        }
+        if let Some(fields) = data.as_object() {
+            for (k, v) in fields {
+                self.ticker.insert(k.clone(), v.clone());
+            }
+        }
+    }
+
+    fn apply_orders(&mut self, data: &Value) {
+        for order in self.for_symbol(data) {
+            let id = order["orderId"].clone();
+            let closed = order["orderStatus"]
+                .as_str()
+                .is_some_and(|s| CLOSED_STATUSES.contains(&s));
+            match self.orders.iter().position(|o| o["orderId"] == id) {
+                Some(i) if closed => {
+                    self.orders.remove(i);
+                }
+                Some(i) => self.orders[i] = order,
+                None if !closed => self.orders.push(order),
+                None => {}
+            }
+        }
+        self.selected = self.selected.min(self.orders.len().saturating_sub(1));
+    }
+
+    fn apply_positions(&mut self, data: &Value) {
+        for position in self.for_symbol(data) {
+            let idx = position["positionIdx"].clone();
+            let flat = position["size"]
+                .as_str()
+                .is_none_or(|s| s.parse::<f64>().map_or(true, |s| s == 0.0));
+            match self.positions.iter().position(|p| p["positionIdx"] == idx) {
+                Some(i) if flat => {
+                    self.positions.remove(i);
+                }
+                Some(i) => self.positions[i] = position,
+                None if !flat => self.positions.push(position),
+                None => {}
+            }
+        }
+    }
+
+    /// Elements of `data` (an array or `{"list": [...]}`) for the watched symbol.
+    fn for_symbol(&self, data: &Value) -> Vec<Value> {
+        let items = data
+            .as_array()
+            .or_else(|| data["list"].as_array())
+            .cloned()
+            .unwrap_or_default();
+        items
+            .into_iter()
+            .filter(|i| i["symbol"].as_str() == Some(self.symbol.as_str()))
+            .collect()
+    }
+
+    fn selected_order_id(&self) -> Option<String> {
+        self.orders
+            .get(self.selected)
+            .and_then(|o| o["orderId"].as_str())
+            .map(str::to_string)
+    }
+}
+
+pub async fn run(args: WatchArgs, ctx: &Context) -> anyhow::Result<()> {
+    let category = ctx.category();
+    let symbol = args.symbol.to_uppercase();
+    let depth = book_depth(args.depth, category)?;
+    let mut dashboard = Dashboard::new(&symbol);
+
+    let mut public = WsClient::public(ctx.env, category).await?;
+    public
+        .subscribe(vec![
+            topics::orderbook(depth, &symbol),
+            topics::trade(&symbol),
+            topics::ticker::linear(&symbol),
+        ])
+        .await?;
+
+    // Credentials are optional: without them only market data is shown,
+    // but configured credentials that fail to load are an error
+    let mut private = None;
+    let mut order_client = None;
+    if let Some(keys) = ctx.configured_key_pair()? {
+        let client = WsClient::private(ctx.env).await?;
+        let (expires, signature) = generate_auth_params(keys.secret());
+        client.authenticate(keys.key(), expires, &signature).await?;
+        client
+            .subscribe(vec![topics::order::all(), topics::position::all()])
+            .await?;
+
+        let rest = ctx.private_client()?;
+        let orders = OrderClient::new(rest.clone());
+        let open = orders
+            .get_open_orders(
+                category,
+                Some(&symbol),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .context("Failed to load open orders")?;
+        dashboard.apply_orders(&serde_json::to_value(&open.result.list)?);
+        if category != Category::Spot {
+            let positions = PositionClient::new(rest)
+                .get_position_info(GetPositionInfoParams {
+                    category,
+                    symbol: Some(symbol.clone()),
+                    ..Default::default()
+                })
+                .await
+                .context("Failed to load positions")?;
+            dashboard.apply_positions(&serde_json::to_value(&positions.result)?);
+        }
+
+        dashboard.private = true;
+        private = Some(client);
+        order_client = Some(orders);
+    }
+
+    let (key_tx, key_rx) = mpsc::unbounded_channel();
+    std::thread::spawn(move || read_keys(key_tx));
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(
+        &mut terminal,
+        &mut dashboard,
+        &mut public,
+        private.as_mut(),
+        order_client,
+        key_rx,
+        ctx,
+    )
+    .await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    dashboard: &mut Dashboard,
+    public: &mut WsClient,
+    mut private: Option<&mut WsClient>,
+    orders: Option<OrderClient>,
+    mut keys: mpsc::UnboundedReceiver<KeyEvent>,
+    ctx: &Context,
+) -> anyhow::Result<()> {
+    let category = ctx.category();
+    let (status_tx, mut status_rx) = mpsc::unbounded_channel::<String>();
+    let mut redraw = tokio::time::interval(Duration::from_millis(200));
+
+    loop {
+        tokio::select! {
+            msg = public.next() => match msg {
+                Some(WsMessage::Data(data)) => dashboard.apply(&data),
+                Some(WsMessage::Op(_)) => {}
+                None => anyhow::bail!("Public WebSocket stream ended"),
+            },
+            Some(msg) = next_private(&mut private) => match msg {
+                WsMessage::Data(data) => dashboard.apply(&data),
+                WsMessage::Op(op) if op.success == Some(false) => {
+                    dashboard.status = format!(
+                        "{} failed: {}",
+                        op.op.unwrap_or_default(),
+                        op.ret_msg.unwrap_or_default()
+                    );
+                }
+                WsMessage::Op(_) => {}
+            },
+            Some(status) = status_rx.recv() => dashboard.status = status,
+            Some(key) = keys.recv() => {
+                let action = match handle_key(dashboard, key, ctx.global.yes) {
+                    KeyOutcome::Quit => return Ok(()),
+                    KeyOutcome::Run(action) => action,
+                    KeyOutcome::None => None,
+                };
+                if let (Some(action), Some(orders)) = (action, orders.clone()) {
+                    let symbol = dashboard.symbol.clone();
+                    let status_tx = status_tx.clone();
+                    dashboard.status = "Cancelling...".to_string();
+                    tokio::spawn(async move {
+                        let _ = status_tx.send(execute(&orders, category, &symbol, action).await);
+                    });
+                }
+                terminal.draw(|f| draw(f, dashboard))?;
+            }
+            _ = redraw.tick() => {
+                terminal.draw(|f| draw(f, dashboard))?;
+            }
+        }
+    }
+}
+
+async fn next_private(private: &mut Option<&mut WsClient>) -> Option<WsMessage> {
+    match private {
+        Some(client) => client.next().await,
+        None => std::future::pending().await,
+    }
+}
+
+enum KeyOutcome {
+    Quit,
+    Run(Option<Action>),
+    None,
+}
+
+fn handle_key(dashboard: &mut Dashboard, key: KeyEvent, skip_confirm: bool) -> KeyOutcome {
+    if key.kind != KeyEventKind::Press {
+        return KeyOutcome::None;
+    }
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        return KeyOutcome::Quit;
+    }
+
+    if let Some(action) = dashboard.pending.take() {
+        return match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => KeyOutcome::Run(Some(action)),
+            _ => {
+                dashboard.status = "Aborted".to_string();
+                KeyOutcome::None
+            }
+        };
+    }
+
+    let request = match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return KeyOutcome::Quit,
+        KeyCode::Up | KeyCode::Char('k') => {
+            dashboard.selected = dashboard.selected.saturating_sub(1);
+            None
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if dashboard.selected + 1 < dashboard.orders.len() {
+                dashboard.selected += 1;
+            }
+            None
+        }
+        KeyCode::Char('c') if dashboard.private => {
+            dashboard.selected_order_id().map(Action::Cancel)
+        }
+        KeyCode::Char('X') if dashboard.private && !dashboard.orders.is_empty() => {
+            Some(Action::CancelAll)
+        }
+        _ => None,
+    };
+
+    match request {
+        Some(action) if skip_confirm => KeyOutcome::Run(Some(action)),
+        Some(action) => {
+            dashboard.status = match &action {
+                Action::Cancel(id) => format!("Cancel order {}? [y/N]", id),
+                Action::CancelAll => format!("Cancel all {} orders? [y/N]", dashboard.symbol),
+            };
+            dashboard.pending = Some(action);
+            KeyOutcome::None
+        }
+        None => KeyOutcome::None,
+    }
+}
+
+async fn execute(orders: &OrderClient, category: Category, symbol: &str, action: Action) -> String {
+    match action {
+        Action::Cancel(order_id) => {
+            let request = CancelOrderRequest {
+                category,
+                symbol: symbol.to_string(),
+                order_id: Some(order_id.clone()),
+                order_link_id: None,
+                order_filter: None,
+            };
+            match orders.cancel_order(request).await {
+                Ok(_) => format!("Cancelled {}", order_id),
+                Err(e) => format!("Cancel failed: {}", e),
+            }
+        }
+        Action::CancelAll => {
+            match orders
+                .cancel_all_orders(category, Some(symbol), None, None, None)
+                .await
+            {
+                Ok(r) => format!("Cancelled {} orders", r.result.list.len()),
+                Err(e) => format!("Cancel all failed: {}", e),
+            }
+        }
+    }
+}
+
+/// Forward key presses until the receiver is dropped.
+fn read_keys(tx: mpsc::UnboundedSender<KeyEvent>) {
+    while !tx.is_closed() {
+        match event::poll(Duration::from_millis(100)) {
+            Ok(true) => {
+                if let Ok(Event::Key(key)) = event::read() {
+                    if tx.send(key).is_err() {
+                        break;
+                    }
+                }
+            }
+            Ok(false) => {}
+            Err(_) => break,
+        }
+    }
+}
+
+fn draw(f: &mut Frame, d: &Dashboard) {
+    let bottom = if d.private { 10 } else { 0 };
+    let [header, main, account, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(8),
+        Constraint::Length(bottom),
+        Constraint::Length(1),
+    ])
+    .areas(f.area());
+    let [ladder, tape] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(main);
+
+    f.render_widget(Paragraph::new(ticker_line(d)), header);
+    draw_ladder(f, d, ladder);
+    draw_tape(f, d, tape);
+    if d.private {
+        let [orders, positions] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .areas(account);
+        draw_orders(f, d, orders);
+        draw_positions(f, d, positions);
+    }
+
+    let help = if d.private {
+        "q quit  ↑/↓ select  c cancel order  X cancel all"
+    } else {
+        "q quit  (no credentials: market data only)"
+    };
+    let footer_text = if d.status.is_empty() {
+        help.to_string()
+    } else {
+        format!("{}  |  {}", d.status, help)
+    };
+    f.render_widget(
+        Paragraph::new(footer_text).style(Style::default().add_modifier(Modifier::DIM)),
+        footer,
+    );
+}
+
+fn ticker_line(d: &Dashboard) -> Line<'static> {
+    let field = |k: &str| s(d.ticker.get(k).unwrap_or(&Value::Null));
+    let change = d
+        .ticker
+        .get("price24hPcnt")
+        .and_then(Value::as_str)
+        .and_then(|p| p.parse::<f64>().ok())
+        .unwrap_or(0.0);
+    let color = if change >= 0.0 {
+        Color::Green
+    } else {
+        Color::Red
+    };
+    Line::from(vec![
+        Span::styled(
+            format!("{} ", d.symbol),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(field("lastPrice"), Style::default().fg(color)),
+        Span::raw(format!(
+            "  {:+.2}%  high {}  low {}  vol {}  mark {}  funding {}",
+            change * 100.0,
+            field("highPrice24h"),
+            field("lowPrice24h"),
+            field("volume24h"),
+            field("markPrice"),
+            field("fundingRate"),
+        )),
+    ])
+}
+
+fn draw_ladder(f: &mut Frame, d: &Dashboard, area: Rect) {
+    let per_side = (area.height.saturating_sub(3) / 2) as usize;
+    let asks: Vec<_> = d.book.asks(per_side).collect();
+    let rows = asks
+        .into_iter()
+        .rev()
+        .map(|(p, q)| level_row(p, q, Color::Red))
+        .chain(
+            d.book
+                .bids(per_side)
+                .map(|(p, q)| level_row(p, q, Color::Green)),
+        );
+    let table = Table::new(
+        rows,
+        [Constraint::Percentage(50), Constraint::Percentage(50)],
+    )
+    .header(Row::new(["Price", "Size"]).style(Style::default().add_modifier(Modifier::BOLD)))
+    .block(Block::default().borders(Borders::ALL).title("Book"));
+    f.render_widget(table, area);
+}
+
+fn level_row<'a>(price: &'a str, size: &'a str, color: Color) -> Row<'a> {
+    Row::new([price, size]).style(Style::default().fg(color))
+}
+
+fn draw_tape(f: &mut Frame, d: &Dashboard, area: Rect) {
+    let rows = d.trades.iter().map(|t| {
+        let color = if t["S"] == "Buy" {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        Row::new([
+            format_ts(t["T"].as_i64()),
+            s(&t["S"]),
+            s(&t["p"]),
+            s(&t["v"]),
+        ])
+        .style(Style::default().fg(color))
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(5),
+            Constraint::Min(10),
+            Constraint::Min(8),
+        ],
+    )
+    .header(
+        Row::new(["Time", "Side", "Price", "Qty"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(Block::default().borders(Borders::ALL).title("Trades"));
+    f.render_widget(table, area);
+}
+
+fn draw_orders(f: &mut Frame, d: &Dashboard, area: Rect) {
+    let rows = d.orders.iter().map(|o| {
+        Row::new([
+            s(&o["side"]),
+            s(&o["orderType"]),
+            s(&o["price"]),
+            s(&o["qty"]),
+            s(&o["cumExecQty"]),
+            s(&o["orderStatus"]),
+            s(&o["orderId"]),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(5),
+            Constraint::Length(7),
+            Constraint::Min(10),
+            Constraint::Min(8),
+            Constraint::Min(8),
+            Constraint::Length(16),
+            Constraint::Min(12),
+        ],
+    )
+    .header(
+        Row::new([
+            "Side", "Type", "Price", "Qty", "Filled", "Status", "Order ID",
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Open orders ({})", d.orders.len())),
+    );
+    let mut state =
+        TableState::default().with_selected((!d.orders.is_empty()).then_some(d.selected));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_positions(f: &mut Frame, d: &Dashboard, area: Rect) {
+    let rows = d.positions.iter().map(|p| {
+        Row::new([
+            s(&p["side"]),
+            s(&p["size"]),
+            // REST reports avgPrice, the private stream entryPrice
+            s(if p["avgPrice"].is_null() {
+                &p["entryPrice"]
+            } else {
+                &p["avgPrice"]
+            }),
+            s(&p["markPrice"]),
+            s(&p["unrealisedPnl"]),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(5),
+            Constraint::Min(8),
+            Constraint::Min(10),
+            Constraint::Min(10),
+            Constraint::Min(10),
+        ],
+    )
+    .header(
+        Row::new(["Side", "Size", "Entry", "Mark", "uPnL"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(Block::default().borders(Borders::ALL).title("Positions"));
+    f.render_widget(table, area);
+}
+
+/// A JSON value without the quotes around strings.
+fn s(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn format_ts(ts: Option<i64>) -> String {
+    ts.and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+        .map(|t| t.format("%H:%M:%S%.3f").to_string())
+        .unwrap_or_else(|| "--:--:--.---".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_book_depth_per_category() {
+        assert_eq!(book_depth(None, Category::Linear).unwrap(), 50);
+        assert_eq!(book_depth(None, Category::Option).unwrap(), 25);
+        assert_eq!(book_depth(Some(100), Category::Option).unwrap(), 100);
+        assert!(book_depth(Some(50), Category::Option).is_err());
+        assert!(book_depth(Some(25), Category::Spot).is_err());
+    }
+
+    fn push(topic: &str, msg_type: &str, data: Value) -> WsResponse {
+        WsResponse {
+            topic: Some(topic.to_string()),
+            msg_type: Some(msg_type.to_string()),
+            ts: Some(1672304484978),
+            data: Some(data),
+        }
+    }
+
+    #[test]
+    fn test_book_snapshot_and_delta() {
+        let mut d = Dashboard::new("BTCUSDT");
+        d.apply(&push(
+            "orderbook.50.BTCUSDT",
+            "snapshot",
+            json!({"s": "BTCUSDT", "b": [["99.5", "1"], ["100", "2"]], "a": [["101", "3"], ["100.5", "4"]], "u": 1}),
+        ));
+        assert_eq!(d.book.bids(1).next().unwrap().0, "100");
+        assert_eq!(d.book.asks(1).next().unwrap().0, "100.5");
+
+        d.apply(&push(
+            "orderbook.50.BTCUSDT",
+            "delta",
+            json!({"s": "BTCUSDT", "b": [["100", "0"], ["99.9", "5"]], "a": [], "u": 2}),
+        ));
+        let bids: Vec<_> = d.book.bids(5).map(|l| l.0.as_str()).collect();
+        assert_eq!(bids, vec!["99.9", "99.5"]);
+    }
+
+    #[test]
+    fn test_orders_upsert_and_remove() {
+        let mut d = Dashboard::new("BTCUSDT");
+        let order = |id: &str, status: &str| json!([{"symbol": "BTCUSDT", "orderId": id, "orderStatus": status, "qty": "1"}]);
+        d.apply(&push("order", "snapshot", order("a", "New")));
+        d.apply(&push("order", "snapshot", order("b", "New")));
+        d.apply(&push(
+            "order",
+            "snapshot",
+            json!([{"symbol": "ETHUSDT", "orderId": "c", "orderStatus": "New"}]),
+        ));
+        assert_eq!(d.orders.len(), 2);
+
+        d.selected = 1;
+        d.apply(&push("order", "snapshot", order("b", "Filled")));
+        assert_eq!(d.orders.len(), 1);
+        assert_eq!(d.selected, 0);
+        assert_eq!(d.selected_order_id().as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn test_positions_drop_when_flat() {
+        let mut d = Dashboard::new("BTCUSDT");
+        d.apply_positions(
+            &json!({"list": [{"symbol": "BTCUSDT", "positionIdx": 0, "size": "0.5"}]}),
+        );
+        assert_eq!(d.positions.len(), 1);
+        d.apply(&push(
+            "position",
+            "snapshot",
+            json!([{"symbol": "BTCUSDT", "positionIdx": 0, "size": "0"}]),
+        ));
+        assert!(d.positions.is_empty());
+    }
+
+    #[test]
+    fn test_draw_renders_panels() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut d = Dashboard::new("BTCUSDT");
+        d.private = true;
+        d.apply(&push(
+            "orderbook.50.BTCUSDT",
+            "snapshot",
+            json!({"s": "BTCUSDT", "b": [["100", "2"]], "a": [["100.5", "4"]], "u": 1}),
+        ));
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|f| draw(f, &d)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        for text in [
+            "BTCUSDT",
+            "Book",
+            "Trades",
+            "Open orders (0)",
+            "Positions",
+            "100.5",
+        ] {
+            assert!(screen.contains(text), "missing {}", text);
+        }
+    }
+
+    #[test]
+    fn test_cancel_requires_confirmation() {
+        let mut d = Dashboard::new("BTCUSDT");
+        d.private = true;
+        d.apply_orders(&json!([{"symbol": "BTCUSDT", "orderId": "a", "orderStatus": "New"}]));
+
+        let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        assert!(matches!(
+            handle_key(&mut d, press('c'), false),
+            KeyOutcome::None
+        ));
+        assert_eq!(d.pending, Some(Action::Cancel("a".to_string())));
+        assert!(matches!(
+            handle_key(&mut d, press('y'), false),
+            KeyOutcome::Run(Some(Action::Cancel(_)))
+        ));
+
+        assert!(matches!(
+            handle_key(&mut d, press('c'), true),
+            KeyOutcome::Run(Some(Action::Cancel(_)))
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 //! Bybit API endpoint constants.
 //!
-//! Includes mainnet, testnet, and regional endpoints for both
+//! Includes mainnet, testnet, demo and regional endpoints for both
 //! REST and WebSocket connections.
 
 pub const NAME: &str = "bybit.api.rust";
@@ -14,6 +14,8 @@ pub const REST_MAINNET: &str = "https://api.bybit.com";
 pub const REST_MAINNET_BACKUP: &str = "https://api.bytick.com";
 /// Testnet REST API
 pub const REST_TESTNET: &str = "https://api-testnet.bybit.com";
+/// Demo trading REST API
+pub const REST_DEMO: &str = "https://api-demo.bybit.com";
 
 // Regional REST endpoints (V5 requirement)
 pub const REST_TR: &str = "https://api.bybit.tr";
@@ -22,22 +24,32 @@ pub const REST_GE: &str = "https://api.bybitgeorgia.ge";
 pub const REST_AE: &str = "https://api.bybit.ae";
 pub const REST_NL: &str = "https://api.bybit.nl";
 
+// ── WebSocket Hosts ────────────────────────────────────────────
+
+pub const WS_MAINNET: &str = "wss://stream.bybit.com";
+pub const WS_TESTNET: &str = "wss://stream-testnet.bybit.com";
+/// Demo trading WebSocket host (private streams only)
+pub const WS_DEMO: &str = "wss://stream-demo.bybit.com";
+
 // ── WebSocket Public Endpoints ─────────────────────────────────
 
 pub const WS_SPOT_MAINNET: &str = "wss://stream.bybit.com/v5/public/spot";
 pub const WS_LINEAR_MAINNET: &str = "wss://stream.bybit.com/v5/public/linear";
 pub const WS_INVERSE_MAINNET: &str = "wss://stream.bybit.com/v5/public/inverse";
 pub const WS_OPTION_MAINNET: &str = "wss://stream.bybit.com/v5/public/option";
+pub const WS_SPREAD_MAINNET: &str = "wss://stream.bybit.com/v5/public/spread";
 
 pub const WS_SPOT_TESTNET: &str = "wss://stream-testnet.bybit.com/v5/public/spot";
 pub const WS_LINEAR_TESTNET: &str = "wss://stream-testnet.bybit.com/v5/public/linear";
 pub const WS_INVERSE_TESTNET: &str = "wss://stream-testnet.bybit.com/v5/public/inverse";
 pub const WS_OPTION_TESTNET: &str = "wss://stream-testnet.bybit.com/v5/public/option";
+pub const WS_SPREAD_TESTNET: &str = "wss://stream-testnet.bybit.com/v5/public/spread";
 
 // ── WebSocket Private Endpoints ────────────────────────────────
 
 pub const WS_PRIVATE_MAINNET: &str = "wss://stream.bybit.com/v5/private";
 pub const WS_PRIVATE_TESTNET: &str = "wss://stream-testnet.bybit.com/v5/private";
+pub const WS_PRIVATE_DEMO: &str = "wss://stream-demo.bybit.com/v5/private";
 
 // ── WebSocket Trade Endpoints ──────────────────────────────────
 
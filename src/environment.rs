@@ -0,0 +1,296 @@
+//! Environment / region selection.
+//!
+//! [`BybitEnv`] pairs every REST host with its WebSocket counterparts so
+//! callers no longer have to match `consts` strings by hand.
+//!
+//! ```ignore
+//! use bybit_rust_api::{BybitEnv, Category};
+//!
+//! let env: BybitEnv = "testnet".parse()?;
+//! assert_eq!(env.rest_url(), "https://api-testnet.bybit.com");
+//! assert_eq!(
+//!     env.public_ws_url(Category::Linear),
+//!     "wss://stream-testnet.bybit.com/v5/public/linear"
+//! );
+//! ```
+
+use crate::consts;
+use crate::rest::enums::category::Category;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+
+/// Hosts accepted by clients running in demo-only mode.
+const DEMO_HOSTS: [&str; 2] = ["api-demo.bybit.com", "stream-demo.bybit.com"];
+
+/// Regional Bybit entities with dedicated domains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Bybit Turkey
+    Tr,
+    /// Bybit Kazakhstan
+    Kz,
+    /// Bybit Georgia
+    Ge,
+    /// Bybit UAE
+    Ae,
+    /// Bybit EEA (Netherlands)
+    Nl,
+}
+
+/// A Bybit deployment: determines REST and WebSocket endpoint URLs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BybitEnv {
+    #[default]
+    Mainnet,
+    /// Backup REST domain (`api.bytick.com`); WebSocket stays on mainnet
+    MainnetBackup,
+    Testnet,
+    /// Demo trading: mainnet market data with simulated balances
+    Demo,
+    Region(Region),
+}
+
+impl Region {
+    fn rest_url(&self) -> &'static str {
+        match self {
+            Region::Tr => consts::REST_TR,
+            Region::Kz => consts::REST_KZ,
+            Region::Ge => consts::REST_GE,
+            Region::Ae => consts::REST_AE,
+            Region::Nl => consts::REST_NL,
+        }
+    }
+
+    fn stream_host(&self) -> &'static str {
+        match self {
+            Region::Tr => "wss://stream.bybit.tr",
+            Region::Kz => "wss://stream.bybit.kz",
+            Region::Ge => "wss://stream.bybitgeorgia.ge",
+            Region::Ae => "wss://stream.bybit.ae",
+            Region::Nl => "wss://stream.bybit.nl",
+        }
+    }
+}
+
+impl BybitEnv {
+    /// REST API base URL.
+    pub fn rest_url(&self) -> &'static str {
+        match self {
+            BybitEnv::Mainnet => consts::REST_MAINNET,
+            BybitEnv::MainnetBackup => consts::REST_MAINNET_BACKUP,
+            BybitEnv::Testnet => consts::REST_TESTNET,
+            BybitEnv::Demo => consts::REST_DEMO,
+            BybitEnv::Region(region) => region.rest_url(),
+        }
+    }
+
+    /// Host serving public market data streams.
+    ///
+    /// Demo trading has no public stream of its own and uses mainnet data.
+    fn public_stream_host(&self) -> &'static str {
+        match self {
+            BybitEnv::Mainnet | BybitEnv::MainnetBackup | BybitEnv::Demo => consts::WS_MAINNET,
+            BybitEnv::Testnet => consts::WS_TESTNET,
+            BybitEnv::Region(region) => region.stream_host(),
+        }
+    }
+
+    /// Host serving private (and order-entry) streams.
+    fn private_stream_host(&self) -> &'static str {
+        match self {
+            BybitEnv::Demo => consts::WS_DEMO,
+            _ => self.public_stream_host(),
+        }
+    }
+
+    /// Public WebSocket URL for a product category.
+    pub fn public_ws_url(&self, category: Category) -> String {
+        format!("{}/v5/public/{}", self.public_stream_host(), category)
+    }
+
+    /// Public WebSocket URL for spread trading.
+    pub fn spread_ws_url(&self) -> String {
+        format!("{}/v5/public/spread", self.public_stream_host())
+    }
+
+    /// Public WebSocket URL of the `system.status` maintenance stream.
+    pub fn system_status_ws_url(&self) -> String {
+        format!("{}/v5/public/misc/status", self.public_stream_host())
+    }
+
+    /// Private WebSocket URL (position, order, execution, wallet, ...).
+    pub fn private_ws_url(&self) -> String {
+        format!("{}/v5/private", self.private_stream_host())
+    }
+
+    /// Order-entry WebSocket URL.
+    ///
+    /// Returns `None` for demo trading, which does not offer WS order entry.
+    pub fn trade_ws_url(&self) -> Option<String> {
+        match self {
+            BybitEnv::Demo => None,
+            _ => Some(format!("{}/v5/trade", self.private_stream_host())),
+        }
+    }
+
+    pub fn is_testnet(&self) -> bool {
+        matches!(self, BybitEnv::Testnet)
+    }
+
+    pub fn is_demo(&self) -> bool {
+        matches!(self, BybitEnv::Demo)
+    }
+
+    /// Whether `url` points at a demo trading host (REST or WebSocket).
+    pub fn is_demo_url(url: &str) -> bool {
+        url::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| DEMO_HOSTS.contains(&h)))
+            .unwrap_or(false)
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Region::Tr => write!(f, "tr"),
+            Region::Kz => write!(f, "kz"),
+            Region::Ge => write!(f, "ge"),
+            Region::Ae => write!(f, "ae"),
+            Region::Nl => write!(f, "nl"),
+        }
+    }
+}
+
+impl Display for BybitEnv {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            BybitEnv::Mainnet => write!(f, "mainnet"),
+            BybitEnv::MainnetBackup => write!(f, "mainnet-backup"),
+            BybitEnv::Testnet => write!(f, "testnet"),
+            BybitEnv::Demo => write!(f, "demo"),
+            BybitEnv::Region(region) => write!(f, "{}", region),
+        }
+    }
+}
+
+impl FromStr for BybitEnv {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" | "main" | "prod" => Ok(BybitEnv::Mainnet),
+            "mainnet-backup" | "backup" | "bytick" => Ok(BybitEnv::MainnetBackup),
+            "testnet" | "test" => Ok(BybitEnv::Testnet),
+            "demo" => Ok(BybitEnv::Demo),
+            "tr" => Ok(BybitEnv::Region(Region::Tr)),
+            "kz" => Ok(BybitEnv::Region(Region::Kz)),
+            "ge" => Ok(BybitEnv::Region(Region::Ge)),
+            "ae" => Ok(BybitEnv::Region(Region::Ae)),
+            "nl" | "eu" => Ok(BybitEnv::Region(Region::Nl)),
+            other => Err(format!("Unknown Bybit environment: {}", other)),
+        }
+    }
+}
+
+impl Serialize for BybitEnv {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BybitEnv {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mainnet_urls() {
+        let env = BybitEnv::Mainnet;
+        assert_eq!(env.rest_url(), consts::REST_MAINNET);
+        assert_eq!(env.public_ws_url(Category::Spot), consts::WS_SPOT_MAINNET);
+        assert_eq!(
+            env.public_ws_url(Category::Option),
+            consts::WS_OPTION_MAINNET
+        );
+        assert_eq!(env.spread_ws_url(), consts::WS_SPREAD_MAINNET);
+        assert_eq!(
+            env.system_status_ws_url(),
+            "wss://stream.bybit.com/v5/public/misc/status"
+        );
+        assert_eq!(env.private_ws_url(), consts::WS_PRIVATE_MAINNET);
+        assert_eq!(
+            env.trade_ws_url().as_deref(),
+            Some(consts::WS_TRADE_MAINNET)
+        );
+    }
+
+    #[test]
+    fn test_testnet_urls() {
+        let env = BybitEnv::Testnet;
+        assert_eq!(env.rest_url(), consts::REST_TESTNET);
+        assert_eq!(
+            env.public_ws_url(Category::Linear),
+            consts::WS_LINEAR_TESTNET
+        );
+        assert_eq!(env.spread_ws_url(), consts::WS_SPREAD_TESTNET);
+        assert_eq!(env.private_ws_url(), consts::WS_PRIVATE_TESTNET);
+        assert_eq!(
+            env.trade_ws_url().as_deref(),
+            Some(consts::WS_TRADE_TESTNET)
+        );
+    }
+
+    #[test]
+    fn test_demo_urls() {
+        let env = BybitEnv::Demo;
+        assert_eq!(env.rest_url(), "https://api-demo.bybit.com");
+        assert_eq!(
+            env.public_ws_url(Category::Linear),
+            consts::WS_LINEAR_MAINNET
+        );
+        assert_eq!(
+            env.private_ws_url(),
+            "wss://stream-demo.bybit.com/v5/private"
+        );
+        assert!(env.trade_ws_url().is_none());
+        assert!(env.is_demo());
+        assert!(BybitEnv::is_demo_url(env.rest_url()));
+        assert!(BybitEnv::is_demo_url(&env.private_ws_url()));
+        assert!(!BybitEnv::is_demo_url(consts::REST_MAINNET));
+        assert!(!BybitEnv::is_demo_url("https://api-demo.bybit.com.evil.io"));
+    }
+
+    #[test]
+    fn test_region_urls() {
+        let env = BybitEnv::Region(Region::Tr);
+        assert_eq!(env.rest_url(), consts::REST_TR);
+        assert_eq!(
+            env.public_ws_url(Category::Spot),
+            "wss://stream.bybit.tr/v5/public/spot"
+        );
+        assert_eq!(env.private_ws_url(), "wss://stream.bybit.tr/v5/private");
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for env in [
+            BybitEnv::Mainnet,
+            BybitEnv::MainnetBackup,
+            BybitEnv::Testnet,
+            BybitEnv::Demo,
+            BybitEnv::Region(Region::Ge),
+            BybitEnv::Region(Region::Nl),
+        ] {
+            assert_eq!(env.to_string().parse::<BybitEnv>().unwrap(), env);
+        }
+        assert!("moon".parse::<BybitEnv>().is_err());
+    }
+}
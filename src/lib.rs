@@ -2,7 +2,7 @@
 //!
 //! A comprehensive, type-safe Rust SDK for the [Bybit V5 API](https://bybit-exchange.github.io/docs/v5/guide).
 //!
-//! Covers **all 129 REST endpoints** across 13 modules plus
+//! Covers the V5 REST API across 18 modules (see `ENDPOINTS.md`) plus
 //! **full WebSocket support** with 17 streaming channels.
 //!
 //! ## Features
@@ -11,13 +11,18 @@
 // this is an intentional design choice for ergonomic usage.
 #![allow(clippy::too_many_arguments)]
 //!
-//! - **REST API**: 129/129 endpoints (Market, Trade, Account, Position, Asset, User, Broker, etc.)
+//! - **REST API**: Market, Trade, Account, Position, Asset, User, Broker, etc.
 //! - **WebSocket Public**: Orderbook (snapshot/delta), Trade, Ticker, Kline, Liquidation
 //! - **WebSocket Private**: Position, Execution, Order, Wallet, Greeks, DCP
 //! - **WebSocket Trade**: Place, amend, cancel orders via WebSocket
 //! - **Auto-reconnect**: Exponential backoff with automatic re-subscription
+//! - **Record / replay**: Raw WS frames to compressed, rotated NDJSON; offline replay as a `WsMessage` stream
+//! - **Paper trading**: `TradingApi` implemented by live REST clients and a simulated `PaperTrader`
 //! - **Rate limiting**: Token-bucket limiter for REST and WS
+//! - **Environments**: Mainnet, backup, testnet, demo trading and regional domains via `BybitEnv`
+//! - **Transport config**: Timeouts, HTTP/SOCKS5 proxy, custom TLS roots, socket options
 //! - **Authentication**: HMAC-SHA256 signing (REST + WS)
+//! - **Credential profiles**: TOML/JSON profile files, env-var interpolation, passphrase-encrypted secrets
 //! - **Type-safe**: Full serde types for all request/response structures
 //! - **Error handling**: Structured `BybitError` with all API error codes
 //! - **Async**: Built on tokio, implements `futures::Stream` for WS
@@ -113,6 +118,7 @@
 //! | `BYBIT_API_SECRET` | API secret for signing |
 //! | `BYBIT_TESTNET_API_KEY` | Testnet API key |
 //! | `BYBIT_TESTNET_API_SECRET` | Testnet API secret |
+//! | `BYBIT_ENV` | CLI environment (`mainnet`, `testnet`, `demo`, `tr`, ...) |
 //!
 //! ## Module Overview
 //!
@@ -122,25 +128,46 @@
 //! | `ws` | WebSocket streams (public, private, trade) |
 //! | `rest::enums` | Type-safe enums (Category, Side, Interval, OrderType, etc.) |
 //! | `rest::errors` | Error types (`BybitError`, `ErrorCodes`) |
+//! | `config` | Transport settings (timeouts, proxy, TLS roots, headers) |
 //! | `consts` | API endpoint URLs |
+//! | `environment` | `BybitEnv` / `Region` endpoint selection |
+//! | `profiles` | Multi-profile credential store (TOML/JSON, encrypted secrets) |
+//! | `testing` | `MockExchange`: local REST + WebSocket exchange for integration tests (`testing` feature) |
+//! | `trading` | `TradingApi` with live (`LiveTrader`) and simulated (`PaperTrader`) backends |
 //! | `utils` | HMAC signing, rate limiter |
 //!
 //! ## Crate Features
 //!
-//! No feature flags — everything is included by default.
-//! TLS is handled via `rustls` (no OpenSSL dependency).
+//! | Feature | Default | Description |
+//! |---|---|---|
+//! | `testing` | off | Enables the `testing` module (`MockExchange`) and its `httparse` dependency |
+//!
+//! Everything else is always included. TLS is handled via `rustls` (no
+//! OpenSSL dependency).
 
+pub mod config;
 pub mod consts;
+pub mod environment;
 pub mod handlers;
+pub mod maintenance;
+pub mod profiles;
 pub mod rest;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod trading;
 pub mod utils;
 pub mod ws;
 
 // Re-export commonly used types at the top level
+pub use config::ClientConfig;
+pub use environment::{BybitEnv, Region};
+pub use maintenance::MaintenanceGuard;
+pub use profiles::{Profile, ProfileStore};
 pub use rest::{
     AccountClient, AnnouncementsClient, ApiKeyPair, AssetClient, BrokerClient, CryptoLoanClient,
-    InstitutionalLoanClient, MarketClient, OrderClient, PositionClient, PreUpgradeClient,
-    RestClient, ServerResponse, SpotLeverageTokenClient, SpotMarginTradeClient, UserClient,
+    CryptoLoanNewClient, EarnClient, InstitutionalLoanClient, MarketClient, OrderClient, P2pClient,
+    PositionClient, PreUpgradeClient, RestClient, RfqClient, ServerResponse,
+    SpotLeverageTokenClient, SpotMarginTradeClient, SpreadClient, UserClient,
 };
 
 // Re-export common enums directly
@@ -155,4 +182,6 @@ pub mod dto {
     pub use crate::rest::account::dto::*;
     pub use crate::rest::market::dto::*;
     pub use crate::rest::order::dto::*;
+    pub use crate::rest::position::dto::*;
+    pub use crate::rest::user::dto::CreateDemoAccountResult;
 }
@@ -0,0 +1,309 @@
+//! Awareness of announced Bybit maintenance windows.
+//!
+//! A [`MaintenanceGuard`] holds the windows published by
+//! `GET /v5/system/status` and the `system.status` WebSocket topic. It is
+//! cheap to clone and every clone shares the same state, so one guard can be
+//! kept fresh in the background and consulted from anywhere.
+//!
+//! Only windows announced for the guard's environment (mainnet unless set
+//! with [`MaintenanceGuard::with_env`]) count, and callers that know the
+//! product category they are about to touch only see windows for it:
+//!
+//! - [`OrderClient::with_maintenance_guard`](crate::rest::OrderClient::with_maintenance_guard)
+//!   refuses to place or amend orders while a window covering their
+//!   category is active.
+//! - [`ClientConfig::with_maintenance_guard`](crate::ClientConfig::with_maintenance_guard)
+//!   makes the WebSocket client keep reconnecting through a window instead of
+//!   giving up, feeds it any `system.status` pushes it receives, and counts
+//!   the reconnects that happened during a window.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bybit_rust_api::{AnnouncementsClient, ApiKeyPair, BybitEnv, MaintenanceGuard};
+//! use bybit_rust_api::{OrderClient, RestClient};
+//! use std::time::Duration;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let rest = RestClient::for_env(ApiKeyPair::from_env()?, BybitEnv::Mainnet);
+//! let guard = MaintenanceGuard::new()
+//!     .with_env(BybitEnv::Mainnet)
+//!     .with_lead_time(Duration::from_secs(60));
+//! guard.refresh(&AnnouncementsClient::new(rest.clone())).await?;
+//!
+//! let orders = OrderClient::new(rest).with_maintenance_guard(guard.clone());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::environment::BybitEnv;
+use crate::rest::announcements::dto::SystemMaintenance;
+use crate::rest::enums::category::Category;
+use crate::rest::errors::{BybitError, BybitResult};
+use crate::rest::AnnouncementsClient;
+use crate::utils;
+use crate::ws::messages::WsMessage;
+use crate::ws::public::SystemStatusStream;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Shared view of announced maintenance windows.
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceGuard {
+    /// Pending windows by id
+    windows: Arc<RwLock<HashMap<String, SystemMaintenance>>>,
+    /// WebSocket reconnects observed while a window was active
+    reconnects: Arc<AtomicU64>,
+    /// How long before `begin` a window already counts as active (ms)
+    lead_ms: i64,
+    /// Environment the guarded clients talk to
+    env: BybitEnv,
+    /// Account UID, matched against `uidSuffix` when known
+    uid: Option<u64>,
+}
+
+impl MaintenanceGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat windows as active `lead` before their announced start.
+    pub fn with_lead_time(mut self, lead: Duration) -> Self {
+        self.lead_ms = lead.as_millis() as i64;
+        self
+    }
+
+    /// Only honour windows announced for `env` (default: mainnet).
+    pub fn with_env(mut self, env: BybitEnv) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Ignore windows limited to other UID suffixes.
+    ///
+    /// Without a UID every window counts, whatever its `uidSuffix`.
+    pub fn with_uid(mut self, uid: u64) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    /// Merge announced windows, dropping those completed or canceled.
+    pub fn update(&self, list: impl IntoIterator<Item = SystemMaintenance>) {
+        let mut windows = match self.windows.write() {
+            Ok(windows) => windows,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        for window in list {
+            if window.state.is_pending() {
+                windows.insert(window.id.clone(), window);
+            } else {
+                windows.remove(&window.id);
+            }
+        }
+    }
+
+    /// Replace the known windows with the current `GET /v5/system/status` list.
+    pub async fn refresh(&self, client: &AnnouncementsClient) -> BybitResult<()> {
+        let list = client.get_system_status(None, None).await?.result.list;
+        let mut windows = match self.windows.write() {
+            Ok(windows) => windows,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        windows.clear();
+        windows.extend(
+            list.into_iter()
+                .filter(|w| w.state.is_pending())
+                .map(|w| (w.id.clone(), w)),
+        );
+        Ok(())
+    }
+
+    /// Apply a `system.status` push; other messages are ignored.
+    ///
+    /// Returns true if the message was a system status update.
+    pub fn apply_message(&self, msg: &WsMessage) -> bool {
+        let data = match msg {
+            WsMessage::Data(r)
+                if r.topic
+                    .as_deref()
+                    .is_some_and(SystemStatusStream::matches_topic) =>
+            {
+                r.data.as_ref()
+            }
+            _ => return false,
+        };
+        match data.map(SystemStatusStream::parse) {
+            Some(Ok(list)) => self.update(list),
+            Some(Err(e)) => log::warn!("Failed to parse system.status push: {}", e),
+            None => {}
+        }
+        true
+    }
+
+    /// The window covering `now_ms` that applies to this guard's
+    /// environment and `category` (any category when `None`), if any.
+    pub fn active_at(&self, now_ms: i64, category: Option<Category>) -> Option<SystemMaintenance> {
+        let windows = match self.windows.read() {
+            Ok(windows) => windows,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        windows
+            .values()
+            .filter(|w| w.covers(now_ms, self.lead_ms))
+            .filter(|w| self.applies(w, category))
+            .max_by_key(|w| w.end_ms())
+            .cloned()
+    }
+
+    /// The window active right now for `category`, if any.
+    pub fn active(&self, category: Option<Category>) -> Option<SystemMaintenance> {
+        self.active_at(utils::millis() as i64, category)
+    }
+
+    pub fn is_active(&self, category: Option<Category>) -> bool {
+        self.active(category).is_some()
+    }
+
+    fn applies(&self, window: &SystemMaintenance, category: Option<Category>) -> bool {
+        window.applies_to_env(self.env)
+            && category.is_none_or(|c| window.affects_category(c))
+            && self.uid.is_none_or(|uid| window.affects_uid(uid))
+    }
+
+    /// All pending windows, soonest first.
+    pub fn upcoming(&self) -> Vec<SystemMaintenance> {
+        let windows = match self.windows.read() {
+            Ok(windows) => windows,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut list: Vec<_> = windows.values().cloned().collect();
+        list.sort_by_key(|w| w.begin_ms());
+        list
+    }
+
+    /// Fail with `BybitError::Rejected` while a window covering `category`
+    /// is active.
+    pub fn check(&self, category: Option<Category>) -> BybitResult<()> {
+        match self.active(category) {
+            Some(window) => Err(BybitError::Rejected {
+                reason: format!(
+                    "Maintenance guard: '{}' ({}) is active until {}",
+                    window.title, window.id, window.end
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Record a WebSocket reconnect to a stream of `category` (`None` for
+    /// streams serving every category); counted only while a window
+    /// covering it is active.
+    ///
+    /// Returns true if the reconnect fell inside a window.
+    pub fn record_reconnect(&self, category: Option<Category>) -> bool {
+        let active = self.is_active(category);
+        if active {
+            self.reconnects.fetch_add(1, Ordering::Relaxed);
+        }
+        active
+    }
+
+    /// WebSocket reconnects seen during maintenance windows.
+    pub fn reconnects_during_maintenance(&self) -> u64 {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::enums::maintenance_state::MaintenanceState;
+
+    fn window(id: &str, state: MaintenanceState, begin: i64, end: i64) -> SystemMaintenance {
+        SystemMaintenance {
+            id: id.to_string(),
+            title: format!("window {}", id),
+            state,
+            begin: begin.to_string(),
+            end: end.to_string(),
+            href: String::new(),
+            service_types: vec![1, 2],
+            product: vec![1],
+            uid_suffix: vec![],
+            maintain_type: 1,
+            env: 1,
+        }
+    }
+
+    #[test]
+    fn test_guard_tracks_pending_windows() {
+        let guard = MaintenanceGuard::new().with_lead_time(Duration::from_millis(100));
+        guard.update(vec![window("a", MaintenanceState::Scheduled, 1_000, 2_000)]);
+
+        assert!(guard.active_at(850, None).is_none());
+        assert_eq!(guard.active_at(950, None).unwrap().id, "a");
+        assert!(guard.active_at(2_000, None).is_none());
+
+        guard.update(vec![window("a", MaintenanceState::Canceled, 1_000, 2_000)]);
+        assert!(guard.active_at(1_500, None).is_none());
+        assert!(guard.upcoming().is_empty());
+    }
+
+    #[test]
+    fn test_guard_applies_ws_push() {
+        let guard = MaintenanceGuard::new();
+        let msg: WsMessage = serde_json::from_str(
+            r#"{"topic":"system.status","ts":1751858399649,"data":[{"id":"b","title":"upgrade","state":"ongoing","begin":"0","end":"9999999999999","href":"","serviceTypes":[1],"product":[1,2],"uidSuffix":[],"maintainType":1,"env":1}]}"#,
+        )
+        .unwrap();
+        assert!(guard.apply_message(&msg));
+        assert!(guard.check(Some(Category::Spot)).is_err());
+        assert!(guard.record_reconnect(None));
+        assert!(!guard.record_reconnect(Some(Category::Option)));
+        assert_eq!(guard.reconnects_during_maintenance(), 1);
+    }
+
+    #[test]
+    fn test_guard_ignores_unrelated_windows() {
+        let demo = SystemMaintenance {
+            env: 2,
+            ..window("demo", MaintenanceState::Ongoing, 0, 2_000)
+        };
+        let options = SystemMaintenance {
+            product: vec![3],
+            ..window("options", MaintenanceState::Ongoing, 0, 2_000)
+        };
+        let some_uids = SystemMaintenance {
+            uid_suffix: vec![3],
+            ..window("uids", MaintenanceState::Ongoing, 0, 2_000)
+        };
+
+        let mainnet = MaintenanceGuard::new().with_uid(1_000_001);
+        mainnet.update(vec![demo.clone(), options.clone(), some_uids.clone()]);
+        assert!(mainnet.active_at(1_000, Some(Category::Linear)).is_none());
+        assert!(mainnet.active_at(1_000, Some(Category::Spot)).is_none());
+        assert_eq!(
+            mainnet.active_at(1_000, Some(Category::Option)).unwrap().id,
+            "options"
+        );
+
+        let testnet = MaintenanceGuard::new().with_env(BybitEnv::Testnet);
+        testnet.update(vec![options.clone(), some_uids.clone()]);
+        assert!(testnet.active_at(1_000, None).is_none());
+
+        let demo_guard = MaintenanceGuard::new().with_env(BybitEnv::Demo);
+        demo_guard.update(vec![demo, options, some_uids]);
+        assert_eq!(
+            demo_guard
+                .active_at(1_000, Some(Category::Linear))
+                .unwrap()
+                .id,
+            "demo"
+        );
+        assert!(demo_guard
+            .active_at(1_000, Some(Category::Option))
+            .is_none());
+    }
+}
@@ -0,0 +1,532 @@
+//! Multi-profile credential store.
+//!
+//! A profile file (TOML or JSON) holds named API keys together with the
+//! environment they belong to and descriptive permission metadata:
+//!
+//! ```toml
+//! default = "main"
+//!
+//! [profiles.main]
+//! key = "${BYBIT_API_KEY}"
+//! secret = "enc:v1:9m3x...Q=="          # passphrase-encrypted
+//! env = "mainnet"
+//! read_only = true
+//! permissions = ["Spot", "ContractTrade"]
+//!
+//! [profiles.qa]
+//! key = "abc123"
+//! secret = "${BYBIT_DEMO_SECRET:-}"
+//! env = "demo"
+//! ```
+//!
+//! `key` and `secret` support `${VAR}` and `${VAR:-default}` interpolation,
+//! resolved when the profile is turned into an [`ApiKeyPair`]. Secrets
+//! prefixed with `enc:v1:` are encrypted with [`encrypt_secret`]
+//! (Argon2id key derivation + ChaCha20-Poly1305) and need the passphrase.
+//!
+//! ```ignore
+//! use bybit_rust_api::profiles::ProfileStore;
+//! use bybit_rust_api::RestClient;
+//!
+//! let store = ProfileStore::load("~/.config/bybit/profiles.toml")?;
+//! let profile = store.get("main").unwrap();
+//! let keys = profile.key_pair(Some("passphrase"))?;
+//! let client = RestClient::for_env(keys, profile.env);
+//! ```
+
+use crate::environment::BybitEnv;
+use crate::rest::api_key_pair::{redact_key, ApiKeyPair};
+use crate::rest::errors::{BybitError, BybitResult};
+use argon2::Argon2;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::ChaCha20Poly1305;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Prefix marking an encrypted secret.
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// A named set of credentials plus metadata.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Profile {
+    /// Profile name (the key in the `profiles` table)
+    #[serde(skip)]
+    pub name: String,
+    /// API key, may contain `${VAR}` references
+    pub key: String,
+    /// API secret: plain, `${VAR}` reference or `enc:v1:` ciphertext
+    secret: String,
+    /// Environment / region the key was issued for
+    #[serde(default)]
+    pub env: BybitEnv,
+    /// Key was created read-only
+    #[serde(default)]
+    pub read_only: bool,
+    /// Permission scopes granted to the key (e.g. `Spot`, `ContractTrade`, `Wallet`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Profile {
+    /// Create a profile with a plaintext (or `${VAR}`) secret.
+    pub fn new(
+        name: impl Into<String>,
+        key: impl Into<String>,
+        secret: impl Into<String>,
+        env: BybitEnv,
+    ) -> Self {
+        Profile {
+            name: name.into(),
+            key: key.into(),
+            secret: secret.into(),
+            env,
+            read_only: false,
+            permissions: Vec::new(),
+            description: None,
+        }
+    }
+
+    /// Replace the secret with its passphrase-encrypted form.
+    pub fn encrypt(mut self, passphrase: &str) -> BybitResult<Self> {
+        if !self.is_encrypted() {
+            let encrypted = encrypt_secret(&self.secret, passphrase)?;
+            self.secret.zeroize();
+            self.secret = encrypted;
+        }
+        Ok(self)
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<String>, read_only: bool) -> Self {
+        self.permissions = permissions;
+        self.read_only = read_only;
+        self
+    }
+
+    /// Whether the stored secret needs a passphrase.
+    pub fn is_encrypted(&self) -> bool {
+        self.secret.starts_with(ENCRYPTED_PREFIX)
+    }
+
+    /// Whether the key has the given permission scope (case-insensitive).
+    pub fn has_permission(&self, scope: &str) -> bool {
+        self.permissions
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(scope))
+    }
+
+    /// Resolve interpolation / decryption and build an [`ApiKeyPair`].
+    ///
+    /// `passphrase` is required only for encrypted secrets.
+    pub fn key_pair(&self, passphrase: Option<&str>) -> BybitResult<ApiKeyPair> {
+        let key = interpolate(&self.key)?;
+        let secret = if self.is_encrypted() {
+            let passphrase = passphrase.ok_or_else(|| {
+                BybitError::Config(format!(
+                    "Profile {} has an encrypted secret; a passphrase is required",
+                    self.name
+                ))
+            })?;
+            decrypt_secret(&self.secret, passphrase)?
+        } else {
+            Zeroizing::new(interpolate(&self.secret)?)
+        };
+        Ok(ApiKeyPair::new(
+            self.name.clone(),
+            key,
+            secret.as_str().to_string(),
+        ))
+    }
+}
+
+impl fmt::Debug for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Profile")
+            .field("name", &self.name)
+            .field("key", &redact_key(&self.key))
+            .field("secret", &"[REDACTED]")
+            .field("encrypted", &self.is_encrypted())
+            .field("env", &self.env)
+            .field("read_only", &self.read_only)
+            .field("permissions", &self.permissions)
+            .field("description", &self.description)
+            .finish()
+    }
+}
+
+impl Drop for Profile {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Collection of profiles loaded from a TOML or JSON file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileStore {
+    /// Profile used when none is requested explicitly
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl ProfileStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Default profile file location.
+    ///
+    /// `$BYBIT_PROFILES` if set, otherwise `~/.config/bybit/profiles.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        if let Ok(path) = std::env::var("BYBIT_PROFILES") {
+            return Some(PathBuf::from(path));
+        }
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/bybit/profiles.toml"))
+    }
+
+    /// Load a profile file; the format is chosen by extension (`.json`
+    /// or anything else as TOML).
+    pub fn load(path: impl AsRef<Path>) -> BybitResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| BybitError::Config(format!("Failed to read {}: {}", path.display(), e)))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json_str(&content),
+            _ => Self::from_toml_str(&content),
+        }
+    }
+
+    pub fn from_toml_str(content: &str) -> BybitResult<Self> {
+        let store: ProfileStore = toml::from_str(content)
+            .map_err(|e| BybitError::Config(format!("Invalid profile file: {}", e)))?;
+        store.finish()
+    }
+
+    pub fn from_json_str(content: &str) -> BybitResult<Self> {
+        let store: ProfileStore = serde_json::from_str(content)
+            .map_err(|e| BybitError::Config(format!("Invalid profile file: {}", e)))?;
+        store.finish()
+    }
+
+    /// Write the store to `path` (TOML unless the extension is `.json`).
+    ///
+    /// The file is left with `0600` permissions on Unix, including when it
+    /// already existed with wider ones.
+    pub fn save(&self, path: impl AsRef<Path>) -> BybitResult<()> {
+        let path = path.as_ref();
+        let content = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => serde_json::to_string_pretty(self)?,
+            _ => toml::to_string_pretty(self)
+                .map_err(|e| BybitError::Config(format!("Failed to encode profiles: {}", e)))?,
+        };
+        let io_err = |e: std::io::Error| {
+            BybitError::Config(format!("Failed to write {}: {}", path.display(), e))
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(io_err)?;
+        }
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(path).map_err(io_err)?;
+        // `mode` only applies when the file is created
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))
+                .map_err(io_err)?;
+        }
+        std::io::Write::write_all(&mut file, content.as_bytes()).map_err(io_err)
+    }
+
+    fn finish(mut self) -> BybitResult<Self> {
+        for (name, profile) in self.profiles.iter_mut() {
+            profile.name = name.clone();
+        }
+        if let Some(ref default) = self.default {
+            if !self.profiles.contains_key(default) {
+                return Err(BybitError::Config(format!(
+                    "Default profile {} is not defined",
+                    default
+                )));
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn insert(&mut self, profile: Profile) {
+        self.profiles.insert(profile.name.clone(), profile);
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Profile> {
+        self.profiles.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Profile> {
+        self.profiles.get(name)
+    }
+
+    /// The profile named by `default`, or the only profile if there is one.
+    pub fn default_profile(&self) -> Option<&Profile> {
+        match self.default {
+            Some(ref name) => self.profiles.get(name),
+            None if self.profiles.len() == 1 => self.profiles.values().next(),
+            None => None,
+        }
+    }
+
+    /// Look up `name`, falling back to the default profile.
+    pub fn resolve(&self, name: Option<&str>) -> BybitResult<&Profile> {
+        match name {
+            Some(name) => self
+                .get(name)
+                .ok_or_else(|| BybitError::Config(format!("Unknown profile: {}", name))),
+            None => self
+                .default_profile()
+                .ok_or_else(|| BybitError::Config("No default profile configured".to_string())),
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    pub fn profiles(&self) -> impl Iterator<Item = &Profile> {
+        self.profiles.values()
+    }
+}
+
+/// Expand `${VAR}` and `${VAR:-default}` references from the environment.
+fn interpolate(value: &str) -> BybitResult<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..].find('}').ok_or_else(|| {
+            BybitError::Config(format!("Unterminated ${{...}} in profile value: {}", value))
+        })? + start;
+        let expr = &rest[start + 2..end];
+        let (var, default) = match expr.split_once(":-") {
+            Some((var, default)) => (var, Some(default)),
+            None => (expr, None),
+        };
+        match (std::env::var(var), default) {
+            (Ok(v), _) => out.push_str(&v),
+            (Err(_), Some(default)) => out.push_str(default),
+            (Err(_), None) => {
+                return Err(BybitError::Config(format!(
+                    "Environment variable {} is not set",
+                    var
+                )))
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> BybitResult<Zeroizing<[u8; 32]>> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+        .map_err(|e| BybitError::Config(format!("Key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+/// Encrypt a secret with a passphrase.
+///
+/// Returns `enc:v1:<base64(salt | nonce | ciphertext)>`, suitable for the
+/// `secret` field of a profile.
+pub fn encrypt_secret(secret: &str, passphrase: &str) -> BybitResult<String> {
+    let mut salt = [0u8; SALT_LEN];
+    chacha20poly1305::aead::rand_core::RngCore::fill_bytes(&mut OsRng, &mut salt);
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new(key.as_ref().into());
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, secret.as_bytes())
+        .map_err(|_| BybitError::Config("Secret encryption failed".to_string()))?;
+
+    let mut blob = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    blob.extend_from_slice(&salt);
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(&ciphertext);
+    Ok(format!(
+        "{}{}",
+        ENCRYPTED_PREFIX,
+        base64::engine::general_purpose::STANDARD.encode(blob)
+    ))
+}
+
+/// Decrypt a secret produced by [`encrypt_secret`].
+pub fn decrypt_secret(encrypted: &str, passphrase: &str) -> BybitResult<Zeroizing<String>> {
+    let invalid = || BybitError::Config("Malformed encrypted secret".to_string());
+    let encoded = encrypted
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .ok_or_else(invalid)?;
+    let blob = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|_| invalid())?;
+    if blob.len() < SALT_LEN + NONCE_LEN {
+        return Err(invalid());
+    }
+    let (salt, rest) = blob.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_key(passphrase, salt)?;
+    let cipher = ChaCha20Poly1305::new(key.as_ref().into());
+    let plaintext = cipher
+        .decrypt(nonce.into(), ciphertext)
+        .map_err(|_| BybitError::Config("Wrong passphrase or corrupted secret".to_string()))?;
+    String::from_utf8(plaintext)
+        .map(Zeroizing::new)
+        .map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::Region;
+
+    const TOML: &str = r#"
+default = "main"
+
+[profiles.main]
+key = "main-key"
+secret = "main-secret"
+env = "mainnet"
+read_only = true
+permissions = ["Spot", "Wallet"]
+
+[profiles.tr]
+key = "${BYBIT_PROFILES_TEST_KEY:-fallback-key}"
+secret = "tr-secret"
+env = "tr"
+"#;
+
+    #[test]
+    fn test_load_toml() {
+        let store = ProfileStore::from_toml_str(TOML).unwrap();
+        let main = store.default_profile().unwrap();
+        assert_eq!(main.name, "main");
+        assert!(main.read_only);
+        assert!(main.has_permission("spot"));
+        assert!(!main.has_permission("ContractTrade"));
+
+        let tr = store.get("tr").unwrap();
+        assert_eq!(tr.env, BybitEnv::Region(Region::Tr));
+        let keys = tr.key_pair(None).unwrap();
+        assert_eq!(keys.key(), "fallback-key");
+        assert_eq!(keys.profile_name(), "tr");
+    }
+
+    #[test]
+    fn test_load_json() {
+        let json = r#"{
+            "profiles": {
+                "qa": { "key": "k", "secret": "s", "env": "demo" }
+            }
+        }"#;
+        let store = ProfileStore::from_json_str(json).unwrap();
+        let qa = store.resolve(None).unwrap();
+        assert_eq!(qa.env, BybitEnv::Demo);
+        assert!(!qa.read_only);
+        assert!(store.resolve(Some("missing")).is_err());
+    }
+
+    #[test]
+    fn test_unknown_default_is_error() {
+        let toml = "default = \"nope\"\n[profiles.a]\nkey = \"k\"\nsecret = \"s\"\n";
+        assert!(ProfileStore::from_toml_str(toml).is_err());
+    }
+
+    #[test]
+    fn test_interpolation() {
+        std::env::set_var("BYBIT_PROFILES_TEST_VAR", "value");
+        assert_eq!(
+            interpolate("pre-${BYBIT_PROFILES_TEST_VAR}-post").unwrap(),
+            "pre-value-post"
+        );
+        assert_eq!(
+            interpolate("${BYBIT_PROFILES_UNSET:-dflt}").unwrap(),
+            "dflt"
+        );
+        assert!(interpolate("${BYBIT_PROFILES_UNSET}").is_err());
+        assert!(interpolate("${BROKEN").is_err());
+    }
+
+    #[test]
+    fn test_encrypt_round_trip() {
+        let encrypted = encrypt_secret("my-secret", "hunter2").unwrap();
+        assert!(encrypted.starts_with(ENCRYPTED_PREFIX));
+        assert!(!encrypted.contains("my-secret"));
+        assert_eq!(
+            decrypt_secret(&encrypted, "hunter2").unwrap().as_str(),
+            "my-secret"
+        );
+        assert!(decrypt_secret(&encrypted, "wrong").is_err());
+    }
+
+    #[test]
+    fn test_encrypted_profile_requires_passphrase() {
+        let profile = Profile::new("main", "key", "secret", BybitEnv::Testnet)
+            .encrypt("pass")
+            .unwrap();
+        assert!(profile.is_encrypted());
+        assert!(profile.key_pair(None).is_err());
+        assert_eq!(profile.key_pair(Some("pass")).unwrap().secret(), "secret");
+    }
+
+    #[test]
+    fn test_save_and_reload() {
+        let dir = std::env::temp_dir().join(format!("bybit-profiles-{}", std::process::id()));
+        let path = dir.join("profiles.toml");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        }
+        let mut store = ProfileStore::new();
+        store.insert(
+            Profile::new("main", "key", "secret", BybitEnv::Demo)
+                .with_permissions(vec!["Spot".to_string()], false),
+        );
+        store.save(&path).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let loaded = ProfileStore::load(&path).unwrap();
+        let main = loaded.get("main").unwrap();
+        assert_eq!(main.env, BybitEnv::Demo);
+        assert_eq!(main.permissions, vec!["Spot".to_string()]);
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let profile = Profile::new("main", "abcdefgh", "plain-secret", BybitEnv::Mainnet);
+        let debug = format!("{:?}", profile);
+        assert!(!debug.contains("plain-secret"));
+        assert!(!debug.contains("abcdefgh"));
+        assert!(debug.contains("abcd***"));
+    }
+}
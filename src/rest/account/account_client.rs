@@ -1,13 +1,23 @@
 use crate::rest::account::dto::account_info::AccountInfoResult;
 use crate::rest::account::dto::account_wallet::{GetWalletBalanceParams, WalletBalanceResult};
-use crate::rest::account::dto::collateral::{BorrowHistoryResult, CollateralInfoResult};
+use crate::rest::account::dto::collateral::{
+    BorrowHistoryResult, CollateralInfoResult, CollateralSwitch, CollateralSwitchBatchResult,
+    SetCollateralSwitchBatchParams,
+};
 use crate::rest::account::dto::contract_transaction_log::{
     ContractTransactionLogResult, GetContractTransactionLogParams,
 };
+use crate::rest::account::dto::demo::DemoApplyMoneyParams;
 use crate::rest::account::dto::fee_rate::FeeRateResult;
 use crate::rest::account::dto::mmp::{MmpStateResult, ModifyMmpParams};
+use crate::rest::account::dto::repay::{
+    NoConvertRepayParams, QuickRepaymentResult, RepayParams, RepayResult,
+};
 use crate::rest::account::dto::transaction_log::{GetTransactionLogParams, TransactionLogResult};
+use crate::rest::account::dto::user_setting::UserSettingConfig;
 use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::enums::{Category, SwitchStatus};
+use crate::rest::market::dto::InstrumentsInfoResult;
 use crate::rest::BybitResult as Result;
 use serde_json::{json, to_value};
 
@@ -288,13 +298,164 @@ impl AccountClient {
             .await?;
         Ok(response)
     }
+
+    /// Request demo trading funds
+    ///
+    /// Only available on the demo domain (`BybitEnv::Demo`), using the demo
+    /// account's API key. At most once per minute.
+    ///
+    /// API: POST /v5/account/demo-apply-money
+    /// https://bybit-exchange.github.io/docs/v5/demo
+    pub async fn request_demo_funds(
+        &self,
+        params: DemoApplyMoneyParams,
+    ) -> Result<ServerResponse<serde_json::Value>> {
+        let endpoint = "v5/account/demo-apply-money";
+        let body = to_value(&params)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Set collateral coin
+    ///
+    /// API: POST /v5/account/set-collateral-switch
+    /// https://bybit-exchange.github.io/docs/v5/account/set-collateral
+    pub async fn set_collateral_switch(
+        &self,
+        params: CollateralSwitch,
+    ) -> Result<ServerResponse<serde_json::Value>> {
+        let endpoint = "v5/account/set-collateral-switch";
+        let body = to_value(&params)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Batch set collateral coin
+    ///
+    /// API: POST /v5/account/set-collateral-switch-batch
+    /// https://bybit-exchange.github.io/docs/v5/account/batch-set-collateral
+    pub async fn set_collateral_switch_batch(
+        &self,
+        params: SetCollateralSwitchBatchParams,
+    ) -> Result<ServerResponse<CollateralSwitchBatchResult>> {
+        let endpoint = "v5/account/set-collateral-switch-batch";
+        let body = to_value(&params)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Manual repay
+    ///
+    /// API: POST /v5/account/repay
+    /// https://bybit-exchange.github.io/docs/v5/account/repay
+    pub async fn repay(&self, params: RepayParams) -> Result<ServerResponse<RepayResult>> {
+        let endpoint = "v5/account/repay";
+        let body = to_value(&params)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Repay liability, converting other assets where needed
+    ///
+    /// API: POST /v5/account/quick-repayment
+    /// https://bybit-exchange.github.io/docs/v5/account/repay-liability
+    pub async fn quick_repayment(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ServerResponse<QuickRepaymentResult>> {
+        let endpoint = "v5/account/quick-repayment";
+        let mut body = json!({});
+
+        if let Some(coin) = coin {
+            body["coin"] = json!(coin);
+        }
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Manual repay without asset conversion
+    ///
+    /// API: POST /v5/account/no-convert-repay
+    /// https://bybit-exchange.github.io/docs/v5/account/no-convert-repay
+    pub async fn no_convert_repay(
+        &self,
+        params: NoConvertRepayParams,
+    ) -> Result<ServerResponse<RepayResult>> {
+        let endpoint = "v5/account/no-convert-repay";
+        let body = to_value(&params)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Set spot hedging
+    ///
+    /// API: POST /v5/account/set-hedging-mode
+    /// https://bybit-exchange.github.io/docs/v5/account/set-spot-hedge
+    pub async fn set_spot_hedging(
+        &self,
+        mode: SwitchStatus,
+    ) -> Result<ServerResponse<serde_json::Value>> {
+        let endpoint = "v5/account/set-hedging-mode";
+        let body = json!({
+            "setHedgingMode": mode,
+        });
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get user setting config
+    ///
+    /// API: GET /v5/account/user-setting-config
+    /// https://bybit-exchange.github.io/docs/v5/account/user-setting-config
+    pub async fn get_user_setting_config(&self) -> Result<ServerResponse<UserSettingConfig>> {
+        let endpoint = "v5/account/user-setting-config";
+        let response = self
+            .client
+            .get(endpoint, json!({}), SecType::Signed)
+            .await?;
+        Ok(response)
+    }
+
+    /// Get instruments info for this account
+    ///
+    /// Same shape as `MarketClient::get_instruments_info`, limited to what
+    /// the account can trade; the result variant follows `category`.
+    ///
+    /// API: GET /v5/account/instruments-info
+    /// https://bybit-exchange.github.io/docs/v5/account/instrument
+    pub async fn get_instruments_info(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<InstrumentsInfoResult>> {
+        let endpoint = "v5/account/instruments-info";
+        let mut params = json!({
+            "category": category,
+        });
+
+        if let Some(symbol) = symbol {
+            params["symbol"] = json!(symbol);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::rest::enums::account_type::AccountType;
-    use crate::rest::enums::category::Category;
     use crate::rest::ApiKeyPair;
 
     fn create_test_client() -> AccountClient {
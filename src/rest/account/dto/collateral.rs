@@ -1,3 +1,4 @@
+use crate::rest::enums::SwitchStatus;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +49,51 @@ pub struct BorrowHistoryResult {
     pub list: Vec<BorrowHistoryDetails>,
 }
 
+// https://bybit-exchange.github.io/docs/v5/account/set-collateral
+//
+// POST /v5/account/set-collateral-switch
+// {
+//     "coin": "BTC",
+//     "collateralSwitch": "ON"
+// }
+
+/// Whether a coin counts as collateral in the unified account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralSwitch {
+    pub coin: String,
+    pub collateral_switch: SwitchStatus,
+}
+
+impl CollateralSwitch {
+    pub fn new(coin: impl Into<String>, collateral_switch: SwitchStatus) -> Self {
+        CollateralSwitch {
+            coin: coin.into(),
+            collateral_switch,
+        }
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/account/batch-set-collateral
+//
+// POST /v5/account/set-collateral-switch-batch
+// {
+//     "request": [
+//         { "coin": "MATIC", "collateralSwitch": "OFF" },
+//         { "coin": "BTC", "collateralSwitch": "OFF" }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetCollateralSwitchBatchParams {
+    pub request: Vec<CollateralSwitch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollateralSwitchBatchResult {
+    pub list: Vec<CollateralSwitch>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,4 +166,32 @@ mod tests {
         assert_eq!(result.list[0].currency, "USDT");
         assert_eq!(result.list[0].borrow_amount, "100");
     }
+
+    #[test]
+    fn test_collateral_switch_batch() {
+        let params = SetCollateralSwitchBatchParams {
+            request: vec![
+                CollateralSwitch::new("MATIC", SwitchStatus::Off),
+                CollateralSwitch::new("BTC", SwitchStatus::On),
+            ],
+        };
+        let value = serde_json::to_value(&params).unwrap();
+        assert_eq!(value["request"][0]["collateralSwitch"], "OFF");
+        assert_eq!(value["request"][1]["coin"], "BTC");
+
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "result": {
+                "list": [
+                    { "coin": "MATIC", "collateralSwitch": "OFF" },
+                    { "coin": "BTC", "collateralSwitch": "ON" }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1739497225376
+        }"#;
+        let response: ServerResponse<CollateralSwitchBatchResult> = from_str(json_data).unwrap();
+        assert_eq!(response.result.list[1].collateral_switch, SwitchStatus::On);
+    }
 }
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/demo
+//
+// POST /v5/account/demo-apply-money (demo domain only)
+// {
+//     "adjustType": 0,
+//     "utaDemoApplyMoney": [
+//         { "coin": "USDT", "amountStr": "109" },
+//         { "coin": "ETH", "amountStr": "1" }
+//     ]
+// }
+
+/// A single coin to add to (or remove from) the demo wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoApplyMoney {
+    pub coin: String,
+    pub amount_str: String,
+}
+
+impl DemoApplyMoney {
+    pub fn new(coin: impl Into<String>, amount: impl Into<String>) -> Self {
+        DemoApplyMoney {
+            coin: coin.into(),
+            amount_str: amount.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoApplyMoneyParams {
+    /// 0: add funds (default), 1: reduce funds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjust_type: Option<i32>,
+    pub uta_demo_apply_money: Vec<DemoApplyMoney>,
+}
+
+impl DemoApplyMoneyParams {
+    /// Request the given coins to be credited to the demo wallet.
+    pub fn add(coins: Vec<DemoApplyMoney>) -> Self {
+        DemoApplyMoneyParams {
+            adjust_type: Some(0),
+            uta_demo_apply_money: coins,
+        }
+    }
+
+    /// Request the given coins to be deducted from the demo wallet.
+    pub fn reduce(coins: Vec<DemoApplyMoney>) -> Self {
+        DemoApplyMoneyParams {
+            adjust_type: Some(1),
+            uta_demo_apply_money: coins,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_demo_apply_money() {
+        let params = DemoApplyMoneyParams::add(vec![DemoApplyMoney::new("USDT", "10000")]);
+        let value = serde_json::to_value(&params).unwrap();
+        assert_eq!(value["adjustType"], 0);
+        assert_eq!(value["utaDemoApplyMoney"][0]["coin"], "USDT");
+        assert_eq!(value["utaDemoApplyMoney"][0]["amountStr"], "10000");
+    }
+}
@@ -6,9 +6,15 @@ pub mod collateral;
 pub use collateral::*;
 pub mod contract_transaction_log;
 pub use contract_transaction_log::*;
+pub mod demo;
+pub use demo::*;
 pub mod fee_rate;
 pub use fee_rate::*;
 pub mod mmp;
 pub use mmp::*;
+pub mod repay;
+pub use repay::*;
 pub mod transaction_log;
 pub use transaction_log::*;
+pub mod user_setting;
+pub use user_setting::*;
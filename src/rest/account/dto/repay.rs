@@ -0,0 +1,115 @@
+use crate::rest::enums::RepayStatus;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/account/repay
+//
+// POST /v5/account/repay
+// {
+//     "coin": "BTC",
+//     "amount": "0.01"
+// }
+//
+// {
+//     "resultStatus": "P"
+// }
+
+/// Manual repay. Without a coin, all liabilities are repaid, converting
+/// other assets where needed.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RepayParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin: Option<String>,
+    /// Full liability of `coin` if omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/account/no-convert-repay
+//
+// POST /v5/account/no-convert-repay
+// {
+//     "coin": "BTC",
+//     "amount": "0.01"
+// }
+
+/// Repay a coin's liability from its own balance only.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NoConvertRepayParams {
+    pub coin: String,
+    /// Full liability if omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+}
+
+/// Result of manual and no-convert repay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepayResult {
+    pub result_status: RepayStatus,
+}
+
+// https://bybit-exchange.github.io/docs/v5/account/repay-liability
+//
+// POST /v5/account/quick-repayment
+// {
+//     "list": [
+//         { "coin": "BTC", "repaymentQty": "0.10549670" },
+//         { "coin": "ETH", "repaymentQty": "2.27768114" }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuickRepayment {
+    pub coin: String,
+    pub repayment_qty: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickRepaymentResult {
+    pub list: Vec<QuickRepayment>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_repay_params() {
+        let value = serde_json::to_value(RepayParams::default()).unwrap();
+        assert_eq!(value, serde_json::json!({}));
+
+        let value = serde_json::to_value(NoConvertRepayParams {
+            coin: "USDT".to_string(),
+            amount: Some("100".to_string()),
+        })
+        .unwrap();
+        assert_eq!(value["coin"], "USDT");
+        assert_eq!(value["amount"], "100");
+    }
+
+    #[test]
+    fn test_deserialize_repay_results() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": { "resultStatus": "SU" },
+            "retExtInfo": {},
+            "time": 1739499960126
+        }"#;
+        let response: ServerResponse<RepayResult> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.result_status, RepayStatus::Success);
+
+        let json_data = r#"{
+            "list": [
+                { "coin": "BTC", "repaymentQty": "0.10549670" },
+                { "coin": "ETH", "repaymentQty": "2.27768114" }
+            ]
+        }"#;
+        let result: QuickRepaymentResult = serde_json::from_str(json_data).unwrap();
+        assert_eq!(result.list[1].repayment_qty, "2.27768114");
+    }
+}
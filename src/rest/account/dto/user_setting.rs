@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+// https://bybit-exchange.github.io/docs/v5/account/user-setting-config
+//
+// GET /v5/account/user-setting-config
+// {
+//     "retCode": 0,
+//     "retMsg": "OK",
+//     "result": {
+//         "lpaSpot": true,
+//         "lpaPerp": false
+//     },
+//     "retExtInfo": {},
+//     "time": 1739499960126
+// }
+
+/// Trade behaviour settings of the unified account.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingConfig {
+    /// Spot limit orders priced through the book are adjusted instead of rejected
+    #[serde(default)]
+    pub lpa_spot: bool,
+    /// Same for linear/inverse perpetuals and futures
+    #[serde(default)]
+    pub lpa_perp: bool,
+    /// Settings not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_user_setting_config() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": { "lpaSpot": true, "lpaPerp": false, "spotHedgingMode": "ON" },
+            "retExtInfo": {},
+            "time": 1739499960126
+        }"#;
+        let response: ServerResponse<UserSettingConfig> = serde_json::from_str(json_data).unwrap();
+        assert!(response.result.lpa_spot);
+        assert!(!response.result.lpa_perp);
+        assert_eq!(response.result.extra["spotHedgingMode"], "ON");
+        assert!(!response.result.extra.contains_key("lpaSpot"));
+    }
+}
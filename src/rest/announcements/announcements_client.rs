@@ -1,4 +1,6 @@
+use crate::rest::announcements::dto::SystemStatusResult;
 use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::enums::maintenance_state::MaintenanceState;
 use serde::Deserialize;
 use serde_json::json;
 
@@ -69,6 +71,30 @@ impl AnnouncementsClient {
 
         self.client.get(endpoint, params, SecType::None).await
     }
+
+    /// Get system status
+    ///
+    /// Lists scheduled and ongoing platform maintenance windows.
+    ///
+    /// API: GET /v5/system/status
+    /// https://bybit-exchange.github.io/docs/v5/system-status
+    pub async fn get_system_status(
+        &self,
+        id: Option<&str>,
+        state: Option<MaintenanceState>,
+    ) -> crate::rest::BybitResult<ServerResponse<SystemStatusResult>> {
+        let endpoint = "v5/system/status";
+        let mut params = json!({});
+
+        if let Some(id) = id {
+            params["id"] = json!(id);
+        }
+        if let Some(state) = state {
+            params["state"] = json!(state);
+        }
+
+        self.client.get(endpoint, params, SecType::None).await
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,3 @@
+pub mod system_status;
+
+pub use system_status::*;
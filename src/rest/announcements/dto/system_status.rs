@@ -0,0 +1,196 @@
+use crate::environment::BybitEnv;
+use crate::rest::enums::category::Category;
+use crate::rest::enums::maintenance_state::MaintenanceState;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/system-status
+//
+// GET /v5/system/status
+// {
+//     "retCode": 0,
+//     "retMsg": "OK",
+//     "result": {
+//         "list": [
+//             {
+//                 "id": "4d95b2a0-587f-11f0-bcc9-56f28c94d6ea",
+//                 "title": "t06",
+//                 "state": "completed",
+//                 "begin": "1751596902000",
+//                 "end": "1751597011000",
+//                 "href": "",
+//                 "serviceTypes": [2, 3, 4, 5],
+//                 "product": [1, 2],
+//                 "uidSuffix": [],
+//                 "maintainType": 1,
+//                 "env": 1
+//             }
+//         ]
+//     },
+//     "retExtInfo": {},
+//     "time": 1751858399649
+// }
+//
+// The `system.status` WebSocket topic pushes the same objects in `data`.
+
+/// A scheduled or ongoing system maintenance window
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemMaintenance {
+    pub id: String,
+    #[serde(default)]
+    pub title: String,
+    pub state: MaintenanceState,
+    /// Start time, ms
+    pub begin: String,
+    /// End time, ms
+    pub end: String,
+    /// Link to the announcement
+    #[serde(default)]
+    pub href: String,
+    /// Affected services: 1 HTTP trading, 2 WebSocket trading, 3 margin,
+    /// 4 futures, 5 spot, 6 options, 7 trading service
+    #[serde(default)]
+    pub service_types: Vec<i32>,
+    /// Affected products: 1 futures, 2 spot, 3 options, 4 spread
+    #[serde(default)]
+    pub product: Vec<i32>,
+    /// Affected UID suffixes; empty means every user
+    #[serde(default)]
+    pub uid_suffix: Vec<i32>,
+    /// 1 planned, 2 temporary, 3 incident
+    #[serde(default)]
+    pub maintain_type: i32,
+    /// 1 production, 2 production demo trading
+    #[serde(default)]
+    pub env: i32,
+}
+
+impl SystemMaintenance {
+    pub fn begin_ms(&self) -> i64 {
+        self.begin.parse().unwrap_or(0)
+    }
+
+    pub fn end_ms(&self) -> i64 {
+        self.end.parse().unwrap_or(i64::MAX)
+    }
+
+    /// True if the window is pending and `now_ms` falls inside it.
+    ///
+    /// `lead_ms` widens the window so callers can stop ahead of `begin`.
+    pub fn covers(&self, now_ms: i64, lead_ms: i64) -> bool {
+        self.state.is_pending()
+            && now_ms >= self.begin_ms().saturating_sub(lead_ms)
+            && now_ms < self.end_ms()
+    }
+
+    /// True if the window applies to `env`.
+    ///
+    /// Windows are announced for production (`env` 1: mainnet, the backup
+    /// domain and the regional sites) or demo trading (`env` 2); testnet is
+    /// never covered. An unknown `env` is assumed to apply everywhere.
+    pub fn applies_to_env(&self, env: BybitEnv) -> bool {
+        match self.env {
+            1 => !matches!(env, BybitEnv::Testnet | BybitEnv::Demo),
+            2 => env.is_demo(),
+            _ => true,
+        }
+    }
+
+    /// True if the window affects `category`; an empty `product` list
+    /// affects every category.
+    pub fn affects_category(&self, category: Category) -> bool {
+        let product = match category {
+            Category::Linear | Category::Inverse => 1,
+            Category::Spot => 2,
+            Category::Option => 3,
+        };
+        self.product.is_empty() || self.product.contains(&product)
+    }
+
+    /// True if the window affects `uid`; an empty `uid_suffix` list affects
+    /// every user.
+    pub fn affects_uid(&self, uid: u64) -> bool {
+        let uid = uid.to_string();
+        self.uid_suffix.is_empty()
+            || self
+                .uid_suffix
+                .iter()
+                .any(|suffix| uid.ends_with(&suffix.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemStatusResult {
+    #[serde(default)]
+    pub list: Vec<SystemMaintenance>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_system_status() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "id": "4d95b2a0-587f-11f0-bcc9-56f28c94d6ea",
+                        "title": "t06",
+                        "state": "ongoing",
+                        "begin": "1751596902000",
+                        "end": "1751597011000",
+                        "href": "",
+                        "serviceTypes": [2, 3, 4, 5],
+                        "product": [1, 2],
+                        "uidSuffix": [],
+                        "maintainType": 1,
+                        "env": 1
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1751858399649
+        }"#;
+        let response: ServerResponse<SystemStatusResult> = serde_json::from_str(json_data).unwrap();
+        let window = &response.result.list[0];
+        assert_eq!(window.state, MaintenanceState::Ongoing);
+        assert!(window.covers(1751596902000, 0));
+        assert!(window.covers(1751596900000, 5_000));
+        assert!(!window.covers(1751596900000, 0));
+        assert!(!window.covers(1751597011000, 0));
+
+        assert!(window.applies_to_env(BybitEnv::Mainnet));
+        assert!(!window.applies_to_env(BybitEnv::Demo));
+        assert!(!window.applies_to_env(BybitEnv::Testnet));
+        assert!(window.affects_category(Category::Linear));
+        assert!(window.affects_category(Category::Spot));
+        assert!(!window.affects_category(Category::Option));
+        assert!(window.affects_uid(123456));
+    }
+
+    #[test]
+    fn test_window_scope() {
+        let window: SystemMaintenance = serde_json::from_value(serde_json::json!({
+            "id": "demo-options",
+            "state": "scheduled",
+            "begin": "0",
+            "end": "1",
+            "product": [3],
+            "uidSuffix": [7, 42],
+            "env": 2
+        }))
+        .unwrap();
+        assert!(window.applies_to_env(BybitEnv::Demo));
+        assert!(!window.applies_to_env(BybitEnv::Mainnet));
+        assert!(window.affects_category(Category::Option));
+        assert!(!window.affects_category(Category::Inverse));
+        assert!(window.affects_uid(1007));
+        assert!(window.affects_uid(100042));
+        assert!(!window.affects_uid(1004));
+    }
+}
@@ -1,3 +1,4 @@
 mod announcements_client;
+pub mod dto;
 
 pub use announcements_client::AnnouncementsClient;
@@ -1,11 +1,16 @@
 use anyhow::Context;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
 use std::io::BufReader;
+use zeroize::Zeroize;
 
-#[derive(Debug, Clone, Deserialize)]
+/// API key + secret. The secret is redacted from `Debug` output and wiped
+/// from memory when the pair is dropped.
+#[derive(Clone, Deserialize)]
 pub struct ApiKeyPair {
+    #[serde(default)]
     profile_name: String,
     key: String,
     secret: String,
@@ -72,6 +77,31 @@ impl ApiKeyPair {
     }
 }
 
+impl fmt::Debug for ApiKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKeyPair")
+            .field("profile_name", &self.profile_name)
+            .field("key", &redact_key(&self.key))
+            .field("secret", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl Drop for ApiKeyPair {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Keep only a short prefix of the API key so logs stay useful.
+pub(crate) fn redact_key(key: &str) -> String {
+    match key.char_indices().nth(4) {
+        Some((idx, _)) => format!("{}***", &key[..idx]),
+        None if key.is_empty() => String::new(),
+        None => "***".to_string(),
+    }
+}
+
 impl ApiKeyPairs {
     pub fn new() -> ApiKeyPairs {
         ApiKeyPairs {
@@ -100,14 +130,77 @@ impl ApiKeyPairs {
 
     /// Load API keys from a JSON file.
     ///
-    /// Expected format: array of `{ "profile_name", "key", "secret" }` objects.
+    /// Accepts either a `{ "profiles": { "<name>": { "key", "secret" } } }`
+    /// map (the shape `ApiKeyPairs` deserializes from) or the legacy array
+    /// of `{ "profile_name", "key", "secret" }` objects.
+    ///
+    /// For encrypted secrets, env-var interpolation and per-profile
+    /// environments use [`ProfileStore`](crate::profiles::ProfileStore).
     pub fn load_from_json_file(file_path: &str) -> Result<ApiKeyPairs, Box<dyn std::error::Error>> {
-        let mut api_key_pairs = ApiKeyPairs::new();
-        let pairs: Vec<ApiKeyPair> =
+        let value: serde_json::Value =
             serde_json::from_reader(BufReader::new(File::open(file_path)?))?;
-        for pair in pairs {
-            api_key_pairs.add(pair.profile_name, pair.key, pair.secret);
+        Ok(Self::from_json_value(value)?)
+    }
+
+    fn from_json_value(value: serde_json::Value) -> serde_json::Result<ApiKeyPairs> {
+        let mut api_key_pairs = if value.is_array() {
+            let mut api_key_pairs = ApiKeyPairs::new();
+            for pair in serde_json::from_value::<Vec<ApiKeyPair>>(value)? {
+                api_key_pairs.pairs.insert(pair.profile_name.clone(), pair);
+            }
+            api_key_pairs
+        } else {
+            serde_json::from_value::<ApiKeyPairs>(value)?
+        };
+        // In the map format the profile name is the map key
+        for (name, pair) in api_key_pairs.pairs.iter_mut() {
+            if pair.profile_name.is_empty() {
+                pair.profile_name = name.clone();
+            }
         }
         Ok(api_key_pairs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let pair = ApiKeyPair::new(
+            "main".to_string(),
+            "abcdefgh1234".to_string(),
+            "super-secret".to_string(),
+        );
+        let debug = format!("{:?}", pair);
+        assert!(!debug.contains("super-secret"));
+        assert!(!debug.contains("abcdefgh1234"));
+        assert!(debug.contains("abcd***"));
+    }
+
+    #[test]
+    fn test_load_profiles_map_format() {
+        let pairs = ApiKeyPairs::from_json_value(json!({
+            "profiles": {
+                "main": { "key": "k1", "secret": "s1" },
+                "test": { "key": "k2", "secret": "s2" }
+            }
+        }))
+        .unwrap();
+        let main = pairs.get("main").unwrap();
+        assert_eq!(main.profile_name(), "main");
+        assert_eq!(main.key(), "k1");
+        assert_eq!(pairs.get("test").unwrap().secret(), "s2");
+    }
+
+    #[test]
+    fn test_load_legacy_array_format() {
+        let pairs = ApiKeyPairs::from_json_value(json!([
+            { "profile_name": "main", "key": "k1", "secret": "s1" }
+        ]))
+        .unwrap();
+        assert_eq!(pairs.get("main").unwrap().key(), "k1");
+    }
+}
@@ -1,4 +1,8 @@
+use crate::rest::asset::dto::*;
 use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::enums::account_type::AccountType;
+use crate::rest::enums::fiat::FiatCoinType;
+use crate::rest::enums::small_balance_account_type::SmallBalanceAccountType;
 use crate::rest::BybitResult as Result;
 use serde_json::json;
 
@@ -343,6 +347,25 @@ impl AssetClient {
         Ok(response)
     }
 
+    /// Set deposit account
+    ///
+    /// Choose which account on-chain deposits are credited to.
+    ///
+    /// API: POST /v5/asset/deposit/deposit-to-account
+    /// https://bybit-exchange.github.io/docs/v5/asset/deposit/set-deposit-acct
+    pub async fn set_deposit_account(
+        &self,
+        account_type: AccountType,
+    ) -> Result<ServerResponse<SetDepositAccountResult>> {
+        let endpoint = "v5/asset/deposit/deposit-to-account";
+        let body = json!({
+            "accountType": account_type,
+        });
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
     /// Get withdrawal records
     ///
     /// API: GET /v5/asset/withdraw/query-record
@@ -403,40 +426,74 @@ impl AssetClient {
         Ok(response)
     }
 
+    /// Get exchange entity list
+    ///
+    /// VASPs accepted as the `vaspEntityId` of a Travel Rule beneficiary.
+    ///
+    /// API: GET /v5/asset/withdraw/vasp/list
+    /// https://bybit-exchange.github.io/docs/v5/asset/withdraw/vasp-list
+    pub async fn get_vasp_list(&self) -> Result<ServerResponse<VaspListResult>> {
+        let endpoint = "v5/asset/withdraw/vasp/list";
+        let response = self
+            .client
+            .get(endpoint, json!({}), SecType::Signed)
+            .await?;
+        Ok(response)
+    }
+
+    /// Get withdrawal address list
+    ///
+    /// `address_type`: 0 on-chain, 1 internal transfer, 2 both.
+    ///
+    /// API: GET /v5/asset/withdraw/query-address
+    /// https://bybit-exchange.github.io/docs/v5/asset/withdraw/withdraw-address
+    pub async fn get_withdraw_address_list(
+        &self,
+        coin: Option<&str>,
+        chain: Option<&str>,
+        address_type: Option<i32>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<WithdrawAddressResult>> {
+        let endpoint = "v5/asset/withdraw/query-address";
+        let mut params = json!({});
+
+        if let Some(coin) = coin {
+            params["coin"] = json!(coin);
+        }
+        if let Some(chain) = chain {
+            params["chain"] = json!(chain);
+        }
+        if let Some(address_type) = address_type {
+            params["addressType"] = json!(address_type);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
     /// Withdraw
     ///
+    /// A Travel Rule `beneficiary` is checked with `Beneficiary::validate()`
+    /// first, so an incomplete one fails locally without a request being sent.
+    ///
     /// API: POST /v5/asset/withdraw/create
     /// https://bybit-exchange.github.io/docs/v5/asset/withdraw
     pub async fn withdraw(
         &self,
-        coin: &str,
-        chain: &str,
-        address: &str,
-        tag: Option<&str>,
-        amount: &str,
-        timestamp: i64,
-        for_ce_chain: Option<i32>,
-        account_type: Option<&str>,
+        request: WithdrawRequest,
     ) -> Result<ServerResponse<serde_json::Value>> {
         let endpoint = "v5/asset/withdraw/create";
-        let mut body = json!({
-            "coin": coin,
-            "chain": chain,
-            "address": address,
-            "amount": amount,
-            "timestamp": timestamp,
-        });
-
-        if let Some(tag) = tag {
-            body["tag"] = json!(tag);
-        }
-        if let Some(for_ce_chain) = for_ce_chain {
-            body["forceChain"] = json!(for_ce_chain);
+        if let Some(ref beneficiary) = request.beneficiary {
+            beneficiary.validate()?;
         }
-        if let Some(account_type) = account_type {
-            body["accountType"] = json!(account_type);
-        }
-
+        let body = serde_json::to_value(&request)?;
         let response = self.client.post(endpoint, body, SecType::Signed).await?;
         Ok(response)
     }
@@ -745,6 +802,124 @@ impl AssetClient {
         Ok(response)
     }
 
+    /// Get fiat convert coin list
+    ///
+    /// `side`: 0 to buy crypto with fiat, 1 to sell crypto for fiat.
+    ///
+    /// API: GET /v5/fiat/query-coin-list
+    /// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/query-coin-list
+    pub async fn get_fiat_coin_list(
+        &self,
+        side: Option<i32>,
+    ) -> Result<ServerResponse<FiatCoinListResult>> {
+        let endpoint = "v5/fiat/query-coin-list";
+        let mut params = json!({});
+
+        if let Some(side) = side {
+            params["side"] = json!(side);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get fiat reference price
+    ///
+    /// API: GET /v5/fiat/reference-price
+    /// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/reference-price
+    pub async fn get_fiat_reference_price(
+        &self,
+        symbol: &str,
+    ) -> Result<ServerResponse<FiatReferencePrice>> {
+        let endpoint = "v5/fiat/reference-price";
+        let params = json!({
+            "symbol": symbol,
+        });
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Request a fiat convert quote
+    ///
+    /// API: POST /v5/fiat/quote-apply
+    /// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/quote-apply
+    pub async fn request_fiat_quote(
+        &self,
+        request: FiatQuoteRequest,
+    ) -> Result<ServerResponse<FiatQuote>> {
+        let endpoint = "v5/fiat/quote-apply";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Execute a fiat convert quote
+    ///
+    /// API: POST /v5/fiat/trade-execute
+    /// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/confirm-quote
+    pub async fn execute_fiat_trade(
+        &self,
+        request: FiatTradeRequest,
+    ) -> Result<ServerResponse<FiatTradeExecuteResult>> {
+        let endpoint = "v5/fiat/trade-execute";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get fiat convert trade status
+    ///
+    /// Look up by `trade_no`, or by the `merchant_request_id` given at execution.
+    ///
+    /// API: GET /v5/fiat/trade-query
+    /// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/query-trade
+    pub async fn get_fiat_trade(
+        &self,
+        trade_no: Option<&str>,
+        merchant_request_id: Option<&str>,
+    ) -> Result<ServerResponse<FiatTrade>> {
+        let endpoint = "v5/fiat/trade-query";
+        let mut params = json!({});
+
+        if let Some(trade_no) = trade_no {
+            params["tradeNo"] = json!(trade_no);
+        }
+        if let Some(merchant_request_id) = merchant_request_id {
+            params["merchantRequestId"] = json!(merchant_request_id);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get fiat convert trade history
+    ///
+    /// API: GET /v5/fiat/query-trade-history
+    /// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/query-trade-history
+    pub async fn get_fiat_trade_history(
+        &self,
+        from_coin_type: Option<FiatCoinType>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<ServerResponse<FiatTradeHistoryResult>> {
+        let endpoint = "v5/fiat/query-trade-history";
+        let mut params = json!({});
+
+        if let Some(from_coin_type) = from_coin_type {
+            params["fromCoinType"] = json!(from_coin_type);
+        }
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
     /// Get coin greeks
     ///
     /// API: GET /v5/asset/coin-greeks
@@ -856,6 +1031,135 @@ impl AssetClient {
         let response = self.client.post(endpoint, body, SecType::Signed).await?;
         Ok(response)
     }
+
+    /// Get small balance list
+    ///
+    /// API: GET /v5/asset/covert/small-balance-list
+    /// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/small-balance-list
+    pub async fn get_small_balance_list(
+        &self,
+        account_type: SmallBalanceAccountType,
+        from_coin: Option<&str>,
+    ) -> Result<ServerResponse<SmallBalanceListResult>> {
+        let endpoint = "v5/asset/covert/small-balance-list";
+        let mut params = json!({
+            "accountType": account_type,
+        });
+
+        if let Some(from_coin) = from_coin {
+            params["fromCoin"] = json!(from_coin);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Request a small balance convert quote
+    ///
+    /// API: POST /v5/asset/covert/get-quote
+    /// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/request-quote
+    pub async fn get_small_balance_quote(
+        &self,
+        request: SmallBalanceQuoteRequest,
+    ) -> Result<ServerResponse<SmallBalanceQuote>> {
+        let endpoint = "v5/asset/covert/get-quote";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Confirm a small balance convert quote
+    ///
+    /// API: POST /v5/asset/covert/small-balance-execute
+    /// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/confirm-quote
+    pub async fn exchange_small_balance(
+        &self,
+        quote_id: &str,
+    ) -> Result<ServerResponse<SmallBalanceExchangeResult>> {
+        let endpoint = "v5/asset/covert/small-balance-execute";
+        let body = json!({
+            "quoteId": quote_id,
+        });
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get small balance exchange history
+    ///
+    /// API: GET /v5/asset/covert/small-balance-history
+    /// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/exchange-history
+    pub async fn get_small_balance_history(
+        &self,
+        account_type: Option<SmallBalanceAccountType>,
+        quote_id: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        cursor: Option<&str>,
+        size: Option<i32>,
+    ) -> Result<ServerResponse<SmallBalanceHistoryResult>> {
+        let endpoint = "v5/asset/covert/small-balance-history";
+        let mut params = json!({});
+
+        if let Some(account_type) = account_type {
+            params["accountType"] = json!(account_type);
+        }
+        if let Some(quote_id) = quote_id {
+            params["quoteId"] = json!(quote_id);
+        }
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+        if let Some(size) = size {
+            params["size"] = json!(size);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get fund account history
+    ///
+    /// Balance changes on the funding account.
+    ///
+    /// API: GET /v5/asset/fundinghistory
+    /// https://bybit-exchange.github.io/docs/v5/asset/fund-history
+    pub async fn get_fund_account_history(
+        &self,
+        coin: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<FundAccountHistoryResult>> {
+        let endpoint = "v5/asset/fundinghistory";
+        let mut params = json!({});
+
+        if let Some(coin) = coin {
+            params["coin"] = json!(coin);
+        }
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
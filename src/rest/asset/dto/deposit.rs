@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/asset/deposit/set-deposit-acct
+//
+// POST /v5/asset/deposit/deposit-to-account
+// {
+//     "status": 1
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDepositAccountResult {
+    /// 1: success, 0: failed
+    pub status: i32,
+}
+
+impl SetDepositAccountResult {
+    pub fn is_success(&self) -> bool {
+        self.status == 1
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/fund-history
+//
+// GET /v5/asset/fundinghistory
+// {
+//     "list": [
+//         {
+//             "id": "592324",
+//             "coin": "USDT",
+//             "type": "TRANSFER_IN",
+//             "amount": "100",
+//             "balance": "1100",
+//             "createdTime": "1721289012000"
+//         }
+//     ],
+//     "nextPageCursor": ""
+// }
+
+/// A balance change on the funding account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundAccountRecord {
+    pub id: String,
+    pub coin: String,
+    /// Deposit, withdrawal, transfer, convert etc.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Signed change in the coin balance
+    pub amount: String,
+    /// Balance after the change
+    #[serde(default)]
+    pub balance: String,
+    pub created_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundAccountHistoryResult {
+    #[serde(default)]
+    pub list: Vec<FundAccountRecord>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
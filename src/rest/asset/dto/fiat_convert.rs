@@ -0,0 +1,296 @@
+use crate::rest::enums::fiat::{FiatCoinType, FiatTradeStatus};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/query-coin-list
+//
+// GET /v5/fiat/query-coin-list
+// {
+//     "fiats": [
+//         {
+//             "coin": "EUR",
+//             "fullName": "Euro",
+//             "icon": "https://...",
+//             "iconNight": "https://...",
+//             "precision": 2,
+//             "disable": false,
+//             "singleFromMinAmount": "10",
+//             "singleFromMaxAmount": "20000"
+//         }
+//     ],
+//     "cryptos": [
+//         {
+//             "coin": "USDT",
+//             "fullName": "Tether",
+//             "icon": "https://...",
+//             "iconNight": "https://...",
+//             "precision": 4,
+//             "disable": false,
+//             "singleFromMinAmount": "10",
+//             "singleFromMaxAmount": "20000"
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatConvertCoin {
+    pub coin: String,
+    #[serde(default)]
+    pub full_name: String,
+    #[serde(default)]
+    pub icon: String,
+    #[serde(default)]
+    pub icon_night: String,
+    #[serde(default)]
+    pub precision: i32,
+    /// True if the coin is currently unavailable for conversion
+    #[serde(default)]
+    pub disable: bool,
+    #[serde(default)]
+    pub single_from_min_amount: String,
+    #[serde(default)]
+    pub single_from_max_amount: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatCoinListResult {
+    #[serde(default)]
+    pub fiats: Vec<FiatConvertCoin>,
+    #[serde(default)]
+    pub cryptos: Vec<FiatConvertCoin>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/reference-price
+//
+// GET /v5/fiat/reference-price
+// {
+//     "symbol": "USDTEUR",
+//     "fiat": "EUR",
+//     "coin": "USDT",
+//     "buyPrice": "0.9312",
+//     "sellPrice": "0.9241",
+//     "timestamp": "1741773200000"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatReferencePrice {
+    pub symbol: String,
+    pub fiat: String,
+    pub coin: String,
+    /// Fiat paid per coin when buying crypto
+    #[serde(default)]
+    pub buy_price: String,
+    /// Fiat received per coin when selling crypto
+    #[serde(default)]
+    pub sell_price: String,
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/quote-apply
+//
+// POST /v5/fiat/quote-apply
+// {
+//     "fromCoin": "EUR",
+//     "fromCoinType": "fiat",
+//     "toCoin": "USDT",
+//     "toCoinType": "crypto",
+//     "requestAmount": "100",
+//     "requestCoinType": "fiat"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatQuoteRequest {
+    pub from_coin: String,
+    pub from_coin_type: FiatCoinType,
+    pub to_coin: String,
+    pub to_coin_type: FiatCoinType,
+    pub request_amount: String,
+    /// Which side `request_amount` is denominated in
+    pub request_coin_type: FiatCoinType,
+}
+
+impl FiatQuoteRequest {
+    /// Quote for spending `amount` of fiat on crypto
+    pub fn buy_crypto(fiat: &str, crypto: &str, amount: &str) -> Self {
+        Self {
+            from_coin: fiat.to_string(),
+            from_coin_type: FiatCoinType::Fiat,
+            to_coin: crypto.to_string(),
+            to_coin_type: FiatCoinType::Crypto,
+            request_amount: amount.to_string(),
+            request_coin_type: FiatCoinType::Fiat,
+        }
+    }
+
+    /// Quote for selling `amount` of crypto for fiat
+    pub fn sell_crypto(crypto: &str, fiat: &str, amount: &str) -> Self {
+        Self {
+            from_coin: crypto.to_string(),
+            from_coin_type: FiatCoinType::Crypto,
+            to_coin: fiat.to_string(),
+            to_coin_type: FiatCoinType::Fiat,
+            request_amount: amount.to_string(),
+            request_coin_type: FiatCoinType::Crypto,
+        }
+    }
+}
+
+// {
+//     "quoteTxId": "QuoteTaxId1741773200",
+//     "exchangeRate": "1.0738",
+//     "fromCoin": "EUR",
+//     "fromCoinType": "fiat",
+//     "toCoin": "USDT",
+//     "toCoinType": "crypto",
+//     "fromAmount": "100",
+//     "toAmount": "107.38",
+//     "expiredTime": "1741773230000"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatQuote {
+    pub quote_tx_id: String,
+    pub exchange_rate: String,
+    pub from_coin: String,
+    pub from_coin_type: FiatCoinType,
+    pub to_coin: String,
+    pub to_coin_type: FiatCoinType,
+    pub from_amount: String,
+    pub to_amount: String,
+    #[serde(default)]
+    pub expired_time: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/confirm-quote
+//
+// POST /v5/fiat/trade-execute
+// {
+//     "quoteTxId": "QuoteTaxId1741773200",
+//     "subUserId": "",
+//     "merchantRequestId": "desk-20250312-0001"
+// }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatTradeRequest {
+    pub quote_tx_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// Caller-chosen id, usable with `get_fiat_trade` instead of `tradeNo`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_request_id: Option<String>,
+}
+
+// {
+//     "tradeNo": "TradeNo1741773201",
+//     "merchantRequestId": "desk-20250312-0001"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatTradeExecuteResult {
+    pub trade_no: String,
+    #[serde(default)]
+    pub merchant_request_id: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/query-trade
+//
+// GET /v5/fiat/trade-query
+// {
+//     "tradeNo": "TradeNo1741773201",
+//     "status": "success",
+//     "quoteTxId": "QuoteTaxId1741773200",
+//     "exchangeRate": "1.0738",
+//     "fromCoin": "EUR",
+//     "fromCoinType": "fiat",
+//     "toCoin": "USDT",
+//     "toCoinType": "crypto",
+//     "fromAmount": "100",
+//     "toAmount": "107.38",
+//     "createdAt": "1741773201000",
+//     "subUserId": ""
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatTrade {
+    pub trade_no: String,
+    pub status: FiatTradeStatus,
+    #[serde(default)]
+    pub quote_tx_id: String,
+    pub exchange_rate: String,
+    pub from_coin: String,
+    pub from_coin_type: FiatCoinType,
+    pub to_coin: String,
+    pub to_coin_type: FiatCoinType,
+    pub from_amount: String,
+    pub to_amount: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub sub_user_id: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/fiat-convert/query-trade-history
+//
+// GET /v5/fiat/query-trade-history
+// {
+//     "list": [ ...same shape as trade-query... ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatTradeHistoryResult {
+    #[serde(default)]
+    pub list: Vec<FiatTrade>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_buy_crypto_quote() {
+        let body =
+            serde_json::to_value(FiatQuoteRequest::buy_crypto("EUR", "USDT", "100")).unwrap();
+        assert_eq!(body["fromCoinType"], "fiat");
+        assert_eq!(body["toCoinType"], "crypto");
+        assert_eq!(body["requestCoinType"], "fiat");
+    }
+
+    #[test]
+    fn test_deserialize_trade() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "tradeNo": "TradeNo1741773201",
+                "status": "processing",
+                "quoteTxId": "QuoteTaxId1741773200",
+                "exchangeRate": "1.0738",
+                "fromCoin": "EUR",
+                "fromCoinType": "fiat",
+                "toCoin": "USDT",
+                "toCoinType": "crypto",
+                "fromAmount": "100",
+                "toAmount": "107.38",
+                "createdAt": "1741773201000",
+                "subUserId": ""
+            },
+            "retExtInfo": {},
+            "time": 1741773201000
+        }"#;
+        let response: ServerResponse<FiatTrade> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.status, FiatTradeStatus::Processing);
+        assert!(!response.result.status.is_final());
+    }
+}
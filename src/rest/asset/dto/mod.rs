@@ -1,2 +1,9 @@
-// Asset module DTOs
-// DTOs will be added as needed for typed responses
+pub mod deposit;
+pub mod fiat_convert;
+pub mod small_balance;
+pub mod withdraw;
+
+pub use deposit::*;
+pub use fiat_convert::*;
+pub use small_balance::*;
+pub use withdraw::*;
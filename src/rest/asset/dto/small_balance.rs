@@ -0,0 +1,268 @@
+use crate::rest::enums::small_balance_account_type::SmallBalanceAccountType;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/small-balance-list
+//
+// GET /v5/asset/covert/small-balance-list
+// {
+//     "smallAssetCoins": [
+//         {
+//             "fromCoin": "XRP",
+//             "supportConvert": 1,
+//             "availableBalance": "0.0002",
+//             "baseValue": "0.00000011",
+//             "toAmount": "0.0001",
+//             "exchangeRate": "0.5",
+//             "feeInfo": {
+//                 "feeCoin": "USDT",
+//                 "amount": "0.000002",
+//                 "feeRate": "0.02"
+//             }
+//         }
+//     ],
+//     "supportToCoins": ["BTC", "USDT", "MNT"],
+//     "totalBaseValue": "0.00000011"
+// }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceFee {
+    #[serde(default)]
+    pub fee_coin: String,
+    #[serde(default)]
+    pub amount: String,
+    #[serde(default)]
+    pub fee_rate: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceCoin {
+    pub from_coin: String,
+    /// 1: can be converted, 2: cannot
+    #[serde(default)]
+    pub support_convert: i32,
+    #[serde(default)]
+    pub available_balance: String,
+    #[serde(default)]
+    pub base_value: String,
+    #[serde(default)]
+    pub to_amount: String,
+    #[serde(default)]
+    pub exchange_rate: String,
+    #[serde(default)]
+    pub fee_info: SmallBalanceFee,
+}
+
+impl SmallBalanceCoin {
+    pub fn is_convertible(&self) -> bool {
+        self.support_convert == 1
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceListResult {
+    #[serde(default)]
+    pub small_asset_coins: Vec<SmallBalanceCoin>,
+    #[serde(default)]
+    pub support_to_coins: Vec<String>,
+    #[serde(default)]
+    pub total_base_value: String,
+}
+
+impl SmallBalanceListResult {
+    /// Coins that can go straight into a `get-quote` request
+    pub fn convertible_coins(&self) -> Vec<String> {
+        self.small_asset_coins
+            .iter()
+            .filter(|coin| coin.is_convertible())
+            .map(|coin| coin.from_coin.clone())
+            .collect()
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/request-quote
+//
+// POST /v5/asset/covert/get-quote
+// {
+//     "accountType": "eb_convert_uta",
+//     "fromCoinList": ["XRP", "SOL"],
+//     "toCoin": "USDT"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceQuoteRequest {
+    pub account_type: SmallBalanceAccountType,
+    pub from_coin_list: Vec<String>,
+    pub to_coin: String,
+}
+
+// {
+//     "quoteId": "1010020692439481682687668224",
+//     "toCoin": "USDT",
+//     "totalToAmount": "0.0002",
+//     "expiredTime": "1737022211000",
+//     "smallAssetCoins": [ ...same shape as small-balance-list... ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceQuote {
+    pub quote_id: String,
+    #[serde(default)]
+    pub to_coin: String,
+    #[serde(default)]
+    pub total_to_amount: String,
+    #[serde(default)]
+    pub expired_time: String,
+    #[serde(default)]
+    pub small_asset_coins: Vec<SmallBalanceCoin>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/confirm-quote
+//
+// POST /v5/asset/covert/small-balance-execute
+// {
+//     "exchangeTxId": "1010020692439483803499737088",
+//     "status": "init"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceExchangeResult {
+    pub exchange_tx_id: String,
+    /// init, processing, success, failure
+    #[serde(default)]
+    pub status: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/convert-small-balance/exchange-history
+//
+// GET /v5/asset/covert/small-balance-history
+// {
+//     "cursor": "",
+//     "size": 1,
+//     "lastId": "0",
+//     "totalCount": 1,
+//     "records": [
+//         {
+//             "accountType": "eb_convert_uta",
+//             "exchangeTxId": "1010020692439483803499737088",
+//             "toCoin": "USDT",
+//             "toAmount": "0.0002",
+//             "status": "success",
+//             "createdAt": "1737022152000",
+//             "subRecords": [
+//                 {
+//                     "fromCoin": "XRP",
+//                     "fromAmount": "0.0002",
+//                     "toCoin": "USDT",
+//                     "toAmount": "0.0001",
+//                     "status": "success",
+//                     "feeInfo": { "feeCoin": "USDT", "amount": "0.000002", "feeRate": "0.02" }
+//                 }
+//             ]
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceSubRecord {
+    pub from_coin: String,
+    pub from_amount: String,
+    pub to_coin: String,
+    pub to_amount: String,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub fee_info: SmallBalanceFee,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceExchangeRecord {
+    pub account_type: SmallBalanceAccountType,
+    pub exchange_tx_id: String,
+    pub to_coin: String,
+    pub to_amount: String,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub sub_records: Vec<SmallBalanceSubRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallBalanceHistoryResult {
+    #[serde(default)]
+    pub cursor: String,
+    #[serde(default)]
+    pub size: i32,
+    #[serde(default)]
+    pub last_id: String,
+    #[serde(default)]
+    pub total_count: i32,
+    #[serde(default)]
+    pub records: Vec<SmallBalanceExchangeRecord>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_small_balance_list() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "ok",
+            "result": {
+                "smallAssetCoins": [
+                    {
+                        "fromCoin": "XRP",
+                        "supportConvert": 1,
+                        "availableBalance": "0.0002",
+                        "baseValue": "0.00000011",
+                        "toAmount": "0.0001",
+                        "exchangeRate": "0.5",
+                        "feeInfo": {"feeCoin": "USDT", "amount": "0.000002", "feeRate": "0.02"}
+                    },
+                    {
+                        "fromCoin": "LUNC",
+                        "supportConvert": 2,
+                        "availableBalance": "3",
+                        "baseValue": "0"
+                    }
+                ],
+                "supportToCoins": ["BTC", "USDT", "MNT"],
+                "totalBaseValue": "0.00000011"
+            },
+            "retExtInfo": {},
+            "time": 1737022152000
+        }"#;
+        let response: ServerResponse<SmallBalanceListResult> =
+            serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.convertible_coins(), vec!["XRP".to_string()]);
+        assert_eq!(
+            response.result.small_asset_coins[0].fee_info.fee_rate,
+            "0.02"
+        );
+    }
+
+    #[test]
+    fn test_serialize_quote_request() {
+        let request = SmallBalanceQuoteRequest {
+            account_type: SmallBalanceAccountType::Unified,
+            from_coin_list: vec!["XRP".to_string()],
+            to_coin: "USDT".to_string(),
+        };
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["accountType"], "eb_convert_uta");
+        assert_eq!(body["fromCoinList"][0], "XRP");
+    }
+}
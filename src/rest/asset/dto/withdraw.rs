@@ -0,0 +1,340 @@
+use crate::rest::enums::travel_rule::{
+    BeneficiaryLegalType, BeneficiaryWalletType, UnhostedWalletType,
+};
+use crate::rest::{BybitError, BybitResult};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/asset/withdraw
+//
+// POST /v5/asset/withdraw/create
+// {
+//     "coin": "USDT",
+//     "chain": "ETH",
+//     "address": "0x99ced129603abc771c0dabe935c326ff6c86645d",
+//     "amount": "24",
+//     "timestamp": 1672196561407,
+//     "forceChain": 0,
+//     "accountType": "FUND"
+// }
+
+/// Body of `AssetClient::withdraw`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRequest {
+    pub coin: String,
+    pub chain: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    pub amount: String,
+    /// Current time in milliseconds
+    pub timestamp: i64,
+    /// 0: on-chain or internal by address, 1: on-chain only, 2: internal by UID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_chain: Option<i32>,
+    /// "SPOT", "FUND", "UTA" or "FUND,UTA"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+    /// Travel Rule details, required by some jurisdictions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary: Option<Beneficiary>,
+}
+
+impl WithdrawRequest {
+    /// A withdrawal timestamped now
+    pub fn new(coin: &str, chain: &str, address: &str, amount: &str) -> Self {
+        WithdrawRequest {
+            coin: coin.to_string(),
+            chain: chain.to_string(),
+            address: address.to_string(),
+            amount: amount.to_string(),
+            timestamp: crate::utils::millis() as i64,
+            ..Default::default()
+        }
+    }
+}
+
+// POST /v5/asset/withdraw/create, `beneficiary` object
+// {
+//     "vaspEntityId": "others",
+//     "beneficiaryName": "Satoshi Nakamoto",
+//     "beneficiaryLegalType": "individual",
+//     "beneficiaryWalletType": "1",
+//     "beneficiaryUnhostedWalletType": "0",
+//     "beneficiaryPoiNumber": "P1234567",
+//     "beneficiaryPoiType": "passport",
+//     "beneficiaryPoiIssuingCountry": "JPN",
+//     "beneficiaryPoiExpiredDate": "2030-01-01",
+//     "beneficiaryDateOfBirth": "1975-04-05",
+//     "beneficiaryPlaceOfBirth": "Tokyo"
+// }
+
+/// Travel Rule details of the withdrawal recipient
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Beneficiary {
+    /// Receiving exchange id from `get_vasp_list()`, or `"others"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vasp_entity_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_legal_type: Option<BeneficiaryLegalType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_wallet_type: Option<BeneficiaryWalletType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_unhosted_wallet_type: Option<UnhostedWalletType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_poi_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_poi_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_poi_issuing_country: Option<String>,
+    /// yyyy-mm-dd
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_poi_expired_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_address_country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_address_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_address_city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_address_building: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_address_street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_address_postal_code: Option<String>,
+    /// yyyy-mm-dd
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_date_of_birth: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary_place_of_birth: Option<String>,
+}
+
+impl Beneficiary {
+    /// Check the fields Bybit requires together before the withdrawal is sent
+    ///
+    /// A custodial wallet needs the receiving VASP, a self-hosted wallet
+    /// needs its owner, and dates must be `yyyy-mm-dd`. Returns
+    /// `BybitError::Rejected` naming the first offending field.
+    pub fn validate(&self) -> BybitResult<()> {
+        fn present(value: &Option<String>) -> bool {
+            value.as_deref().is_some_and(|v| !v.trim().is_empty())
+        }
+        fn invalid(message: &str) -> BybitError {
+            BybitError::Rejected {
+                reason: format!("Invalid Travel Rule beneficiary: {}", message),
+            }
+        }
+
+        if self.beneficiary_legal_type.is_some() && !present(&self.beneficiary_name) {
+            return Err(invalid(
+                "beneficiaryName is required with beneficiaryLegalType",
+            ));
+        }
+        match self.beneficiary_wallet_type {
+            Some(BeneficiaryWalletType::Custodial) if !present(&self.vasp_entity_id) => {
+                return Err(invalid("vaspEntityId is required for a custodial wallet"));
+            }
+            Some(BeneficiaryWalletType::NonCustodial)
+                if self.beneficiary_unhosted_wallet_type.is_none() =>
+            {
+                return Err(invalid(
+                    "beneficiaryUnhostedWalletType is required for a non-custodial wallet",
+                ));
+            }
+            _ => {}
+        }
+        if self.beneficiary_wallet_type.is_none() && !present(&self.vasp_entity_id) {
+            return Err(invalid(
+                "either vaspEntityId or beneficiaryWalletType is required",
+            ));
+        }
+        if present(&self.beneficiary_poi_number) && !present(&self.beneficiary_poi_type) {
+            return Err(invalid(
+                "beneficiaryPoiType is required with beneficiaryPoiNumber",
+            ));
+        }
+        for (name, value) in [
+            (
+                "beneficiaryPoiExpiredDate",
+                &self.beneficiary_poi_expired_date,
+            ),
+            ("beneficiaryDateOfBirth", &self.beneficiary_date_of_birth),
+        ] {
+            if let Some(date) = value {
+                if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+                    return Err(invalid(&format!(
+                        "{} must be yyyy-mm-dd, got {}",
+                        name, date
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/withdraw/vasp-list
+//
+// GET /v5/asset/withdraw/vasp/list
+// {
+//     "vasp": [
+//         {
+//             "vaspEntityId": "basic-finance",
+//             "vaspName": "Basic-finance"
+//         },
+//         {
+//             "vaspEntityId": "others",
+//             "vaspName": "Others"
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vasp {
+    pub vasp_entity_id: String,
+    pub vasp_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaspListResult {
+    #[serde(default)]
+    pub vasp: Vec<Vasp>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/withdraw/withdraw-address
+//
+// GET /v5/asset/withdraw/query-address
+// {
+//     "rows": [
+//         {
+//             "coin": "USDT",
+//             "chain": "ETH",
+//             "address": "0x3c5e4f8a2b1d9e7f6a5c4b3d2e1f0a9b8c7d6e5f",
+//             "tag": "",
+//             "remark": "cold wallet",
+//             "status": 0,
+//             "addressType": 0,
+//             "verified": 1,
+//             "createAt": "1734083455000"
+//         }
+//     ],
+//     "nextPageCursor": ""
+// }
+
+/// An entry in the withdrawal address book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawAddress {
+    pub coin: String,
+    pub chain: String,
+    pub address: String,
+    #[serde(default)]
+    pub tag: String,
+    #[serde(default)]
+    pub remark: String,
+    /// 0: normal, 1: new address still in its waiting period
+    #[serde(default)]
+    pub status: i32,
+    /// 0: on-chain, 1: internal transfer, 2: universal (any coin)
+    #[serde(default)]
+    pub address_type: i32,
+    /// 0: unverified, 1: verified
+    #[serde(default)]
+    pub verified: i32,
+    #[serde(default)]
+    pub create_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawAddressResult {
+    #[serde(default)]
+    pub rows: Vec<WithdrawAddress>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    fn self_hosted() -> Beneficiary {
+        Beneficiary {
+            beneficiary_name: Some("Satoshi Nakamoto".to_string()),
+            beneficiary_legal_type: Some(BeneficiaryLegalType::Individual),
+            beneficiary_wallet_type: Some(BeneficiaryWalletType::NonCustodial),
+            beneficiary_unhosted_wallet_type: Some(UnhostedWalletType::Own),
+            beneficiary_date_of_birth: Some("1975-04-05".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_beneficiary_validate() {
+        assert!(self_hosted().validate().is_ok());
+
+        let mut missing_owner = self_hosted();
+        missing_owner.beneficiary_unhosted_wallet_type = None;
+        assert!(missing_owner.validate().is_err());
+
+        let custodial = Beneficiary {
+            beneficiary_wallet_type: Some(BeneficiaryWalletType::Custodial),
+            ..Default::default()
+        };
+        assert!(custodial.validate().is_err());
+
+        let mut bad_date = self_hosted();
+        bad_date.beneficiary_date_of_birth = Some("05/04/1975".to_string());
+        let err = bad_date.validate().unwrap_err().to_string();
+        assert!(err.starts_with("Request rejected: Invalid Travel Rule beneficiary"));
+        assert!(err.contains("beneficiaryDateOfBirth"));
+
+        assert!(Beneficiary::default().validate().is_err());
+    }
+
+    #[test]
+    fn test_serialize_beneficiary() {
+        let body = serde_json::to_value(self_hosted()).unwrap();
+        assert_eq!(body["beneficiaryWalletType"], "1");
+        assert_eq!(body["beneficiaryUnhostedWalletType"], "0");
+        assert_eq!(body["beneficiaryLegalType"], "individual");
+        assert!(body.get("vaspEntityId").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_withdraw_address() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "rows": [
+                    {
+                        "coin": "USDT",
+                        "chain": "ETH",
+                        "address": "0x3c5e4f8a2b1d9e7f6a5c4b3d2e1f0a9b8c7d6e5f",
+                        "tag": "",
+                        "remark": "cold wallet",
+                        "status": 0,
+                        "addressType": 0,
+                        "verified": 1,
+                        "createAt": "1734083455000"
+                    }
+                ],
+                "nextPageCursor": ""
+            },
+            "retExtInfo": {},
+            "time": 1734083455000
+        }"#;
+        let response: ServerResponse<WithdrawAddressResult> =
+            serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.rows[0].chain, "ETH");
+        assert_eq!(response.result.rows[0].verified, 1);
+    }
+}
@@ -1,4 +1,4 @@
 mod asset_client;
-mod dto;
+pub mod dto;
 
 pub use asset_client::AssetClient;
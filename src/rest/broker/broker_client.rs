@@ -1,7 +1,13 @@
+use crate::rest::broker::dto::*;
 use crate::rest::client::{RestClient, SecType, ServerResponse};
-use crate::rest::BybitResult as Result;
+use crate::rest::enums::broker_biz_type::BrokerBizType;
+use crate::rest::{BybitError, BybitResult as Result};
+use futures_util::{stream, Stream, TryStreamExt};
 use serde_json::json;
 
+/// Page size used by [`BrokerClient::earning_stream`]
+const EARNING_PAGE_LIMIT: i32 = 1000;
+
 #[derive(Clone)]
 pub struct BrokerClient {
     client: RestClient,
@@ -61,16 +67,18 @@ impl BrokerClient {
     }
 
     /// Get broker earning
+    ///
+    /// API: GET /v5/broker/earning-record
     /// https://bybit-exchange.github.io/docs/v5/broker/earning
     pub async fn get_earning_record(
         &self,
-        biz_type: Option<&str>,
+        biz_type: Option<BrokerBizType>,
         begin: Option<&str>,
         end: Option<&str>,
         uid: Option<&str>,
         limit: Option<i32>,
         cursor: Option<&str>,
-    ) -> Result<ServerResponse<serde_json::Value>> {
+    ) -> Result<ServerResponse<BrokerEarningResult>> {
         let endpoint = "v5/broker/earning-record";
         let mut params = json!({});
 
@@ -97,6 +105,53 @@ impl BrokerClient {
         Ok(response)
     }
 
+    /// Stream every earning record in the window, following `nextPageCursor`
+    ///
+    /// Pass a `biz_type` to walk one business type at a time; `None` returns
+    /// all of them. Pages are fetched lazily as the stream is polled, and the
+    /// stream ends after the first error.
+    pub fn earning_stream(
+        &self,
+        biz_type: Option<BrokerBizType>,
+        begin: Option<&str>,
+        end: Option<&str>,
+        uid: Option<&str>,
+    ) -> impl Stream<Item = Result<BrokerEarning>> {
+        let client = self.clone();
+        let begin = begin.map(str::to_string);
+        let end = end.map(str::to_string);
+        let uid = uid.map(str::to_string);
+
+        // State is the cursor of the next page; `None` once the last page is read
+        stream::try_unfold(Some(String::new()), move |cursor| {
+            let client = client.clone();
+            let begin = begin.clone();
+            let end = end.clone();
+            let uid = uid.clone();
+            async move {
+                let Some(cursor) = cursor else {
+                    return Ok(None);
+                };
+                let page = client
+                    .get_earning_record(
+                        biz_type,
+                        begin.as_deref(),
+                        end.as_deref(),
+                        uid.as_deref(),
+                        Some(EARNING_PAGE_LIMIT),
+                        (!cursor.is_empty()).then_some(cursor.as_str()),
+                    )
+                    .await?
+                    .result;
+                let next =
+                    Some(page.next_page_cursor).filter(|next| !next.is_empty() && *next != cursor);
+                let records = stream::iter(page.details.into_iter().map(Ok));
+                Ok::<_, BybitError>(Some((records, next)))
+            }
+        })
+        .try_flatten()
+    }
+
     /// Get award info
     /// https://bybit-exchange.github.io/docs/v5/broker/award-info
     pub async fn get_award_info(
@@ -165,37 +220,79 @@ impl BrokerClient {
     }
 
     /// Get distribution record
-    /// https://bybit-exchange.github.io/docs/v5/broker/distribution-record
+    ///
+    /// Look up a voucher handed to a sub-account. The `spec_code` picks which
+    /// distribution of the award to read, so every voucher type is covered.
+    ///
+    /// API: POST /v5/broker/award/distribution-record
+    /// https://bybit-exchange.github.io/docs/v5/broker/reward/distribution-record
     pub async fn get_distribution_record(
         &self,
-        coin: Option<&str>,
-        page: Option<i32>,
-        limit: Option<i32>,
-        start_date: Option<&str>,
-        end_date: Option<&str>,
-    ) -> Result<ServerResponse<serde_json::Value>> {
+        request: DistributionRecordRequest,
+    ) -> Result<ServerResponse<DistributionRecord>> {
         let endpoint = "v5/broker/award/distribution-record";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Set sub-account API rate limits
+    ///
+    /// Each entry applies one per-second rate to a business type for a set
+    /// of sub-account uids. Check `failures()` on the result, as entries are
+    /// applied independently.
+    ///
+    /// API: POST /v5/broker/apilimit/set
+    /// https://bybit-exchange.github.io/docs/v5/broker/apilimit/set
+    pub async fn set_api_rate_limit(
+        &self,
+        request: SetApiRateLimitRequest,
+    ) -> Result<ServerResponse<SetApiRateLimitResult>> {
+        let endpoint = "v5/broker/apilimit/set";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Query sub-account API rate limits
+    ///
+    /// API: GET /v5/broker/apilimit/query-all
+    /// https://bybit-exchange.github.io/docs/v5/broker/apilimit/query-all
+    pub async fn get_api_rate_limits(
+        &self,
+        uids: Option<&[&str]>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<ApiRateLimitResult>> {
+        let endpoint = "v5/broker/apilimit/query-all";
         let mut params = json!({});
 
-        if let Some(coin) = coin {
-            params["coin"] = json!(coin);
-        }
-        if let Some(page) = page {
-            params["page"] = json!(page);
+        if let Some(uids) = uids {
+            params["uids"] = json!(uids.join(","));
         }
         if let Some(limit) = limit {
             params["limit"] = json!(limit);
         }
-        if let Some(start_date) = start_date {
-            params["startDate"] = json!(start_date);
-        }
-        if let Some(end_date) = end_date {
-            params["endDate"] = json!(end_date);
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
         }
 
         let response = self.client.get(endpoint, params, SecType::Signed).await?;
         Ok(response)
     }
+
+    /// Get the broker's API rate limit caps
+    ///
+    /// API: GET /v5/broker/apilimit/query-cap
+    /// https://bybit-exchange.github.io/docs/v5/broker/apilimit/query-cap
+    pub async fn get_api_rate_limit_cap(&self) -> Result<ServerResponse<ApiRateLimitCapResult>> {
+        let endpoint = "v5/broker/apilimit/query-cap";
+        let response = self
+            .client
+            .get(endpoint, json!({}), SecType::Signed)
+            .await?;
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
@@ -249,7 +346,7 @@ mod tests {
         let client = create_test_client();
         let result = client
             .get_earning_record(
-                Some("trading"),
+                Some(BrokerBizType::Spot),
                 Some("2023-01-01"),
                 Some("2023-12-31"),
                 None,
@@ -0,0 +1,189 @@
+use crate::rest::enums::broker_biz_type::BrokerBizType;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/broker/apilimit/set
+//
+// POST /v5/broker/apilimit/set
+// {
+//     "list": [
+//         {
+//             "uids": "106293838",
+//             "bizType": "DERIVATIVES",
+//             "rate": 50
+//         }
+//     ]
+// }
+
+/// Per-second rate limit for one or more sub-account uids
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRateLimit {
+    /// Comma separated sub-account uids
+    pub uids: String,
+    pub biz_type: BrokerBizType,
+    /// Requests per second
+    pub rate: i32,
+}
+
+impl ApiRateLimit {
+    pub fn new(uids: &[&str], biz_type: BrokerBizType, rate: i32) -> Self {
+        Self {
+            uids: uids.join(","),
+            biz_type,
+            rate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetApiRateLimitRequest {
+    pub list: Vec<ApiRateLimit>,
+}
+
+// {
+//     "result": [
+//         {
+//             "uids": "106293838",
+//             "bizType": "DERIVATIVES",
+//             "rate": 50,
+//             "success": true,
+//             "msg": "API limit updated successfully"
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetApiRateLimitEntry {
+    pub uids: String,
+    pub biz_type: BrokerBizType,
+    pub rate: i32,
+    pub success: bool,
+    #[serde(default)]
+    pub msg: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetApiRateLimitResult {
+    #[serde(default)]
+    pub result: Vec<SetApiRateLimitEntry>,
+}
+
+impl SetApiRateLimitResult {
+    /// Entries the exchange refused to apply
+    pub fn failures(&self) -> impl Iterator<Item = &SetApiRateLimitEntry> {
+        self.result.iter().filter(|entry| !entry.success)
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/broker/apilimit/query-all
+//
+// GET /v5/broker/apilimit/query-all
+// {
+//     "list": [
+//         {
+//             "uids": "290118",
+//             "bizType": "SPOT",
+//             "rate": 600
+//         }
+//     ],
+//     "nextPageCursor": ""
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRateLimitResult {
+    #[serde(default)]
+    pub list: Vec<ApiRateLimit>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/broker/apilimit/query-cap
+//
+// GET /v5/broker/apilimit/query-cap
+// {
+//     "list": [
+//         {
+//             "bizType": "SPOT",
+//             "totalRate": "50000",
+//             "insCap": "30000",
+//             "uidCap": "600"
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRateLimitCap {
+    pub biz_type: BrokerBizType,
+    /// Total rate already assigned to sub-accounts
+    pub total_rate: String,
+    /// Institution-wide cap
+    pub ins_cap: String,
+    /// Maximum rate for a single uid
+    pub uid_cap: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRateLimitCapResult {
+    #[serde(default)]
+    pub list: Vec<ApiRateLimitCap>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_set_request() {
+        let request = SetApiRateLimitRequest {
+            list: vec![ApiRateLimit::new(
+                &["106293838", "106293839"],
+                BrokerBizType::Derivatives,
+                50,
+            )],
+        };
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["list"][0]["uids"], "106293838,106293839");
+        assert_eq!(body["list"][0]["bizType"], "DERIVATIVES");
+        assert_eq!(body["list"][0]["rate"], 50);
+    }
+
+    #[test]
+    fn test_deserialize_set_result() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "result": [
+                    {
+                        "uids": "106293838",
+                        "bizType": "DERIVATIVES",
+                        "rate": 50,
+                        "success": true,
+                        "msg": "API limit updated successfully"
+                    },
+                    {
+                        "uids": "106293839",
+                        "bizType": "SPOT",
+                        "rate": 5000,
+                        "success": false,
+                        "msg": "rate exceeds uid cap"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1739506526000
+        }"#;
+        let response: ServerResponse<SetApiRateLimitResult> =
+            serde_json::from_str(json_data).unwrap();
+        let failures: Vec<_> = response.result.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].uids, "106293839");
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/broker/reward/distribution-record
+//
+// POST /v5/broker/award/distribution-record
+// {
+//     "accountId": "5714139",
+//     "awardId": "189528",
+//     "specCode": "demo000"
+// }
+
+/// Query for a voucher distributed to a sub-account
+///
+/// `spec_code` is the customised code chosen when the voucher was
+/// distributed, so one award id can be queried for every voucher type it
+/// was handed out as.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionRecordRequest {
+    pub account_id: String,
+    pub award_id: String,
+    pub spec_code: String,
+}
+
+impl DistributionRecordRequest {
+    pub fn new(account_id: &str, award_id: &str, spec_code: &str) -> Self {
+        Self {
+            account_id: account_id.to_string(),
+            award_id: award_id.to_string(),
+            spec_code: spec_code.to_string(),
+        }
+    }
+}
+
+// {
+//     "accountId": "5714139",
+//     "awardId": "189528",
+//     "specCode": "demo000",
+//     "amount": "1",
+//     "isClaimed": true,
+//     "startAt": "1725926400",
+//     "endAt": "1733788800",
+//     "effectiveAt": "1726531200",
+//     "ineffectiveAt": "1733817600",
+//     "usedAmount": ""
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionRecord {
+    pub account_id: String,
+    pub award_id: String,
+    pub spec_code: String,
+    pub amount: String,
+    /// Whether the sub-account has claimed the voucher
+    pub is_claimed: bool,
+    /// Claim window start, in seconds
+    #[serde(default)]
+    pub start_at: String,
+    /// Claim window end, in seconds
+    #[serde(default)]
+    pub end_at: String,
+    /// When the voucher becomes usable, in seconds
+    #[serde(default)]
+    pub effective_at: String,
+    /// When the voucher expires, in seconds
+    #[serde(default)]
+    pub ineffective_at: String,
+    #[serde(default)]
+    pub used_amount: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_distribution_record() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "accountId": "5714139",
+                "awardId": "189528",
+                "specCode": "demo000",
+                "amount": "1",
+                "isClaimed": true,
+                "startAt": "1725926400",
+                "endAt": "1733788800",
+                "effectiveAt": "1726531200",
+                "ineffectiveAt": "1733817600",
+                "usedAmount": ""
+            },
+            "retExtInfo": {},
+            "time": 1726112099846
+        }"#;
+        let response: ServerResponse<DistributionRecord> = serde_json::from_str(json_data).unwrap();
+        assert!(response.result.is_claimed);
+        assert_eq!(response.result.spec_code, "demo000");
+    }
+}
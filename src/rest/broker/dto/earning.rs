@@ -0,0 +1,162 @@
+use crate::rest::enums::broker_biz_type::BrokerBizType;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/broker/earning
+//
+// GET /v5/broker/earning-record
+// {
+//     "totalEarningCat": {
+//         "spot": [],
+//         "derivatives": [
+//             {
+//                 "coin": "USDT",
+//                 "earning": "0.00027844"
+//             }
+//         ],
+//         "options": [],
+//         "convert": [],
+//         "total": [
+//             {
+//                 "coin": "USDT",
+//                 "earning": "0.00027844"
+//             }
+//         ]
+//     },
+//     "details": [
+//         {
+//             "userId": "117894077",
+//             "bizType": "DERIVATIVES",
+//             "symbol": "DOGEUSDT",
+//             "coin": "USDT",
+//             "earning": "0.00016166",
+//             "markupEarning": "0.000032332",
+//             "baseFeeEarning": "0.000129328",
+//             "orderId": "ec2132f2-a7e0-4a0c-9219-9f3cbcd8e878",
+//             "execId": "c4ea2cc4-6fd2-52c1-b5dd-3b3a2f41c3b5",
+//             "execTime": "1712735720000"
+//         }
+//     ],
+//     "nextPageCursor": ""
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinEarning {
+    pub coin: String,
+    pub earning: String,
+}
+
+/// Earnings summed per coin, grouped by business type
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotalEarningCategory {
+    #[serde(default)]
+    pub spot: Vec<CoinEarning>,
+    #[serde(default)]
+    pub derivatives: Vec<CoinEarning>,
+    #[serde(default)]
+    pub options: Vec<CoinEarning>,
+    #[serde(default)]
+    pub convert: Vec<CoinEarning>,
+    #[serde(default)]
+    pub total: Vec<CoinEarning>,
+}
+
+impl TotalEarningCategory {
+    /// Per-coin totals for a single business type
+    pub fn for_biz_type(&self, biz_type: BrokerBizType) -> &[CoinEarning] {
+        match biz_type {
+            BrokerBizType::Spot => &self.spot,
+            BrokerBizType::Derivatives => &self.derivatives,
+            BrokerBizType::Options => &self.options,
+            BrokerBizType::Convert => &self.convert,
+        }
+    }
+}
+
+/// A single commission record earned from a sub-account execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerEarning {
+    pub user_id: String,
+    pub biz_type: BrokerBizType,
+    #[serde(default)]
+    pub symbol: String,
+    pub coin: String,
+    pub earning: String,
+    #[serde(default)]
+    pub markup_earning: String,
+    #[serde(default)]
+    pub base_fee_earning: String,
+    #[serde(default)]
+    pub order_id: String,
+    #[serde(default)]
+    pub exec_id: String,
+    pub exec_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerEarningResult {
+    #[serde(default)]
+    pub total_earning_cat: TotalEarningCategory,
+    #[serde(default)]
+    pub details: Vec<BrokerEarning>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_earning_record() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "totalEarningCat": {
+                    "spot": [],
+                    "derivatives": [{"coin": "USDT", "earning": "0.00027844"}],
+                    "options": [],
+                    "convert": [],
+                    "total": [{"coin": "USDT", "earning": "0.00027844"}]
+                },
+                "details": [
+                    {
+                        "userId": "117894077",
+                        "bizType": "DERIVATIVES",
+                        "symbol": "DOGEUSDT",
+                        "coin": "USDT",
+                        "earning": "0.00016166",
+                        "markupEarning": "0.000032332",
+                        "baseFeeEarning": "0.000129328",
+                        "orderId": "ec2132f2-a7e0-4a0c-9219-9f3cbcd8e878",
+                        "execId": "c4ea2cc4-6fd2-52c1-b5dd-3b3a2f41c3b5",
+                        "execTime": "1712735720000"
+                    }
+                ],
+                "nextPageCursor": ""
+            },
+            "retExtInfo": {},
+            "time": 1712735720000
+        }"#;
+        let response: ServerResponse<BrokerEarningResult> =
+            serde_json::from_str(json_data).unwrap();
+        let result = response.result;
+        assert_eq!(result.details[0].biz_type, BrokerBizType::Derivatives);
+        assert_eq!(
+            result
+                .total_earning_cat
+                .for_biz_type(BrokerBizType::Derivatives)[0]
+                .earning,
+            "0.00027844"
+        );
+        assert!(result
+            .total_earning_cat
+            .for_biz_type(BrokerBizType::Spot)
+            .is_empty());
+    }
+}
@@ -1,2 +1,7 @@
-// Broker module DTOs
-// DTOs will be added as needed for typed responses
+pub mod api_limit;
+pub mod award;
+pub mod earning;
+
+pub use api_limit::*;
+pub use award::*;
+pub use earning::*;
@@ -1,4 +1,4 @@
 mod broker_client;
-mod dto;
+pub mod dto;
 
 pub use broker_client::BrokerClient;
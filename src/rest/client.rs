@@ -1,8 +1,10 @@
+use crate::config::ClientConfig;
 use crate::consts::{
     API_REQUEST_KEY, RECV_WINDOW_KEY, SIGNATURE_KEY, SIGN_TYPE_KEY, TIMESTAMP_KEY,
 };
+use crate::environment::BybitEnv;
 use crate::rest::api_key_pair::ApiKeyPair;
-use crate::rest::errors::{BybitResult, ErrorCodes};
+use crate::rest::errors::{BybitError, BybitResult, ErrorCodes};
 use crate::utils::{millis, sign, RateLimiter};
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
@@ -22,6 +24,7 @@ pub struct RestClient {
     http_client: reqwest::Client,
     recv_window: String,
     rate_limiter: Option<RateLimiter>,
+    demo_only: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -52,6 +55,75 @@ struct RawServerResponse {
     pub time: i64,
 }
 
+// P2P endpoints answer with a snake_case envelope instead:
+// {"ret_code":0,"ret_msg":"SUCCESS","ext_code":"","ext_info":{},"result":{},"time_now":"1736213457.123"}
+#[derive(Deserialize, Debug)]
+struct P2pRawServerResponse {
+    ret_code: i32,
+    ret_msg: String,
+    #[serde(default)]
+    result: serde_json::Value,
+    #[serde(default)]
+    ext_info: serde_json::Value,
+    /// Fractional seconds
+    time_now: String,
+}
+
+impl RawServerResponse {
+    /// Parse either envelope; the P2P one is recognised by its `ret_code` key.
+    fn from_value(value: serde_json::Value) -> BybitResult<Self> {
+        if value.get("ret_code").is_none() {
+            return Ok(serde_json::from_value(value)?);
+        }
+        let p2p: P2pRawServerResponse = serde_json::from_value(value)?;
+        let secs: f64 = p2p
+            .time_now
+            .parse()
+            .map_err(|_| BybitError::Internal(format!("Invalid P2P time_now: {}", p2p.time_now)))?;
+        Ok(RawServerResponse {
+            ret_code: p2p.ret_code,
+            ret_msg: p2p.ret_msg,
+            result: p2p.result,
+            ret_ext_info: p2p.ext_info,
+            time: (secs * 1000.0) as i64,
+        })
+    }
+}
+
+/// Percent-encode `"`, CR and LF so a `Content-Disposition` parameter
+/// cannot end early or inject part headers.
+fn escape_disposition_param(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+async fn parse_response<A: DeserializeOwned>(
+    r: reqwest::Response,
+) -> BybitResult<ServerResponse<A>> {
+    let raw_response = RawServerResponse::from_value(r.json().await?)?;
+
+    if raw_response.ret_code != 0 {
+        let code_str = raw_response.ret_code.to_string();
+        // Try to parse into known ErrorCodes, otherwise fallback to generic
+        if let Ok(error_code) = serde_json::from_value(serde_json::json!(code_str)) {
+            return Err(BybitError::Api(error_code));
+        } else {
+            return Err(BybitError::Api(ErrorCodes::E10001));
+        }
+    }
+
+    let result: A = serde_json::from_value(raw_response.result)?;
+    Ok(ServerResponse {
+        ret_code: raw_response.ret_code,
+        ret_msg: raw_response.ret_msg,
+        result,
+        ret_ext_info: raw_response.ret_ext_info,
+        time: raw_response.time,
+    })
+}
+
 impl RestClient {
     pub fn new(api_key_pair: ApiKeyPair, base_url: String) -> RestClient {
         RestClient {
@@ -60,9 +132,22 @@ impl RestClient {
             http_client: reqwest::Client::new(),
             recv_window: "5000".to_string(),
             rate_limiter: None,
+            demo_only: false,
         }
     }
 
+    /// Create a client for a [`BybitEnv`] (mainnet, testnet, demo, region, ...).
+    ///
+    /// Clients created for `BybitEnv::Demo` are demo-only.
+    pub fn for_env(api_key_pair: ApiKeyPair, env: BybitEnv) -> RestClient {
+        RestClient::new(api_key_pair, env.rest_url().to_string()).with_demo_only(env.is_demo())
+    }
+
+    /// REST API base URL this client sends requests to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Set a rate limiter for this client.
     ///
     /// Bybit limits: 50 req/s for both public and private endpoints.
@@ -72,11 +157,44 @@ impl RestClient {
         self
     }
 
+    /// Refuse to send requests unless the base URL is the demo trading host.
+    ///
+    /// Guards test strategies against accidentally trading on a live account.
+    pub fn with_demo_only(mut self, enabled: bool) -> Self {
+        self.demo_only = enabled;
+        self
+    }
+
+    pub fn is_demo_only(&self) -> bool {
+        self.demo_only
+    }
+
     pub fn with_recv_window(mut self, recv_window: impl Into<String>) -> Self {
         self.recv_window = recv_window.into();
         self
     }
 
+    /// Rebuild the underlying HTTP client from a [`ClientConfig`].
+    ///
+    /// Applies timeouts, proxy, TLS roots, connection pool sizing, socket
+    /// options and default headers. Fails if the config is invalid
+    /// (e.g. malformed proxy URL or certificate).
+    pub fn with_config(mut self, config: &ClientConfig) -> BybitResult<Self> {
+        self.http_client = config.build_http_client()?;
+        self.demo_only |= config.demo_only;
+        Ok(self)
+    }
+
+    fn check_demo_host(&self) -> BybitResult<()> {
+        if self.demo_only && !BybitEnv::is_demo_url(&self.base_url) {
+            return Err(BybitError::Config(format!(
+                "Demo-only client refuses non-demo host {}",
+                self.base_url
+            )));
+        }
+        Ok(())
+    }
+
     fn query_string(&self, query: serde_json::Value) -> BybitResult<String> {
         let object = query.as_object().ok_or_else(|| {
             crate::rest::errors::BybitError::Internal(
@@ -118,6 +236,7 @@ impl RestClient {
         query: serde_json::Value,
         sec_type: SecType,
     ) -> BybitResult<ServerResponse<A>> {
+        self.check_demo_host()?;
         if let Some(ref limiter) = self.rate_limiter {
             limiter.acquire().await;
         }
@@ -136,28 +255,7 @@ impl RestClient {
         log::debug!("url: {}", url);
 
         let r = request_builder.send().await?;
-        let raw_response: RawServerResponse = r.json().await?;
-
-        if raw_response.ret_code != 0 {
-            let code_str = raw_response.ret_code.to_string();
-            // Try to parse into known ErrorCodes, otherwise fallback to generic
-            if let Ok(error_code) = serde_json::from_value(serde_json::json!(code_str)) {
-                return Err(crate::rest::errors::BybitError::Api(error_code));
-            } else {
-                return Err(crate::rest::errors::BybitError::Api(
-                    crate::rest::errors::ErrorCodes::E10001,
-                )); // System error fallback or similar
-            }
-        }
-
-        let result: A = serde_json::from_value(raw_response.result)?;
-        Ok(ServerResponse {
-            ret_code: raw_response.ret_code,
-            ret_msg: raw_response.ret_msg,
-            result,
-            ret_ext_info: raw_response.ret_ext_info,
-            time: raw_response.time,
-        })
+        parse_response(r).await
     }
 
     pub async fn post<A: DeserializeOwned>(
@@ -166,6 +264,7 @@ impl RestClient {
         body: serde_json::Value,
         sec_type: SecType,
     ) -> BybitResult<ServerResponse<A>> {
+        self.check_demo_host()?;
         if let Some(ref limiter) = self.rate_limiter {
             limiter.acquire().await;
         }
@@ -178,24 +277,56 @@ impl RestClient {
         }
 
         let r = request_builder.json(&body).send().await?;
-        let raw_response: RawServerResponse = r.json().await?;
-
-        if raw_response.ret_code != 0 {
-            let code_str = raw_response.ret_code.to_string();
-            if let Ok(error_code) = serde_json::from_value(serde_json::json!(code_str)) {
-                return Err(crate::rest::errors::BybitError::Api(error_code));
-            } else {
-                return Err(crate::rest::errors::BybitError::Api(ErrorCodes::E10001));
-            }
+        parse_response(r).await
+    }
+
+    /// POST a single file as `multipart/form-data`.
+    ///
+    /// The body is not JSON, so a signed request signs an empty payload.
+    /// `"`, CR and LF in `field` and `file_name` are percent-encoded; a
+    /// `content_type` containing control characters is refused with
+    /// `BybitError::Rejected`, as it cannot be escaped.
+    pub async fn post_multipart<A: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        field: &str,
+        file_name: &str,
+        content_type: &str,
+        content: Vec<u8>,
+        sec_type: SecType,
+    ) -> BybitResult<ServerResponse<A>> {
+        self.check_demo_host()?;
+        if content_type.chars().any(char::is_control) {
+            return Err(BybitError::Rejected {
+                reason: format!("Invalid multipart content type: {:?}", content_type),
+            });
         }
+        if let Some(ref limiter) = self.rate_limiter {
+            limiter.acquire().await;
+        }
+        let url = format!("{}/{}", self.base_url, endpoint);
+        let boundary = format!("----bybit-rust-api-{}", uuid::Uuid::new_v4().simple());
 
-        let result: A = serde_json::from_value(raw_response.result)?;
-        Ok(ServerResponse {
-            ret_code: raw_response.ret_code,
-            ret_msg: raw_response.ret_msg,
-            result,
-            ret_ext_info: raw_response.ret_ext_info,
-            time: raw_response.time,
-        })
+        let mut body = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            boundary,
+            escape_disposition_param(field),
+            escape_disposition_param(file_name),
+            content_type
+        )
+        .into_bytes();
+        body.extend_from_slice(&content);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+        let mut request_builder = self.http_client.post(&url).header(
+            reqwest::header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", boundary),
+        );
+        if sec_type == SecType::Signed {
+            request_builder = self.sign_request(request_builder, String::new());
+        }
+
+        let r = request_builder.body(body).send().await?;
+        parse_response(r).await
     }
 }
@@ -0,0 +1,461 @@
+use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::crypto_loan_new::dto::*;
+use crate::rest::BybitResult as Result;
+use serde_json::json;
+
+/// Crypto loans on the `crypto-loan-common`, `crypto-loan-flexible` and
+/// `crypto-loan-fixed` endpoints, which replace the legacy `crypto-loan`
+/// endpoints of [`CryptoLoanClient`](crate::rest::CryptoLoanClient).
+#[derive(Clone)]
+pub struct CryptoLoanNewClient {
+    client: RestClient,
+}
+
+impl CryptoLoanNewClient {
+    pub fn new(client: RestClient) -> Self {
+        CryptoLoanNewClient { client }
+    }
+
+    /// Get borrowable coins
+    ///
+    /// API: GET /v5/crypto-loan-common/loanable-data
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/loan-coin
+    pub async fn get_loanable_data(
+        &self,
+        currency: Option<&str>,
+        vip_level: Option<&str>,
+    ) -> Result<ServerResponse<LoanableDataResult>> {
+        let endpoint = "v5/crypto-loan-common/loanable-data";
+        let mut params = json!({});
+
+        if let Some(currency) = currency {
+            params["currency"] = json!(currency);
+        }
+        if let Some(vip_level) = vip_level {
+            params["vipLevel"] = json!(vip_level);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get collateral coins
+    ///
+    /// API: GET /v5/crypto-loan-common/collateral-data
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/collateral-coin
+    pub async fn get_collateral_coins(
+        &self,
+        currency: Option<&str>,
+    ) -> Result<ServerResponse<CollateralCoinResult>> {
+        let endpoint = "v5/crypto-loan-common/collateral-data";
+        let mut params = json!({});
+
+        if let Some(currency) = currency {
+            params["currency"] = json!(currency);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get max loan amount
+    ///
+    /// API: POST /v5/crypto-loan-common/max-loan
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/max-loan
+    pub async fn get_max_loan(
+        &self,
+        request: MaxLoanRequest,
+    ) -> Result<ServerResponse<MaxLoanResult>> {
+        let endpoint = "v5/crypto-loan-common/max-loan";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get max collateral reduction amount
+    ///
+    /// API: GET /v5/crypto-loan-common/max-collateral-amount
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/reduce-max-collateral-amt
+    pub async fn get_max_collateral_amount(
+        &self,
+        currency: &str,
+    ) -> Result<ServerResponse<MaxCollateralAmountResult>> {
+        let endpoint = "v5/crypto-loan-common/max-collateral-amount";
+        let params = json!({
+            "currency": currency,
+        });
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Adjust collateral amount
+    ///
+    /// API: POST /v5/crypto-loan-common/adjust-ltv
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/adjust-collateral
+    pub async fn adjust_ltv(
+        &self,
+        request: AdjustLtvRequest,
+    ) -> Result<ServerResponse<AdjustLtvResult>> {
+        let endpoint = "v5/crypto-loan-common/adjust-ltv";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get collateral adjustment history
+    ///
+    /// API: GET /v5/crypto-loan-common/adjustment-history
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/ltv-adjust-history
+    pub async fn get_adjustment_history(
+        &self,
+        adjust_id: Option<&str>,
+        collateral_currency: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<LtvAdjustmentResult>> {
+        let endpoint = "v5/crypto-loan-common/adjustment-history";
+        let mut params = json!({});
+
+        if let Some(adjust_id) = adjust_id {
+            params["adjustId"] = json!(adjust_id);
+        }
+        if let Some(collateral_currency) = collateral_currency {
+            params["collateralCurrency"] = json!(collateral_currency);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Borrow (flexible)
+    ///
+    /// API: POST /v5/crypto-loan-flexible/borrow
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/borrow
+    pub async fn flexible_borrow(
+        &self,
+        request: FlexibleBorrowRequest,
+    ) -> Result<ServerResponse<LoanOrderResult>> {
+        let endpoint = "v5/crypto-loan-flexible/borrow";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Repay (flexible)
+    ///
+    /// API: POST /v5/crypto-loan-flexible/repay
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/repay
+    pub async fn flexible_repay(
+        &self,
+        request: FlexibleRepayRequest,
+    ) -> Result<ServerResponse<LoanRepayResult>> {
+        let endpoint = "v5/crypto-loan-flexible/repay";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Repay with collateral (flexible)
+    ///
+    /// API: POST /v5/crypto-loan-flexible/repay-collateral
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/repay-collateral
+    pub async fn flexible_collateral_repay(
+        &self,
+        request: FlexibleCollateralRepayRequest,
+    ) -> Result<ServerResponse<LoanRepayResult>> {
+        let endpoint = "v5/crypto-loan-flexible/repay-collateral";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get ongoing flexible loans
+    ///
+    /// API: GET /v5/crypto-loan-flexible/ongoing-coin
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/unpaid-loan-order
+    pub async fn get_flexible_loans(
+        &self,
+        loan_currency: Option<&str>,
+    ) -> Result<ServerResponse<FlexibleLoanResult>> {
+        let endpoint = "v5/crypto-loan-flexible/ongoing-coin";
+        let mut params = json!({});
+
+        if let Some(loan_currency) = loan_currency {
+            params["loanCurrency"] = json!(loan_currency);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get flexible borrow history
+    ///
+    /// API: GET /v5/crypto-loan-flexible/borrow-history
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/loan-orders
+    pub async fn get_flexible_borrow_history(
+        &self,
+        order_id: Option<&str>,
+        loan_currency: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<FlexibleBorrowHistoryResult>> {
+        let endpoint = "v5/crypto-loan-flexible/borrow-history";
+        let mut params = json!({});
+
+        if let Some(order_id) = order_id {
+            params["orderId"] = json!(order_id);
+        }
+        if let Some(loan_currency) = loan_currency {
+            params["loanCurrency"] = json!(loan_currency);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get flexible repayment history
+    ///
+    /// API: GET /v5/crypto-loan-flexible/repayment-history
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/repay-hist
+    pub async fn get_flexible_repayment_history(
+        &self,
+        repay_id: Option<&str>,
+        loan_currency: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<LoanRepaymentResult>> {
+        let endpoint = "v5/crypto-loan-flexible/repayment-history";
+        let mut params = json!({});
+
+        if let Some(repay_id) = repay_id {
+            params["repayId"] = json!(repay_id);
+        }
+        if let Some(loan_currency) = loan_currency {
+            params["loanCurrency"] = json!(loan_currency);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get fixed-term supply market
+    ///
+    /// `order_by` is "apy", "term" or "quantity"; `sort` 0 ascending, 1 descending.
+    ///
+    /// API: GET /v5/crypto-loan-fixed/supply-order-quote
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/supply-market
+    pub async fn get_fixed_supply_quotes(
+        &self,
+        order_currency: &str,
+        order_by: &str,
+        term: Option<i32>,
+        sort: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<ServerResponse<FixedLoanQuoteResult>> {
+        let endpoint = "v5/crypto-loan-fixed/supply-order-quote";
+        let mut params = json!({
+            "orderCurrency": order_currency,
+            "orderBy": order_by,
+        });
+
+        if let Some(term) = term {
+            params["term"] = json!(term);
+        }
+        if let Some(sort) = sort {
+            params["sort"] = json!(sort);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get fixed-term borrow market
+    ///
+    /// `order_by` is "apy", "term" or "quantity"; `sort` 0 ascending, 1 descending.
+    ///
+    /// API: GET /v5/crypto-loan-fixed/borrow-order-quote
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/borrow-market
+    pub async fn get_fixed_borrow_quotes(
+        &self,
+        order_currency: &str,
+        order_by: &str,
+        term: Option<i32>,
+        sort: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<ServerResponse<FixedLoanQuoteResult>> {
+        let endpoint = "v5/crypto-loan-fixed/borrow-order-quote";
+        let mut params = json!({
+            "orderCurrency": order_currency,
+            "orderBy": order_by,
+        });
+
+        if let Some(term) = term {
+            params["term"] = json!(term);
+        }
+        if let Some(sort) = sort {
+            params["sort"] = json!(sort);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Borrow (fixed term)
+    ///
+    /// API: POST /v5/crypto-loan-fixed/borrow
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/borrow
+    pub async fn fixed_borrow(
+        &self,
+        request: FixedBorrowRequest,
+    ) -> Result<ServerResponse<LoanOrderResult>> {
+        let endpoint = "v5/crypto-loan-fixed/borrow";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Supply (fixed term)
+    ///
+    /// API: POST /v5/crypto-loan-fixed/supply
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/supply
+    pub async fn fixed_supply(
+        &self,
+        request: FixedSupplyRequest,
+    ) -> Result<ServerResponse<LoanOrderResult>> {
+        let endpoint = "v5/crypto-loan-fixed/supply";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get fixed-term borrow contracts
+    ///
+    /// API: GET /v5/crypto-loan-fixed/borrow-contract-info
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/borrow-contract
+    pub async fn get_fixed_borrow_contracts(
+        &self,
+        order_id: Option<&str>,
+        loan_id: Option<&str>,
+        order_currency: Option<&str>,
+        term: Option<i32>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<FixedBorrowContractResult>> {
+        let endpoint = "v5/crypto-loan-fixed/borrow-contract-info";
+        let mut params = json!({});
+
+        if let Some(order_id) = order_id {
+            params["orderId"] = json!(order_id);
+        }
+        if let Some(loan_id) = loan_id {
+            params["loanId"] = json!(loan_id);
+        }
+        if let Some(order_currency) = order_currency {
+            params["orderCurrency"] = json!(order_currency);
+        }
+        if let Some(term) = term {
+            params["term"] = json!(term);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Repay in full (fixed term)
+    ///
+    /// API: POST /v5/crypto-loan-fixed/fully-repay
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/repay
+    pub async fn fixed_repay(
+        &self,
+        request: FixedRepayRequest,
+    ) -> Result<ServerResponse<LoanRepayResult>> {
+        let endpoint = "v5/crypto-loan-fixed/fully-repay";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Repay with collateral (fixed term)
+    ///
+    /// API: POST /v5/crypto-loan-fixed/repay-collateral
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/repay-collateral
+    pub async fn fixed_collateral_repay(
+        &self,
+        request: FixedCollateralRepayRequest,
+    ) -> Result<ServerResponse<LoanRepayResult>> {
+        let endpoint = "v5/crypto-loan-fixed/repay-collateral";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get fixed-term repayment history
+    ///
+    /// API: GET /v5/crypto-loan-fixed/repayment-history
+    /// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/repay-hist
+    pub async fn get_fixed_repayment_history(
+        &self,
+        repay_id: Option<&str>,
+        loan_currency: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<LoanRepaymentResult>> {
+        let endpoint = "v5/crypto-loan-fixed/repayment-history";
+        let mut params = json!({});
+
+        if let Some(repay_id) = repay_id {
+            params["repayId"] = json!(repay_id);
+        }
+        if let Some(loan_currency) = loan_currency {
+            params["loanCurrency"] = json!(loan_currency);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,399 @@
+use crate::rest::enums::LtvAdjustDirection;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/loan-coin
+//
+// GET /v5/crypto-loan-common/loanable-data?currency=ETH
+// {
+//     "list": [
+//         {
+//             "currency": "ETH",
+//             "fixedBorrowable": true,
+//             "fixedBorrowingAccuracy": 6,
+//             "flexibleBorrowable": true,
+//             "flexibleBorrowingAccuracy": 4,
+//             "maxBorrowingAmount": "1100",
+//             "minFixedBorrowingAmount": "0.1",
+//             "minFlexibleBorrowingAmount": "0.001",
+//             "vipLevel": "VIP0",
+//             "flexibleAnnualizedInterestRate": "0.002",
+//             "lowestFixedAnnualizedInterestRate": "0.0015",
+//             "highestFixedAnnualizedInterestRate": "0.05"
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanableCoin {
+    pub currency: String,
+    #[serde(default)]
+    pub fixed_borrowable: bool,
+    /// Decimal places of fixed-term borrow amounts
+    #[serde(default)]
+    pub fixed_borrowing_accuracy: i32,
+    #[serde(default)]
+    pub flexible_borrowable: bool,
+    #[serde(default)]
+    pub flexible_borrowing_accuracy: i32,
+    #[serde(default)]
+    pub max_borrowing_amount: String,
+    #[serde(default)]
+    pub min_fixed_borrowing_amount: String,
+    #[serde(default)]
+    pub min_flexible_borrowing_amount: String,
+    #[serde(default)]
+    pub vip_level: String,
+    #[serde(default)]
+    pub flexible_annualized_interest_rate: String,
+    #[serde(default)]
+    pub lowest_fixed_annualized_interest_rate: String,
+    #[serde(default)]
+    pub highest_fixed_annualized_interest_rate: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanableDataResult {
+    pub list: Vec<LoanableCoin>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/collateral-coin
+//
+// GET /v5/crypto-loan-common/collateral-data?currency=BTC
+// {
+//     "collateralRatioConfigList": [
+//         {
+//             "collateralRatioList": [
+//                 { "collateralRatio": "0.8", "maxValue": "1000000", "minValue": "0" },
+//                 { "collateralRatio": "0.7", "maxValue": "", "minValue": "1000000" }
+//             ],
+//             "currencies": "BTC,ETH"
+//         }
+//     ],
+//     "currencyLiquidationList": [
+//         { "currency": "BTC", "liquidationOrder": 1 }
+//     ]
+// }
+
+/// Collateral value ratio applied to a USD value tier.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralRatioTier {
+    pub collateral_ratio: String,
+    /// Empty for the open-ended top tier
+    #[serde(default)]
+    pub max_value: String,
+    #[serde(default)]
+    pub min_value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralRatioConfig {
+    pub collateral_ratio_list: Vec<CollateralRatioTier>,
+    /// Comma-separated coins the tiers apply to
+    pub currencies: String,
+}
+
+/// Order in which collateral is liquidated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrencyLiquidation {
+    pub currency: String,
+    pub liquidation_order: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralCoinResult {
+    #[serde(default)]
+    pub collateral_ratio_config_list: Vec<CollateralRatioConfig>,
+    #[serde(default)]
+    pub currency_liquidation_list: Vec<CurrencyLiquidation>,
+}
+
+/// Collateral coin and amount, used when borrowing or estimating the
+/// maximum loan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanCollateral {
+    pub currency: String,
+    pub amount: String,
+}
+
+impl LoanCollateral {
+    pub fn new(currency: impl Into<String>, amount: impl Into<String>) -> Self {
+        LoanCollateral {
+            currency: currency.into(),
+            amount: amount.into(),
+        }
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/max-loan
+//
+// POST /v5/crypto-loan-common/max-loan
+// {
+//     "currency": "BTC",
+//     "collateralList": [ { "currency": "XRP", "amount": "1000" } ]
+// }
+//
+// {
+//     "currency": "BTC",
+//     "maxLoan": "0.1722",
+//     "notionalUsd": "18370.22",
+//     "remainingQuota": "3999999.9"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxLoanRequest {
+    pub currency: String,
+    /// Extra collateral to include in the estimate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collateral_list: Option<Vec<LoanCollateral>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxLoanResult {
+    pub currency: String,
+    pub max_loan: String,
+    #[serde(default)]
+    pub notional_usd: String,
+    #[serde(default)]
+    pub remaining_quota: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/reduce-max-collateral-amt
+//
+// GET /v5/crypto-loan-common/max-collateral-amount?currency=BTC
+// {
+//     "maxCollateralAmount": "0.00016"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxCollateralAmountResult {
+    pub max_collateral_amount: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/adjust-collateral
+//
+// POST /v5/crypto-loan-common/adjust-ltv
+// {
+//     "currency": "BTC",
+//     "amount": "0.08",
+//     "direction": "1"
+// }
+//
+// {
+//     "adjustId": 27511
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustLtvRequest {
+    /// Collateral coin
+    pub currency: String,
+    pub amount: String,
+    pub direction: LtvAdjustDirection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustLtvResult {
+    pub adjust_id: i64,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/ltv-adjust-history
+//
+// GET /v5/crypto-loan-common/adjustment-history?adjustId=27511
+// {
+//     "list": [
+//         {
+//             "amount": "0.08",
+//             "adjustId": 27511,
+//             "adjustTime": 1750218512000,
+//             "afterLTV": "0.8",
+//             "collateralCurrency": "BTC",
+//             "direction": 1,
+//             "preLTV": "0.5",
+//             "status": 1
+//         }
+//     ],
+//     "nextPageCursor": "27511"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LtvAdjustment {
+    pub collateral_currency: String,
+    pub amount: String,
+    pub adjust_id: i64,
+    pub adjust_time: i64,
+    #[serde(rename = "preLTV")]
+    pub pre_ltv: String,
+    #[serde(rename = "afterLTV")]
+    pub after_ltv: String,
+    /// 0: add collateral, 1: reduce collateral
+    pub direction: i32,
+    /// 0: processing, 1: success, 2: failed
+    pub status: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LtvAdjustmentResult {
+    pub list: Vec<LtvAdjustment>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+/// Result of borrow and supply orders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanOrderResult {
+    pub order_id: String,
+}
+
+/// Result of repayments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanRepayResult {
+    #[serde(default)]
+    pub repay_id: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/repay-hist
+//
+// GET /v5/crypto-loan-flexible/repayment-history
+// {
+//     "list": [
+//         {
+//             "loanCurrency": "BTC",
+//             "repayAmount": "0.10",
+//             "repayId": "1771",
+//             "repayStatus": 1,
+//             "repayTime": 1750218512000,
+//             "repayType": 1
+//         }
+//     ],
+//     "nextPageCursor": "1771"
+// }
+//
+// Fixed-term repayment history has the same shape plus `loanId`.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanRepayment {
+    pub loan_currency: String,
+    pub repay_amount: String,
+    pub repay_id: String,
+    /// 0: processing, 1: success, 2: failed
+    pub repay_status: i32,
+    pub repay_time: i64,
+    /// 1: repaid by the user, 2: liquidation, 3: collateral repay, ...
+    #[serde(default)]
+    pub repay_type: i32,
+    /// Fixed-term loans only
+    #[serde(default)]
+    pub loan_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanRepaymentResult {
+    pub list: Vec<LoanRepayment>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_loanable_data() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "request.success",
+            "result": {
+                "list": [
+                    {
+                        "currency": "ETH",
+                        "fixedBorrowable": true,
+                        "fixedBorrowingAccuracy": 6,
+                        "flexibleBorrowable": true,
+                        "flexibleBorrowingAccuracy": 4,
+                        "maxBorrowingAmount": "1100",
+                        "minFixedBorrowingAmount": "0.1",
+                        "minFlexibleBorrowingAmount": "0.001",
+                        "vipLevel": "VIP0",
+                        "flexibleAnnualizedInterestRate": "0.002"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1750218512000
+        }"#;
+        let response: ServerResponse<LoanableDataResult> = serde_json::from_str(json_data).unwrap();
+        let coin = &response.result.list[0];
+        assert!(coin.flexible_borrowable);
+        assert_eq!(coin.fixed_borrowing_accuracy, 6);
+        assert_eq!(coin.lowest_fixed_annualized_interest_rate, "");
+    }
+
+    #[test]
+    fn test_deserialize_collateral_coins() {
+        let json_data = r#"{
+            "collateralRatioConfigList": [
+                {
+                    "collateralRatioList": [
+                        { "collateralRatio": "0.8", "maxValue": "1000000", "minValue": "0" },
+                        { "collateralRatio": "0.7", "maxValue": "", "minValue": "1000000" }
+                    ],
+                    "currencies": "BTC,ETH"
+                }
+            ],
+            "currencyLiquidationList": [ { "currency": "BTC", "liquidationOrder": 1 } ]
+        }"#;
+        let result: CollateralCoinResult = serde_json::from_str(json_data).unwrap();
+        assert_eq!(
+            result.collateral_ratio_config_list[0].collateral_ratio_list[1].collateral_ratio,
+            "0.7"
+        );
+        assert_eq!(result.currency_liquidation_list[0].liquidation_order, 1);
+    }
+
+    #[test]
+    fn test_adjust_ltv_request_and_history() {
+        let request = AdjustLtvRequest {
+            currency: "BTC".to_string(),
+            amount: "0.08".to_string(),
+            direction: LtvAdjustDirection::Reduce,
+        };
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["direction"], "1");
+
+        let json_data = r#"{
+            "list": [
+                {
+                    "amount": "0.08",
+                    "adjustId": 27511,
+                    "adjustTime": 1750218512000,
+                    "afterLTV": "0.8",
+                    "collateralCurrency": "BTC",
+                    "direction": 1,
+                    "preLTV": "0.5",
+                    "status": 1
+                }
+            ],
+            "nextPageCursor": "27511"
+        }"#;
+        let result: LtvAdjustmentResult = serde_json::from_str(json_data).unwrap();
+        assert_eq!(result.list[0].after_ltv, "0.8");
+        assert_eq!(result.list[0].adjust_id, 27511);
+    }
+}
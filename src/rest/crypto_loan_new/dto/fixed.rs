@@ -0,0 +1,220 @@
+use super::LoanCollateral;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/supply-market
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/borrow-market
+//
+// GET /v5/crypto-loan-fixed/supply-order-quote?orderCurrency=USDT&orderBy=apy
+// {
+//     "list": [
+//         { "orderCurrency": "USDT", "term": 7, "annualRate": "0.02", "qty": "1000" }
+//     ]
+// }
+
+/// One level of the fixed-term supply or borrow market.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedLoanQuote {
+    pub order_currency: String,
+    /// Term in days
+    pub term: i32,
+    pub annual_rate: String,
+    pub qty: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedLoanQuoteResult {
+    pub list: Vec<FixedLoanQuote>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/borrow
+//
+// POST /v5/crypto-loan-fixed/borrow
+// {
+//     "orderCurrency": "ETH",
+//     "orderAmount": "1.5",
+//     "annualRate": "0.022",
+//     "term": "30",
+//     "autoRepay": "true",
+//     "collateralList": [ { "currency": "BTC", "amount": "0.1" } ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedBorrowRequest {
+    pub order_currency: String,
+    pub order_amount: String,
+    /// Highest acceptable annual rate
+    pub annual_rate: String,
+    /// Term in days
+    pub term: String,
+    /// "true" to repay automatically at maturity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_repay: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collateral_list: Option<Vec<LoanCollateral>>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/supply
+//
+// POST /v5/crypto-loan-fixed/supply
+// {
+//     "orderCurrency": "USDT",
+//     "orderAmount": "2000",
+//     "annualRate": "0.02",
+//     "term": "7"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedSupplyRequest {
+    pub order_currency: String,
+    pub order_amount: String,
+    /// Lowest acceptable annual rate
+    pub annual_rate: String,
+    /// Term in days
+    pub term: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/borrow-contract
+//
+// GET /v5/crypto-loan-fixed/borrow-contract-info
+// {
+//     "list": [
+//         {
+//             "annualRate": "0.022",
+//             "autoRepay": "true",
+//             "borrowCurrency": "ETH",
+//             "borrowTime": "1750218512000",
+//             "interestPaid": "0",
+//             "loanId": "571",
+//             "orderId": "13007",
+//             "repaymentTime": "1752810512000",
+//             "residualPenaltyInterest": "0",
+//             "residualPrincipal": "1.5",
+//             "status": 1,
+//             "term": "30"
+//         }
+//     ],
+//     "nextPageCursor": "571"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedBorrowContract {
+    pub annual_rate: String,
+    #[serde(default)]
+    pub auto_repay: String,
+    pub borrow_currency: String,
+    pub borrow_time: String,
+    #[serde(default)]
+    pub interest_paid: String,
+    pub loan_id: String,
+    pub order_id: String,
+    /// Maturity
+    pub repayment_time: String,
+    #[serde(default)]
+    pub residual_penalty_interest: String,
+    pub residual_principal: String,
+    /// 1: unrepaid, 2: fully repaid, 3: overdue
+    pub status: i32,
+    pub term: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedBorrowContractResult {
+    pub list: Vec<FixedBorrowContract>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/repay
+//
+// POST /v5/crypto-loan-fixed/fully-repay
+// {
+//     "loanId": "571"
+// }
+
+/// Repay fixed-term loans in full, either one loan or every loan of a coin.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedRepayRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan_currency: Option<String>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/fixed/repay-collateral
+//
+// POST /v5/crypto-loan-fixed/repay-collateral
+// {
+//     "loanCurrency": "ETH",
+//     "collateralCoin": "BTC",
+//     "amount": "0.5"
+// }
+
+/// Repay fixed-term loans by selling pledged collateral.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedCollateralRepayRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan_currency: Option<String>,
+    /// Comma-separated collateral coins to sell
+    pub collateral_coin: String,
+    pub amount: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_fixed_quotes() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "request.success",
+            "result": {
+                "list": [
+                    { "orderCurrency": "USDT", "term": 7, "annualRate": "0.02", "qty": "1000" },
+                    { "orderCurrency": "USDT", "term": 30, "annualRate": "0.025", "qty": "250" }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1750218512000
+        }"#;
+        let response: ServerResponse<FixedLoanQuoteResult> =
+            serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.list[1].term, 30);
+    }
+
+    #[test]
+    fn test_deserialize_fixed_borrow_contract() {
+        let json_data = r#"{
+            "list": [
+                {
+                    "annualRate": "0.022",
+                    "autoRepay": "true",
+                    "borrowCurrency": "ETH",
+                    "borrowTime": "1750218512000",
+                    "interestPaid": "0",
+                    "loanId": "571",
+                    "orderId": "13007",
+                    "repaymentTime": "1752810512000",
+                    "residualPenaltyInterest": "0",
+                    "residualPrincipal": "1.5",
+                    "status": 1,
+                    "term": "30"
+                }
+            ],
+            "nextPageCursor": "571"
+        }"#;
+        let result: FixedBorrowContractResult = serde_json::from_str(json_data).unwrap();
+        assert_eq!(result.list[0].loan_id, "571");
+        assert_eq!(result.list[0].residual_principal, "1.5");
+    }
+}
@@ -0,0 +1,166 @@
+use super::LoanCollateral;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/borrow
+//
+// POST /v5/crypto-loan-flexible/borrow
+// {
+//     "loanCurrency": "BTC",
+//     "loanAmount": "0.1",
+//     "collateralList": [ { "currency": "USDT", "amount": "1000" } ]
+// }
+//
+// {
+//     "orderId": "1363"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleBorrowRequest {
+    pub loan_currency: String,
+    pub loan_amount: String,
+    /// Collateral to pledge with the loan; existing collateral is used if
+    /// omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collateral_list: Option<Vec<LoanCollateral>>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/repay
+//
+// POST /v5/crypto-loan-flexible/repay
+// {
+//     "loanCurrency": "BTC",
+//     "amount": "0.005"
+// }
+//
+// {
+//     "repayId": "1771"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRepayRequest {
+    pub loan_currency: String,
+    pub amount: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/repay-collateral
+//
+// POST /v5/crypto-loan-flexible/repay-collateral
+// {
+//     "loanCurrency": "BTC",
+//     "collateralCoin": "ETH",
+//     "amount": "0.01"
+// }
+
+/// Repay a flexible loan by selling pledged collateral.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleCollateralRepayRequest {
+    pub loan_currency: String,
+    /// Comma-separated collateral coins to sell
+    pub collateral_coin: String,
+    /// Loan amount to repay
+    pub amount: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/unpaid-loan-order
+//
+// GET /v5/crypto-loan-flexible/ongoing-coin?loanCurrency=BTC
+// {
+//     "list": [
+//         {
+//             "hourlyInterestRate": "0.0000010241",
+//             "loanCurrency": "BTC",
+//             "totalDebt": "0.1000002"
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoan {
+    pub loan_currency: String,
+    /// Principal plus accrued interest
+    pub total_debt: String,
+    pub hourly_interest_rate: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlexibleLoanResult {
+    pub list: Vec<FlexibleLoan>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/new-crypto-loan/flexible/loan-orders
+//
+// GET /v5/crypto-loan-flexible/borrow-history
+// {
+//     "list": [
+//         {
+//             "borrowTime": 1750218512000,
+//             "initialLoanAmount": "0.1",
+//             "loanCurrency": "BTC",
+//             "orderId": "1363",
+//             "status": 1
+//         }
+//     ],
+//     "nextPageCursor": "1363"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleBorrowOrder {
+    pub borrow_time: i64,
+    pub initial_loan_amount: String,
+    pub loan_currency: String,
+    pub order_id: String,
+    /// 1: success, 2: processing, 3: failed
+    pub status: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleBorrowHistoryResult {
+    pub list: Vec<FlexibleBorrowOrder>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_flexible_borrow_request() {
+        let request = FlexibleBorrowRequest {
+            loan_currency: "USDT".to_string(),
+            loan_amount: "5000".to_string(),
+            collateral_list: Some(vec![LoanCollateral::new("BTC", "0.2")]),
+        };
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["loanCurrency"], "USDT");
+        assert_eq!(value["collateralList"][0]["currency"], "BTC");
+    }
+
+    #[test]
+    fn test_deserialize_flexible_loans() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "request.success",
+            "result": {
+                "list": [
+                    {
+                        "hourlyInterestRate": "0.0000010241",
+                        "loanCurrency": "BTC",
+                        "totalDebt": "0.1000002"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1750218512000
+        }"#;
+        let response: ServerResponse<FlexibleLoanResult> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.list[0].total_debt, "0.1000002");
+    }
+}
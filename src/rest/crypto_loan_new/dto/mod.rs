@@ -0,0 +1,7 @@
+pub mod common;
+pub mod fixed;
+pub mod flexible;
+
+pub use common::*;
+pub use fixed::*;
+pub use flexible::*;
@@ -0,0 +1,4 @@
+pub mod crypto_loan_new_client;
+pub mod dto;
+
+pub use crypto_loan_new_client::CryptoLoanNewClient;
@@ -0,0 +1,201 @@
+use crate::rest::enums::{AccountType, EarnCategory, EarnOrderType};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/earn/create-order
+//
+// POST /v5/earn/place-order
+// {
+//     "category": "FlexibleSaving",
+//     "orderType": "Stake",
+//     "accountType": "FUND",
+//     "amount": "0.35",
+//     "coin": "BTC",
+//     "productId": "430",
+//     "orderLinkId": "btc-earn-001"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnPlaceOrderRequest {
+    pub category: EarnCategory,
+    pub order_type: EarnOrderType,
+    /// Account to stake from / redeem to: `FUND` or `UNIFIED`
+    pub account_type: AccountType,
+    pub amount: String,
+    pub coin: String,
+    pub product_id: String,
+    /// Required by Bybit; used to deduplicate retries
+    pub order_link_id: String,
+    /// On-chain redeem only: the position to redeem from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redeem_position_id: Option<String>,
+    /// On-chain redeem only: account to receive the funds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_account_type: Option<AccountType>,
+}
+
+impl EarnPlaceOrderRequest {
+    /// Stake `amount` of `coin` into a product.
+    pub fn stake(
+        category: EarnCategory,
+        product_id: impl Into<String>,
+        coin: impl Into<String>,
+        amount: impl Into<String>,
+        account_type: AccountType,
+        order_link_id: impl Into<String>,
+    ) -> Self {
+        EarnPlaceOrderRequest {
+            category,
+            order_type: EarnOrderType::Stake,
+            account_type,
+            amount: amount.into(),
+            coin: coin.into(),
+            product_id: product_id.into(),
+            order_link_id: order_link_id.into(),
+            redeem_position_id: None,
+            to_account_type: None,
+        }
+    }
+
+    /// Redeem `amount` of `coin` from a product back to `account_type`.
+    pub fn redeem(
+        category: EarnCategory,
+        product_id: impl Into<String>,
+        coin: impl Into<String>,
+        amount: impl Into<String>,
+        account_type: AccountType,
+        order_link_id: impl Into<String>,
+    ) -> Self {
+        EarnPlaceOrderRequest {
+            order_type: EarnOrderType::Redeem,
+            ..Self::stake(
+                category,
+                product_id,
+                coin,
+                amount,
+                account_type,
+                order_link_id,
+            )
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnPlaceOrderResponse {
+    pub order_id: String,
+    pub order_link_id: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/earn/order-history
+//
+// GET /v5/earn/order?category=FlexibleSaving&orderId=9640dc23-df1a-448a-ad24-e1a48028a51f
+// {
+//     "coin": "BTC",
+//     "orderValue": "1",
+//     "orderType": "Redeem",
+//     "orderId": "9640dc23-df1a-448a-ad24-e1a48028a51f",
+//     "orderLinkId": "",
+//     "status": "Success",
+//     "createdAt": "1733220962184",
+//     "productId": "8",
+//     "updatedAt": "1733220962184",
+//     "swapOrderValue": "",
+//     "estimateRedeemTime": "",
+//     "estimateStakeTime": ""
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnOrder {
+    pub coin: String,
+    pub order_value: String,
+    pub order_type: EarnOrderType,
+    pub order_id: String,
+    pub order_link_id: String,
+    /// "Success", "Fail" or "Pending"
+    pub status: String,
+    pub created_at: String,
+    pub product_id: String,
+    pub updated_at: String,
+    /// On-chain only: amount of the swap coin received or paid
+    #[serde(default)]
+    pub swap_order_value: String,
+    #[serde(default)]
+    pub estimate_redeem_time: String,
+    #[serde(default)]
+    pub estimate_stake_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnOrderResult {
+    pub list: Vec<EarnOrder>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_place_order() {
+        let request = EarnPlaceOrderRequest::redeem(
+            EarnCategory::FlexibleSaving,
+            "428",
+            "USDT",
+            "250",
+            AccountType::UNIFIED,
+            "treasury-1",
+        );
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "category": "FlexibleSaving",
+                "orderType": "Redeem",
+                "accountType": "UNIFIED",
+                "amount": "250",
+                "coin": "USDT",
+                "productId": "428",
+                "orderLinkId": "treasury-1"
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_order_history() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "list": [
+                    {
+                        "coin": "BTC",
+                        "orderValue": "1",
+                        "orderType": "Redeem",
+                        "orderId": "9640dc23-df1a-448a-ad24-e1a48028a51f",
+                        "orderLinkId": "",
+                        "status": "Success",
+                        "createdAt": "1733220962184",
+                        "productId": "8",
+                        "updatedAt": "1733220962184",
+                        "swapOrderValue": "",
+                        "estimateRedeemTime": "",
+                        "estimateStakeTime": ""
+                    }
+                ],
+                "nextPageCursor": ""
+            },
+            "retExtInfo": {},
+            "time": 1733220983325
+        }"#;
+        let response: ServerResponse<EarnOrderResult> = serde_json::from_str(json_data).unwrap();
+        let order = &response.result.list[0];
+        assert_eq!(order.order_type, EarnOrderType::Redeem);
+        assert_eq!(order.status, "Success");
+        assert_eq!(order.order_value, "1");
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/earn/position
+//
+// GET /v5/earn/position?category=FlexibleSaving&coin=USDT
+// {
+//     "coin": "USDT",
+//     "productId": "428",
+//     "amount": "250.5",
+//     "totalPnl": "0.27",
+//     "claimableYield": "0",
+//     "id": "1218",
+//     "status": "Active",
+//     "orderId": "",
+//     "estimateRedeemTime": "",
+//     "estimateStakeTime": "",
+//     "estimateInterestCalculationTime": "",
+//     "settlementTime": ""
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnPosition {
+    pub coin: String,
+    pub product_id: String,
+    pub amount: String,
+    pub total_pnl: String,
+    pub claimable_yield: String,
+    /// On-chain only: position id, for partial redeems
+    #[serde(default)]
+    pub id: String,
+    /// On-chain only: "Processing" or "Active"
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub order_id: String,
+    #[serde(default)]
+    pub estimate_redeem_time: String,
+    #[serde(default)]
+    pub estimate_stake_time: String,
+    #[serde(default)]
+    pub estimate_interest_calculation_time: String,
+    #[serde(default)]
+    pub settlement_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarnPositionResult {
+    pub list: Vec<EarnPosition>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_earn_position() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "list": [
+                    {
+                        "coin": "USDT",
+                        "productId": "428",
+                        "amount": "250.5",
+                        "totalPnl": "0.27",
+                        "claimableYield": "0"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1739338924138
+        }"#;
+        let response: ServerResponse<EarnPositionResult> = serde_json::from_str(json_data).unwrap();
+        let position = &response.result.list[0];
+        assert_eq!(position.amount, "250.5");
+        assert_eq!(position.total_pnl, "0.27");
+        assert!(position.id.is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::rest::enums::EarnCategory;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/earn/product-info
+//
+// GET /v5/earn/product?category=FlexibleSaving&coin=USDT
+// {
+//     "category": "FlexibleSaving",
+//     "estimateApr": "3%",
+//     "coin": "USDT",
+//     "minStakeAmount": "0.001",
+//     "maxStakeAmount": "100000",
+//     "precision": "8",
+//     "productId": "428",
+//     "status": "Available",
+//     "bonusEvents": [],
+//     "minRedeemAmount": "",
+//     "maxRedeemAmount": "",
+//     "duration": "",
+//     "term": 0,
+//     "swapCoin": "",
+//     "swapCoinPrecision": "",
+//     "stakeExchangeRate": "",
+//     "redeemExchangeRate": "",
+//     "rewardDistributionType": "",
+//     "rewardIntervalMinute": 0,
+//     "redeemProcessingMinute": 0
+// }
+
+/// Bonus APR on top of the base rate, e.g. for new stakers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnBonusEvent {
+    pub apr: String,
+    pub coin: String,
+    #[serde(default)]
+    pub announcement: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnProduct {
+    pub category: EarnCategory,
+    /// Annual rate, e.g. "3%"
+    pub estimate_apr: String,
+    pub coin: String,
+    pub min_stake_amount: String,
+    pub max_stake_amount: String,
+    pub precision: String,
+    pub product_id: String,
+    /// "Available" or "NotAvailable"
+    pub status: String,
+    #[serde(default)]
+    pub bonus_events: Vec<EarnBonusEvent>,
+    #[serde(default)]
+    pub min_redeem_amount: String,
+    #[serde(default)]
+    pub max_redeem_amount: String,
+    /// On-chain only: "Fixed" or "Flexible"
+    #[serde(default)]
+    pub duration: String,
+    /// On-chain only: lock-up days of a fixed product
+    #[serde(default)]
+    pub term: i64,
+    /// On-chain only: coin received when staking, e.g. "METH"
+    #[serde(default)]
+    pub swap_coin: String,
+    #[serde(default)]
+    pub swap_coin_precision: String,
+    #[serde(default)]
+    pub stake_exchange_rate: String,
+    #[serde(default)]
+    pub redeem_exchange_rate: String,
+    #[serde(default)]
+    pub reward_distribution_type: String,
+    #[serde(default)]
+    pub reward_interval_minute: i64,
+    #[serde(default)]
+    pub redeem_processing_minute: i64,
+}
+
+impl EarnProduct {
+    /// Whether the product currently accepts stakes.
+    pub fn is_available(&self) -> bool {
+        self.status == "Available"
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarnProductResult {
+    pub list: Vec<EarnProduct>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_earn_product() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "list": [
+                    {
+                        "category": "FlexibleSaving",
+                        "estimateApr": "3%",
+                        "coin": "USDT",
+                        "minStakeAmount": "0.001",
+                        "maxStakeAmount": "100000",
+                        "precision": "8",
+                        "productId": "428",
+                        "status": "Available",
+                        "bonusEvents": [
+                            {"apr": "1%", "coin": "USDT", "announcement": "https://announcements.bybit.com"}
+                        ],
+                        "minRedeemAmount": "",
+                        "maxRedeemAmount": "",
+                        "duration": "",
+                        "term": 0,
+                        "swapCoin": "",
+                        "swapCoinPrecision": "",
+                        "stakeExchangeRate": "",
+                        "redeemExchangeRate": "",
+                        "rewardDistributionType": "",
+                        "rewardIntervalMinute": 0,
+                        "redeemProcessingMinute": 0
+                    },
+                    {
+                        "category": "OnChain",
+                        "estimateApr": "4.2%",
+                        "coin": "ETH",
+                        "minStakeAmount": "0.01",
+                        "maxStakeAmount": "500",
+                        "precision": "8",
+                        "productId": "9",
+                        "status": "NotAvailable",
+                        "duration": "Flexible",
+                        "swapCoin": "METH"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1739338924138
+        }"#;
+        let response: ServerResponse<EarnProductResult> = serde_json::from_str(json_data).unwrap();
+        let products = response.result.list;
+        assert_eq!(products[0].category, EarnCategory::FlexibleSaving);
+        assert_eq!(products[0].product_id, "428");
+        assert_eq!(products[0].bonus_events[0].apr, "1%");
+        assert!(products[0].is_available());
+        assert_eq!(products[1].category, EarnCategory::OnChain);
+        assert_eq!(products[1].swap_coin, "METH");
+        assert!(!products[1].is_available());
+    }
+}
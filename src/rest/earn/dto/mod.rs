@@ -0,0 +1,7 @@
+pub mod earn_order;
+pub mod earn_position;
+pub mod earn_product;
+
+pub use earn_order::*;
+pub use earn_position::*;
+pub use earn_product::*;
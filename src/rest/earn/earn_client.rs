@@ -0,0 +1,125 @@
+use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::earn::dto::*;
+use crate::rest::enums::EarnCategory;
+use crate::rest::BybitResult as Result;
+use serde_json::json;
+
+#[derive(Clone)]
+pub struct EarnClient {
+    client: RestClient,
+}
+
+impl EarnClient {
+    pub fn new(client: RestClient) -> Self {
+        EarnClient { client }
+    }
+
+    /// Get product info
+    ///
+    /// API: GET /v5/earn/product
+    /// https://bybit-exchange.github.io/docs/v5/earn/product-info
+    pub async fn get_products(
+        &self,
+        category: EarnCategory,
+        coin: Option<&str>,
+    ) -> Result<ServerResponse<EarnProductResult>> {
+        let endpoint = "v5/earn/product";
+        let mut params = json!({
+            "category": category,
+        });
+
+        if let Some(coin) = coin {
+            params["coin"] = json!(coin);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Stake or redeem
+    ///
+    /// API: POST /v5/earn/place-order
+    /// https://bybit-exchange.github.io/docs/v5/earn/create-order
+    pub async fn place_order(
+        &self,
+        request: EarnPlaceOrderRequest,
+    ) -> Result<ServerResponse<EarnPlaceOrderResponse>> {
+        let endpoint = "v5/earn/place-order";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get stake/redeem order history
+    ///
+    /// API: GET /v5/earn/order
+    /// https://bybit-exchange.github.io/docs/v5/earn/order-history
+    pub async fn get_order_history(
+        &self,
+        category: EarnCategory,
+        order_id: Option<&str>,
+        order_link_id: Option<&str>,
+        product_id: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<EarnOrderResult>> {
+        let endpoint = "v5/earn/order";
+        let mut params = json!({
+            "category": category,
+        });
+
+        if let Some(order_id) = order_id {
+            params["orderId"] = json!(order_id);
+        }
+        if let Some(order_link_id) = order_link_id {
+            params["orderLinkId"] = json!(order_link_id);
+        }
+        if let Some(product_id) = product_id {
+            params["productId"] = json!(product_id);
+        }
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get staked positions
+    ///
+    /// API: GET /v5/earn/position
+    /// https://bybit-exchange.github.io/docs/v5/earn/position
+    pub async fn get_positions(
+        &self,
+        category: EarnCategory,
+        product_id: Option<&str>,
+        coin: Option<&str>,
+    ) -> Result<ServerResponse<EarnPositionResult>> {
+        let endpoint = "v5/earn/position";
+        let mut params = json!({
+            "category": category,
+        });
+
+        if let Some(product_id) = product_id {
+            params["productId"] = json!(product_id);
+        }
+        if let Some(coin) = coin {
+            params["coin"] = json!(coin);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+}
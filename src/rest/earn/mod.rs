@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod earn_client;
+
+pub use earn_client::EarnClient;
@@ -0,0 +1,26 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum BrokerBizType {
+    #[serde(rename = "SPOT")]
+    Spot, // Spot trading commission
+    #[serde(rename = "DERIVATIVES")]
+    Derivatives, // Perpetual and futures commission
+    #[serde(rename = "OPTIONS")]
+    Options, // Options commission
+    #[serde(rename = "CONVERT")]
+    Convert, // Convert commission
+}
+
+impl Display for BrokerBizType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            BrokerBizType::Spot => write!(f, "SPOT"),
+            BrokerBizType::Derivatives => write!(f, "DERIVATIVES"),
+            BrokerBizType::Options => write!(f, "OPTIONS"),
+            BrokerBizType::Convert => write!(f, "CONVERT"),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, Default)]
 pub enum Category {
     #[default]
     #[serde(rename = "spot")]
@@ -24,3 +24,18 @@ impl Display for Category {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_category_as_map_key() {
+        let mut books = HashMap::new();
+        books.insert((Category::Linear, "BTCUSDT"), 1);
+        books.insert((Category::Spot, "BTCUSDT"), 2);
+        assert_eq!(books[&(Category::Linear, "BTCUSDT")], 1);
+        assert_eq!(books.len(), 2);
+    }
+}
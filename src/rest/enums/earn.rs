@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum EarnCategory {
+    #[serde(rename = "FlexibleSaving")]
+    FlexibleSaving, // Flexible savings
+    #[serde(rename = "OnChain")]
+    OnChain, // On-chain staking
+}
+
+impl Display for EarnCategory {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            EarnCategory::FlexibleSaving => write!(f, "FlexibleSaving"),
+            EarnCategory::OnChain => write!(f, "OnChain"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum EarnOrderType {
+    #[serde(rename = "Stake")]
+    Stake,
+    #[serde(rename = "Redeem")]
+    Redeem,
+}
+
+impl Display for EarnOrderType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            EarnOrderType::Stake => write!(f, "Stake"),
+            EarnOrderType::Redeem => write!(f, "Redeem"),
+        }
+    }
+}
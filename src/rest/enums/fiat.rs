@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FiatCoinType {
+    #[serde(rename = "crypto")]
+    Crypto, // Digital asset
+    #[serde(rename = "fiat")]
+    Fiat, // Fiat currency
+}
+
+impl Display for FiatCoinType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            FiatCoinType::Crypto => write!(f, "crypto"),
+            FiatCoinType::Fiat => write!(f, "fiat"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FiatTradeStatus {
+    #[serde(rename = "processing")]
+    Processing, // Trade submitted, not yet settled
+    #[serde(rename = "success")]
+    Success, // Trade settled
+    #[serde(rename = "failure")]
+    Failure, // Trade failed
+}
+
+impl FiatTradeStatus {
+    /// True once the trade has settled or failed.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, FiatTradeStatus::Processing)
+    }
+}
+
+impl Display for FiatTradeStatus {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            FiatTradeStatus::Processing => write!(f, "processing"),
+            FiatTradeStatus::Success => write!(f, "success"),
+            FiatTradeStatus::Failure => write!(f, "failure"),
+        }
+    }
+}
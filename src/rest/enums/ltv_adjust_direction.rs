@@ -0,0 +1,20 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum LtvAdjustDirection {
+    #[serde(rename = "0")]
+    Add, // Add collateral, lowering LTV
+    #[serde(rename = "1")]
+    Reduce, // Withdraw collateral, raising LTV
+}
+
+impl Display for LtvAdjustDirection {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            LtvAdjustDirection::Add => write!(f, "0"),
+            LtvAdjustDirection::Reduce => write!(f, "1"),
+        }
+    }
+}
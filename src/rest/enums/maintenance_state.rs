@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MaintenanceState {
+    #[serde(rename = "scheduled")]
+    Scheduled, // Announced, not started yet
+    #[serde(rename = "ongoing")]
+    Ongoing, // Maintenance in progress
+    #[serde(rename = "completed")]
+    Completed, // Finished
+    #[serde(rename = "canceled")]
+    Canceled, // Called off
+}
+
+impl MaintenanceState {
+    /// True while the window is still upcoming or in progress.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            MaintenanceState::Scheduled | MaintenanceState::Ongoing
+        )
+    }
+}
+
+impl Display for MaintenanceState {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            MaintenanceState::Scheduled => write!(f, "scheduled"),
+            MaintenanceState::Ongoing => write!(f, "ongoing"),
+            MaintenanceState::Completed => write!(f, "completed"),
+            MaintenanceState::Canceled => write!(f, "canceled"),
+        }
+    }
+}
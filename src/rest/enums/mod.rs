@@ -6,6 +6,8 @@ pub mod announcement_tag;
 pub use announcement_tag::*;
 pub mod announcement_type;
 pub use announcement_type::*;
+pub mod broker_biz_type;
+pub use broker_biz_type::*;
 pub mod cancel_type;
 pub use cancel_type::*;
 pub mod category;
@@ -22,8 +24,12 @@ pub mod data_recording_period;
 pub use data_recording_period::*;
 pub mod deposit_status;
 pub use deposit_status::*;
+pub mod earn;
+pub use earn::*;
 pub mod exec_type;
 pub use exec_type::*;
+pub mod fiat;
+pub use fiat::*;
 pub mod interval;
 pub use interval::*;
 pub mod interval_time;
@@ -32,6 +38,10 @@ pub mod locale;
 pub use locale::*;
 pub mod lt_status;
 pub use lt_status::*;
+pub mod ltv_adjust_direction;
+pub use ltv_adjust_direction::*;
+pub mod maintenance_state;
+pub use maintenance_state::*;
 pub mod margin_trading;
 pub use margin_trading::*;
 pub mod option_period;
@@ -40,20 +50,34 @@ pub mod order_status;
 pub use order_status::*;
 pub mod order_type;
 pub use order_type::*;
+pub mod p2p;
+pub use p2p::*;
 pub mod position_idx;
 pub use position_idx::*;
+pub mod position_mode;
+pub use position_mode::*;
 pub mod position_status;
 pub use position_status::*;
 pub mod reject_reason;
 pub use reject_reason::*;
+pub mod repay_status;
+pub use repay_status::*;
+pub mod rfq;
+pub use rfq::*;
 pub mod side;
 pub use side::*;
+pub mod small_balance_account_type;
+pub use small_balance_account_type::*;
 pub mod smp_type;
 pub use smp_type::*;
+pub mod spread_contract_type;
+pub use spread_contract_type::*;
 pub mod status;
 pub use status::*;
 pub mod stop_order_type;
 pub use stop_order_type::*;
+pub mod switch_status;
+pub use switch_status::*;
 pub mod symbol;
 pub use symbol::*;
 pub mod symbol_type;
@@ -62,8 +86,14 @@ pub mod tick_direction;
 pub use tick_direction::*;
 pub mod time_in_force;
 pub use time_in_force::*;
+pub mod tp_sl_mode;
+pub use tp_sl_mode::*;
+pub mod trade_mode;
+pub use trade_mode::*;
 pub mod transfer_status;
 pub use transfer_status::*;
+pub mod travel_rule;
+pub use travel_rule::*;
 pub mod trigger_by;
 pub use trigger_by::*;
 pub mod unified_margin_status;
@@ -74,3 +104,49 @@ pub mod vip_level;
 pub use vip_level::*;
 pub mod withdraw_status;
 pub use withdraw_status::*;
+
+use serde::{Deserialize, Deserializer};
+
+/// Deserialize an integer enum code sent either as a number or a string.
+///
+/// Position endpoints send and return codes such as `positionIdx` and
+/// `tradeMode` as integers; some older payloads quote them.
+pub(crate) fn deserialize_int_code<'de, D>(deserializer: D) -> std::result::Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(n) => n.as_i64().ok_or_else(|| {
+            serde::de::Error::custom(format!("expected an integer code, got {}", n))
+        }),
+        serde_json::Value::String(s) => s
+            .parse()
+            .map_err(|_| serde::de::Error::custom(format!("expected an integer code, got {}", s))),
+        other => Err(serde::de::Error::custom(format!(
+            "expected an integer code, got {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int_codes_accept_numbers_and_strings() {
+        assert_eq!(
+            serde_json::from_str::<PositionIdx>("2").unwrap(),
+            PositionIdx::SellSideHedge
+        );
+        assert_eq!(
+            serde_json::from_str::<TradeMode>(r#""1""#).unwrap(),
+            TradeMode::IsolatedMargin
+        );
+        assert!(serde_json::from_str::<PositionIdx>(r#""one""#).is_err());
+        assert_eq!(
+            serde_json::to_string(&PositionIdx::BuySideHedge).unwrap(),
+            "1"
+        );
+    }
+}
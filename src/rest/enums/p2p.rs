@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+// P2P requests take these codes as strings, while responses return them as
+// numbers, so deserialization accepts both.
+fn deserialize_code<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a string or number code, got {}",
+            other
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum P2pSide {
+    #[serde(rename = "0")]
+    Buy, // Buy crypto with fiat
+    #[serde(rename = "1")]
+    Sell, // Sell crypto for fiat
+}
+
+impl<'de> Deserialize<'de> for P2pSide {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        match deserialize_code(deserializer)?.as_str() {
+            "0" => Ok(P2pSide::Buy),
+            "1" => Ok(P2pSide::Sell),
+            other => Err(serde::de::Error::custom(format!(
+                "unknown P2P side {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for P2pSide {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            P2pSide::Buy => write!(f, "0"),
+            P2pSide::Sell => write!(f, "1"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum P2pPriceType {
+    #[serde(rename = "0")]
+    Fixed, // Fixed fiat price
+    #[serde(rename = "1")]
+    Floating, // Premium over the reference price
+}
+
+impl<'de> Deserialize<'de> for P2pPriceType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        match deserialize_code(deserializer)?.as_str() {
+            "0" => Ok(P2pPriceType::Fixed),
+            "1" => Ok(P2pPriceType::Floating),
+            other => Err(serde::de::Error::custom(format!(
+                "unknown P2P price type {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for P2pPriceType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            P2pPriceType::Fixed => write!(f, "0"),
+            P2pPriceType::Floating => write!(f, "1"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum P2pAdAction {
+    #[serde(rename = "MODIFY")]
+    Modify, // Edit an online ad
+    #[serde(rename = "ACTIVE")]
+    Active, // Relist an offline ad
+}
+
+impl Display for P2pAdAction {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            P2pAdAction::Modify => write!(f, "MODIFY"),
+            P2pAdAction::Active => write!(f, "ACTIVE"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum P2pContentType {
+    #[serde(rename = "str")]
+    Text, // Plain text
+    #[serde(rename = "pic")]
+    Picture, // Image uploaded with `upload_file`
+    #[serde(rename = "pdf")]
+    Pdf, // PDF uploaded with `upload_file`
+    #[serde(rename = "video")]
+    Video, // Video uploaded with `upload_file`
+}
+
+impl Display for P2pContentType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            P2pContentType::Text => write!(f, "str"),
+            P2pContentType::Picture => write!(f, "pic"),
+            P2pContentType::Pdf => write!(f, "pdf"),
+            P2pContentType::Video => write!(f, "video"),
+        }
+    }
+}
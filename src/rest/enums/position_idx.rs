@@ -1,23 +1,49 @@
 use std::fmt::{Display, Formatter, Result};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+use super::deserialize_int_code;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PositionIdx {
-    #[serde(rename = "0")]
-    OneWayMode, // one-way mode position
-    #[serde(rename = "1")]
-    BuySideHedge, // Buy side of hedge-mode position
-    #[serde(rename = "2")]
-    SellSideHedge, // Sell side of hedge-mode position
+    #[default]
+    OneWayMode, // 0: one-way mode position
+    BuySideHedge,  // 1: Buy side of hedge-mode position
+    SellSideHedge, // 2: Sell side of hedge-mode position
 }
 
-impl Display for PositionIdx {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+impl PositionIdx {
+    pub fn code(&self) -> i32 {
         match self {
-            PositionIdx::OneWayMode => write!(f, "0"),
-            PositionIdx::BuySideHedge => write!(f, "1"),
-            PositionIdx::SellSideHedge => write!(f, "2"),
+            PositionIdx::OneWayMode => 0,
+            PositionIdx::BuySideHedge => 1,
+            PositionIdx::SellSideHedge => 2,
         }
     }
 }
+
+impl Serialize for PositionIdx {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for PositionIdx {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        match deserialize_int_code(deserializer)? {
+            0 => Ok(PositionIdx::OneWayMode),
+            1 => Ok(PositionIdx::BuySideHedge),
+            2 => Ok(PositionIdx::SellSideHedge),
+            other => Err(serde::de::Error::custom(format!(
+                "unknown position index {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for PositionIdx {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.code())
+    }
+}
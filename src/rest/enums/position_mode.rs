@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::deserialize_int_code;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PositionMode {
+    #[default]
+    MergedSingle, // 0: one-way mode
+    BothSides, // 3: hedge mode
+}
+
+impl PositionMode {
+    pub fn code(&self) -> i32 {
+        match self {
+            PositionMode::MergedSingle => 0,
+            PositionMode::BothSides => 3,
+        }
+    }
+}
+
+impl Serialize for PositionMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for PositionMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        match deserialize_int_code(deserializer)? {
+            0 => Ok(PositionMode::MergedSingle),
+            3 => Ok(PositionMode::BothSides),
+            other => Err(serde::de::Error::custom(format!(
+                "unknown position mode {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for PositionMode {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.code())
+    }
+}
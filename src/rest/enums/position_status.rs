@@ -2,8 +2,9 @@ use std::fmt::{Display, Formatter, Result};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
 pub enum PositionStatus {
+    #[default]
     #[serde(rename = "Normal")]
     Normal,
     #[serde(rename = "Liq")]
@@ -0,0 +1,23 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RepayStatus {
+    #[serde(rename = "P")]
+    Processing, // Repayment in progress
+    #[serde(rename = "SU")]
+    Success, // Repaid
+    #[serde(rename = "FA")]
+    Failed, // Repayment failed
+}
+
+impl Display for RepayStatus {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            RepayStatus::Processing => write!(f, "P"),
+            RepayStatus::Success => write!(f, "SU"),
+            RepayStatus::Failed => write!(f, "FA"),
+        }
+    }
+}
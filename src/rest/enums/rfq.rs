@@ -0,0 +1,90 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RfqStatus {
+    #[serde(rename = "Active")]
+    Active, // Open for quotes
+    #[serde(rename = "Canceled")]
+    Canceled, // Cancelled by the requester
+    #[serde(rename = "Filled")]
+    Filled, // A quote was executed
+    #[serde(rename = "Expired")]
+    Expired, // No quote executed before expiry
+    #[serde(rename = "Failed")]
+    Failed, // Quote execution failed
+}
+
+impl RfqStatus {
+    /// True once the RFQ can no longer be quoted or executed.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, RfqStatus::Active)
+    }
+}
+
+impl Display for RfqStatus {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            RfqStatus::Active => write!(f, "Active"),
+            RfqStatus::Canceled => write!(f, "Canceled"),
+            RfqStatus::Filled => write!(f, "Filled"),
+            RfqStatus::Expired => write!(f, "Expired"),
+            RfqStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum QuoteStatus {
+    #[serde(rename = "Active")]
+    Active, // Executable by the requester
+    #[serde(rename = "Canceled")]
+    Canceled, // Cancelled by the quoter
+    #[serde(rename = "PendingFill")]
+    PendingFill, // Execution in progress
+    #[serde(rename = "Filled")]
+    Filled, // Executed
+    #[serde(rename = "Expired")]
+    Expired, // Not executed before expiry
+    #[serde(rename = "Failed")]
+    Failed, // Execution failed
+}
+
+impl QuoteStatus {
+    /// True once the quote can no longer be executed or filled.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, QuoteStatus::Active | QuoteStatus::PendingFill)
+    }
+}
+
+impl Display for QuoteStatus {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            QuoteStatus::Active => write!(f, "Active"),
+            QuoteStatus::Canceled => write!(f, "Canceled"),
+            QuoteStatus::PendingFill => write!(f, "PendingFill"),
+            QuoteStatus::Filled => write!(f, "Filled"),
+            QuoteStatus::Expired => write!(f, "Expired"),
+            QuoteStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// Side of the RFQ flow a query is made from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RfqTraderType {
+    #[serde(rename = "request")]
+    Request, // RFQs sent / quotes received
+    #[serde(rename = "quote")]
+    Quote, // RFQs received / quotes sent
+}
+
+impl Display for RfqTraderType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            RfqTraderType::Request => write!(f, "request"),
+            RfqTraderType::Quote => write!(f, "quote"),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SmallBalanceAccountType {
+    #[serde(rename = "eb_convert_uta")]
+    Unified, // Unified trading account
+    #[serde(rename = "eb_convert_funding")]
+    Funding, // Funding account
+}
+
+impl Display for SmallBalanceAccountType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            SmallBalanceAccountType::Unified => write!(f, "eb_convert_uta"),
+            SmallBalanceAccountType::Funding => write!(f, "eb_convert_funding"),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SpreadContractType {
+    // https://bybit-exchange.github.io/docs/v5/spread/market/instrument
+    #[serde(rename = "FundingRateArb")]
+    FundingRateArb, // Spot vs perpetual
+    #[serde(rename = "CarryTrade")]
+    CarryTrade, // Spot vs dated future
+    #[serde(rename = "FutureSpread")]
+    FutureSpread, // Calendar spread between two dated futures
+    #[serde(rename = "PerpBasis")]
+    PerpBasis, // Perpetual vs dated future
+}
+
+impl Display for SpreadContractType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            SpreadContractType::FundingRateArb => write!(f, "FundingRateArb"),
+            SpreadContractType::CarryTrade => write!(f, "CarryTrade"),
+            SpreadContractType::FutureSpread => write!(f, "FutureSpread"),
+            SpreadContractType::PerpBasis => write!(f, "PerpBasis"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+/// On/off account setting, e.g. collateral switch or spot hedging.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SwitchStatus {
+    #[serde(rename = "ON")]
+    On,
+    #[serde(rename = "OFF")]
+    Off,
+}
+
+impl Display for SwitchStatus {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            SwitchStatus::On => write!(f, "ON"),
+            SwitchStatus::Off => write!(f, "OFF"),
+        }
+    }
+}
@@ -12,6 +12,8 @@ pub enum TimeInForce {
     IOC, // ImmediateOrCancel
     #[serde(rename = "FOK")]
     FOK, // FillOrKill
+    #[serde(rename = "PostOnly")]
+    PostOnly, // Maker only: cancelled instead of taking liquidity
 }
 
 impl Display for TimeInForce {
@@ -20,6 +22,22 @@ impl Display for TimeInForce {
             TimeInForce::GTC => write!(f, "GTC"),
             TimeInForce::IOC => write!(f, "IOC"),
             TimeInForce::FOK => write!(f, "FOK"),
+            TimeInForce::PostOnly => write!(f, "PostOnly"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_only_round_trip() {
+        // Orders listed with timeInForce "PostOnly" failed to deserialize
+        // before this variant existed.
+        let tif: TimeInForce = serde_json::from_str(r#""PostOnly""#).unwrap();
+        assert_eq!(tif, TimeInForce::PostOnly);
+        assert_eq!(serde_json::to_value(tif).unwrap(), "PostOnly");
+        assert_eq!(tif.to_string(), "PostOnly");
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum TpSlMode {
+    #[default]
+    #[serde(rename = "Full")]
+    Full, // TP/SL closes the entire position
+    #[serde(rename = "Partial")]
+    Partial, // TP/SL closes part of the position
+}
+
+impl Display for TpSlMode {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            TpSlMode::Full => write!(f, "Full"),
+            TpSlMode::Partial => write!(f, "Partial"),
+        }
+    }
+}
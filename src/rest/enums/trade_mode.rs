@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::deserialize_int_code;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TradeMode {
+    #[default]
+    CrossMargin, // 0
+    IsolatedMargin, // 1
+}
+
+impl TradeMode {
+    pub fn code(&self) -> i32 {
+        match self {
+            TradeMode::CrossMargin => 0,
+            TradeMode::IsolatedMargin => 1,
+        }
+    }
+}
+
+impl Serialize for TradeMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for TradeMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        match deserialize_int_code(deserializer)? {
+            0 => Ok(TradeMode::CrossMargin),
+            1 => Ok(TradeMode::IsolatedMargin),
+            other => Err(serde::de::Error::custom(format!(
+                "unknown trade mode {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for TradeMode {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.code())
+    }
+}
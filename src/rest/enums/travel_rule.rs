@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter, Result};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BeneficiaryLegalType {
+    #[serde(rename = "individual")]
+    Individual, // Natural person
+    #[serde(rename = "company")]
+    Company, // Legal entity
+}
+
+impl Display for BeneficiaryLegalType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            BeneficiaryLegalType::Individual => write!(f, "individual"),
+            BeneficiaryLegalType::Company => write!(f, "company"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BeneficiaryWalletType {
+    #[serde(rename = "0")]
+    Custodial, // Wallet hosted by an exchange or other VASP
+    #[serde(rename = "1")]
+    NonCustodial, // Self-hosted wallet
+}
+
+impl Display for BeneficiaryWalletType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            BeneficiaryWalletType::Custodial => write!(f, "0"),
+            BeneficiaryWalletType::NonCustodial => write!(f, "1"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum UnhostedWalletType {
+    #[serde(rename = "0")]
+    Own, // Self-hosted wallet belonging to the withdrawer
+    #[serde(rename = "1")]
+    Others, // Self-hosted wallet belonging to someone else
+}
+
+impl Display for UnhostedWalletType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            UnhostedWalletType::Own => write!(f, "0"),
+            UnhostedWalletType::Others => write!(f, "1"),
+        }
+    }
+}
@@ -17,6 +17,10 @@ pub enum BybitError {
     #[error("Configuration error: {0}")]
     Config(String),
 
+    /// A client-side guard refused the request before it was sent
+    #[error("Request rejected: {reason}")]
+    Rejected { reason: String },
+
     #[error("Internal error: {0}")]
     Internal(String),
 
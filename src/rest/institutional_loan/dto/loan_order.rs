@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/otc/loan-info
+//
+// GET /v5/ins-loan/loan-order
+// {
+//     "loanInfo": [
+//         {
+//             "orderId": "1468005106166530304",
+//             "orderProductId": "96",
+//             "parentUid": "1631521",
+//             "loanTime": "1689745773187",
+//             "loanCoin": "USDT",
+//             "loanAmount": "2",
+//             "unpaidAmount": "1.3",
+//             "unpaidInterest": "0.00003",
+//             "repaidAmount": "0.7",
+//             "repaidInterest": "0.0001",
+//             "interestRate": "0.0001",
+//             "status": 1,
+//             "leverage": "4",
+//             "supportSpot": 1,
+//             "supportContract": 1,
+//             "withdrawLine": "",
+//             "transferLine": "",
+//             "spotBuyLine": "0.71428571",
+//             "spotSellLine": "0.71428571",
+//             "contractOpenLine": "0.71428571",
+//             "liquidationLine": "0.75000000",
+//             "stopLiquidationLine": "0.35000000",
+//             "contractLeverage": "7",
+//             "transferRatio": "0",
+//             "spotSymbols": [],
+//             "contractSymbols": []
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsLoanOrder {
+    pub order_id: String,
+    pub order_product_id: String,
+    pub parent_uid: String,
+    pub loan_time: String,
+    pub loan_coin: String,
+    pub loan_amount: String,
+    pub unpaid_amount: String,
+    pub unpaid_interest: String,
+    pub repaid_amount: String,
+    pub repaid_interest: String,
+    /// Daily interest rate
+    pub interest_rate: String,
+    /// 1: outstanding, 2: paid off
+    pub status: i32,
+    #[serde(default)]
+    pub leverage: String,
+    #[serde(default)]
+    pub support_spot: i32,
+    #[serde(default)]
+    pub support_contract: i32,
+    #[serde(default)]
+    pub withdraw_line: String,
+    #[serde(default)]
+    pub transfer_line: String,
+    #[serde(default)]
+    pub spot_buy_line: String,
+    #[serde(default)]
+    pub spot_sell_line: String,
+    #[serde(default)]
+    pub contract_open_line: String,
+    #[serde(default)]
+    pub liquidation_line: String,
+    #[serde(default)]
+    pub stop_liquidation_line: String,
+    #[serde(default)]
+    pub contract_leverage: String,
+    #[serde(default)]
+    pub transfer_ratio: String,
+    #[serde(default)]
+    pub spot_symbols: Vec<String>,
+    #[serde(default)]
+    pub contract_symbols: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsLoanOrderResult {
+    pub loan_info: Vec<InsLoanOrder>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/otc/repay-info
+//
+// GET /v5/ins-loan/repaid-history
+// {
+//     "repayInfo": [
+//         {
+//             "repayOrderId": "8189",
+//             "repaidTime": "1663126393000",
+//             "token": "USDT",
+//             "quantity": "30000",
+//             "interest": "0",
+//             "businessType": "1",
+//             "status": "1"
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsLoanRepayment {
+    pub repay_order_id: String,
+    pub repaid_time: String,
+    pub token: String,
+    pub quantity: String,
+    pub interest: String,
+    /// "1": normal repayment, "2": repaid by liquidation
+    pub business_type: String,
+    /// "1": success, "2": failed
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsLoanRepaymentResult {
+    pub repay_info: Vec<InsLoanRepayment>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_repaid_history() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "repayInfo": [
+                    {
+                        "repayOrderId": "8189",
+                        "repaidTime": "1663126393000",
+                        "token": "USDT",
+                        "quantity": "30000",
+                        "interest": "0",
+                        "businessType": "1",
+                        "status": "1"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1669367335163
+        }"#;
+        let response: ServerResponse<InsLoanRepaymentResult> =
+            serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.repay_info[0].quantity, "30000");
+    }
+}
@@ -0,0 +1,162 @@
+use super::InsLoanProduct;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/otc/ltv-convert
+//
+// GET /v5/ins-loan/ltv-convert
+// {
+//     "ltvInfo": [
+//         {
+//             "ltv": "0.75",
+//             "rst": "",
+//             "parentUid": "78903",
+//             "subAccountUids": ["78903"],
+//             "unpaidAmount": "30",
+//             "unpaidInfo": [
+//                 { "token": "USDT", "unpaidQty": "30", "unpaidInterest": "0" }
+//             ],
+//             "balance": "40",
+//             "balanceInfo": [
+//                 { "token": "USDT", "price": "1", "qty": "40", "convertedAmount": "40" }
+//             ]
+//         }
+//     ]
+// }
+//
+// The deprecated GET /v5/ins-loan/ltv returns the same shape.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnpaidInfo {
+    pub token: String,
+    pub unpaid_qty: String,
+    pub unpaid_interest: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceInfo {
+    pub token: String,
+    pub price: String,
+    pub qty: String,
+    /// Collateral value after the convert ratio, in USD
+    pub converted_amount: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LtvInfo {
+    /// Unpaid amount over converted balance
+    pub ltv: String,
+    /// Remaining liquidation time in hours, empty unless liquidating
+    #[serde(default)]
+    pub rst: String,
+    pub parent_uid: String,
+    #[serde(default)]
+    pub sub_account_uids: Vec<String>,
+    /// Total debt in USD
+    pub unpaid_amount: String,
+    #[serde(default)]
+    pub unpaid_info: Vec<UnpaidInfo>,
+    /// Total converted collateral in USD
+    pub balance: String,
+    #[serde(default)]
+    pub balance_info: Vec<BalanceInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsLoanLtvResult {
+    pub ltv_info: Vec<LtvInfo>,
+}
+
+/// Distance of a loan from its liquidation line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LtvHeadroom {
+    pub ltv: f64,
+    pub liquidation_ltv: f64,
+    /// `liquidation_ltv - ltv`; negative once past the line
+    pub ltv_headroom: f64,
+    /// Fraction the converted collateral value can fall before liquidation
+    pub collateral_drawdown: f64,
+    /// Additional debt, in USD, before liquidation
+    pub debt_headroom: f64,
+}
+
+impl LtvInfo {
+    /// Headroom before `liquidation_ltv`, e.g. the product's `liquidation_line`.
+    ///
+    /// Returns `None` if the amounts do not parse or `liquidation_ltv` is not
+    /// positive.
+    pub fn headroom(&self, liquidation_ltv: f64) -> Option<LtvHeadroom> {
+        if liquidation_ltv <= 0.0 {
+            return None;
+        }
+        let ltv: f64 = self.ltv.parse().ok()?;
+        let unpaid: f64 = self.unpaid_amount.parse().ok()?;
+        let balance: f64 = self.balance.parse().ok()?;
+        Some(LtvHeadroom {
+            ltv,
+            liquidation_ltv,
+            ltv_headroom: liquidation_ltv - ltv,
+            collateral_drawdown: 1.0 - ltv / liquidation_ltv,
+            debt_headroom: balance * liquidation_ltv - unpaid,
+        })
+    }
+
+    /// [`headroom`](Self::headroom) against a product's liquidation line.
+    pub fn headroom_for(&self, product: &InsLoanProduct) -> Option<LtvHeadroom> {
+        self.headroom(product.liquidation_line.parse().ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    const LTV: &str = r#"{
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "ltvInfo": [
+                {
+                    "ltv": "0.6",
+                    "rst": "",
+                    "parentUid": "78903",
+                    "subAccountUids": ["78903"],
+                    "unpaidAmount": "600000",
+                    "unpaidInfo": [
+                        { "token": "USDT", "unpaidQty": "600000", "unpaidInterest": "12.5" }
+                    ],
+                    "balance": "1000000",
+                    "balanceInfo": [
+                        { "token": "BTC", "price": "100000", "qty": "10.5", "convertedAmount": "1000000" }
+                    ]
+                }
+            ]
+        },
+        "retExtInfo": {},
+        "time": 1669367335163
+    }"#;
+
+    #[test]
+    fn test_deserialize_ltv() {
+        let response: ServerResponse<InsLoanLtvResult> = serde_json::from_str(LTV).unwrap();
+        let info = &response.result.ltv_info[0];
+        assert_eq!(info.unpaid_info[0].unpaid_interest, "12.5");
+        assert_eq!(info.balance_info[0].converted_amount, "1000000");
+    }
+
+    #[test]
+    fn test_ltv_headroom() {
+        let response: ServerResponse<InsLoanLtvResult> = serde_json::from_str(LTV).unwrap();
+        let headroom = response.result.ltv_info[0].headroom(0.75).unwrap();
+        assert!((headroom.ltv_headroom - 0.15).abs() < 1e-9);
+        // 0.6 / 0.75: collateral can lose 20% before liquidation
+        assert!((headroom.collateral_drawdown - 0.2).abs() < 1e-9);
+        assert!((headroom.debt_headroom - 150_000.0).abs() < 1e-6);
+
+        assert!(response.result.ltv_info[0].headroom(0.0).is_none());
+    }
+}
@@ -1,2 +1,7 @@
-// institutional_loan module DTOs
-// DTOs will be added as needed for typed responses
+pub mod loan_order;
+pub mod ltv;
+pub mod product;
+
+pub use loan_order::*;
+pub use ltv::*;
+pub use product::*;
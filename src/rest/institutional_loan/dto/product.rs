@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/otc/margin-product-info
+//
+// GET /v5/ins-loan/product-infos?productId=91
+// {
+//     "marginProductInfo": [
+//         {
+//             "productId": "91",
+//             "leverage": "4.00000000",
+//             "supportSpot": 1,
+//             "supportContract": 1,
+//             "supportMarginTrading": 1,
+//             "withdrawLine": "",
+//             "transferLine": "",
+//             "spotBuyLine": "0.71428571",
+//             "spotSellLine": "0.71428571",
+//             "contractOpenLine": "0.71428571",
+//             "liquidationLine": "0.75000000",
+//             "stopLiquidationLine": "0.35000000",
+//             "contractLeverage": "7",
+//             "transferRatio": "0",
+//             "spotSymbols": [],
+//             "contractSymbols": [],
+//             "supportUSDCContract": 0,
+//             "supportUSDCOptions": 0,
+//             "marginLeverage": "4"
+//         }
+//     ]
+// }
+
+/// Terms of an institutional loan product. LTV lines are ratios.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsLoanProduct {
+    pub product_id: String,
+    pub leverage: String,
+    /// 1: spot trading allowed
+    #[serde(default)]
+    pub support_spot: i32,
+    /// 1: contract trading allowed
+    #[serde(default)]
+    pub support_contract: i32,
+    #[serde(default)]
+    pub support_margin_trading: i32,
+    /// LTV below which withdrawals are allowed, empty if never
+    #[serde(default)]
+    pub withdraw_line: String,
+    #[serde(default)]
+    pub transfer_line: String,
+    #[serde(default)]
+    pub spot_buy_line: String,
+    #[serde(default)]
+    pub spot_sell_line: String,
+    #[serde(default)]
+    pub contract_open_line: String,
+    /// LTV at which liquidation starts
+    pub liquidation_line: String,
+    /// LTV at which liquidation stops
+    #[serde(default)]
+    pub stop_liquidation_line: String,
+    #[serde(default)]
+    pub contract_leverage: String,
+    #[serde(default)]
+    pub transfer_ratio: String,
+    /// Allowed spot symbols, empty for all
+    #[serde(default)]
+    pub spot_symbols: Vec<String>,
+    /// Allowed contract symbols, empty for all
+    #[serde(default)]
+    pub contract_symbols: Vec<String>,
+    #[serde(default, rename = "supportUSDCContract")]
+    pub support_usdc_contract: i32,
+    #[serde(default, rename = "supportUSDCOptions")]
+    pub support_usdc_options: i32,
+    #[serde(default)]
+    pub margin_leverage: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsLoanProductResult {
+    pub margin_product_info: Vec<InsLoanProduct>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/otc/margin-coin-convert-info
+//
+// GET /v5/ins-loan/ensure-tokens-convert?productId=81
+// {
+//     "marginToken": [
+//         {
+//             "productId": "81",
+//             "tokenInfo": [
+//                 {
+//                     "token": "USDT",
+//                     "convertRatioList": [
+//                         { "ladder": "0-500", "convertRatio": "0.95" },
+//                         { "ladder": "500-1000", "convertRatio": "0.9" }
+//                     ]
+//                 }
+//             ]
+//         }
+//     ]
+// }
+
+/// Collateral value ratio applied to one amount ladder of a margin coin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertRatio {
+    /// Amount range, e.g. "0-500"
+    pub ladder: String,
+    pub convert_ratio: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTokenInfo {
+    pub token: String,
+    pub convert_ratio_list: Vec<ConvertRatio>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginToken {
+    pub product_id: String,
+    pub token_info: Vec<MarginTokenInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTokenResult {
+    pub margin_token: Vec<MarginToken>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_product_infos() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "marginProductInfo": [
+                    {
+                        "productId": "91",
+                        "leverage": "4.00000000",
+                        "supportSpot": 1,
+                        "supportContract": 1,
+                        "supportMarginTrading": 1,
+                        "withdrawLine": "",
+                        "transferLine": "",
+                        "spotBuyLine": "0.71428571",
+                        "spotSellLine": "0.71428571",
+                        "contractOpenLine": "0.71428571",
+                        "liquidationLine": "0.75000000",
+                        "stopLiquidationLine": "0.35000000",
+                        "contractLeverage": "7",
+                        "transferRatio": "0",
+                        "spotSymbols": [],
+                        "contractSymbols": [],
+                        "supportUSDCContract": 0,
+                        "supportUSDCOptions": 0,
+                        "marginLeverage": "4"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1689745773187
+        }"#;
+        let response: ServerResponse<InsLoanProductResult> =
+            serde_json::from_str(json_data).unwrap();
+        let product = &response.result.margin_product_info[0];
+        assert_eq!(product.liquidation_line, "0.75000000");
+        assert_eq!(product.support_spot, 1);
+    }
+
+    #[test]
+    fn test_deserialize_margin_tokens() {
+        let json_data = r#"{
+            "marginToken": [
+                {
+                    "productId": "81",
+                    "tokenInfo": [
+                        {
+                            "token": "USDT",
+                            "convertRatioList": [
+                                { "ladder": "0-500", "convertRatio": "0.95" },
+                                { "ladder": "500-1000", "convertRatio": "0.9" }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let result: MarginTokenResult = serde_json::from_str(json_data).unwrap();
+        let token = &result.margin_token[0].token_info[0];
+        assert_eq!(token.convert_ratio_list[1].convert_ratio, "0.9");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::institutional_loan::dto::*;
 use crate::rest::BybitResult as Result;
 use serde_json::json;
 
@@ -14,6 +15,8 @@ impl InstitutionalLoanClient {
 
     /// Get LTV (Loan-to-Value) information
     /// Get institutional loan LTV ratio information
+    ///
+    /// Deprecated by Bybit in favour of [`get_ltv_convert`](Self::get_ltv_convert).
     pub async fn get_ltv(
         &self,
         currency: Option<&str>,
@@ -21,7 +24,7 @@ impl InstitutionalLoanClient {
         end_time: Option<i64>,
         limit: Option<i32>,
         cursor: Option<&str>,
-    ) -> Result<ServerResponse<serde_json::Value>> {
+    ) -> Result<ServerResponse<InsLoanLtvResult>> {
         let endpoint = "v5/ins-loan/ltv";
         let mut params = json!({});
 
@@ -61,6 +64,117 @@ impl InstitutionalLoanClient {
         let response = self.client.post(endpoint, body, SecType::Signed).await?;
         Ok(response)
     }
+
+    /// Get product info
+    ///
+    /// API: GET /v5/ins-loan/product-infos
+    /// https://bybit-exchange.github.io/docs/v5/otc/margin-product-info
+    pub async fn get_product_infos(
+        &self,
+        product_id: Option<&str>,
+    ) -> Result<ServerResponse<InsLoanProductResult>> {
+        let endpoint = "v5/ins-loan/product-infos";
+        let mut params = json!({});
+
+        if let Some(product_id) = product_id {
+            params["productId"] = json!(product_id);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get margin coin conversion info
+    ///
+    /// API: GET /v5/ins-loan/ensure-tokens-convert
+    /// https://bybit-exchange.github.io/docs/v5/otc/margin-coin-convert-info
+    pub async fn get_margin_coin_info(
+        &self,
+        product_id: Option<&str>,
+    ) -> Result<ServerResponse<MarginTokenResult>> {
+        let endpoint = "v5/ins-loan/ensure-tokens-convert";
+        let mut params = json!({});
+
+        if let Some(product_id) = product_id {
+            params["productId"] = json!(product_id);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get loan orders
+    ///
+    /// API: GET /v5/ins-loan/loan-order
+    /// https://bybit-exchange.github.io/docs/v5/otc/loan-info
+    pub async fn get_loan_orders(
+        &self,
+        order_id: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+    ) -> Result<ServerResponse<InsLoanOrderResult>> {
+        let endpoint = "v5/ins-loan/loan-order";
+        let mut params = json!({});
+
+        if let Some(order_id) = order_id {
+            params["orderId"] = json!(order_id);
+        }
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get repayment orders
+    ///
+    /// API: GET /v5/ins-loan/repaid-history
+    /// https://bybit-exchange.github.io/docs/v5/otc/repay-info
+    pub async fn get_repaid_history(
+        &self,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+    ) -> Result<ServerResponse<InsLoanRepaymentResult>> {
+        let endpoint = "v5/ins-loan/repaid-history";
+        let mut params = json!({});
+
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get LTV with collateral conversion
+    ///
+    /// Use [`LtvInfo::headroom`] for the distance to liquidation.
+    ///
+    /// API: GET /v5/ins-loan/ltv-convert
+    /// https://bybit-exchange.github.io/docs/v5/otc/ltv-convert
+    pub async fn get_ltv_convert(&self) -> Result<ServerResponse<InsLoanLtvResult>> {
+        let endpoint = "v5/ins-loan/ltv-convert";
+        let response = self
+            .client
+            .get(endpoint, json!({}), SecType::Signed)
+            .await?;
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-mod dto;
+pub mod dto;
 pub mod institutional_loan_client;
 
 pub use institutional_loan_client::InstitutionalLoanClient;
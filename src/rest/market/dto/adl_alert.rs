@@ -0,0 +1,70 @@
+use crate::rest::client::ServerResponse;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/market/adl-alert#request-parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAdlAlertParams {
+    pub symbol: Option<String>, // Contract name. e.g. BTCUSDT. Default: all symbols
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/adl-alert#response-parameters
+/*
+{
+    "retCode": 0,
+    "retMsg": "OK",
+    "result": {
+        "updatedTime": "1757733960000",
+        "list": [
+            {
+                "coin": "USDT",
+                "symbol": "BTCUSDT",
+                "balance": "73412365.12",
+                "maxBalance": "73412365.12",
+                "insurancePnlRatio": "0.01",
+                "pnlRatio": "-0.02",
+                "adlTriggerThreshold": "10000",
+                "adlStopRatio": "-0.3"
+            }
+        ]
+    },
+    "retExtInfo": {},
+    "time": 1757734022019
+}
+*/
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdlAlert {
+    pub coin: String,    // Settle coin of the insurance pool
+    pub symbol: String,  // Contract name
+    pub balance: String, // Insurance pool balance
+    #[serde(rename = "maxBalance")]
+    pub max_balance: String, // Maximum balance of the pool in the last 8 hours
+    #[serde(rename = "insurancePnlRatio")]
+    pub insurance_pnl_ratio: String, // PnL ratio that triggers ADL
+    #[serde(rename = "pnlRatio")]
+    pub pnl_ratio: String, // Current PnL ratio of the pool
+    #[serde(rename = "adlTriggerThreshold")]
+    pub adl_trigger_threshold: String, // Pool balance below which ADL may trigger
+    #[serde(rename = "adlStopRatio")]
+    pub adl_stop_ratio: String, // PnL ratio at which ADL stops
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdlAlertResult {
+    #[serde(rename = "updatedTime")]
+    pub updated_time: String,
+    pub list: Vec<AdlAlert>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdlAlertResponse(ServerResponse<AdlAlertResult>);
+
+impl AdlAlertResponse {
+    pub fn into_inner(self) -> AdlAlertResult {
+        self.0.result
+    }
+
+    pub fn into_response(self) -> ServerResponse<AdlAlertResult> {
+        self.0
+    }
+}
@@ -0,0 +1,70 @@
+use crate::rest::client::ServerResponse;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/market/index-components#request-parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetIndexPriceComponentsParams {
+    #[serde(rename = "indexName")]
+    pub index_name: String, // Index name. e.g. BTCUSDT
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/index-components#response-parameters
+/*
+{
+    "retCode": 0,
+    "retMsg": "OK",
+    "result": {
+        "indexName": "1000BTTUSDT",
+        "lastPrice": "0.0006091",
+        "updateTime": "1758182745072",
+        "components": [
+            {
+                "exchange": "GateIO",
+                "spotPair": "BTT_USDT",
+                "equivalentPrice": "0.0006087",
+                "multiplier": "1000",
+                "price": "0.0006087",
+                "weight": "0.0183"
+            }
+        ]
+    },
+    "retExtInfo": {},
+    "time": 1758182745621
+}
+*/
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexPriceComponent {
+    pub exchange: String, // Source exchange
+    #[serde(rename = "spotPair")]
+    pub spot_pair: String, // Pair on the source exchange
+    #[serde(rename = "equivalentPrice")]
+    pub equivalent_price: String, // Price converted to the index quote currency
+    pub multiplier: String, // Multiplier applied to the source price
+    pub price: String,    // Raw source price
+    pub weight: String,   // Weight in the index
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexPriceComponentsResult {
+    #[serde(rename = "indexName")]
+    pub index_name: String,
+    #[serde(rename = "lastPrice")]
+    pub last_price: String,
+    #[serde(rename = "updateTime")]
+    pub update_time: String,
+    pub components: Vec<IndexPriceComponent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexPriceComponentsResponse(ServerResponse<IndexPriceComponentsResult>);
+
+impl IndexPriceComponentsResponse {
+    pub fn into_inner(self) -> IndexPriceComponentsResult {
+        self.0.result
+    }
+
+    pub fn into_response(self) -> ServerResponse<IndexPriceComponentsResult> {
+        self.0
+    }
+}
@@ -0,0 +1,108 @@
+use super::instruments_info_inverse::InstrumentsInfoInverseResult;
+use super::instruments_info_linear::InstrumentsInfoLinearResult;
+use super::instruments_info_option::InstrumentsInfoOptionResult;
+use super::instruments_info_spot::InstrumentsInfoSpotResult;
+use crate::rest::enums::category::Category;
+use serde::{Deserialize, Serialize};
+
+/// Instruments of any category, picked by the `category` of the result.
+///
+/// Returned where one call can yield any category, e.g.
+/// `AccountClient::get_instruments_info`.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum InstrumentsInfoResult {
+    Spot(InstrumentsInfoSpotResult),
+    Linear(InstrumentsInfoLinearResult),
+    Inverse(InstrumentsInfoInverseResult),
+    Option(InstrumentsInfoOptionResult),
+}
+
+impl InstrumentsInfoResult {
+    pub fn category(&self) -> Category {
+        match self {
+            InstrumentsInfoResult::Spot(_) => Category::Spot,
+            InstrumentsInfoResult::Linear(_) => Category::Linear,
+            InstrumentsInfoResult::Inverse(_) => Category::Inverse,
+            InstrumentsInfoResult::Option(_) => Category::Option,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InstrumentsInfoResult {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let category = value
+            .get("category")
+            .cloned()
+            .ok_or_else(|| D::Error::missing_field("category"))?;
+        let category: Category = serde_json::from_value(category).map_err(D::Error::custom)?;
+        match category {
+            Category::Spot => serde_json::from_value(value).map(InstrumentsInfoResult::Spot),
+            Category::Linear => serde_json::from_value(value).map(InstrumentsInfoResult::Linear),
+            Category::Inverse => serde_json::from_value(value).map(InstrumentsInfoResult::Inverse),
+            Category::Option => serde_json::from_value(value).map(InstrumentsInfoResult::Option),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_by_category() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "category": "spot",
+                "nextPageCursor": "",
+                "list": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "baseCoin": "BTC",
+                        "quoteCoin": "USDT",
+                        "innovation": "0",
+                        "status": "Trading",
+                        "marginTrading": "utaOnly",
+                        "stTag": "0",
+                        "lotSizeFilter": {
+                            "basePrecision": "0.000001",
+                            "quotePrecision": "0.00000001",
+                            "minOrderQty": "0.000011",
+                            "maxOrderQty": "83",
+                            "minOrderAmt": "5",
+                            "maxOrderAmt": "8000000"
+                        },
+                        "priceFilter": { "tickSize": "0.1" },
+                        "riskParameters": {
+                            "limitParameter": "0.05",
+                            "marketParameter": "0.05"
+                        },
+                        "symbolType": ""
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1739499960126
+        }"#;
+        let response: ServerResponse<InstrumentsInfoResult> =
+            serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.category(), Category::Spot);
+        match response.result {
+            InstrumentsInfoResult::Spot(spot) => {
+                assert_eq!(spot.list[0].symbol, "BTCUSDT");
+                assert_eq!(spot.list[0].lot_size_filter.min_order_amt, "5");
+            }
+            other => panic!("expected spot instruments, got {:?}", other.category()),
+        }
+
+        let err = serde_json::from_str::<InstrumentsInfoResult>(r#"{"list":[]}"#).unwrap_err();
+        assert!(err.to_string().contains("category"));
+    }
+}
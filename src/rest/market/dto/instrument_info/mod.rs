@@ -1,5 +1,8 @@
 pub mod instrument_info_params;
+pub mod instruments_info;
 pub mod instruments_info_inverse;
 pub mod instruments_info_linear;
 pub mod instruments_info_option;
 pub mod instruments_info_spot;
+
+pub use instruments_info::InstrumentsInfoResult;
@@ -1,9 +1,13 @@
+pub mod adl_alert;
+pub use adl_alert::*;
 pub mod delivery_price;
 pub use delivery_price::*;
 pub mod funding_rate_history;
 pub use funding_rate_history::*;
 pub mod historical_volatility;
 pub use historical_volatility::*;
+pub mod index_price_components;
+pub use index_price_components::*;
 pub mod index_price_kline;
 pub use index_price_kline::*;
 pub mod instrument_info;
@@ -16,16 +20,22 @@ pub mod long_short_ratio;
 pub use long_short_ratio::*;
 pub mod mark_price_kline;
 pub use mark_price_kline::*;
+pub mod new_delivery_price;
+pub use new_delivery_price::*;
 pub mod open_interest;
 pub use open_interest::*;
 pub mod order_book;
 pub use order_book::*;
 pub mod premium_index_price_kline;
 pub use premium_index_price_kline::*;
+pub mod price_limit;
+pub use price_limit::*;
 pub mod recent_trade;
 pub use recent_trade::*;
 pub mod risk_limit;
 pub use risk_limit::*;
+pub mod rpi_order_book;
+pub use rpi_order_book::*;
 pub mod server_time;
 pub use server_time::*;
 pub mod tickers;
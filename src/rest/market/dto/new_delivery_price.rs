@@ -0,0 +1,59 @@
+use crate::rest::client::ServerResponse;
+use crate::rest::enums::category::Category;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/market/new-delivery-price#request-parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetNewDeliveryPriceParams {
+    pub category: Category, // Product type. option
+    #[serde(rename = "baseCoin")]
+    pub base_coin: String, // Base coin. e.g. BTC
+    #[serde(rename = "settleCoin")]
+    pub settle_coin: Option<String>, // Settle coin. USDT or USDC. Default: USDC
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/new-delivery-price#response-parameters
+/*
+{
+    "retCode": 0,
+    "retMsg": "success",
+    "result": {
+        "category": "option",
+        "list": [
+            {
+                "deliveryPrice": "114109.83413355",
+                "deliveryTime": "1758182400000"
+            }
+        ]
+    },
+    "retExtInfo": {},
+    "time": 1758185123456
+}
+*/
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewDeliveryPrice {
+    #[serde(rename = "deliveryPrice")]
+    pub delivery_price: String,
+    #[serde(rename = "deliveryTime")]
+    pub delivery_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewDeliveryPriceResult {
+    pub category: String,
+    pub list: Vec<NewDeliveryPrice>, // Most recent delivery first
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewDeliveryPriceResponse(ServerResponse<NewDeliveryPriceResult>);
+
+impl NewDeliveryPriceResponse {
+    pub fn into_inner(self) -> NewDeliveryPriceResult {
+        self.0.result
+    }
+
+    pub fn into_response(self) -> ServerResponse<NewDeliveryPriceResult> {
+        self.0
+    }
+}
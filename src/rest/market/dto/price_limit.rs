@@ -0,0 +1,89 @@
+use crate::rest::client::ServerResponse;
+use crate::rest::enums::category::Category;
+use crate::rest::enums::side::Side;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/market/order-price-limit#request-parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPriceLimitParams {
+    pub category: Option<Category>, // Product type. spot, linear, inverse. Default: linear
+    pub symbol: String,             // Symbol name. e.g. BTCUSDT
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/order-price-limit#response-parameters
+/*
+{
+    "retCode": 0,
+    "retMsg": "OK",
+    "result": {
+        "symbol": "BTCUSDT",
+        "buyLmt": "105878.10",
+        "sellLmt": "103781.60",
+        "ts": "1750302284491"
+    },
+    "retExtInfo": {},
+    "time": 1750302285376
+}
+*/
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceLimitResult {
+    pub symbol: String,
+    #[serde(rename = "buyLmt")]
+    pub buy_lmt: String, // Highest price a buy order may be placed at
+    #[serde(rename = "sellLmt")]
+    pub sell_lmt: String, // Lowest price a sell order may be placed at
+    #[serde(default)]
+    pub ts: String, // Timestamp (ms)
+}
+
+impl PriceLimitResult {
+    /// Pull a limit price inside the band the exchange will accept
+    ///
+    /// Buys are capped at `buyLmt` and sells floored at `sellLmt`. A limit
+    /// that fails to parse leaves the price unchanged.
+    pub fn clamp(&self, side: Side, price: f64) -> f64 {
+        match side {
+            Side::Buy => match self.buy_lmt.parse::<f64>() {
+                Ok(limit) => price.min(limit),
+                Err(_) => price,
+            },
+            Side::Sell => match self.sell_lmt.parse::<f64>() {
+                Ok(limit) => price.max(limit),
+                Err(_) => price,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceLimitResponse(ServerResponse<PriceLimitResult>);
+
+impl PriceLimitResponse {
+    pub fn into_inner(self) -> PriceLimitResult {
+        self.0.result
+    }
+
+    pub fn into_response(self) -> ServerResponse<PriceLimitResult> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_to_price_limit() {
+        let limit = PriceLimitResult {
+            symbol: "BTCUSDT".to_string(),
+            buy_lmt: "105878.10".to_string(),
+            sell_lmt: "103781.60".to_string(),
+            ts: "1750302284491".to_string(),
+        };
+        assert_eq!(limit.clamp(Side::Buy, 110000.0), 105878.10);
+        assert_eq!(limit.clamp(Side::Buy, 104000.0), 104000.0);
+        assert_eq!(limit.clamp(Side::Sell, 100000.0), 103781.60);
+        assert_eq!(limit.clamp(Side::Sell, 104000.0), 104000.0);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::rest::client::ServerResponse;
+use crate::rest::enums::category::Category;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/market/rpi-orderbook#request-parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetRpiOrderBookParams {
+    pub category: Option<Category>, // Product type. spot, linear, inverse
+    pub symbol: String,             // Symbol name. e.g. BTCUSDT
+    pub limit: i32,                 // Depth per side, [1, 50]
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/rpi-orderbook#response-parameters
+/*
+{
+    "retCode": 0,
+    "retMsg": "OK",
+    "result": {
+        "s": "BTCUSDT",
+        "b": [
+            [
+                "116760.3",
+                "2.331",
+                "0.127"
+            ]
+        ],
+        "a": [
+            [
+                "116760.4",
+                "3.018",
+                "0"
+            ]
+        ],
+        "ts": 1758183221331,
+        "u": 42390184,
+        "seq": 81298710374,
+        "cts": 1758183221328
+    },
+    "retExtInfo": {},
+    "time": 1758183221352
+}
+*/
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpiOrderBookResult {
+    pub s: String, // Symbol name
+    pub b: Vec<
+        // Bid, buyer. Order by price desc
+        // b[0]: Bid price
+        // b[1]: Non-RPI size
+        // b[2]: RPI size
+        [String; 3],
+    >,
+    pub a: Vec<
+        // Ask, seller. Order by price asc
+        // a[0]: Ask price
+        // a[1]: Non-RPI size
+        // a[2]: RPI size
+        [String; 3],
+    >,
+    pub ts: i64,  // Timestamp (ms) that the system generates the data
+    pub u: i64,   // Update ID
+    pub seq: i64, // Cross sequence
+    #[serde(default)]
+    pub cts: i64, // Matching engine timestamp (ms)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpiOrderBookResponse(ServerResponse<RpiOrderBookResult>);
+
+impl RpiOrderBookResponse {
+    pub fn into_inner(self) -> RpiOrderBookResult {
+        self.0.result
+    }
+
+    pub fn into_response(self) -> ServerResponse<RpiOrderBookResult> {
+        self.0
+    }
+}
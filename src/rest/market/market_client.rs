@@ -3,10 +3,11 @@ use crate::rest::enums::category::Category;
 use crate::rest::enums::interval::Interval;
 use crate::rest::enums::interval_time::IntervalTime;
 use crate::rest::market::dto::{
-    DeliveryPriceResult, FundingRateHistoryResult, HistoricalVolatilityResult,
-    IndexPriceKlineResult, InsuranceResult, KlineResult, LongShortRatioResult,
-    MarkPriceKlineResult, OpenInterestResult, OrderBookResult, PremiumIndexPriceKlineResult,
-    RecentTradeResult, RiskLimitResult, ServerTimeResult, TickersResult,
+    AdlAlertResult, DeliveryPriceResult, FundingRateHistoryResult, HistoricalVolatilityResult,
+    IndexPriceComponentsResult, IndexPriceKlineResult, InsuranceResult, KlineResult,
+    LongShortRatioResult, MarkPriceKlineResult, NewDeliveryPriceResult, OpenInterestResult,
+    OrderBookResult, PremiumIndexPriceKlineResult, PriceLimitResult, RecentTradeResult,
+    RiskLimitResult, RpiOrderBookResult, ServerTimeResult, TickersResult,
 };
 use crate::rest::BybitResult as Result;
 use serde_json::json;
@@ -501,6 +502,122 @@ impl MarketClient {
             self.client.get(endpoint, params, SecType::None).await?;
         Ok(response)
     }
+
+    /// Get order price limit
+    ///
+    /// The highest buy and lowest sell price currently accepted for a symbol;
+    /// `PriceLimitResult::clamp` pulls a limit price inside that band.
+    ///
+    /// API: GET /v5/market/price-limit
+    /// https://bybit-exchange.github.io/docs/v5/market/order-price-limit
+    pub async fn get_price_limit(
+        &self,
+        category: Option<Category>,
+        symbol: &str,
+    ) -> Result<ServerResponse<PriceLimitResult>> {
+        let endpoint = "v5/market/price-limit";
+        let mut params = json!({
+            "symbol": symbol,
+        });
+
+        if let Some(category) = category {
+            params["category"] = json!(category);
+        }
+
+        let response: ServerResponse<PriceLimitResult> =
+            self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get index price components
+    ///
+    /// API: GET /v5/market/index-price-components
+    /// https://bybit-exchange.github.io/docs/v5/market/index-components
+    pub async fn get_index_price_components(
+        &self,
+        index_name: &str,
+    ) -> Result<ServerResponse<IndexPriceComponentsResult>> {
+        let endpoint = "v5/market/index-price-components";
+        let params = json!({
+            "indexName": index_name,
+        });
+
+        let response: ServerResponse<IndexPriceComponentsResult> =
+            self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get new delivery price
+    ///
+    /// API: GET /v5/market/new-delivery-price
+    /// https://bybit-exchange.github.io/docs/v5/market/new-delivery-price
+    pub async fn get_new_delivery_price(
+        &self,
+        category: Category,
+        base_coin: &str,
+        settle_coin: Option<&str>,
+    ) -> Result<ServerResponse<NewDeliveryPriceResult>> {
+        let endpoint = "v5/market/new-delivery-price";
+        let mut params = json!({
+            "category": category,
+            "baseCoin": base_coin,
+        });
+
+        if let Some(settle_coin) = settle_coin {
+            params["settleCoin"] = json!(settle_coin);
+        }
+
+        let response: ServerResponse<NewDeliveryPriceResult> =
+            self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get ADL alert
+    ///
+    /// API: GET /v5/market/adlAlert
+    /// https://bybit-exchange.github.io/docs/v5/market/adl-alert
+    pub async fn get_adl_alert(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<ServerResponse<AdlAlertResult>> {
+        let endpoint = "v5/market/adlAlert";
+        let mut params = json!({});
+
+        if let Some(symbol) = symbol {
+            params["symbol"] = json!(symbol);
+        }
+
+        let response: ServerResponse<AdlAlertResult> =
+            self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get RPI orderbook
+    ///
+    /// Orderbook with retail price improvement (RPI) size split out per level.
+    ///
+    /// API: GET /v5/market/rpi_orderbook
+    /// https://bybit-exchange.github.io/docs/v5/market/rpi-orderbook
+    pub async fn get_rpi_orderbook(
+        &self,
+        category: Option<Category>,
+        symbol: &str,
+        limit: i32,
+    ) -> Result<ServerResponse<RpiOrderBookResult>> {
+        let endpoint = "v5/market/rpi_orderbook";
+        let mut params = json!({
+            "symbol": symbol,
+            "limit": limit,
+        });
+
+        if let Some(category) = category {
+            params["category"] = json!(category);
+        }
+
+        let response: ServerResponse<RpiOrderBookResult> =
+            self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
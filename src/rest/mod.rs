@@ -5,15 +5,20 @@ pub mod asset;
 pub mod broker;
 pub mod client;
 pub mod crypto_loan;
+pub mod crypto_loan_new;
+pub mod earn;
 pub mod enums;
 pub mod errors;
 pub mod institutional_loan;
 pub mod market;
 pub mod order;
+pub mod p2p;
 pub mod position;
 pub mod pre_upgrade;
+pub mod rfq;
 pub mod spot_leverage_token;
 pub mod spot_margin_trade;
+pub mod spread;
 pub mod user;
 
 // Re-export commonly used types
@@ -24,12 +29,17 @@ pub use asset::AssetClient;
 pub use broker::BrokerClient;
 pub use client::{RestClient, SecType, ServerResponse};
 pub use crypto_loan::CryptoLoanClient;
+pub use crypto_loan_new::CryptoLoanNewClient;
+pub use earn::EarnClient;
 pub use errors::{BybitError, BybitResult};
 pub use institutional_loan::InstitutionalLoanClient;
 pub use market::MarketClient;
 pub use order::OrderClient;
+pub use p2p::P2pClient;
 pub use position::PositionClient;
 pub use pre_upgrade::PreUpgradeClient;
+pub use rfq::RfqClient;
 pub use spot_leverage_token::SpotLeverageTokenClient;
 pub use spot_margin_trade::SpotMarginTradeClient;
+pub use spread::SpreadClient;
 pub use user::UserClient;
@@ -2,8 +2,10 @@ pub mod amend_order;
 pub mod cancel_order;
 pub mod order_history;
 pub mod place_order;
+pub mod pre_check;
 
 pub use amend_order::*;
 pub use cancel_order::*;
 pub use order_history::*;
 pub use place_order::*;
+pub use pre_check::*;
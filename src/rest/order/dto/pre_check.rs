@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/order/pre-check-order
+//
+// POST /v5/order/pre-check
+// {
+//     "orderId": "24920bd1-df28-4926-a08e-4e2ff5e5bd3b",
+//     "orderLinkId": "",
+//     "preImrE4": 30,
+//     "preMmrE4": 21,
+//     "postImrE4": 357,
+//     "postMmrE4": 294
+// }
+
+/// Account margin rates before and after a hypothetical order
+///
+/// Rates are reported in units of 1e-4, so `postImrE4: 357` is 3.57%.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreCheckOrderResult {
+    #[serde(default)]
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+    pub pre_imr_e4: i64,
+    pub pre_mmr_e4: i64,
+    pub post_imr_e4: i64,
+    pub post_mmr_e4: i64,
+}
+
+impl PreCheckOrderResult {
+    /// Initial margin rate before the order, as a fraction
+    pub fn pre_imr(&self) -> f64 {
+        self.pre_imr_e4 as f64 / 1e4
+    }
+
+    /// Maintenance margin rate before the order, as a fraction
+    pub fn pre_mmr(&self) -> f64 {
+        self.pre_mmr_e4 as f64 / 1e4
+    }
+
+    /// Initial margin rate after the order, as a fraction
+    pub fn post_imr(&self) -> f64 {
+        self.post_imr_e4 as f64 / 1e4
+    }
+
+    /// Maintenance margin rate after the order, as a fraction
+    pub fn post_mmr(&self) -> f64 {
+        self.post_mmr_e4 as f64 / 1e4
+    }
+
+    /// True if the order raises IMR and leaves it above `max_imr`
+    ///
+    /// Orders that lower IMR, such as reductions, are never flagged even
+    /// when the account is already above the limit.
+    pub fn exceeds_imr(&self, max_imr: f64) -> bool {
+        self.post_imr_e4 > self.pre_imr_e4 && self.post_imr() > max_imr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_pre_check() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "orderId": "24920bd1-df28-4926-a08e-4e2ff5e5bd3b",
+                "orderLinkId": "",
+                "preImrE4": 30,
+                "preMmrE4": 21,
+                "postImrE4": 357,
+                "postMmrE4": 294
+            },
+            "retExtInfo": {},
+            "time": 1749541599589
+        }"#;
+        let response: ServerResponse<PreCheckOrderResult> =
+            serde_json::from_str(json_data).unwrap();
+        let result = response.result;
+        assert_eq!(result.post_imr(), 0.0357);
+        assert!(result.exceeds_imr(0.03));
+        assert!(!result.exceeds_imr(0.05));
+    }
+
+    #[test]
+    fn test_reducing_order_never_exceeds() {
+        let result = PreCheckOrderResult {
+            order_id: String::new(),
+            order_link_id: String::new(),
+            pre_imr_e4: 9000,
+            pre_mmr_e4: 7000,
+            post_imr_e4: 8500,
+            post_mmr_e4: 6500,
+        };
+        assert!(!result.exceeds_imr(0.5));
+    }
+}
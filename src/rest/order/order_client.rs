@@ -1,27 +1,70 @@
+use crate::maintenance::MaintenanceGuard;
 use crate::rest::client::{RestClient, SecType, ServerResponse};
 use crate::rest::enums::category::Category;
 use crate::rest::order::dto::*;
-use crate::rest::BybitResult as Result;
+use crate::rest::{BybitError, BybitResult as Result};
 use serde_json::json;
 
 #[derive(Clone)]
 pub struct OrderClient {
     client: RestClient,
+    max_imr: Option<f64>,
+    maintenance: Option<MaintenanceGuard>,
 }
 
 impl OrderClient {
     pub fn new(client: RestClient) -> Self {
-        OrderClient { client }
+        OrderClient {
+            client,
+            max_imr: None,
+            maintenance: None,
+        }
+    }
+
+    /// Refuse orders that would raise the account IMR above `max_imr`
+    ///
+    /// `max_imr` is a fraction, e.g. `0.5` for 50%. Once set, `place_order`
+    /// and `batch_place_orders` first call `pre_check` for each order and
+    /// return `BybitError::Rejected` instead of placing an order that fails
+    /// the check. Batch orders are checked one by one against the current
+    /// account, not against each other.
+    ///
+    /// `amend_order` and `batch_amend_orders` are not checked: Bybit's
+    /// pre-check only takes new orders, so an amend that raises the qty of a
+    /// resting order can push IMR past `max_imr`. Cancel and re-place the
+    /// order instead to keep it under the guard.
+    pub fn with_max_imr(mut self, max_imr: f64) -> Self {
+        self.max_imr = Some(max_imr);
+        self
+    }
+
+    /// Refuse to place orders during announced maintenance windows
+    ///
+    /// While `guard` reports an active window for the order's category,
+    /// `place_order`, `batch_place_orders`, `amend_order` and
+    /// `batch_amend_orders` return `BybitError::Rejected` without contacting
+    /// the server. Cancels are always sent. Windows are matched against the
+    /// guard's environment (`MaintenanceGuard::with_env`), so configure it
+    /// for the same environment as this client.
+    pub fn with_maintenance_guard(mut self, guard: MaintenanceGuard) -> Self {
+        self.maintenance = Some(guard);
+        self
     }
 
     /// Place an order
     ///
+    /// Runs the maintenance guard (`with_maintenance_guard`) and the IMR
+    /// guard (`with_max_imr`) first when set.
+    ///
     /// API: POST /v5/order/create
     /// https://bybit-exchange.github.io/docs/v5/order/create-order
     pub async fn place_order(
         &self,
         order: PlaceOrderRequest,
     ) -> Result<ServerResponse<PlaceOrderResponse>> {
+        self.check_maintenance(order.category)?;
+        self.check_imr(&order).await?;
+
         let endpoint = "v5/order/create";
         let body = serde_json::to_value(&order)?;
 
@@ -30,8 +73,57 @@ impl OrderClient {
         Ok(response)
     }
 
+    fn check_maintenance(&self, category: Category) -> Result<()> {
+        match self.maintenance {
+            Some(ref guard) => guard.check(Some(category)),
+            None => Ok(()),
+        }
+    }
+
+    /// Refuse `order` if the IMR guard is set and the pre-check fails it.
+    async fn check_imr(&self, order: &PlaceOrderRequest) -> Result<()> {
+        let Some(max_imr) = self.max_imr else {
+            return Ok(());
+        };
+        let check = self.pre_check(order.clone()).await?.result;
+        if check.exceeds_imr(max_imr) {
+            return Err(BybitError::Rejected {
+                reason: format!(
+                    "IMR guard refused {} order: IMR would rise from {:.2}% to {:.2}%, above the {:.2}% limit",
+                    order.symbol,
+                    check.pre_imr() * 100.0,
+                    check.post_imr() * 100.0,
+                    max_imr * 100.0
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Pre-check an order
+    ///
+    /// Returns the initial and maintenance margin rates before and after
+    /// the order, without placing it.
+    ///
+    /// API: POST /v5/order/pre-check
+    /// https://bybit-exchange.github.io/docs/v5/order/pre-check-order
+    pub async fn pre_check(
+        &self,
+        order: PlaceOrderRequest,
+    ) -> Result<ServerResponse<PreCheckOrderResult>> {
+        let endpoint = "v5/order/pre-check";
+        let body = serde_json::to_value(&order)?;
+
+        let response: ServerResponse<PreCheckOrderResult> =
+            self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
     /// Batch place orders (Option only)
     ///
+    /// With the IMR guard set, the whole batch is refused if any order
+    /// fails its pre-check.
+    ///
     /// API: POST /v5/order/create-batch
     /// https://bybit-exchange.github.io/docs/v5/order/batch-place
     pub async fn batch_place_orders(
@@ -39,6 +131,11 @@ impl OrderClient {
         category: Category,
         orders: Vec<PlaceOrderRequest>,
     ) -> Result<ServerResponse<BatchPlaceOrderResponse>> {
+        self.check_maintenance(category)?;
+        for order in &orders {
+            self.check_imr(order).await?;
+        }
+
         let endpoint = "v5/order/create-batch";
         let body = json!({
             "category": category,
@@ -58,6 +155,7 @@ impl OrderClient {
         &self,
         amend_request: AmendOrderRequest,
     ) -> Result<ServerResponse<AmendOrderResponse>> {
+        self.check_maintenance(amend_request.category)?;
         let endpoint = "v5/order/amend";
         let body = serde_json::to_value(&amend_request)?;
 
@@ -75,6 +173,7 @@ impl OrderClient {
         category: Category,
         amendments: Vec<AmendOrderRequest>,
     ) -> Result<ServerResponse<BatchAmendOrderResponse>> {
+        self.check_maintenance(category)?;
         let endpoint = "v5/order/amend-batch";
         let body = json!({
             "category": category,
@@ -0,0 +1,375 @@
+use crate::rest::enums::p2p::{P2pAdAction, P2pPriceType, P2pSide};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/p2p/ad/online-ad-list
+//
+// POST /v5/p2p/item/online
+// {
+//     "tokenId": "USDT",
+//     "currencyId": "EUR",
+//     "side": "0",
+//     "page": "1",
+//     "size": "10"
+// }
+
+/// Query for the public ad board
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pOnlineAdsRequest {
+    pub token_id: String,
+    pub currency_id: String,
+    /// Ads to buy from (`Sell` ads) or sell to (`Buy` ads)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<P2pSide>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+}
+
+// POST /v5/p2p/item/personal/list
+// {
+//     "status": "2",
+//     "side": "1",
+//     "tokenId": "USDT",
+//     "currencyId": "EUR"
+// }
+
+/// Query for the caller's own ads
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pPersonalAdsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    /// 1: sold out, 2: available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<P2pSide>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+}
+
+/// Counterparty requirements attached to an ad
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingPreferenceSet {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_unpost_ad: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_kyc: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_email: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_mobile: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_register_time: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub register_time_threshold: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_finish_number_day30: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete_rate_day30: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nationality_limit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_order_finish_number_day30: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_complete_rate_day30: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_nationality_limit: Option<i32>,
+}
+
+// {
+//     "count": 1,
+//     "items": [
+//         {
+//             "id": "1898988222063644672",
+//             "accountId": "1510",
+//             "userId": "123487",
+//             "nickName": "Desk-01",
+//             "tokenId": "USDT",
+//             "tokenName": "USDT",
+//             "currencyId": "EUR",
+//             "side": 1,
+//             "priceType": 0,
+//             "price": "0.93",
+//             "premium": "",
+//             "lastQuantity": "980.5",
+//             "quantity": "1000",
+//             "frozenQuantity": "0",
+//             "executedQuantity": "19.5",
+//             "minAmount": "20",
+//             "maxAmount": "900",
+//             "remark": "",
+//             "status": 10,
+//             "createDate": "1741594618000",
+//             "payments": ["14"],
+//             "orderNum": 3,
+//             "finishNum": 2,
+//             "recentOrderNum": 3,
+//             "recentExecuteRate": 66,
+//             "fee": "",
+//             "isOnline": true,
+//             "lastLogoutTime": "1741595000000",
+//             "paymentPeriod": 15,
+//             "itemType": "ORIGIN"
+//         }
+//     ]
+// }
+
+/// A P2P advertisement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pAd {
+    pub id: String,
+    #[serde(default)]
+    pub account_id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub nick_name: String,
+    pub token_id: String,
+    #[serde(default)]
+    pub token_name: String,
+    pub currency_id: String,
+    pub side: P2pSide,
+    pub price_type: P2pPriceType,
+    pub price: String,
+    /// Floating rate premium, in percent
+    #[serde(default)]
+    pub premium: String,
+    /// Quantity still available
+    #[serde(default)]
+    pub last_quantity: String,
+    #[serde(default)]
+    pub quantity: String,
+    #[serde(default)]
+    pub frozen_quantity: String,
+    #[serde(default)]
+    pub executed_quantity: String,
+    /// Minimum fiat amount per order
+    pub min_amount: String,
+    /// Maximum fiat amount per order
+    pub max_amount: String,
+    #[serde(default)]
+    pub remark: String,
+    /// 10: online, 20: offline, 30: completed
+    #[serde(default)]
+    pub status: i32,
+    #[serde(default)]
+    pub create_date: String,
+    /// Payment method ids
+    #[serde(default)]
+    pub payments: Vec<String>,
+    #[serde(default)]
+    pub order_num: i32,
+    #[serde(default)]
+    pub finish_num: i32,
+    #[serde(default)]
+    pub recent_order_num: i32,
+    /// Completion rate over the last 30 days, in percent
+    #[serde(default)]
+    pub recent_execute_rate: i32,
+    #[serde(default)]
+    pub fee: String,
+    #[serde(default)]
+    pub is_online: bool,
+    #[serde(default)]
+    pub last_logout_time: String,
+    /// Minutes the buyer has to pay
+    #[serde(default)]
+    pub payment_period: i32,
+    #[serde(default)]
+    pub item_type: String,
+    #[serde(default)]
+    pub trading_preference_set: Option<TradingPreferenceSet>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pAdListResult {
+    #[serde(default)]
+    pub count: i64,
+    #[serde(default)]
+    pub items: Vec<P2pAd>,
+}
+
+// https://bybit-exchange.github.io/docs/p2p/ad/post-new-ad
+//
+// POST /v5/p2p/item/create
+// {
+//     "tokenId": "USDT",
+//     "currencyId": "EUR",
+//     "side": "1",
+//     "priceType": "0",
+//     "premium": "",
+//     "price": "0.93",
+//     "minAmount": "20",
+//     "maxAmount": "900",
+//     "remark": "",
+//     "tradingPreferenceSet": {},
+//     "paymentIds": ["14"],
+//     "quantity": "1000",
+//     "paymentPeriod": "15",
+//     "itemType": "ORIGIN"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pCreateAdRequest {
+    pub token_id: String,
+    pub currency_id: String,
+    pub side: P2pSide,
+    pub price_type: P2pPriceType,
+    /// Required for `Floating` ads, empty otherwise
+    pub premium: String,
+    pub price: String,
+    pub min_amount: String,
+    pub max_amount: String,
+    pub remark: String,
+    pub trading_preference_set: TradingPreferenceSet,
+    pub payment_ids: Vec<String>,
+    pub quantity: String,
+    pub payment_period: String,
+    /// ORIGIN or BULK
+    pub item_type: String,
+}
+
+// {
+//     "itemId": "1898988222063644672",
+//     "securityRiskToken": "",
+//     "riskTokenType": "challenge",
+//     "riskVersion": "1",
+//     "needSecurityRisk": false
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pCreateAdResult {
+    pub item_id: String,
+    #[serde(default)]
+    pub security_risk_token: String,
+    #[serde(default)]
+    pub risk_token_type: String,
+    #[serde(default)]
+    pub risk_version: String,
+    /// True if the ad is held for a security check before going live
+    #[serde(default)]
+    pub need_security_risk: bool,
+}
+
+// https://bybit-exchange.github.io/docs/p2p/ad/update-list-ad
+//
+// POST /v5/p2p/item/update
+// {
+//     "id": "1898988222063644672",
+//     "priceType": "0",
+//     "premium": "",
+//     "price": "0.94",
+//     "minAmount": "20",
+//     "maxAmount": "900",
+//     "remark": "",
+//     "tradingPreferenceSet": {},
+//     "paymentIds": ["14"],
+//     "actionType": "MODIFY",
+//     "quantity": "1000",
+//     "paymentPeriod": "15"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pUpdateAdRequest {
+    pub id: String,
+    pub price_type: P2pPriceType,
+    pub premium: String,
+    pub price: String,
+    pub min_amount: String,
+    pub max_amount: String,
+    pub remark: String,
+    pub trading_preference_set: TradingPreferenceSet,
+    pub payment_ids: Vec<String>,
+    pub action_type: P2pAdAction,
+    pub quantity: String,
+    pub payment_period: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pUpdateAdResult {
+    #[serde(default)]
+    pub security_risk_token: String,
+    #[serde(default)]
+    pub risk_token_type: String,
+    #[serde(default)]
+    pub risk_version: String,
+    #[serde(default)]
+    pub need_security_risk: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_online_ads() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "result": {
+                "count": 1,
+                "items": [
+                    {
+                        "id": "1898988222063644672",
+                        "accountId": "1510",
+                        "userId": "123487",
+                        "nickName": "Desk-01",
+                        "tokenId": "USDT",
+                        "tokenName": "USDT",
+                        "currencyId": "EUR",
+                        "side": 1,
+                        "priceType": 0,
+                        "price": "0.93",
+                        "premium": "",
+                        "lastQuantity": "980.5",
+                        "quantity": "1000",
+                        "minAmount": "20",
+                        "maxAmount": "900",
+                        "status": 10,
+                        "payments": ["14"],
+                        "recentExecuteRate": 66,
+                        "isOnline": true,
+                        "paymentPeriod": 15
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1741594618000
+        }"#;
+        let response: ServerResponse<P2pAdListResult> = serde_json::from_str(json_data).unwrap();
+        let ad = &response.result.items[0];
+        assert_eq!(ad.side, P2pSide::Sell);
+        assert_eq!(ad.price_type, P2pPriceType::Fixed);
+        assert_eq!(ad.payments, vec!["14".to_string()]);
+    }
+
+    #[test]
+    fn test_serialize_online_ads_request() {
+        let request = P2pOnlineAdsRequest {
+            token_id: "USDT".to_string(),
+            currency_id: "EUR".to_string(),
+            side: Some(P2pSide::Buy),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["side"], "0");
+        assert!(body.get("page").is_none());
+    }
+}
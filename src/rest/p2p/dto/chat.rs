@@ -0,0 +1,143 @@
+use crate::rest::enums::p2p::P2pContentType;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/p2p/order/send-chat-msg
+//
+// POST /v5/p2p/order/message/send
+// {
+//     "message": "Paid, please check",
+//     "contentType": "str",
+//     "orderId": "1899736326186143744",
+//     "msgUuid": "6a1b5e1f-c7a8-4a5c-9d8c-4f7b0a2e9c11"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pSendMessageRequest {
+    /// Text, or the url returned by `upload_file` for other content types
+    pub message: String,
+    pub content_type: P2pContentType,
+    pub order_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msg_uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+}
+
+impl P2pSendMessageRequest {
+    pub fn text(order_id: &str, message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            content_type: P2pContentType::Text,
+            order_id: order_id.to_string(),
+            msg_uuid: None,
+            file_name: None,
+        }
+    }
+
+    /// Share a file previously sent through `upload_file`
+    pub fn file(order_id: &str, upload: &P2pUploadResult, file_name: &str) -> Self {
+        let content_type = match upload.file_type.as_str() {
+            "pdf" => P2pContentType::Pdf,
+            "video" => P2pContentType::Video,
+            _ => P2pContentType::Picture,
+        };
+        Self {
+            message: upload.url.clone(),
+            content_type,
+            order_id: order_id.to_string(),
+            msg_uuid: None,
+            file_name: Some(file_name.to_string()),
+        }
+    }
+}
+
+// https://bybit-exchange.github.io/docs/p2p/order/chat-msg
+//
+// POST /v5/p2p/order/message/listpage
+// [
+//     {
+//         "id": "35401",
+//         "message": "Paid, please check",
+//         "userId": "200013",
+//         "msgType": 1,
+//         "msgCode": 0,
+//         "createDate": "1741773150000",
+//         "contentType": "str",
+//         "orderId": "1899736326186143744",
+//         "msgUuid": "6a1b5e1f-c7a8-4a5c-9d8c-4f7b0a2e9c11",
+//         "nickName": "buyer-7",
+//         "fileName": "",
+//         "accountId": "2204",
+//         "isRead": 1,
+//         "roleType": "user",
+//         "onlyForCustomer": 0
+//     }
+// ]
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pChatMessage {
+    pub id: String,
+    pub message: String,
+    #[serde(default)]
+    pub user_id: String,
+    /// 0: system, 1: user, 2: customer support
+    #[serde(default)]
+    pub msg_type: i32,
+    #[serde(default)]
+    pub msg_code: i32,
+    #[serde(default)]
+    pub create_date: String,
+    pub content_type: P2pContentType,
+    pub order_id: String,
+    #[serde(default)]
+    pub msg_uuid: String,
+    #[serde(default)]
+    pub nick_name: String,
+    #[serde(default)]
+    pub file_name: String,
+    #[serde(default)]
+    pub account_id: String,
+    #[serde(default)]
+    pub is_read: i32,
+    #[serde(default)]
+    pub role_type: String,
+    #[serde(default)]
+    pub only_for_customer: i32,
+}
+
+// https://bybit-exchange.github.io/docs/p2p/order/upload-chat-file
+//
+// POST /v5/p2p/oss/upload_file (multipart/form-data, field "upload_file")
+// {
+//     "url": "/fiat/p2p/oss/show/b4f7d4c5e8.png?e=1741773200&token=...",
+//     "type": "pic"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pUploadResult {
+    pub url: String,
+    /// pic, pdf or video
+    #[serde(rename = "type", default)]
+    pub file_type: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_message_from_upload() {
+        let upload = P2pUploadResult {
+            url: "/fiat/p2p/oss/show/receipt.pdf".to_string(),
+            file_type: "pdf".to_string(),
+        };
+        let request = P2pSendMessageRequest::file("1899736326186143744", &upload, "receipt.pdf");
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["contentType"], "pdf");
+        assert_eq!(body["message"], "/fiat/p2p/oss/show/receipt.pdf");
+        assert_eq!(body["fileName"], "receipt.pdf");
+    }
+}
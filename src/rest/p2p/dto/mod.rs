@@ -0,0 +1,9 @@
+pub mod ad;
+pub mod chat;
+pub mod order;
+pub mod user;
+
+pub use ad::*;
+pub use chat::*;
+pub use order::*;
+pub use user::*;
@@ -0,0 +1,310 @@
+use crate::rest::enums::p2p::P2pSide;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/p2p/order/order-list
+//
+// POST /v5/p2p/order/simplifyList
+// POST /v5/p2p/order/pending/simplifyList
+// {
+//     "page": 1,
+//     "size": 10,
+//     "status": 10,
+//     "tokenId": "USDT"
+// }
+
+/// Query for the order list, also used for pending orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pOrderListRequest {
+    pub page: i32,
+    pub size: i32,
+    /// Order status, see [`P2pOrder::status`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+    /// 0: buy, 1: sell
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<i32>,
+}
+
+impl P2pOrderListRequest {
+    pub fn new(page: i32, size: i32) -> Self {
+        Self {
+            page,
+            size,
+            status: None,
+            begin_time: None,
+            end_time: None,
+            token_id: None,
+            side: None,
+        }
+    }
+}
+
+// {
+//     "count": 1,
+//     "items": [
+//         {
+//             "id": "1899736326186143744",
+//             "side": 1,
+//             "tokenId": "USDT",
+//             "orderType": "ORIGIN",
+//             "amount": "93",
+//             "currencyId": "EUR",
+//             "price": "0.93",
+//             "notifyTokenQuantity": "100",
+//             "notifyTokenId": "USDT",
+//             "fee": "0",
+//             "targetNickName": "buyer-7",
+//             "targetUserId": "200013",
+//             "status": 20,
+//             "selfUnreadMsgCount": "1",
+//             "createDate": "1741773037000",
+//             "transferLastSeconds": "0",
+//             "appealLastSeconds": "0",
+//             "userId": "123487",
+//             "sellerRealName": "",
+//             "buyerRealName": ""
+//         }
+//     ]
+// }
+
+/// Order as returned by the order lists
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pOrderSummary {
+    pub id: String,
+    pub side: P2pSide,
+    pub token_id: String,
+    #[serde(default)]
+    pub order_type: String,
+    /// Fiat amount
+    pub amount: String,
+    pub currency_id: String,
+    pub price: String,
+    /// Crypto quantity
+    #[serde(default)]
+    pub notify_token_quantity: String,
+    #[serde(default)]
+    pub notify_token_id: String,
+    #[serde(default)]
+    pub fee: String,
+    #[serde(default)]
+    pub target_nick_name: String,
+    #[serde(default)]
+    pub target_user_id: String,
+    pub status: i32,
+    #[serde(default)]
+    pub self_unread_msg_count: String,
+    #[serde(default)]
+    pub create_date: String,
+    #[serde(default)]
+    pub transfer_last_seconds: String,
+    #[serde(default)]
+    pub appeal_last_seconds: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub seller_real_name: String,
+    #[serde(default)]
+    pub buyer_real_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pOrderListResult {
+    #[serde(default)]
+    pub count: i64,
+    #[serde(default)]
+    pub items: Vec<P2pOrderSummary>,
+}
+
+// https://bybit-exchange.github.io/docs/p2p/order/order-detail
+//
+// POST /v5/p2p/order/info
+// {
+//     "id": "1899736326186143744",
+//     "side": 1,
+//     "itemId": "1898988222063644672",
+//     "userId": "123487",
+//     "nickName": "Desk-01",
+//     "makerUserId": "123487",
+//     "targetUserId": "200013",
+//     "targetNickName": "buyer-7",
+//     "sellerRealName": "",
+//     "buyerRealName": "",
+//     "tokenId": "USDT",
+//     "currencyId": "EUR",
+//     "price": "0.93",
+//     "quantity": "100",
+//     "amount": "93",
+//     "payCode": "",
+//     "paymentType": 14,
+//     "transferDate": "1741773100000",
+//     "status": 20,
+//     "createDate": "1741773037000",
+//     "paymentTermList": [
+//         {
+//             "id": "5027",
+//             "realName": "Jane Doe",
+//             "paymentType": 14,
+//             "bankName": "",
+//             "branchName": "",
+//             "accountNo": "DE00123456780000000000",
+//             "qrcode": "",
+//             "visible": 1,
+//             "payMessage": ""
+//         }
+//     ],
+//     "remark": "",
+//     "transferLastSeconds": "0",
+//     "appealLastSeconds": "0",
+//     "fee": "0"
+// }
+
+/// A payment method offered on an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pPaymentTerm {
+    pub id: String,
+    #[serde(default)]
+    pub real_name: String,
+    pub payment_type: i32,
+    #[serde(default)]
+    pub bank_name: String,
+    #[serde(default)]
+    pub branch_name: String,
+    #[serde(default)]
+    pub account_no: String,
+    #[serde(default)]
+    pub qrcode: String,
+    #[serde(default)]
+    pub visible: i32,
+    #[serde(default)]
+    pub pay_message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pOrder {
+    pub id: String,
+    pub side: P2pSide,
+    #[serde(default)]
+    pub item_id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub nick_name: String,
+    #[serde(default)]
+    pub maker_user_id: String,
+    #[serde(default)]
+    pub target_user_id: String,
+    #[serde(default)]
+    pub target_nick_name: String,
+    #[serde(default)]
+    pub seller_real_name: String,
+    #[serde(default)]
+    pub buyer_real_name: String,
+    pub token_id: String,
+    pub currency_id: String,
+    pub price: String,
+    pub quantity: String,
+    pub amount: String,
+    #[serde(default)]
+    pub pay_code: String,
+    /// Payment method the buyer paid with
+    #[serde(default)]
+    pub payment_type: i32,
+    #[serde(default)]
+    pub transfer_date: String,
+    /// 5: waiting for chain, 10: waiting for buyer to pay,
+    /// 20: waiting for seller to release, 30: appealing, 40: cancelled,
+    /// 50: finished, 60: paying, 70: pay failed,
+    /// 80: cancelled by exception, 90: waiting for buyer to pick a token,
+    /// 100: objecting, 110: waiting for objection
+    pub status: i32,
+    #[serde(default)]
+    pub create_date: String,
+    #[serde(default)]
+    pub payment_term_list: Vec<P2pPaymentTerm>,
+    #[serde(default)]
+    pub remark: String,
+    #[serde(default)]
+    pub transfer_last_seconds: String,
+    #[serde(default)]
+    pub appeal_last_seconds: String,
+    #[serde(default)]
+    pub fee: String,
+}
+
+impl P2pOrder {
+    /// True once the order is finished or cancelled
+    pub fn is_final(&self) -> bool {
+        matches!(self.status, 40 | 50 | 80)
+    }
+}
+
+// https://bybit-exchange.github.io/docs/p2p/order/mark-order-as-paid
+//
+// POST /v5/p2p/order/pay
+// {
+//     "orderId": "1899736326186143744",
+//     "paymentType": "14",
+//     "paymentId": "5027"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pMarkPaidRequest {
+    pub order_id: String,
+    pub payment_type: String,
+    /// `P2pPaymentTerm::id` of the method that was paid to
+    pub payment_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_order_detail() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "result": {
+                "id": "1899736326186143744",
+                "side": 1,
+                "itemId": "1898988222063644672",
+                "tokenId": "USDT",
+                "currencyId": "EUR",
+                "price": "0.93",
+                "quantity": "100",
+                "amount": "93",
+                "paymentType": 14,
+                "status": 20,
+                "paymentTermList": [
+                    {
+                        "id": "5027",
+                        "realName": "Jane Doe",
+                        "paymentType": 14,
+                        "accountNo": "DE00123456780000000000",
+                        "visible": 1
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1741773100000
+        }"#;
+        let response: ServerResponse<P2pOrder> = serde_json::from_str(json_data).unwrap();
+        let order = response.result;
+        assert_eq!(order.side, P2pSide::Sell);
+        assert_eq!(order.payment_term_list[0].id, "5027");
+        assert!(!order.is_final());
+    }
+}
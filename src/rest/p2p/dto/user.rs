@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/p2p/user/acct-info
+//
+// POST /v5/p2p/user/personal/info
+// {
+//     "nickName": "Desk-01",
+//     "defaultNickName": false,
+//     "isOnline": true,
+//     "kycLevel": 2,
+//     "email": "de***@example.com",
+//     "mobile": "",
+//     "lastLogoutTime": "1741595000000",
+//     "recentRate": 98,
+//     "totalFinishCount": 1240,
+//     "totalFinishSellCount": 800,
+//     "totalFinishBuyCount": 440,
+//     "recentFinishCount": 120,
+//     "averageReleaseTime": "2.5",
+//     "averageTransferTime": "4.1",
+//     "accountCreateDays": 812,
+//     "firstTrade": 790,
+//     "realName": "Jane Doe",
+//     "recentTradeAmount": "120000",
+//     "totalTradeAmount": "1900000",
+//     "registerTime": "1671430000000",
+//     "authStatus": 2,
+//     "kycCountryCode": "DE",
+//     "blocked": "",
+//     "goodAppraiseRate": 99,
+//     "goodAppraiseCount": 600,
+//     "badAppraiseCount": 3,
+//     "accountId": "1510",
+//     "paymentCount": 2,
+//     "contactCount": 0,
+//     "vipLevel": 1,
+//     "userId": "123487",
+//     "realNameEn": "Jane Doe"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pUserInfo {
+    pub nick_name: String,
+    #[serde(default)]
+    pub default_nick_name: bool,
+    #[serde(default)]
+    pub is_online: bool,
+    #[serde(default)]
+    pub kyc_level: i32,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub mobile: String,
+    #[serde(default)]
+    pub last_logout_time: String,
+    /// Completion rate over the last 30 days, in percent
+    #[serde(default)]
+    pub recent_rate: i32,
+    #[serde(default)]
+    pub total_finish_count: i64,
+    #[serde(default)]
+    pub total_finish_sell_count: i64,
+    #[serde(default)]
+    pub total_finish_buy_count: i64,
+    #[serde(default)]
+    pub recent_finish_count: i64,
+    /// Minutes
+    #[serde(default)]
+    pub average_release_time: String,
+    /// Minutes
+    #[serde(default)]
+    pub average_transfer_time: String,
+    #[serde(default)]
+    pub account_create_days: i64,
+    #[serde(default)]
+    pub first_trade: i64,
+    #[serde(default)]
+    pub real_name: String,
+    #[serde(default)]
+    pub recent_trade_amount: String,
+    #[serde(default)]
+    pub total_trade_amount: String,
+    #[serde(default)]
+    pub register_time: String,
+    #[serde(default)]
+    pub auth_status: i32,
+    #[serde(default)]
+    pub kyc_country_code: String,
+    #[serde(default)]
+    pub blocked: String,
+    #[serde(default)]
+    pub good_appraise_rate: i32,
+    #[serde(default)]
+    pub good_appraise_count: i64,
+    #[serde(default)]
+    pub bad_appraise_count: i64,
+    #[serde(default)]
+    pub account_id: String,
+    #[serde(default)]
+    pub payment_count: i32,
+    #[serde(default)]
+    pub contact_count: i32,
+    #[serde(default)]
+    pub vip_level: i32,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub real_name_en: String,
+}
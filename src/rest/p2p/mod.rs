@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod p2p_client;
+
+pub use p2p_client::P2pClient;
@@ -0,0 +1,263 @@
+use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::p2p::dto::*;
+use crate::rest::BybitResult as Result;
+use serde_json::json;
+
+#[derive(Clone)]
+pub struct P2pClient {
+    client: RestClient,
+}
+
+impl P2pClient {
+    pub fn new(client: RestClient) -> Self {
+        P2pClient { client }
+    }
+
+    /// Get online ads
+    ///
+    /// API: POST /v5/p2p/item/online
+    /// https://bybit-exchange.github.io/docs/p2p/ad/online-ad-list
+    pub async fn get_online_ads(
+        &self,
+        request: P2pOnlineAdsRequest,
+    ) -> Result<ServerResponse<P2pAdListResult>> {
+        let endpoint = "v5/p2p/item/online";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Post an ad
+    ///
+    /// API: POST /v5/p2p/item/create
+    /// https://bybit-exchange.github.io/docs/p2p/ad/post-new-ad
+    pub async fn create_ad(
+        &self,
+        request: P2pCreateAdRequest,
+    ) -> Result<ServerResponse<P2pCreateAdResult>> {
+        let endpoint = "v5/p2p/item/create";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Remove an ad
+    ///
+    /// API: POST /v5/p2p/item/cancel
+    /// https://bybit-exchange.github.io/docs/p2p/ad/remove-ad
+    pub async fn cancel_ad(&self, item_id: &str) -> Result<ServerResponse<serde_json::Value>> {
+        let endpoint = "v5/p2p/item/cancel";
+        let body = json!({
+            "itemId": item_id,
+        });
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Update or relist an ad
+    ///
+    /// API: POST /v5/p2p/item/update
+    /// https://bybit-exchange.github.io/docs/p2p/ad/update-list-ad
+    pub async fn update_ad(
+        &self,
+        request: P2pUpdateAdRequest,
+    ) -> Result<ServerResponse<P2pUpdateAdResult>> {
+        let endpoint = "v5/p2p/item/update";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get my ads
+    ///
+    /// API: POST /v5/p2p/item/personal/list
+    /// https://bybit-exchange.github.io/docs/p2p/ad/ad-list
+    pub async fn get_personal_ads(
+        &self,
+        request: P2pPersonalAdsRequest,
+    ) -> Result<ServerResponse<P2pAdListResult>> {
+        let endpoint = "v5/p2p/item/personal/list";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get all orders
+    ///
+    /// API: POST /v5/p2p/order/simplifyList
+    /// https://bybit-exchange.github.io/docs/p2p/order/order-list
+    pub async fn get_orders(
+        &self,
+        request: P2pOrderListRequest,
+    ) -> Result<ServerResponse<P2pOrderListResult>> {
+        let endpoint = "v5/p2p/order/simplifyList";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get order detail
+    ///
+    /// API: POST /v5/p2p/order/info
+    /// https://bybit-exchange.github.io/docs/p2p/order/order-detail
+    pub async fn get_order_info(&self, order_id: &str) -> Result<ServerResponse<P2pOrder>> {
+        let endpoint = "v5/p2p/order/info";
+        let body = json!({
+            "orderId": order_id,
+        });
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get pending orders
+    ///
+    /// API: POST /v5/p2p/order/pending/simplifyList
+    /// https://bybit-exchange.github.io/docs/p2p/order/pending-order
+    pub async fn get_pending_orders(
+        &self,
+        request: P2pOrderListRequest,
+    ) -> Result<ServerResponse<P2pOrderListResult>> {
+        let endpoint = "v5/p2p/order/pending/simplifyList";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Mark order as paid
+    ///
+    /// API: POST /v5/p2p/order/pay
+    /// https://bybit-exchange.github.io/docs/p2p/order/mark-order-as-paid
+    pub async fn mark_paid(
+        &self,
+        request: P2pMarkPaidRequest,
+    ) -> Result<ServerResponse<serde_json::Value>> {
+        let endpoint = "v5/p2p/order/pay";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Release digital assets
+    ///
+    /// API: POST /v5/p2p/order/finish
+    /// https://bybit-exchange.github.io/docs/p2p/order/release-digital-asset
+    pub async fn release_assets(
+        &self,
+        order_id: &str,
+    ) -> Result<ServerResponse<serde_json::Value>> {
+        let endpoint = "v5/p2p/order/finish";
+        let body = json!({
+            "orderId": order_id,
+        });
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Send chat message
+    ///
+    /// API: POST /v5/p2p/order/message/send
+    /// https://bybit-exchange.github.io/docs/p2p/order/send-chat-msg
+    pub async fn send_message(
+        &self,
+        request: P2pSendMessageRequest,
+    ) -> Result<ServerResponse<serde_json::Value>> {
+        let endpoint = "v5/p2p/order/message/send";
+        let body = serde_json::to_value(&request)?;
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get chat messages
+    ///
+    /// API: POST /v5/p2p/order/message/listpage
+    /// https://bybit-exchange.github.io/docs/p2p/order/chat-msg
+    pub async fn get_messages(
+        &self,
+        order_id: &str,
+        current_page: Option<i32>,
+        size: i32,
+    ) -> Result<ServerResponse<Vec<P2pChatMessage>>> {
+        let endpoint = "v5/p2p/order/message/listpage";
+        let mut body = json!({
+            "orderId": order_id,
+            "size": size.to_string(),
+        });
+
+        if let Some(current_page) = current_page {
+            body["currentPage"] = json!(current_page.to_string());
+        }
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get account information
+    ///
+    /// API: POST /v5/p2p/user/personal/info
+    /// https://bybit-exchange.github.io/docs/p2p/user/acct-info
+    pub async fn get_user_info(&self) -> Result<ServerResponse<P2pUserInfo>> {
+        let endpoint = "v5/p2p/user/personal/info";
+        let response = self
+            .client
+            .post(endpoint, json!({}), SecType::Signed)
+            .await?;
+        Ok(response)
+    }
+
+    /// Upload chat file
+    ///
+    /// Sends `content` as a multipart upload. The MIME type is taken from
+    /// the `file_name` extension (jpg, jpeg, png, pdf, mp4). Share the result
+    /// in the chat with `P2pSendMessageRequest::file`.
+    ///
+    /// API: POST /v5/p2p/oss/upload_file
+    /// https://bybit-exchange.github.io/docs/p2p/order/upload-chat-file
+    pub async fn upload_file(
+        &self,
+        file_name: &str,
+        content: Vec<u8>,
+    ) -> Result<ServerResponse<P2pUploadResult>> {
+        let endpoint = "v5/p2p/oss/upload_file";
+        let response = self
+            .client
+            .post_multipart(
+                endpoint,
+                "upload_file",
+                file_name,
+                content_type_for(file_name),
+                content,
+                SecType::Signed,
+            )
+            .await?;
+        Ok(response)
+    }
+}
+
+fn content_type_for(file_name: &str) -> &'static str {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "pdf" => "application/pdf",
+        "mp4" => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_for() {
+        assert_eq!(content_type_for("receipt.PDF"), "application/pdf");
+        assert_eq!(content_type_for("proof.jpeg"), "image/jpeg");
+        assert_eq!(content_type_for("noext"), "application/octet-stream");
+    }
+}
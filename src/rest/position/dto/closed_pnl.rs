@@ -0,0 +1,150 @@
+use crate::rest::enums::{category::Category, order_type::OrderType, side::Side};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/position/close-pnl
+//
+// GET /v5/position/closed-pnl
+// {
+//     "retCode": 0,
+//     "retMsg": "OK",
+//     "result": {
+//         "nextPageCursor": "5a373bfe-188d-4913-9c81-d57ab5be8068%3A1672214887231423699",
+//         "category": "linear",
+//         "list": [
+//             {
+//                 "symbol": "ETHPERP",
+//                 "orderType": "Market",
+//                 "leverage": "3",
+//                 "updatedTime": "1672214887236",
+//                 "side": "Sell",
+//                 "orderId": "5a373bfe-188d-4913-9c81-d57ab5be8068",
+//                 "closedPnl": "-47.4065323",
+//                 "avgEntryPrice": "1194.97516667",
+//                 "qty": "3",
+//                 "cumEntryValue": "3584.9255",
+//                 "createdTime": "1672214887231",
+//                 "orderPrice": "1122.95",
+//                 "closedSize": "3",
+//                 "avgExitPrice": "1180.59833333",
+//                 "execType": "Trade",
+//                 "fillCount": "4",
+//                 "cumExitValue": "3541.795",
+//                 "openFee": "0.8962314",
+//                 "closeFee": "0.8854488"
+//             }
+//         ]
+//     },
+//     "retExtInfo": {},
+//     "time": 1672284129153
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetClosedPnlParams {
+    pub category: Category,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnl {
+    pub symbol: String,
+    pub order_id: String,
+    pub side: Side,
+    pub qty: String,
+    #[serde(default)]
+    pub order_price: String,
+    pub order_type: OrderType,
+    #[serde(default)]
+    pub exec_type: String,
+    #[serde(default)]
+    pub closed_size: String,
+    #[serde(default)]
+    pub cum_entry_value: String,
+    #[serde(default)]
+    pub avg_entry_price: String,
+    #[serde(default)]
+    pub cum_exit_value: String,
+    #[serde(default)]
+    pub avg_exit_price: String,
+    pub closed_pnl: String,
+    #[serde(default)]
+    pub fill_count: String,
+    #[serde(default)]
+    pub leverage: String,
+    #[serde(default)]
+    pub open_fee: String,
+    #[serde(default)]
+    pub close_fee: String,
+    pub created_time: String,
+    #[serde(default)]
+    pub updated_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnlResult {
+    #[serde(default)]
+    pub category: Option<Category>,
+    #[serde(default)]
+    pub list: Vec<ClosedPnl>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_closed_pnl() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "nextPageCursor": "5a373bfe%3A1672214887231423699",
+                "category": "linear",
+                "list": [
+                    {
+                        "symbol": "ETHPERP",
+                        "orderType": "Market",
+                        "leverage": "3",
+                        "updatedTime": "1672214887236",
+                        "side": "Sell",
+                        "orderId": "5a373bfe-188d-4913-9c81-d57ab5be8068",
+                        "closedPnl": "-47.4065323",
+                        "avgEntryPrice": "1194.97516667",
+                        "qty": "3",
+                        "cumEntryValue": "3584.9255",
+                        "createdTime": "1672214887231",
+                        "orderPrice": "1122.95",
+                        "closedSize": "3",
+                        "avgExitPrice": "1180.59833333",
+                        "execType": "Trade",
+                        "fillCount": "4",
+                        "cumExitValue": "3541.795",
+                        "openFee": "0.8962314",
+                        "closeFee": "0.8854488"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672284129153
+        }"#;
+        let response: ServerResponse<ClosedPnlResult> = serde_json::from_str(json_data).unwrap();
+        let pnl = &response.result.list[0];
+        assert_eq!(pnl.side, Side::Sell);
+        assert_eq!(pnl.order_type, OrderType::Market);
+        assert_eq!(pnl.closed_pnl, "-47.4065323");
+    }
+}
@@ -0,0 +1,49 @@
+use crate::rest::enums::{category::Category, position_mode::PositionMode, trade_mode::TradeMode};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/position/leverage
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLeverageRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub buy_leverage: String,
+    pub sell_leverage: String,
+}
+
+impl SetLeverageRequest {
+    /// Same leverage on both sides, as one-way mode requires
+    pub fn new(category: Category, symbol: &str, leverage: &str) -> Self {
+        SetLeverageRequest {
+            category,
+            symbol: symbol.to_string(),
+            buy_leverage: leverage.to_string(),
+            sell_leverage: leverage.to_string(),
+        }
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/position/cross-isolate
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchIsolatedRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub trade_mode: TradeMode,
+    pub buy_leverage: String,
+    pub sell_leverage: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/position/position-mode
+//
+// Either `symbol` or `coin` is required.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchPositionModeRequest {
+    pub category: Category,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin: Option<String>,
+    pub mode: PositionMode,
+}
@@ -0,0 +1,35 @@
+use crate::rest::enums::{category::Category, position_idx::PositionIdx};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/position/auto-add-margin
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAutoAddMarginRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub auto_add_margin: i32, // 0: off, 1: on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_idx: Option<PositionIdx>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/position/manual-add-margin
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AddMarginRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub margin: String, // positive to add, negative to reduce
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_idx: Option<PositionIdx>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/position/set-risk-limit
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetRiskLimitRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub risk_id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_idx: Option<PositionIdx>,
+}
@@ -1,2 +1,11 @@
-// Position module DTOs
-// DTOs will be added as needed for typed responses
+pub mod closed_pnl;
+pub mod leverage;
+pub mod margin;
+pub mod position_info;
+pub mod trading_stop;
+
+pub use closed_pnl::*;
+pub use leverage::*;
+pub use margin::*;
+pub use position_info::*;
+pub use trading_stop::*;
@@ -0,0 +1,238 @@
+use crate::rest::enums::{
+    category::Category, position_idx::PositionIdx, position_status::PositionStatus,
+    tp_sl_mode::TpSlMode, trade_mode::TradeMode,
+};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/position
+//
+// GET /v5/position/list
+// {
+//     "retCode": 0,
+//     "retMsg": "OK",
+//     "result": {
+//         "list": [
+//             {
+//                 "positionIdx": 0,
+//                 "riskId": 1,
+//                 "riskLimitValue": "150",
+//                 "symbol": "BTCUSD",
+//                 "side": "Sell",
+//                 "size": "300",
+//                 "avgPrice": "27464.50441675",
+//                 "positionValue": "0.01092319",
+//                 "tradeMode": 0,
+//                 "positionStatus": "Normal",
+//                 "autoAddMargin": 1,
+//                 "adlRankIndicator": 2,
+//                 "leverage": "10",
+//                 "positionBalance": "0.00139186",
+//                 "markPrice": "28224.50",
+//                 "liqPrice": "",
+//                 "bustPrice": "999999.00",
+//                 "positionMM": "0.0000015",
+//                 "positionIM": "0.00010923",
+//                 "tpslMode": "Full",
+//                 "takeProfit": "0.00",
+//                 "stopLoss": "0.00",
+//                 "trailingStop": "0.00",
+//                 "unrealisedPnl": "-0.00029413",
+//                 "curRealisedPnl": "0.00013123",
+//                 "cumRealisedPnl": "-0.00096902",
+//                 "seq": 5723621632,
+//                 "isReduceOnly": false,
+//                 "createdTime": "1676538056258",
+//                 "updatedTime": "1697673600012"
+//             }
+//         ],
+//         "nextPageCursor": "",
+//         "category": "inverse"
+//     },
+//     "retExtInfo": {},
+//     "time": 1697684980172
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPositionInfoParams {
+    pub category: Category,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_coin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settle_coin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionInfo {
+    #[serde(default)]
+    pub position_idx: PositionIdx,
+    #[serde(default)]
+    pub risk_id: i64,
+    #[serde(default)]
+    pub risk_limit_value: String,
+    pub symbol: String,
+    /// `Buy`, `Sell`, or empty when there is no position in one-way mode
+    #[serde(default)]
+    pub side: String,
+    pub size: String,
+    #[serde(default)]
+    pub avg_price: String,
+    #[serde(default)]
+    pub position_value: String,
+    #[serde(default)]
+    pub trade_mode: TradeMode,
+    #[serde(default)]
+    pub position_status: PositionStatus,
+    /// 0: off, 1: on
+    #[serde(default)]
+    pub auto_add_margin: i32,
+    /// Auto-deleverage rank, 0 to 5
+    #[serde(default)]
+    pub adl_rank_indicator: i32,
+    #[serde(default)]
+    pub leverage: String,
+    #[serde(default)]
+    pub position_balance: String,
+    #[serde(default)]
+    pub mark_price: String,
+    #[serde(default)]
+    pub liq_price: String,
+    #[serde(default)]
+    pub bust_price: String,
+    #[serde(default, rename = "positionMM")]
+    pub position_mm: String,
+    #[serde(default, rename = "positionIM")]
+    pub position_im: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tpsl_mode: Option<TpSlMode>,
+    #[serde(default)]
+    pub take_profit: String,
+    #[serde(default)]
+    pub stop_loss: String,
+    #[serde(default)]
+    pub trailing_stop: String,
+    #[serde(default)]
+    pub session_avg_price: String,
+    #[serde(default)]
+    pub delta: String,
+    #[serde(default)]
+    pub gamma: String,
+    #[serde(default)]
+    pub vega: String,
+    #[serde(default)]
+    pub theta: String,
+    #[serde(default)]
+    pub unrealised_pnl: String,
+    #[serde(default)]
+    pub cur_realised_pnl: String,
+    #[serde(default)]
+    pub cum_realised_pnl: String,
+    #[serde(default)]
+    pub seq: i64,
+    #[serde(default)]
+    pub is_reduce_only: bool,
+    #[serde(default)]
+    pub created_time: String,
+    #[serde(default)]
+    pub updated_time: String,
+}
+
+impl PositionInfo {
+    /// True if the position has no size.
+    pub fn is_flat(&self) -> bool {
+        self.size.parse::<f64>().map_or(true, |size| size == 0.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionInfoResult {
+    #[serde(default)]
+    pub category: Option<Category>,
+    #[serde(default)]
+    pub list: Vec<PositionInfo>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_position_info() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "positionIdx": 0,
+                        "riskId": 1,
+                        "riskLimitValue": "150",
+                        "symbol": "BTCUSD",
+                        "side": "Sell",
+                        "size": "300",
+                        "avgPrice": "27464.50441675",
+                        "positionValue": "0.01092319",
+                        "tradeMode": 0,
+                        "positionStatus": "Normal",
+                        "autoAddMargin": 1,
+                        "adlRankIndicator": 2,
+                        "leverage": "10",
+                        "positionBalance": "0.00139186",
+                        "markPrice": "28224.50",
+                        "liqPrice": "",
+                        "bustPrice": "999999.00",
+                        "positionMM": "0.0000015",
+                        "positionIM": "0.00010923",
+                        "tpslMode": "Full",
+                        "takeProfit": "0.00",
+                        "stopLoss": "0.00",
+                        "trailingStop": "0.00",
+                        "unrealisedPnl": "-0.00029413",
+                        "curRealisedPnl": "0.00013123",
+                        "cumRealisedPnl": "-0.00096902",
+                        "seq": 5723621632,
+                        "isReduceOnly": false,
+                        "createdTime": "1676538056258",
+                        "updatedTime": "1697673600012"
+                    }
+                ],
+                "nextPageCursor": "",
+                "category": "inverse"
+            },
+            "retExtInfo": {},
+            "time": 1697684980172
+        }"#;
+        let response: ServerResponse<PositionInfoResult> = serde_json::from_str(json_data).unwrap();
+        let position = &response.result.list[0];
+        assert_eq!(response.result.category, Some(Category::Inverse));
+        assert_eq!(position.position_idx, PositionIdx::OneWayMode);
+        assert_eq!(position.trade_mode, TradeMode::CrossMargin);
+        assert_eq!(position.position_mm, "0.0000015");
+        assert_eq!(position.tpsl_mode, Some(TpSlMode::Full));
+        assert!(!position.is_flat());
+    }
+
+    #[test]
+    fn test_serialize_position_info_params() {
+        let params = GetPositionInfoParams {
+            category: Category::Linear,
+            settle_coin: Some("USDT".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({ "category": "linear", "settleCoin": "USDT" })
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::rest::enums::{
+    category::Category, order_type::OrderType, position_idx::PositionIdx, tp_sl_mode::TpSlMode,
+    trigger_by::TriggerBy,
+};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/position/trading-stop
+//
+// Prices of "0" cancel the corresponding TP, SL or trailing stop.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTradingStopRequest {
+    pub category: Category,
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpsl_mode: Option<TpSlMode>,
+    pub position_idx: PositionIdx,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_limit_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_limit_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_order_type: Option<OrderType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_order_type: Option<OrderType>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/position/tpsl-mode
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTpSlModeRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub tp_sl_mode: TpSlMode,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_trading_stop() {
+        let request = SetTradingStopRequest {
+            category: Category::Linear,
+            symbol: "BTCUSDT".to_string(),
+            tpsl_mode: Some(TpSlMode::Full),
+            position_idx: PositionIdx::BuySideHedge,
+            take_profit: Some("70000".to_string()),
+            tp_trigger_by: Some(TriggerBy::MarkPrice),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "tpslMode": "Full",
+                "positionIdx": 1,
+                "takeProfit": "70000",
+                "tpTriggerBy": "MarkPrice"
+            })
+        );
+    }
+}
@@ -1,4 +1,4 @@
-mod dto;
+pub mod dto;
 mod position_client;
 
 pub use position_client::PositionClient;
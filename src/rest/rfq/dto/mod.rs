@@ -0,0 +1,9 @@
+pub mod rfq_config;
+pub mod rfq_quote;
+pub mod rfq_request;
+pub mod rfq_trade;
+
+pub use rfq_config::*;
+pub use rfq_quote::*;
+pub use rfq_request::*;
+pub use rfq_trade::*;
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/rfq-config
+//
+// GET /v5/rfq/config
+// {
+//     "deskCode": "1nu9d1",
+//     "maxLegs": 25,
+//     "maxLP": 50,
+//     "maxActiveRfq": 10,
+//     "rfqExpireTime": 300,
+//     "minLimitQtySpotOrder": 1,
+//     "minLimitQtyContractOrder": 1,
+//     "minLimitQtyOptionOrder": 1,
+//     "strategyTypes": [ { "strategyName": "custom" } ],
+//     "list": [ { "deskCode": "test0904", "type": "LP" } ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqStrategyType {
+    pub strategy_name: String,
+}
+
+/// A desk that can be sent RFQs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqCounterparty {
+    pub desk_code: String,
+    /// "LP" for liquidity providers
+    #[serde(rename = "type")]
+    pub counterparty_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqConfig {
+    /// Own desk code, shown to counterparties unless anonymous
+    pub desk_code: String,
+    pub max_legs: i32,
+    #[serde(rename = "maxLP")]
+    pub max_lp: i32,
+    pub max_active_rfq: i32,
+    /// Seconds until an RFQ expires
+    pub rfq_expire_time: i64,
+    #[serde(default)]
+    pub min_limit_qty_spot_order: f64,
+    #[serde(default)]
+    pub min_limit_qty_contract_order: f64,
+    #[serde(default)]
+    pub min_limit_qty_option_order: f64,
+    #[serde(default)]
+    pub strategy_types: Vec<RfqStrategyType>,
+    /// Available counterparties
+    #[serde(default)]
+    pub list: Vec<RfqCounterparty>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_rfq_config() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "deskCode": "1nu9d1",
+                "maxLegs": 25,
+                "maxLP": 50,
+                "maxActiveRfq": 10,
+                "rfqExpireTime": 300,
+                "minLimitQtySpotOrder": 1,
+                "minLimitQtyContractOrder": 1,
+                "minLimitQtyOptionOrder": 1,
+                "strategyTypes": [{ "strategyName": "custom" }],
+                "list": [{ "deskCode": "test0904", "type": "LP" }]
+            },
+            "retExtInfo": {},
+            "time": 1757584580000
+        }"#;
+        let response: ServerResponse<RfqConfig> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.max_lp, 50);
+        assert_eq!(response.result.list[0].counterparty_type, "LP");
+    }
+}
@@ -0,0 +1,212 @@
+use crate::rest::enums::{Category, QuoteStatus, Side};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/create-quote
+//
+// POST /v5/rfq/create-quote
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "quoteLinkId": "quote00993",
+//     "anonymous": false,
+//     "expireIn": 60,
+//     "quoteBuyList": [
+//         { "category": "linear", "symbol": "BTCUSDT", "price": "113790" }
+//     ],
+//     "quoteSellList": [
+//         { "category": "linear", "symbol": "BTCUSDT", "price": "113810" }
+//     ]
+// }
+
+/// Price of one RFQ leg in a quote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteLeg {
+    pub category: Category,
+    pub symbol: String,
+    pub price: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateQuoteRequest {
+    pub rfq_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_link_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anonymous: Option<bool>,
+    /// Seconds the quote stays executable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_in: Option<i32>,
+    /// Prices at which the requester can buy the RFQ legs as sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_buy_list: Option<Vec<QuoteLeg>>,
+    /// Prices at which the requester can sell the RFQ legs as sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_sell_list: Option<Vec<QuoteLeg>>,
+}
+
+// POST /v5/rfq/create-quote
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "quoteId": "1754364447601610516653123084412814",
+//     "quoteLinkId": "quote00993",
+//     "expiresAt": "1757579760000",
+//     "deskCode": "LP4",
+//     "status": "Active"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateQuoteResponse {
+    pub rfq_id: String,
+    pub quote_id: String,
+    #[serde(default)]
+    pub quote_link_id: String,
+    pub expires_at: String,
+    pub desk_code: String,
+    pub status: QuoteStatus,
+}
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/execute-quote
+//
+// POST /v5/rfq/execute-quote
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "quoteId": "1754364447601610516653123084412814",
+//     "quoteSide": "Buy"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecuteQuoteRequest {
+    pub rfq_id: String,
+    pub quote_id: String,
+    /// `Buy` executes the quote's buy list, `Sell` its sell list
+    pub quote_side: Side,
+}
+
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "rfqLinkId": "rfq00993",
+//     "quoteId": "1754364447601610516653123084412814",
+//     "status": "PendingFill"
+// }
+
+/// Execution is asynchronous: the final state arrives on the
+/// `rfq.open.quotes` / `rfq.open.trades` topics or via `get_trade_list`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecuteQuoteResponse {
+    pub rfq_id: String,
+    #[serde(default)]
+    pub rfq_link_id: String,
+    pub quote_id: String,
+    pub status: QuoteStatus,
+}
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/quote-realtime
+//
+// GET /v5/rfq/quote-realtime?traderType=request
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "rfqLinkId": "rfq00993",
+//     "quoteId": "1754364447601610516653123084412814",
+//     "quoteLinkId": "",
+//     "expiresAt": "1757579760000",
+//     "deskCode": "LP4",
+//     "status": "Active",
+//     "execQuoteSide": "",
+//     "createdAt": "1757579700000",
+//     "updatedAt": "1757579700000",
+//     "quoteBuyList": [ { "category": "linear", "symbol": "BTCUSDT", "price": "113790" } ],
+//     "quoteSellList": [ { "category": "linear", "symbol": "BTCUSDT", "price": "113810" } ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteInfo {
+    pub rfq_id: String,
+    #[serde(default)]
+    pub rfq_link_id: String,
+    pub quote_id: String,
+    #[serde(default)]
+    pub quote_link_id: String,
+    pub expires_at: String,
+    /// Quoter desk, empty for anonymous quotes
+    #[serde(default)]
+    pub desk_code: String,
+    pub status: QuoteStatus,
+    /// Side executed by the requester, empty until executed
+    #[serde(default)]
+    pub exec_quote_side: String,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub quote_buy_list: Vec<QuoteLeg>,
+    #[serde(default)]
+    pub quote_sell_list: Vec<QuoteLeg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteInfoResult {
+    pub list: Vec<QuoteInfo>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_create_quote_request() {
+        let request = CreateQuoteRequest {
+            rfq_id: "1754364447601610516653123084412812".to_string(),
+            expire_in: Some(60),
+            quote_buy_list: Some(vec![QuoteLeg {
+                category: Category::Linear,
+                symbol: "BTCUSDT".to_string(),
+                price: "113790".to_string(),
+            }]),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["expireIn"], 60);
+        assert_eq!(value["quoteBuyList"][0]["price"], "113790");
+        assert!(value.get("quoteSellList").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_quote_realtime() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "list": [
+                    {
+                        "rfqId": "1754364447601610516653123084412812",
+                        "rfqLinkId": "rfq00993",
+                        "quoteId": "1754364447601610516653123084412814",
+                        "quoteLinkId": "",
+                        "expiresAt": "1757579760000",
+                        "deskCode": "LP4",
+                        "status": "PendingFill",
+                        "execQuoteSide": "Buy",
+                        "createdAt": "1757579700000",
+                        "updatedAt": "1757579710000",
+                        "quoteBuyList": [
+                            { "category": "linear", "symbol": "BTCUSDT", "price": "113790" }
+                        ],
+                        "quoteSellList": []
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1757579710001
+        }"#;
+        let response: ServerResponse<QuoteInfoResult> = serde_json::from_str(json_data).unwrap();
+        let quote = &response.result.list[0];
+        assert_eq!(quote.status, QuoteStatus::PendingFill);
+        assert!(!quote.status.is_final());
+        assert_eq!(quote.exec_quote_side, "Buy");
+    }
+}
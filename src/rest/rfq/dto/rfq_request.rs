@@ -0,0 +1,178 @@
+use crate::rest::enums::{Category, RfqStatus, Side};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/create-rfq
+//
+// POST /v5/rfq/create-rfq
+// {
+//     "counterparties": ["LP4", "LP5"],
+//     "rfqLinkId": "rfq00993",
+//     "anonymous": false,
+//     "strategyType": "custom",
+//     "list": [
+//         { "category": "linear", "symbol": "BTCUSDT", "side": "Buy", "qty": "5" },
+//         { "category": "option", "symbol": "BTC-26SEP25-110000-C-USDT", "side": "Sell", "qty": "5" }
+//     ]
+// }
+
+/// One leg of an RFQ.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqLeg {
+    pub category: Category,
+    pub symbol: String,
+    pub side: Side,
+    pub qty: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRfqRequest {
+    /// Desk codes of the quoters to ask
+    pub counterparties: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rfq_link_id: Option<String>,
+    /// Hide the own desk code from quoters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anonymous: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<String>,
+    pub list: Vec<RfqLeg>,
+}
+
+// POST /v5/rfq/create-rfq
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "rfqLinkId": "rfq00993",
+//     "status": "Active",
+//     "expiresAt": "1757580000000",
+//     "deskCode": "1nu9d1"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRfqResponse {
+    pub rfq_id: String,
+    #[serde(default)]
+    pub rfq_link_id: String,
+    pub status: RfqStatus,
+    pub expires_at: String,
+    pub desk_code: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/cancel-rfq
+//
+// POST /v5/rfq/cancel-rfq
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "rfqLinkId": ""
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelRfqResponse {
+    pub rfq_id: String,
+    #[serde(default)]
+    pub rfq_link_id: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/rfq-realtime
+//
+// GET /v5/rfq/rfq-realtime?traderType=request
+// {
+//     "rfqId": "1754364447601610516653123084412812",
+//     "rfqLinkId": "rfq00993",
+//     "counterparties": ["LP4", "LP5"],
+//     "expiresAt": "1757580000000",
+//     "strategyType": "custom",
+//     "status": "Active",
+//     "deskCode": "1nu9d1",
+//     "createdAt": "1757579700000",
+//     "updatedAt": "1757579700000",
+//     "legs": [
+//         { "category": "linear", "symbol": "BTCUSDT", "side": "Buy", "qty": "5" }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqInfo {
+    pub rfq_id: String,
+    #[serde(default)]
+    pub rfq_link_id: String,
+    #[serde(default)]
+    pub counterparties: Vec<String>,
+    pub expires_at: String,
+    #[serde(default)]
+    pub strategy_type: String,
+    pub status: RfqStatus,
+    /// Requester desk, empty for anonymous RFQs
+    #[serde(default)]
+    pub desk_code: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub legs: Vec<RfqLeg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RfqInfoResult {
+    pub list: Vec<RfqInfo>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_create_rfq_request() {
+        let request = CreateRfqRequest {
+            counterparties: vec!["LP4".to_string()],
+            rfq_link_id: Some("rfq00993".to_string()),
+            list: vec![RfqLeg {
+                category: Category::Linear,
+                symbol: "BTCUSDT".to_string(),
+                side: Side::Buy,
+                qty: "5".to_string(),
+            }],
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["list"][0]["category"], "linear");
+        assert_eq!(value["rfqLinkId"], "rfq00993");
+        assert!(value.get("anonymous").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_rfq_realtime() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "list": [
+                    {
+                        "rfqId": "1754364447601610516653123084412812",
+                        "rfqLinkId": "rfq00993",
+                        "counterparties": ["LP4", "LP5"],
+                        "expiresAt": "1757580000000",
+                        "strategyType": "custom",
+                        "status": "Expired",
+                        "deskCode": "1nu9d1",
+                        "createdAt": "1757579700000",
+                        "updatedAt": "1757580000000",
+                        "legs": [
+                            { "category": "option", "symbol": "BTC-26SEP25-110000-C-USDT", "side": "Sell", "qty": "5" }
+                        ]
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1757580000001
+        }"#;
+        let response: ServerResponse<RfqInfoResult> = serde_json::from_str(json_data).unwrap();
+        let rfq = &response.result.list[0];
+        assert_eq!(rfq.status, RfqStatus::Expired);
+        assert!(rfq.status.is_final());
+        assert_eq!(rfq.legs[0].category, Category::Option);
+    }
+}
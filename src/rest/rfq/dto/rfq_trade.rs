@@ -0,0 +1,208 @@
+use crate::rest::enums::{Category, Side};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/trade-list
+//
+// GET /v5/rfq/trade-list?traderType=request
+// {
+//     "cursor": "",
+//     "list": [
+//         {
+//             "rfqId": "1754364447601610516653123084412812",
+//             "rfqLinkId": "rfq00993",
+//             "quoteId": "1754364447601610516653123084412814",
+//             "quoteLinkId": "",
+//             "quoteSide": "Buy",
+//             "strategyType": "custom",
+//             "status": "Filled",
+//             "rfqDeskCode": "1nu9d1",
+//             "quoteDeskCode": "LP4",
+//             "createdAt": "1757579710000",
+//             "updatedAt": "1757579711000",
+//             "legs": [
+//                 {
+//                     "category": "linear",
+//                     "orderId": "be9a9e9d-87f3-4b06-a1f3-4f9f8c9a0a11",
+//                     "symbol": "BTCUSDT",
+//                     "side": "Buy",
+//                     "price": "113790",
+//                     "qty": "5",
+//                     "markPrice": "113800.1",
+//                     "execFee": "312.9225",
+//                     "execId": "5a8f6e2b-9d8f-5c34-8f6d-1d1d0f5e7a90",
+//                     "resultCode": 0,
+//                     "resultMessage": "",
+//                     "rejectParty": ""
+//                 }
+//             ]
+//         }
+//     ]
+// }
+
+/// Fill of one leg of an RFQ block trade.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqTradeLeg {
+    pub category: Category,
+    /// Order created for this leg, empty if the leg was rejected
+    #[serde(default)]
+    pub order_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub price: String,
+    pub qty: String,
+    #[serde(default)]
+    pub mark_price: String,
+    #[serde(default)]
+    pub exec_fee: String,
+    #[serde(default)]
+    pub exec_id: String,
+    /// 0 on success
+    #[serde(default)]
+    pub result_code: i32,
+    #[serde(default)]
+    pub result_message: String,
+    /// "Taker", "Maker" or "bybit" when a leg was rejected
+    #[serde(default)]
+    pub reject_party: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqTrade {
+    pub rfq_id: String,
+    #[serde(default)]
+    pub rfq_link_id: String,
+    pub quote_id: String,
+    #[serde(default)]
+    pub quote_link_id: String,
+    pub quote_side: Side,
+    #[serde(default)]
+    pub strategy_type: String,
+    /// "Filled" or "Failed"
+    pub status: String,
+    #[serde(default)]
+    pub rfq_desk_code: String,
+    #[serde(default)]
+    pub quote_desk_code: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub legs: Vec<RfqTradeLeg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RfqTradeResult {
+    #[serde(default)]
+    pub cursor: String,
+    pub list: Vec<RfqTrade>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/rfq/trade/public-trades
+//
+// GET /v5/rfq/public-trades
+// {
+//     "cursor": "",
+//     "list": [
+//         {
+//             "rfqId": "1754364447601610516653123084412812",
+//             "strategyType": "custom",
+//             "createdAt": "1757579710000",
+//             "updatedAt": "1757579711000",
+//             "legs": [
+//                 {
+//                     "category": "linear",
+//                     "symbol": "BTCUSDT",
+//                     "side": "Buy",
+//                     "price": "113790",
+//                     "qty": "5",
+//                     "markPrice": "113800.1"
+//                 }
+//             ]
+//         }
+//     ]
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqPublicTradeLeg {
+    pub category: Category,
+    pub symbol: String,
+    pub side: Side,
+    pub price: String,
+    pub qty: String,
+    #[serde(default)]
+    pub mark_price: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqPublicTrade {
+    pub rfq_id: String,
+    #[serde(default)]
+    pub strategy_type: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub legs: Vec<RfqPublicTradeLeg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RfqPublicTradeResult {
+    #[serde(default)]
+    pub cursor: String,
+    pub list: Vec<RfqPublicTrade>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_rfq_trade_list() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "cursor": "page2",
+                "list": [
+                    {
+                        "rfqId": "1754364447601610516653123084412812",
+                        "rfqLinkId": "rfq00993",
+                        "quoteId": "1754364447601610516653123084412814",
+                        "quoteLinkId": "",
+                        "quoteSide": "Buy",
+                        "strategyType": "custom",
+                        "status": "Failed",
+                        "rfqDeskCode": "1nu9d1",
+                        "quoteDeskCode": "LP4",
+                        "createdAt": "1757579710000",
+                        "updatedAt": "1757579711000",
+                        "legs": [
+                            {
+                                "category": "linear",
+                                "orderId": "",
+                                "symbol": "BTCUSDT",
+                                "side": "Buy",
+                                "price": "113790",
+                                "qty": "5",
+                                "markPrice": "113800.1",
+                                "execFee": "",
+                                "execId": "",
+                                "resultCode": 110007,
+                                "resultMessage": "Insufficient available balance",
+                                "rejectParty": "Taker"
+                            }
+                        ]
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1757579711001
+        }"#;
+        let response: ServerResponse<RfqTradeResult> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.cursor, "page2");
+        let leg = &response.result.list[0].legs[0];
+        assert_eq!(leg.result_code, 110007);
+        assert_eq!(leg.reject_party, "Taker");
+    }
+}
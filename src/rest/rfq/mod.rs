@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod rfq_client;
+
+pub use rfq_client::RfqClient;
@@ -0,0 +1,238 @@
+use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::enums::RfqTraderType;
+use crate::rest::rfq::dto::*;
+use crate::rest::BybitResult as Result;
+use serde_json::json;
+
+/// RFQ (request for quote) block trading.
+///
+/// Requesters send an RFQ to chosen desks with [`create_rfq`](Self::create_rfq)
+/// and execute one of the returned quotes with
+/// [`execute_quote`](Self::execute_quote). Quoters answer RFQs with
+/// [`create_quote`](Self::create_quote).
+#[derive(Clone)]
+pub struct RfqClient {
+    client: RestClient,
+}
+
+impl RfqClient {
+    pub fn new(client: RestClient) -> Self {
+        RfqClient { client }
+    }
+
+    /// Get RFQ configuration
+    ///
+    /// API: GET /v5/rfq/config
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/rfq-config
+    pub async fn get_config(&self) -> Result<ServerResponse<RfqConfig>> {
+        let endpoint = "v5/rfq/config";
+        let params = json!({});
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Create RFQ
+    ///
+    /// API: POST /v5/rfq/create-rfq
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/create-rfq
+    pub async fn create_rfq(
+        &self,
+        request: CreateRfqRequest,
+    ) -> Result<ServerResponse<CreateRfqResponse>> {
+        let endpoint = "v5/rfq/create-rfq";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Cancel RFQ
+    ///
+    /// API: POST /v5/rfq/cancel-rfq
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/cancel-rfq
+    pub async fn cancel_rfq(
+        &self,
+        rfq_id: Option<&str>,
+        rfq_link_id: Option<&str>,
+    ) -> Result<ServerResponse<CancelRfqResponse>> {
+        let endpoint = "v5/rfq/cancel-rfq";
+        let mut body = json!({});
+
+        if let Some(rfq_id) = rfq_id {
+            body["rfqId"] = json!(rfq_id);
+        }
+        if let Some(rfq_link_id) = rfq_link_id {
+            body["rfqLinkId"] = json!(rfq_link_id);
+        }
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Create quote
+    ///
+    /// API: POST /v5/rfq/create-quote
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/create-quote
+    pub async fn create_quote(
+        &self,
+        request: CreateQuoteRequest,
+    ) -> Result<ServerResponse<CreateQuoteResponse>> {
+        let endpoint = "v5/rfq/create-quote";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Execute quote
+    ///
+    /// API: POST /v5/rfq/execute-quote
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/execute-quote
+    pub async fn execute_quote(
+        &self,
+        request: ExecuteQuoteRequest,
+    ) -> Result<ServerResponse<ExecuteQuoteResponse>> {
+        let endpoint = "v5/rfq/execute-quote";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get real-time RFQ information
+    ///
+    /// API: GET /v5/rfq/rfq-realtime
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/rfq-realtime
+    pub async fn get_rfq_realtime(
+        &self,
+        rfq_id: Option<&str>,
+        rfq_link_id: Option<&str>,
+        trader_type: Option<RfqTraderType>,
+    ) -> Result<ServerResponse<RfqInfoResult>> {
+        let endpoint = "v5/rfq/rfq-realtime";
+        let mut params = json!({});
+
+        if let Some(rfq_id) = rfq_id {
+            params["rfqId"] = json!(rfq_id);
+        }
+        if let Some(rfq_link_id) = rfq_link_id {
+            params["rfqLinkId"] = json!(rfq_link_id);
+        }
+        if let Some(trader_type) = trader_type {
+            params["traderType"] = json!(trader_type);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get real-time quote information
+    ///
+    /// API: GET /v5/rfq/quote-realtime
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/quote-realtime
+    pub async fn get_quote_realtime(
+        &self,
+        rfq_id: Option<&str>,
+        quote_id: Option<&str>,
+        quote_link_id: Option<&str>,
+        trader_type: Option<RfqTraderType>,
+    ) -> Result<ServerResponse<QuoteInfoResult>> {
+        let endpoint = "v5/rfq/quote-realtime";
+        let mut params = json!({});
+
+        if let Some(rfq_id) = rfq_id {
+            params["rfqId"] = json!(rfq_id);
+        }
+        if let Some(quote_id) = quote_id {
+            params["quoteId"] = json!(quote_id);
+        }
+        if let Some(quote_link_id) = quote_link_id {
+            params["quoteLinkId"] = json!(quote_link_id);
+        }
+        if let Some(trader_type) = trader_type {
+            params["traderType"] = json!(trader_type);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get RFQ trade history
+    ///
+    /// API: GET /v5/rfq/trade-list
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/trade-list
+    pub async fn get_trade_list(
+        &self,
+        rfq_id: Option<&str>,
+        rfq_link_id: Option<&str>,
+        quote_id: Option<&str>,
+        quote_link_id: Option<&str>,
+        trader_type: Option<RfqTraderType>,
+        status: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<RfqTradeResult>> {
+        let endpoint = "v5/rfq/trade-list";
+        let mut params = json!({});
+
+        if let Some(rfq_id) = rfq_id {
+            params["rfqId"] = json!(rfq_id);
+        }
+        if let Some(rfq_link_id) = rfq_link_id {
+            params["rfqLinkId"] = json!(rfq_link_id);
+        }
+        if let Some(quote_id) = quote_id {
+            params["quoteId"] = json!(quote_id);
+        }
+        if let Some(quote_link_id) = quote_link_id {
+            params["quoteLinkId"] = json!(quote_link_id);
+        }
+        if let Some(trader_type) = trader_type {
+            params["traderType"] = json!(trader_type);
+        }
+        if let Some(status) = status {
+            params["status"] = json!(status);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get public RFQ trades
+    ///
+    /// API: GET /v5/rfq/public-trades
+    /// https://bybit-exchange.github.io/docs/v5/rfq/trade/public-trades
+    pub async fn get_public_trades(
+        &self,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<RfqPublicTradeResult>> {
+        let endpoint = "v5/rfq/public-trades";
+        let mut params = json!({});
+
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+}
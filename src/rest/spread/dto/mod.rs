@@ -0,0 +1,5 @@
+pub mod spread_market;
+pub mod spread_order;
+
+pub use spread_market::*;
+pub use spread_order::*;
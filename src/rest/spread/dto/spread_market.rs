@@ -0,0 +1,209 @@
+use crate::rest::enums::{Side, SpreadContractType};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/spread/market/instrument
+//
+// GET /v5/spread/instrument?symbol=SOLUSDT_SOL/USDT
+// {
+//     "symbol": "SOLUSDT_SOL/USDT",
+//     "contractType": "FundingRateArb",
+//     "status": "Trading",
+//     "baseCoin": "SOL",
+//     "quoteCoin": "USDT",
+//     "settleCoin": "USDT",
+//     "tickSize": "0.005",
+//     "minPrice": "-1999.99",
+//     "maxPrice": "1999.99",
+//     "lotSize": "0.1",
+//     "minSize": "0.1",
+//     "maxSize": "50000",
+//     "launchTime": "1743675300000",
+//     "deliveryTime": "0",
+//     "legs": [
+//         { "symbol": "SOLUSDT", "contractType": "LinearPerpetual" },
+//         { "symbol": "SOLUSDT", "contractType": "Spot" }
+//     ]
+// }
+
+/// One leg of a spread instrument.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadLeg {
+    pub symbol: String,
+    /// "LinearPerpetual", "LinearFutures" or "Spot"
+    pub contract_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadInstrument {
+    pub symbol: String,
+    pub contract_type: SpreadContractType,
+    pub status: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub settle_coin: String,
+    pub tick_size: String,
+    /// Spread prices can be negative
+    pub min_price: String,
+    pub max_price: String,
+    pub lot_size: String,
+    pub min_size: String,
+    pub max_size: String,
+    pub launch_time: String,
+    /// "0" for perpetual-only spreads
+    pub delivery_time: String,
+    pub legs: Vec<SpreadLeg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadInstrumentResult {
+    pub list: Vec<SpreadInstrument>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/spread/market/tickers
+//
+// GET /v5/spread/tickers?symbol=SOLUSDT_SOL/USDT
+// {
+//     "symbol": "SOLUSDT_SOL/USDT",
+//     "bidPrice": "19.444",
+//     "bidSize": "0.1",
+//     "askPrice": "20.226",
+//     "askSize": "0.1",
+//     "lastPrice": "20",
+//     "highPrice24h": "22.133",
+//     "lowPrice24h": "17.88",
+//     "prevPrice24h": "21.3",
+//     "volume24h": "1224.5"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadTicker {
+    pub symbol: String,
+    pub bid_price: String,
+    pub bid_size: String,
+    pub ask_price: String,
+    pub ask_size: String,
+    pub last_price: String,
+    pub high_price24h: String,
+    pub low_price24h: String,
+    pub prev_price24h: String,
+    pub volume24h: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadTickerResult {
+    pub list: Vec<SpreadTicker>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/spread/market/recent-trade
+//
+// GET /v5/spread/recent-trade?symbol=SOLUSDT_SOL/USDT&limit=2
+// {
+//     "execId": "c6e9a0ba-8d87-4fd5-a4ed-ba0d2f5bc51b",
+//     "symbol": "SOLUSDT_SOL/USDT",
+//     "price": "20.2805",
+//     "size": "0.3",
+//     "side": "Sell",
+//     "time": "1744078324035"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadTrade {
+    pub exec_id: String,
+    pub symbol: String,
+    pub price: String,
+    pub size: String,
+    pub side: Side,
+    pub time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadTradeResult {
+    pub list: Vec<SpreadTrade>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_spread_instrument() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "result": {
+                "list": [
+                    {
+                        "symbol": "BTCUSDT_BTC-27JUN25",
+                        "contractType": "PerpBasis",
+                        "status": "Trading",
+                        "baseCoin": "BTC",
+                        "quoteCoin": "USDT",
+                        "settleCoin": "USDT",
+                        "tickSize": "0.1",
+                        "minPrice": "-1999999.9",
+                        "maxPrice": "1999999.9",
+                        "lotSize": "0.001",
+                        "minSize": "0.001",
+                        "maxSize": "50",
+                        "launchTime": "1743675300000",
+                        "deliveryTime": "1751011200000",
+                        "legs": [
+                            { "symbol": "BTCUSDT", "contractType": "LinearPerpetual" },
+                            { "symbol": "BTC-27JUN25", "contractType": "LinearFutures" }
+                        ]
+                    }
+                ],
+                "nextPageCursor": ""
+            },
+            "retExtInfo": {},
+            "time": 1744076802479
+        }"#;
+        let response: ServerResponse<SpreadInstrumentResult> =
+            serde_json::from_str(json_data).unwrap();
+        let instrument = &response.result.list[0];
+        assert_eq!(instrument.contract_type, SpreadContractType::PerpBasis);
+        assert_eq!(instrument.min_price, "-1999999.9");
+        assert_eq!(instrument.legs[1].symbol, "BTC-27JUN25");
+    }
+
+    #[test]
+    fn test_deserialize_spread_tickers_and_trades() {
+        let tickers = r#"{
+            "list": [{
+                "symbol": "SOLUSDT_SOL/USDT",
+                "bidPrice": "19.444",
+                "bidSize": "0.1",
+                "askPrice": "20.226",
+                "askSize": "0.1",
+                "lastPrice": "20",
+                "highPrice24h": "22.133",
+                "lowPrice24h": "17.88",
+                "prevPrice24h": "21.3",
+                "volume24h": "1224.5"
+            }]
+        }"#;
+        let tickers: SpreadTickerResult = serde_json::from_str(tickers).unwrap();
+        assert_eq!(tickers.list[0].ask_price, "20.226");
+
+        let trades = r#"{
+            "list": [{
+                "execId": "c6e9a0ba-8d87-4fd5-a4ed-ba0d2f5bc51b",
+                "symbol": "SOLUSDT_SOL/USDT",
+                "price": "20.2805",
+                "size": "0.3",
+                "side": "Sell",
+                "time": "1744078324035"
+            }]
+        }"#;
+        let trades: SpreadTradeResult = serde_json::from_str(trades).unwrap();
+        assert_eq!(trades.list[0].side, Side::Sell);
+    }
+}
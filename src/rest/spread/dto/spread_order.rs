@@ -0,0 +1,345 @@
+use crate::rest::enums::{OrderStatus, OrderType, Side, TimeInForce};
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/spread/trade/create-order
+//
+// POST /v5/spread/order/create
+// {
+//     "symbol": "SOLUSDT_SOL/USDT",
+//     "side": "Buy",
+//     "orderType": "Limit",
+//     "qty": "0.1",
+//     "price": "21",
+//     "orderLinkId": "1744072052193428479",
+//     "timeInForce": "PostOnly"
+// }
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadPlaceOrderRequest {
+    pub symbol: String,
+    pub side: Side,
+    pub order_type: OrderType,
+    pub qty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/spread/trade/amend-order
+//
+// POST /v5/spread/order/amend
+// {
+//     "symbol": "SOLUSDT_SOL/USDT",
+//     "orderLinkId": "1744072052193428475",
+//     "price": "14",
+//     "qty": "0.2"
+// }
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadAmendOrderRequest {
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/spread/trade/cancel-order
+//
+// POST /v5/spread/order/cancel
+// {
+//     "orderLinkId": "1744072052193428476"
+// }
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadCancelOrderRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+}
+
+/// Result of create, amend and cancel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadOrderResponse {
+    pub order_id: String,
+    pub order_link_id: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/spread/trade/cancel-all
+//
+// POST /v5/spread/order/cancel-all
+// {
+//     "list": [
+//         { "orderId": "b0e6c938-9731-4122-8552-01e6dc06b303", "orderLinkId": "" }
+//     ],
+//     "success": "1"
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadCancelAllResult {
+    pub list: Vec<SpreadOrderResponse>,
+    /// "1" on success, "0" on failure
+    #[serde(default)]
+    pub success: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/spread/trade/open-order
+// https://bybit-exchange.github.io/docs/v5/spread/trade/order-history
+//
+// GET /v5/spread/order/realtime?symbol=SOLUSDT_SOL/USDT
+// {
+//     "symbol": "SOLUSDT_SOL/USDT",
+//     "baseCoin": "SOL",
+//     "orderType": "Limit",
+//     "orderLinkId": "1744072052193428479",
+//     "side": "Buy",
+//     "timeInForce": "PostOnly",
+//     "orderId": "bec1bf4e-e7bb-4b4b-913a-3a4c2ce0e4e5",
+//     "leavesQty": "0.1",
+//     "orderStatus": "New",
+//     "cumExecQty": "0",
+//     "price": "14",
+//     "qty": "0.1",
+//     "createdTime": "1744101307567",
+//     "updatedTime": "1744101307570"
+// }
+//
+// Order history entries additionally carry the cancel reason and the
+// per-leg order ids.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadOrder {
+    pub symbol: String,
+    #[serde(default)]
+    pub base_coin: String,
+    pub order_type: OrderType,
+    pub order_link_id: String,
+    pub side: Side,
+    pub time_in_force: TimeInForce,
+    pub order_id: String,
+    pub leaves_qty: String,
+    pub order_status: OrderStatus,
+    pub cum_exec_qty: String,
+    pub price: String,
+    pub qty: String,
+    pub created_time: String,
+    pub updated_time: String,
+    /// History only
+    #[serde(default)]
+    pub avg_price: String,
+    #[serde(default)]
+    pub cxl_rej_reason: String,
+    #[serde(default)]
+    pub leg1_symbol: String,
+    #[serde(default)]
+    pub leg1_prod_type: String,
+    #[serde(default)]
+    pub leg1_order_id: String,
+    #[serde(default)]
+    pub leg1_side: String,
+    #[serde(default)]
+    pub leg2_symbol: String,
+    #[serde(default)]
+    pub leg2_prod_type: String,
+    #[serde(default)]
+    pub leg2_order_id: String,
+    #[serde(default)]
+    pub leg2_side: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadOrderResult {
+    pub list: Vec<SpreadOrder>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+// https://bybit-exchange.github.io/docs/v5/spread/trade/trade-history
+//
+// GET /v5/spread/execution/list?symbol=SOLUSDT_SOL/USDT
+// {
+//     "symbol": "SOLUSDT_SOL/USDT",
+//     "orderLinkId": "",
+//     "side": "Buy",
+//     "orderId": "5e010c35-2b44-4f03-8081-8fa31fb73376",
+//     "execPrice": "20.1",
+//     "execTime": "1744104031626",
+//     "execType": "Trade",
+//     "execQty": "0.1",
+//     "execId": "3fdf9d7e-4f85-4f64-b1f5-d4a2b9d0e1c2",
+//     "legs": [
+//         {
+//             "symbol": "SOLUSDT",
+//             "side": "Buy",
+//             "execPrice": "120.33",
+//             "execTime": "1744104031626",
+//             "execValue": "12.033",
+//             "execType": "Trade",
+//             "category": "linear",
+//             "execQty": "0.1",
+//             "execFee": "0.0066181",
+//             "execId": "e0b2f6ae-3de2-5ff6-9ac8-1b3f3f0b1d49"
+//         }
+//     ]
+// }
+
+/// Fill of one leg of a spread execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadExecutionLeg {
+    pub symbol: String,
+    pub side: Side,
+    pub exec_price: String,
+    pub exec_time: String,
+    pub exec_value: String,
+    pub exec_type: String,
+    /// "linear" or "spot"
+    pub category: String,
+    pub exec_qty: String,
+    pub exec_fee: String,
+    pub exec_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadExecution {
+    pub symbol: String,
+    pub order_link_id: String,
+    pub side: Side,
+    pub order_id: String,
+    pub exec_price: String,
+    pub exec_time: String,
+    pub exec_type: String,
+    pub exec_qty: String,
+    pub exec_id: String,
+    pub legs: Vec<SpreadExecutionLeg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadExecutionResult {
+    pub list: Vec<SpreadExecution>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_serialize_spread_place_order_request() {
+        let request = SpreadPlaceOrderRequest {
+            symbol: "SOLUSDT_SOL/USDT".to_string(),
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            qty: "0.1".to_string(),
+            price: Some("-0.5".to_string()),
+            time_in_force: Some(TimeInForce::PostOnly),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["orderType"], "Limit");
+        assert_eq!(value["timeInForce"], "PostOnly");
+        assert_eq!(value["price"], "-0.5");
+        assert!(value.get("orderLinkId").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_spread_order_history() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "list": [
+                    {
+                        "symbol": "SOLUSDT_SOL/USDT",
+                        "orderType": "Limit",
+                        "orderLinkId": "",
+                        "orderId": "5e010c35-2b44-4f03-8081-8fa31fb73376",
+                        "cxlRejReason": "CANCELED_BY_USER",
+                        "orderStatus": "Cancelled",
+                        "price": "14",
+                        "createdTime": "1744103617596",
+                        "updatedTime": "1744103620802",
+                        "side": "Buy",
+                        "timeInForce": "GTC",
+                        "baseCoin": "SOL",
+                        "qty": "0.1",
+                        "leavesQty": "0",
+                        "cumExecQty": "0",
+                        "avgPrice": "0",
+                        "leg1Symbol": "SOLUSDT",
+                        "leg1ProdType": "Futures",
+                        "leg1OrderId": "",
+                        "leg1Side": "",
+                        "leg2Symbol": "SOLUSDT",
+                        "leg2ProdType": "Spot",
+                        "leg2OrderId": "",
+                        "leg2Side": ""
+                    }
+                ],
+                "nextPageCursor": "aaaee090-fab3-42ba-ab7f-8b6f5d4b0c0f%3A1744103617596"
+            },
+            "retExtInfo": {},
+            "time": 1744103651165
+        }"#;
+        let response: ServerResponse<SpreadOrderResult> = serde_json::from_str(json_data).unwrap();
+        let order = &response.result.list[0];
+        assert_eq!(order.order_status, OrderStatus::Cancelled);
+        assert_eq!(order.cxl_rej_reason, "CANCELED_BY_USER");
+        assert_eq!(order.leg2_prod_type, "Spot");
+    }
+
+    #[test]
+    fn test_deserialize_spread_execution() {
+        let json_data = r#"{
+            "list": [
+                {
+                    "symbol": "SOLUSDT_SOL/USDT",
+                    "orderLinkId": "",
+                    "side": "Buy",
+                    "orderId": "5e010c35-2b44-4f03-8081-8fa31fb73376",
+                    "execPrice": "20.1",
+                    "execTime": "1744104031626",
+                    "execType": "Trade",
+                    "execQty": "0.1",
+                    "execId": "3fdf9d7e-4f85-4f64-b1f5-d4a2b9d0e1c2",
+                    "legs": [
+                        {
+                            "symbol": "SOLUSDT",
+                            "side": "Buy",
+                            "execPrice": "120.33",
+                            "execTime": "1744104031626",
+                            "execValue": "12.033",
+                            "execType": "Trade",
+                            "category": "linear",
+                            "execQty": "0.1",
+                            "execFee": "0.0066181",
+                            "execId": "e0b2f6ae-3de2-5ff6-9ac8-1b3f3f0b1d49"
+                        }
+                    ]
+                }
+            ],
+            "nextPageCursor": ""
+        }"#;
+        let result: SpreadExecutionResult = serde_json::from_str(json_data).unwrap();
+        assert_eq!(result.list[0].legs[0].category, "linear");
+        assert_eq!(result.list[0].legs[0].exec_fee, "0.0066181");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod spread_client;
+
+pub use spread_client::SpreadClient;
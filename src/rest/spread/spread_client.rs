@@ -0,0 +1,305 @@
+use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::market::dto::OrderBookResult;
+use crate::rest::spread::dto::*;
+use crate::rest::BybitResult as Result;
+use serde_json::json;
+
+#[derive(Clone)]
+pub struct SpreadClient {
+    client: RestClient,
+}
+
+impl SpreadClient {
+    pub fn new(client: RestClient) -> Self {
+        SpreadClient { client }
+    }
+
+    /// Get spread instruments info
+    ///
+    /// API: GET /v5/spread/instrument
+    /// https://bybit-exchange.github.io/docs/v5/spread/market/instrument
+    pub async fn get_instruments_info(
+        &self,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<SpreadInstrumentResult>> {
+        let endpoint = "v5/spread/instrument";
+        let mut params = json!({});
+
+        if let Some(symbol) = symbol {
+            params["symbol"] = json!(symbol);
+        }
+        if let Some(base_coin) = base_coin {
+            params["baseCoin"] = json!(base_coin);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get spread orderbook
+    ///
+    /// API: GET /v5/spread/orderbook
+    /// https://bybit-exchange.github.io/docs/v5/spread/market/orderbook
+    pub async fn get_orderbook(
+        &self,
+        symbol: &str,
+        limit: Option<i32>,
+    ) -> Result<ServerResponse<OrderBookResult>> {
+        let endpoint = "v5/spread/orderbook";
+        let mut params = json!({
+            "symbol": symbol,
+        });
+
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get spread tickers
+    ///
+    /// API: GET /v5/spread/tickers
+    /// https://bybit-exchange.github.io/docs/v5/spread/market/tickers
+    pub async fn get_tickers(&self, symbol: &str) -> Result<ServerResponse<SpreadTickerResult>> {
+        let endpoint = "v5/spread/tickers";
+        let params = json!({
+            "symbol": symbol,
+        });
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Get spread public recent trades
+    ///
+    /// API: GET /v5/spread/recent-trade
+    /// https://bybit-exchange.github.io/docs/v5/spread/market/recent-trade
+    pub async fn get_recent_trades(
+        &self,
+        symbol: &str,
+        limit: Option<i32>,
+    ) -> Result<ServerResponse<SpreadTradeResult>> {
+        let endpoint = "v5/spread/recent-trade";
+        let mut params = json!({
+            "symbol": symbol,
+        });
+
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::None).await?;
+        Ok(response)
+    }
+
+    /// Create a spread order
+    ///
+    /// API: POST /v5/spread/order/create
+    /// https://bybit-exchange.github.io/docs/v5/spread/trade/create-order
+    pub async fn place_order(
+        &self,
+        request: SpreadPlaceOrderRequest,
+    ) -> Result<ServerResponse<SpreadOrderResponse>> {
+        let endpoint = "v5/spread/order/create";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Amend a spread order
+    ///
+    /// API: POST /v5/spread/order/amend
+    /// https://bybit-exchange.github.io/docs/v5/spread/trade/amend-order
+    pub async fn amend_order(
+        &self,
+        request: SpreadAmendOrderRequest,
+    ) -> Result<ServerResponse<SpreadOrderResponse>> {
+        let endpoint = "v5/spread/order/amend";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Cancel a spread order
+    ///
+    /// API: POST /v5/spread/order/cancel
+    /// https://bybit-exchange.github.io/docs/v5/spread/trade/cancel-order
+    pub async fn cancel_order(
+        &self,
+        request: SpreadCancelOrderRequest,
+    ) -> Result<ServerResponse<SpreadOrderResponse>> {
+        let endpoint = "v5/spread/order/cancel";
+        let body = serde_json::to_value(&request)?;
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Cancel all spread orders
+    ///
+    /// Without a symbol, `cancel_all` must be true to cancel every open
+    /// spread order.
+    ///
+    /// API: POST /v5/spread/order/cancel-all
+    /// https://bybit-exchange.github.io/docs/v5/spread/trade/cancel-all
+    pub async fn cancel_all_orders(
+        &self,
+        symbol: Option<&str>,
+        cancel_all: Option<bool>,
+    ) -> Result<ServerResponse<SpreadCancelAllResult>> {
+        let endpoint = "v5/spread/order/cancel-all";
+        let mut body = json!({});
+
+        if let Some(symbol) = symbol {
+            body["symbol"] = json!(symbol);
+        }
+        if let Some(cancel_all) = cancel_all {
+            body["cancelAll"] = json!(cancel_all);
+        }
+
+        let response = self.client.post(endpoint, body, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get open spread orders
+    ///
+    /// API: GET /v5/spread/order/realtime
+    /// https://bybit-exchange.github.io/docs/v5/spread/trade/open-order
+    pub async fn get_open_orders(
+        &self,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        order_id: Option<&str>,
+        order_link_id: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<SpreadOrderResult>> {
+        let endpoint = "v5/spread/order/realtime";
+        let mut params = json!({});
+
+        if let Some(symbol) = symbol {
+            params["symbol"] = json!(symbol);
+        }
+        if let Some(base_coin) = base_coin {
+            params["baseCoin"] = json!(base_coin);
+        }
+        if let Some(order_id) = order_id {
+            params["orderId"] = json!(order_id);
+        }
+        if let Some(order_link_id) = order_link_id {
+            params["orderLinkId"] = json!(order_link_id);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get spread order history
+    ///
+    /// API: GET /v5/spread/order/history
+    /// https://bybit-exchange.github.io/docs/v5/spread/trade/order-history
+    pub async fn get_order_history(
+        &self,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        order_id: Option<&str>,
+        order_link_id: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<SpreadOrderResult>> {
+        let endpoint = "v5/spread/order/history";
+        let mut params = json!({});
+
+        if let Some(symbol) = symbol {
+            params["symbol"] = json!(symbol);
+        }
+        if let Some(base_coin) = base_coin {
+            params["baseCoin"] = json!(base_coin);
+        }
+        if let Some(order_id) = order_id {
+            params["orderId"] = json!(order_id);
+        }
+        if let Some(order_link_id) = order_link_id {
+            params["orderLinkId"] = json!(order_link_id);
+        }
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
+    /// Get spread trade history
+    ///
+    /// API: GET /v5/spread/execution/list
+    /// https://bybit-exchange.github.io/docs/v5/spread/trade/trade-history
+    pub async fn get_executions(
+        &self,
+        symbol: Option<&str>,
+        order_id: Option<&str>,
+        order_link_id: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ServerResponse<SpreadExecutionResult>> {
+        let endpoint = "v5/spread/execution/list";
+        let mut params = json!({});
+
+        if let Some(symbol) = symbol {
+            params["symbol"] = json!(symbol);
+        }
+        if let Some(order_id) = order_id {
+            params["orderId"] = json!(order_id);
+        }
+        if let Some(order_link_id) = order_link_id {
+            params["orderLinkId"] = json!(order_link_id);
+        }
+        if let Some(start_time) = start_time {
+            params["startTime"] = json!(start_time);
+        }
+        if let Some(end_time) = end_time {
+            params["endTime"] = json!(end_time);
+        }
+        if let Some(limit) = limit {
+            params["limit"] = json!(limit);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/affiliate/affiliate-user-list
+//
+// GET /v5/affiliate/aff-user-list
+// {
+//     "list": [
+//         {
+//             "userId": "1001",
+//             "registerTime": "2024-03-18",
+//             "source": 1,
+//             "remarks": "",
+//             "isKyc": true,
+//             "takerVol30Day": "1200.5",
+//             "makerVol30Day": "300",
+//             "tradeVol30Day": "1500.5",
+//             "depositAmount30Day": "1000",
+//             "takerVol365Day": "12000",
+//             "makerVol365Day": "4000",
+//             "tradeVol365Day": "16000",
+//             "depositAmount365Day": "5000"
+//         }
+//     ],
+//     "nextPageCursor": "1001"
+// }
+
+/// A user referred by the affiliate
+///
+/// Volume and deposit fields are only filled when requested with the
+/// matching `need_deposit` / `need_30` / `need_365` flags.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliateUser {
+    pub user_id: String,
+    #[serde(default)]
+    pub register_time: String,
+    /// Referral source
+    #[serde(default)]
+    pub source: i32,
+    #[serde(default)]
+    pub remarks: String,
+    #[serde(default)]
+    pub is_kyc: bool,
+    #[serde(default, rename = "takerVol30Day")]
+    pub taker_vol_30_day: String,
+    #[serde(default, rename = "makerVol30Day")]
+    pub maker_vol_30_day: String,
+    #[serde(default, rename = "tradeVol30Day")]
+    pub trade_vol_30_day: String,
+    #[serde(default, rename = "depositAmount30Day")]
+    pub deposit_amount_30_day: String,
+    #[serde(default, rename = "takerVol365Day")]
+    pub taker_vol_365_day: String,
+    #[serde(default, rename = "makerVol365Day")]
+    pub maker_vol_365_day: String,
+    #[serde(default, rename = "tradeVol365Day")]
+    pub trade_vol_365_day: String,
+    #[serde(default, rename = "depositAmount365Day")]
+    pub deposit_amount_365_day: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliateUserListResult {
+    #[serde(default)]
+    pub list: Vec<AffiliateUser>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_affiliate_user_list() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "list": [
+                    {
+                        "userId": "1001",
+                        "registerTime": "2024-03-18",
+                        "source": 1,
+                        "remarks": "",
+                        "isKyc": true,
+                        "takerVol30Day": "1200.5",
+                        "makerVol30Day": "300",
+                        "tradeVol30Day": "1500.5",
+                        "depositAmount30Day": "1000"
+                    }
+                ],
+                "nextPageCursor": "1001"
+            },
+            "retExtInfo": {},
+            "time": 1741773201000
+        }"#;
+        let response: ServerResponse<AffiliateUserListResult> =
+            serde_json::from_str(json_data).unwrap();
+        let user = &response.result.list[0];
+        assert_eq!(user.trade_vol_30_day, "1500.5");
+        assert_eq!(user.trade_vol_365_day, "");
+        assert_eq!(response.result.next_page_cursor, "1001");
+    }
+}
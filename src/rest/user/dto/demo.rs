@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/demo
+//
+// POST /v5/user/create-demo-member (mainnet domain, production key)
+// {
+//     "retCode": 0,
+//     "retMsg": "success",
+//     "result": { "subMemberId": "1664563" },
+//     "retExtInfo": {},
+//     "time": 1701941546201
+// }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDemoAccountResult {
+    pub sub_member_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::client::ServerResponse;
+
+    #[test]
+    fn test_deserialize_create_demo_account() {
+        let json_data = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": { "subMemberId": "1664563" },
+            "retExtInfo": {},
+            "time": 1701941546201
+        }"#;
+        let response: ServerResponse<CreateDemoAccountResult> =
+            serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.result.sub_member_id, "1664563");
+    }
+}
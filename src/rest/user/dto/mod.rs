@@ -1,2 +1,5 @@
-// User module DTOs
-// DTOs will be added as needed for typed responses
+pub mod affiliate;
+pub mod demo;
+
+pub use affiliate::*;
+pub use demo::*;
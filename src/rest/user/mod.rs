@@ -1,4 +1,4 @@
-mod dto;
+pub mod dto;
 mod user_client;
 
 pub use user_client::UserClient;
@@ -1,4 +1,6 @@
 use crate::rest::client::{RestClient, SecType, ServerResponse};
+use crate::rest::user::dto::CreateDemoAccountResult;
+use crate::rest::user::dto::*;
 use crate::rest::BybitResult as Result;
 use serde_json::json;
 
@@ -189,6 +191,44 @@ impl UserClient {
         Ok(response)
     }
 
+    /// Get affiliate user list
+    ///
+    /// Pass `need_deposit`, `need_30` and `need_365` to include deposit and
+    /// trading volume figures for each user.
+    ///
+    /// API: GET /v5/affiliate/aff-user-list
+    /// https://bybit-exchange.github.io/docs/v5/affiliate/affiliate-user-list
+    pub async fn get_affiliate_user_list(
+        &self,
+        size: Option<i32>,
+        cursor: Option<&str>,
+        need_deposit: Option<bool>,
+        need_30: Option<bool>,
+        need_365: Option<bool>,
+    ) -> Result<ServerResponse<AffiliateUserListResult>> {
+        let endpoint = "v5/affiliate/aff-user-list";
+        let mut params = json!({});
+
+        if let Some(size) = size {
+            params["size"] = json!(size);
+        }
+        if let Some(cursor) = cursor {
+            params["cursor"] = json!(cursor);
+        }
+        if let Some(need_deposit) = need_deposit {
+            params["needDeposit"] = json!(need_deposit);
+        }
+        if let Some(need_30) = need_30 {
+            params["need30"] = json!(need_30);
+        }
+        if let Some(need_365) = need_365 {
+            params["need365"] = json!(need_365);
+        }
+
+        let response = self.client.get(endpoint, params, SecType::Signed).await?;
+        Ok(response)
+    }
+
     /// Freeze sub UID
     ///
     /// API: POST /v5/user/frozen-sub-member
@@ -307,6 +347,23 @@ impl UserClient {
         let response = self.client.post(endpoint, body, SecType::Signed).await?;
         Ok(response)
     }
+
+    /// Create a demo trading account
+    ///
+    /// Must be called on the mainnet domain with a production API key. Returns
+    /// the existing demo UID if one was already created. Create an API key for
+    /// the demo UID afterwards and use it with `BybitEnv::Demo`.
+    ///
+    /// API: POST /v5/user/create-demo-member
+    /// https://bybit-exchange.github.io/docs/v5/demo
+    pub async fn create_demo_account(&self) -> Result<ServerResponse<CreateDemoAccountResult>> {
+        let endpoint = "v5/user/create-demo-member";
+        let response = self
+            .client
+            .post(endpoint, json!({}), SecType::Signed)
+            .await?;
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,335 @@
+//! Connection handling and REST routes of the mock exchange.
+//!
+//! One listener serves both protocols: each connection's request head is
+//! parsed by hand, WebSocket upgrades are handed to [`streams`](super::streams)
+//! and everything else is answered as keep-alive HTTP/1.1.
+
+use super::streams;
+use super::Shared;
+use crate::consts::{API_REQUEST_KEY, RECV_WINDOW_KEY, SIGNATURE_KEY, TIMESTAMP_KEY};
+use crate::trading::engine::{category_param, fmt, param, split_symbol, Reject};
+use crate::utils::{millis, sign};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+use tokio::task::JoinSet;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::Role;
+use tokio_tungstenite::WebSocketStream;
+
+/// Largest request head or body accepted.
+const MAX_REQUEST_BYTES: usize = 1 << 20;
+
+/// Accept connections until `shutdown` fires, then drop them all.
+pub(crate) async fn serve(
+    listener: TcpListener,
+    shared: Arc<Shared>,
+    mut shutdown: oneshot::Receiver<()>,
+) {
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let _ = stream.set_nodelay(true);
+                    connections.spawn(handle_connection(stream, shared.clone()));
+                }
+                Err(e) => log::warn!("MockExchange accept failed: {}", e),
+            },
+            Some(_) = connections.join_next() => {}
+            _ = &mut shutdown => break,
+        }
+    }
+}
+
+/// A parsed request head.
+struct Head {
+    method: String,
+    target: String,
+    /// Lower-case header names
+    headers: HashMap<String, String>,
+    len: usize,
+}
+
+fn parse_head(buf: &[u8]) -> Option<Head> {
+    let mut headers = [httparse::EMPTY_HEADER; 64];
+    let mut request = httparse::Request::new(&mut headers);
+    let len = match request.parse(buf) {
+        Ok(httparse::Status::Complete(len)) => len,
+        _ => return None,
+    };
+    Some(Head {
+        method: request.method?.to_string(),
+        target: request.path?.to_string(),
+        headers: request
+            .headers
+            .iter()
+            .map(|h| {
+                (
+                    h.name.to_ascii_lowercase(),
+                    String::from_utf8_lossy(h.value).into_owned(),
+                )
+            })
+            .collect(),
+        len,
+    })
+}
+
+/// Read until `buf` holds at least `n` bytes. False on EOF or error.
+async fn fill(stream: &mut TcpStream, buf: &mut Vec<u8>, n: usize) -> bool {
+    let mut chunk = [0u8; 8192];
+    while buf.len() < n {
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return false,
+            Ok(read) => buf.extend_from_slice(&chunk[..read]),
+        }
+    }
+    true
+}
+
+async fn handle_connection(mut stream: TcpStream, shared: Arc<Shared>) {
+    let mut buf = Vec::new();
+    loop {
+        let head = loop {
+            if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                match parse_head(&buf[..end + 4]) {
+                    Some(head) => break head,
+                    None => return,
+                }
+            }
+            let want = buf.len() + 1;
+            if want > MAX_REQUEST_BYTES || !fill(&mut stream, &mut buf, want).await {
+                return;
+            }
+        };
+
+        let upgrade = head
+            .headers
+            .get("upgrade")
+            .is_some_and(|u| u.eq_ignore_ascii_case("websocket"));
+        if upgrade {
+            let Some(key) = head.headers.get("sec-websocket-key") else {
+                return;
+            };
+            let response = format!(
+                "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                derive_accept_key(key.as_bytes())
+            );
+            if stream.write_all(response.as_bytes()).await.is_err() {
+                return;
+            }
+            let ws = WebSocketStream::from_raw_socket(stream, Role::Server, None).await;
+            streams::run_session(ws, &head.target, shared).await;
+            return;
+        }
+
+        let body_len: usize = head
+            .headers
+            .get("content-length")
+            .and_then(|l| l.parse().ok())
+            .unwrap_or(0);
+        if body_len > MAX_REQUEST_BYTES || !fill(&mut stream, &mut buf, head.len + body_len).await {
+            return;
+        }
+        let body = String::from_utf8_lossy(&buf[head.len..head.len + body_len]).into_owned();
+        let (status, reply) = handle_request(&shared, &head, &body);
+        let reply = reply.to_string();
+        let response = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            status,
+            if status == 200 { "OK" } else { "Not Found" },
+            reply.len(),
+            reply
+        );
+        if stream.write_all(response.as_bytes()).await.is_err() {
+            return;
+        }
+        buf.drain(..head.len + body_len);
+    }
+}
+
+/// Bybit's response envelope.
+fn envelope(result: Result<Value, Reject>) -> Value {
+    let (code, msg, result) = match result {
+        Ok(result) => (0, "OK".to_string(), result),
+        Err((code, msg)) => (code, msg, json!({})),
+    };
+    json!({
+        "retCode": code,
+        "retMsg": msg,
+        "result": result,
+        "retExtInfo": {},
+        "time": millis() as i64,
+    })
+}
+
+fn handle_request(shared: &Shared, head: &Head, body: &str) -> (u16, Value) {
+    let (path, query) = head.target.split_once('?').unwrap_or((&head.target, ""));
+    let params = if head.method == "GET" {
+        let pairs: HashMap<String, String> = serde_urlencoded::from_str(query).unwrap_or_default();
+        json!(pairs)
+    } else {
+        serde_json::from_str(body).unwrap_or_else(|_| json!({}))
+    };
+
+    let private = [
+        "/v5/order/",
+        "/v5/position/",
+        "/v5/account/",
+        "/v5/execution/",
+    ]
+    .iter()
+    .any(|prefix| path.starts_with(prefix));
+    if private {
+        let payload = if head.method == "GET" { query } else { body };
+        if let Err(reject) = check_signature(shared, &head.headers, payload) {
+            return (200, envelope(Err(reject)));
+        }
+    }
+
+    let mut events = Vec::new();
+    let result = {
+        let mut engine = shared.engine();
+        match (head.method.as_str(), path) {
+            ("GET", "/v5/market/time") => {
+                let nanos = millis() * 1_000_000;
+                Ok(json!({
+                    "timeSecond": (nanos / 1_000_000_000).to_string(),
+                    "timeNano": nanos.to_string(),
+                }))
+            }
+            ("GET", "/v5/market/orderbook") => category_param(&params).and_then(|category| {
+                let symbol = param(&params, "symbol").unwrap_or_default();
+                if !engine.has_symbol(category, &symbol) {
+                    return Err((10001, format!("params error: symbol invalid: {}", symbol)));
+                }
+                let depth = param(&params, "limit")
+                    .and_then(|l| l.parse().ok())
+                    .unwrap_or(25);
+                Ok(engine.orderbook_json(category, &symbol, depth))
+            }),
+            ("GET", "/v5/market/tickers") => category_param(&params).map(|category| {
+                let symbol = param(&params, "symbol");
+                json!({
+                    "category": category,
+                    "list": engine.tickers_json(category, symbol.as_deref()),
+                })
+            }),
+            ("GET", "/v5/market/recent-trade") => category_param(&params).map(|category| {
+                let symbol = param(&params, "symbol").unwrap_or_default();
+                let limit = param(&params, "limit")
+                    .and_then(|l| l.parse().ok())
+                    .unwrap_or(60);
+                json!({
+                    "category": category,
+                    "list": engine.recent_trades_json(category, &symbol, limit),
+                })
+            }),
+            ("GET", "/v5/market/instruments-info") => category_param(&params).map(|category| {
+                let symbol = param(&params, "symbol");
+                let list: Vec<Value> = engine
+                    .symbols(category)
+                    .into_iter()
+                    .filter(|s| symbol.as_ref().is_none_or(|x| x == s))
+                    .map(|s| {
+                        let (base, quote) = split_symbol(&s);
+                        json!({
+                            "symbol": s,
+                            "status": "Trading",
+                            "baseCoin": base,
+                            "quoteCoin": quote,
+                            "settleCoin": quote,
+                        })
+                    })
+                    .collect();
+                json!({"category": category, "list": list, "nextPageCursor": ""})
+            }),
+            ("POST", "/v5/order/create") => engine.place_order(&params, &mut events),
+            ("POST", "/v5/order/amend") => engine.amend_order(&params, &mut events),
+            ("POST", "/v5/order/cancel") => engine.cancel_order(&params, &mut events),
+            ("POST", "/v5/order/cancel-all") => engine.cancel_all(&params, &mut events),
+            ("GET", "/v5/order/realtime") => engine.orders_json(&params, true),
+            ("GET", "/v5/order/history") => engine.orders_json(&params, false),
+            ("GET", "/v5/execution/list") => engine.executions_json(&params),
+            ("GET", "/v5/position/list") => engine.positions_json(&params),
+            ("GET", "/v5/account/wallet-balance") => {
+                let coins: Option<Vec<String>> =
+                    param(&params, "coin").map(|c| c.split(',').map(str::to_string).collect());
+                Ok(json!({"list": [engine.wallet_json(coins.as_deref())]}))
+            }
+            ("GET", "/v5/account/fee-rate") => category_param(&params).map(|category| {
+                let (maker, taker) = engine.fee_rates();
+                let list: Vec<Value> = engine
+                    .symbols(category)
+                    .into_iter()
+                    .filter(|s| param(&params, "symbol").is_none_or(|x| x == *s))
+                    .map(|s| {
+                        json!({
+                            "symbol": s,
+                            "baseCoin": "",
+                            "makerFeeRate": fmt(maker),
+                            "takerFeeRate": fmt(taker),
+                        })
+                    })
+                    .collect();
+                json!({"list": list})
+            }),
+            (method, path) => {
+                return (
+                    404,
+                    envelope(Err((
+                        10001,
+                        format!("MockExchange does not implement {} {}", method, path),
+                    ))),
+                )
+            }
+        }
+    };
+    shared.publish(events);
+    (200, envelope(result))
+}
+
+/// Validate Bybit's `X-BAPI-*` signature headers.
+fn check_signature(
+    shared: &Shared,
+    headers: &HashMap<String, String>,
+    payload: &str,
+) -> Result<(), Reject> {
+    let header = |name: &str| {
+        headers
+            .get(&name.to_ascii_lowercase())
+            .cloned()
+            .unwrap_or_default()
+    };
+    let key = header(API_REQUEST_KEY);
+    let Some(secret) = shared.secret(&key) else {
+        return Err((10003, "API key is invalid.".to_string()));
+    };
+    let timestamp = header(TIMESTAMP_KEY);
+    let recv_window = header(RECV_WINDOW_KEY);
+    let window: i64 = recv_window.parse().unwrap_or(5000);
+    let now = millis() as i64;
+    match timestamp.parse::<i64>() {
+        Ok(ts) if ts <= now + 1000 && now - ts <= window => {}
+        _ => {
+            return Err((
+                10002,
+                "The request time exceeds the time window range.".to_string(),
+            ))
+        }
+    }
+    let expected = sign(
+        secret,
+        &format!("{}{}{}{}", timestamp, key, recv_window, payload),
+    );
+    if header(SIGNATURE_KEY) != expected {
+        return Err((
+            10004,
+            "Error sign, please check your signature generation algorithm.".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,275 @@
+//! [`MockExchange`]: a local Bybit V5 server for integration tests.
+
+use super::http;
+use crate::dto::{OrderInfo, PositionInfo, TradeHistory};
+use crate::rest::api_key_pair::ApiKeyPair;
+use crate::rest::client::RestClient;
+use crate::rest::enums::{Category, Side};
+use crate::rest::errors::{BybitError, BybitResult};
+use crate::trading::engine::{Engine, Event};
+use serde_json::Value;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, oneshot};
+
+/// Events buffered per WebSocket session before it starts dropping them.
+const EVENT_BUFFER: usize = 4096;
+
+/// A scripted order book: `(price, size)` levels.
+type Levels = Vec<(f64, f64)>;
+
+/// Initial state of a [`MockExchange`].
+#[derive(Debug, Clone)]
+pub struct MockExchangeConfig {
+    api_keys: Vec<(String, String)>,
+    balances: Vec<(String, f64)>,
+    books: Vec<(Category, String, Levels, Levels)>,
+    maker_fee: f64,
+    taker_fee: f64,
+}
+
+impl Default for MockExchangeConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockExchangeConfig {
+    /// No keys, no balances, no books; 0.02% maker / 0.055% taker fees.
+    pub fn new() -> Self {
+        MockExchangeConfig {
+            api_keys: Vec::new(),
+            balances: Vec::new(),
+            books: Vec::new(),
+            maker_fee: 0.0002,
+            taker_fee: 0.00055,
+        }
+    }
+
+    /// Accept requests signed with this key pair.
+    pub fn with_api_key(mut self, key: impl Into<String>, secret: impl Into<String>) -> Self {
+        self.api_keys.push((key.into(), secret.into()));
+        self
+    }
+
+    /// Credit `amount` of `coin` to the unified wallet.
+    pub fn with_balance(mut self, coin: impl Into<String>, amount: f64) -> Self {
+        self.balances.push((coin.into(), amount));
+        self
+    }
+
+    /// List a symbol with an initial order book. Orders are only accepted
+    /// for listed symbols.
+    pub fn with_book(
+        mut self,
+        category: Category,
+        symbol: impl Into<String>,
+        bids: &[(f64, f64)],
+        asks: &[(f64, f64)],
+    ) -> Self {
+        self.books
+            .push((category, symbol.into(), bids.to_vec(), asks.to_vec()));
+        self
+    }
+
+    /// Maker and taker fee rates, e.g. `0.0002` for 0.02%.
+    pub fn with_fee_rates(mut self, maker: f64, taker: f64) -> Self {
+        self.maker_fee = maker;
+        self.taker_fee = taker;
+        self
+    }
+}
+
+/// State shared by the connection tasks.
+pub(crate) struct Shared {
+    engine: Mutex<Engine>,
+    api_keys: Vec<(String, String)>,
+    events: broadcast::Sender<Event>,
+}
+
+impl Shared {
+    pub(crate) fn engine(&self) -> MutexGuard<'_, Engine> {
+        // A panicking test must not wedge every other connection
+        self.engine.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn secret(&self, key: &str) -> Option<&str> {
+        self.api_keys
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, s)| s.as_str())
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.events.subscribe()
+    }
+
+    pub(crate) fn publish(&self, events: Vec<Event>) {
+        for event in events {
+            // No receivers is fine: nobody is subscribed yet
+            let _ = self.events.send(event);
+        }
+    }
+
+    fn apply<R>(&self, f: impl FnOnce(&mut Engine, &mut Vec<Event>) -> R) -> R {
+        let mut events = Vec::new();
+        let result = f(&mut self.engine(), &mut events);
+        self.publish(events);
+        result
+    }
+}
+
+/// A local Bybit V5 exchange for tests.
+///
+/// Serves the REST API and the public, private and trade WebSocket streams
+/// on one `127.0.0.1` port. Private requests must be signed with a key from
+/// [`MockExchangeConfig::with_api_key`]; orders are matched against scripted
+/// books and fill events are pushed to private subscribers.
+pub struct MockExchange {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    shutdown: Option<oneshot::Sender<()>>,
+    handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl MockExchange {
+    /// Bind a free local port and start serving.
+    pub async fn start(config: MockExchangeConfig) -> BybitResult<MockExchange> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| BybitError::Internal(format!("MockExchange bind failed: {}", e)))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| BybitError::Internal(format!("MockExchange bind failed: {}", e)))?;
+
+        let mut engine = Engine::new(config.maker_fee, config.taker_fee);
+        for (coin, amount) in &config.balances {
+            engine.deposit(coin, *amount);
+        }
+        for (category, symbol, bids, asks) in &config.books {
+            engine.set_book(*category, symbol, bids, asks, &mut Vec::new());
+        }
+        let shared = Arc::new(Shared {
+            engine: Mutex::new(engine),
+            api_keys: config.api_keys,
+            events: broadcast::channel(EVENT_BUFFER).0,
+        });
+
+        let (shutdown, shutdown_rx) = oneshot::channel();
+        let handle = tokio::spawn(http::serve(listener, shared.clone(), shutdown_rx));
+        Ok(MockExchange {
+            addr,
+            shared,
+            shutdown: Some(shutdown),
+            handle: Some(handle),
+        })
+    }
+
+    /// REST base URL, for [`RestClient::new`].
+    pub fn rest_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Public WebSocket URL of a category.
+    pub fn public_ws_url(&self, category: Category) -> String {
+        format!("ws://{}/v5/public/{}", self.addr, category)
+    }
+
+    /// Private WebSocket URL.
+    pub fn private_ws_url(&self) -> String {
+        format!("ws://{}/v5/private", self.addr)
+    }
+
+    /// Trade (order entry) WebSocket URL.
+    pub fn trade_ws_url(&self) -> String {
+        format!("ws://{}/v5/trade", self.addr)
+    }
+
+    /// A [`RestClient`] for this exchange, signed with the first configured
+    /// API key (unsigned if there is none).
+    pub fn rest_client(&self) -> RestClient {
+        let (key, secret) = self.shared.api_keys.first().cloned().unwrap_or_default();
+        RestClient::new(
+            ApiKeyPair::new("mock".to_string(), key, secret),
+            self.rest_url(),
+        )
+    }
+
+    /// Replace the book of a symbol (listing it if new). Resting orders the
+    /// new book crosses are filled as maker against it.
+    pub fn set_book(
+        &self,
+        category: Category,
+        symbol: &str,
+        bids: &[(f64, f64)],
+        asks: &[(f64, f64)],
+    ) {
+        self.shared
+            .apply(|engine, events| engine.set_book(category, symbol, bids, asks, events));
+    }
+
+    /// Print a public trade. Resting orders on the other side at or through
+    /// `price` are filled as maker, best price first, up to `qty`.
+    pub fn trade(&self, category: Category, symbol: &str, side: Side, price: f64, qty: f64) {
+        self.shared
+            .apply(|engine, events| engine.trade(category, symbol, side, price, qty, events));
+    }
+
+    /// Every order ever placed, oldest first.
+    ///
+    /// Like the other accessors, panics if the engine produces a row the
+    /// typed DTO cannot parse, so schema drift fails the test.
+    pub fn orders(&self) -> Vec<OrderInfo> {
+        parse_all(self.shared.engine().all_orders())
+    }
+
+    /// Every fill, oldest first.
+    pub fn executions(&self) -> Vec<TradeHistory> {
+        parse_all(self.shared.engine().all_executions())
+    }
+
+    /// Linear positions, as returned by `GET /v5/position/list`.
+    pub fn positions(&self) -> Vec<PositionInfo> {
+        parse_all(self.shared.engine().all_positions())
+    }
+
+    /// Wallet balance of a coin.
+    pub fn balance(&self, coin: &str) -> f64 {
+        self.shared.engine().wallet_balance(coin)
+    }
+
+    /// Stop serving and close all connections.
+    pub async fn shutdown(mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.await;
+        }
+    }
+}
+
+impl Drop for MockExchange {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+fn parse_all<T: serde::de::DeserializeOwned>(values: Vec<Value>) -> Vec<T> {
+    values
+        .into_iter()
+        .map(|v| {
+            serde_json::from_value(v.clone()).unwrap_or_else(|e| {
+                panic!(
+                    "MockExchange row does not parse as {}: {}: {}",
+                    std::any::type_name::<T>(),
+                    e,
+                    v
+                )
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,334 @@
+//! WebSocket sessions of the mock exchange: public, private and trade.
+
+use super::Shared;
+use crate::rest::enums::Category;
+use crate::trading::engine::{param, Event};
+use crate::utils::{millis, sign};
+use crate::ws::auth::auth_payload;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+/// Which stream a session was opened on, from its URL path.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Public(Category),
+    Private,
+    Trade,
+}
+
+impl Kind {
+    fn from_path(path: &str) -> Option<Kind> {
+        Some(match path.split('?').next()? {
+            "/v5/public/spot" => Kind::Public(Category::Spot),
+            "/v5/public/linear" => Kind::Public(Category::Linear),
+            "/v5/public/inverse" => Kind::Public(Category::Inverse),
+            "/v5/public/option" => Kind::Public(Category::Option),
+            "/v5/private" => Kind::Private,
+            "/v5/trade" => Kind::Trade,
+            _ => return None,
+        })
+    }
+}
+
+struct Session {
+    kind: Kind,
+    conn_id: String,
+    shared: Arc<Shared>,
+    authed: bool,
+    topics: Vec<String>,
+}
+
+pub(crate) async fn run_session(
+    mut ws: WebSocketStream<TcpStream>,
+    path: &str,
+    shared: Arc<Shared>,
+) {
+    let Some(kind) = Kind::from_path(path) else {
+        let _ = ws.close(None).await;
+        return;
+    };
+    let mut events = shared.subscribe();
+    let mut session = Session {
+        kind,
+        conn_id: uuid::Uuid::new_v4().to_string(),
+        shared,
+        authed: false,
+        topics: Vec::new(),
+    };
+
+    loop {
+        let replies = tokio::select! {
+            msg = ws.next() => match msg {
+                Some(Ok(Message::Text(text))) => session.handle(&text),
+                Some(Ok(Message::Ping(data))) => {
+                    let _ = ws.send(Message::Pong(data)).await;
+                    continue;
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+            event = events.recv() => match event {
+                Ok(event) => session.push(&event),
+                Err(RecvError::Lagged(n)) => {
+                    log::warn!("MockExchange session {} dropped {} events", session.conn_id, n);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            },
+        };
+        for reply in replies {
+            if ws
+                .send(Message::Text(reply.to_string().into()))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+impl Session {
+    /// Answer one client request.
+    fn handle(&mut self, text: &str) -> Vec<Value> {
+        let Ok(request) = serde_json::from_str::<Value>(text) else {
+            return vec![self.op_reply("", false, "Invalid request", "")];
+        };
+        let op = param(&request, "op").unwrap_or_default();
+        let req_id = param(&request, "req_id")
+            .or_else(|| param(&request, "reqId"))
+            .unwrap_or_default();
+        let args: Vec<Value> = request
+            .get("args")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        match op.as_str() {
+            "ping" => vec![match self.kind {
+                Kind::Public(_) => self.op_reply("ping", true, "pong", &req_id),
+                _ => json!({
+                    "req_id": req_id,
+                    "op": "pong",
+                    "args": [millis().to_string()],
+                    "conn_id": self.conn_id,
+                }),
+            }],
+            "auth" => {
+                let result = self.authenticate(&args);
+                self.authed = result.is_ok();
+                vec![self.op_reply(
+                    "auth",
+                    self.authed,
+                    &result.err().unwrap_or_default(),
+                    &req_id,
+                )]
+            }
+            "subscribe" | "unsubscribe" if self.kind != Kind::Trade => {
+                if self.kind == Kind::Private && !self.authed {
+                    return vec![self.op_reply(&op, false, "Request not authorized", &req_id)];
+                }
+                let topics: Vec<String> = args
+                    .iter()
+                    .filter_map(|t| t.as_str().map(str::to_string))
+                    .collect();
+                let mut replies = vec![self.op_reply(&op, true, "", &req_id)];
+                if op == "subscribe" {
+                    for topic in topics {
+                        replies.extend(self.snapshot(&topic));
+                        if !self.topics.contains(&topic) {
+                            self.topics.push(topic);
+                        }
+                    }
+                } else {
+                    self.topics.retain(|t| !topics.contains(t));
+                }
+                replies
+            }
+            "order.create" | "order.amend" | "order.cancel" if self.kind == Kind::Trade => {
+                vec![self.trade(&op, &req_id, &request, &args)]
+            }
+            _ => vec![self.op_reply(&op, false, &format!("Unsupported op: {}", op), &req_id)],
+        }
+    }
+
+    fn op_reply(&self, op: &str, success: bool, msg: &str, req_id: &str) -> Value {
+        json!({
+            "success": success,
+            "ret_msg": msg,
+            "conn_id": self.conn_id,
+            "req_id": req_id,
+            "op": op,
+        })
+    }
+
+    /// Check `[api_key, expires, signature]` of an `auth` request.
+    fn authenticate(&self, args: &[Value]) -> Result<(), String> {
+        let key = args.first().and_then(Value::as_str).unwrap_or_default();
+        let expires = args.get(1).and_then(Value::as_u64).unwrap_or_default();
+        let signature = args.get(2).and_then(Value::as_str).unwrap_or_default();
+        let secret = self
+            .shared
+            .secret(key)
+            .ok_or_else(|| "Invalid apikey".to_string())?;
+        if (expires as u128) <= millis() {
+            return Err("Params Error: auth expired".to_string());
+        }
+        if sign(secret, &auth_payload(expires)) != signature {
+            return Err("Invalid sign".to_string());
+        }
+        Ok(())
+    }
+
+    fn trade(&self, op: &str, req_id: &str, request: &Value, args: &[Value]) -> Value {
+        let params = args.first().cloned().unwrap_or_else(|| json!({}));
+        let result = if !self.authed {
+            Err((10003, "Request not authorized".to_string()))
+        } else {
+            self.check_header(request, &params).and_then(|_| {
+                let mut events = Vec::new();
+                let result = {
+                    let mut engine = self.shared.engine();
+                    match op {
+                        "order.create" => engine.place_order(&params, &mut events),
+                        "order.amend" => engine.amend_order(&params, &mut events),
+                        _ => engine.cancel_order(&params, &mut events),
+                    }
+                };
+                self.shared.publish(events);
+                result
+            })
+        };
+        let (code, msg, data) = match result {
+            Ok(data) => (0, "OK".to_string(), data),
+            Err((code, msg)) => (code, msg, json!({})),
+        };
+        json!({
+            "reqId": req_id,
+            "retCode": code,
+            "retMsg": msg,
+            "op": op,
+            "data": data,
+            "retExtInfo": {},
+            "header": {"Timenow": millis().to_string()},
+            "connId": self.conn_id,
+        })
+    }
+
+    /// Requests that carry `X-BAPI-SIGN` in their header must be signed
+    /// like REST requests, over the JSON of the order parameters.
+    fn check_header(&self, request: &Value, params: &Value) -> Result<(), (i32, String)> {
+        let header = &request["header"];
+        let Some(signature) = header.get("X-BAPI-SIGN").and_then(Value::as_str) else {
+            return Ok(());
+        };
+        let field = |name: &str| header.get(name).and_then(Value::as_str).unwrap_or_default();
+        let key = field("X-BAPI-API-KEY");
+        let secret = self
+            .shared
+            .secret(key)
+            .ok_or((10003, "API key is invalid.".to_string()))?;
+        let payload = format!(
+            "{}{}{}{}",
+            field("X-BAPI-TIMESTAMP"),
+            key,
+            field("X-BAPI-RECV-WINDOW"),
+            params
+        );
+        if sign(secret, &payload) != signature {
+            return Err((
+                10004,
+                "Error sign, please check your signature generation algorithm.".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Initial push for a new public subscription.
+    fn snapshot(&self, topic: &str) -> Option<Value> {
+        let Kind::Public(category) = self.kind else {
+            return None;
+        };
+        let engine = self.shared.engine();
+        let parts: Vec<&str> = topic.split('.').collect();
+        match parts.as_slice() {
+            ["orderbook", depth, symbol] if engine.has_symbol(category, symbol) => {
+                let depth = depth.parse().unwrap_or(50);
+                Some(json!({
+                    "topic": topic,
+                    "type": "snapshot",
+                    "ts": millis() as i64,
+                    "data": engine.orderbook_json(category, symbol, depth),
+                }))
+            }
+            ["tickers", symbol] => engine.ticker_json(category, symbol).map(|data| {
+                json!({
+                    "topic": topic,
+                    "type": "snapshot",
+                    "ts": millis() as i64,
+                    "data": data,
+                })
+            }),
+            _ => None,
+        }
+    }
+
+    /// Messages this session receives for an engine event.
+    fn push(&self, event: &Event) -> Vec<Value> {
+        match (self.kind, event) {
+            (Kind::Private, Event::Order(data)) => self.private("order", data, true),
+            (Kind::Private, Event::Execution(data)) => self.private("execution", data, true),
+            (Kind::Private, Event::Position(data)) => self.private("position", data, true),
+            (Kind::Private, Event::Wallet(data)) => self.private("wallet", data, false),
+            (Kind::Public(c), Event::Trade { category, data }) if c == *category => {
+                let topic = format!("publicTrade.{}", data["s"].as_str().unwrap_or_default());
+                if !self.topics.contains(&topic) {
+                    return Vec::new();
+                }
+                vec![json!({
+                    "topic": topic,
+                    "type": "snapshot",
+                    "ts": millis() as i64,
+                    "data": [data],
+                })]
+            }
+            (Kind::Public(c), Event::Book { category, symbol }) if c == *category => self
+                .topics
+                .iter()
+                .filter(|t| t.starts_with("orderbook.") && t.ends_with(&format!(".{}", symbol)))
+                .filter_map(|t| self.snapshot(t))
+                .collect(),
+            (Kind::Public(c), Event::Ticker { category, symbol }) if c == *category => {
+                let topic = format!("tickers.{}", symbol);
+                if self.topics.contains(&topic) {
+                    self.snapshot(&topic).into_iter().collect()
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// A private push, to `topic` and/or `topic.{category}` subscribers.
+    fn private(&self, topic: &str, data: &Value, by_category: bool) -> Vec<Value> {
+        let category = data["category"].as_str().unwrap_or_default();
+        self.topics
+            .iter()
+            .filter(|t| *t == topic || by_category && **t == format!("{}.{}", topic, category))
+            .map(|t| {
+                json!({
+                    "id": uuid::Uuid::new_v4().to_string(),
+                    "topic": t,
+                    "creationTime": millis() as i64,
+                    "data": [data],
+                })
+            })
+            .collect()
+    }
+}
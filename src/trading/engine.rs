@@ -0,0 +1,1553 @@
+//! In-memory matching engine shared by
+//! [`PaperTrader`](super::PaperTrader) and, with the `testing` feature,
+//! `testing::MockExchange`.
+//!
+//! Everything is kept in plain `f64`s and rendered into Bybit's string-typed
+//! JSON on the way out. Spot orders move base/quote balances; linear orders
+//! maintain a one-way position per symbol and settle fees and realised PnL
+//! in the quote coin.
+
+use crate::rest::enums::{Category, Side};
+use crate::utils::millis;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Quantities below this are treated as zero.
+const EPS: f64 = 1e-12;
+/// Public trades kept per symbol for `GET /v5/market/recent-trade`.
+const RECENT_TRADES: usize = 500;
+/// Quote coins recognised when splitting a spot symbol.
+const QUOTE_COINS: [&str; 6] = ["USDT", "USDC", "BTC", "ETH", "EUR", "DAI"];
+/// Leverage reported for linear positions.
+const LEVERAGE: &str = "10";
+
+/// A rejected request: Bybit `retCode` and `retMsg`.
+pub(crate) type Reject = (i32, String);
+
+fn reject<T>(code: i32, msg: impl Into<String>) -> Result<T, Reject> {
+    Err((code, msg.into()))
+}
+
+/// State change that is pushed to WebSocket subscribers.
+#[derive(Debug, Clone)]
+pub(crate) enum Event {
+    Order(Value),
+    Execution(Value),
+    Position(Value),
+    Wallet(Value),
+    Book { category: Category, symbol: String },
+    Trade { category: Category, data: Value },
+    Ticker { category: Category, symbol: String },
+}
+
+/// Format a number the way Bybit does: plain decimal, no trailing zeros.
+pub(crate) fn fmt(x: f64) -> String {
+    let s = format!("{:.10}", x);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+/// Split a spot symbol into base and quote coin, e.g. `BTCUSDT` -> `BTC`, `USDT`.
+pub(crate) fn split_symbol(symbol: &str) -> (String, String) {
+    for quote in QUOTE_COINS {
+        if let Some(base) = symbol.strip_suffix(quote) {
+            if !base.is_empty() {
+                return (base.to_string(), quote.to_string());
+            }
+        }
+    }
+    (symbol.to_string(), "USDT".to_string())
+}
+
+/// Read a request parameter that may be sent as a JSON string or number.
+pub(crate) fn param(params: &Value, key: &str) -> Option<String> {
+    match params.get(key)? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn num_param(params: &Value, key: &str) -> Result<Option<f64>, Reject> {
+    match param(params, key) {
+        None => Ok(None),
+        Some(s) => match s.parse::<f64>() {
+            Ok(x) if x.is_finite() => Ok(Some(x)),
+            _ => reject(10001, format!("params error: {} invalid", key)),
+        },
+    }
+}
+
+pub(crate) fn category_param(params: &Value) -> Result<Category, Reject> {
+    let raw = param(params, "category").unwrap_or_default();
+    serde_json::from_value(Value::String(raw.clone()))
+        .or_else(|_| reject(10001, format!("params error: category invalid: {}", raw)))
+}
+
+/// Scripted order book of one symbol, plus last-trade statistics.
+#[derive(Debug, Default)]
+struct Book {
+    /// Price descending
+    bids: Vec<(f64, f64)>,
+    /// Price ascending
+    asks: Vec<(f64, f64)>,
+    update_id: u64,
+    last_price: f64,
+    prev_price: f64,
+    high: f64,
+    low: f64,
+    volume: f64,
+    turnover: f64,
+    trades: VecDeque<PublicTrade>,
+}
+
+impl Book {
+    fn set_levels(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.bids = bids.iter().copied().filter(|l| l.1 > EPS).collect();
+        self.asks = asks.iter().copied().filter(|l| l.1 > EPS).collect();
+        self.bids.sort_by(|a, b| b.0.total_cmp(&a.0));
+        self.asks.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.update_id += 1;
+        if self.last_price == 0.0 {
+            let mid = match (self.bids.first(), self.asks.first()) {
+                (Some(b), Some(a)) => (b.0 + a.0) / 2.0,
+                (Some(b), None) => b.0,
+                (None, Some(a)) => a.0,
+                (None, None) => 0.0,
+            };
+            self.last_price = mid;
+            self.prev_price = mid;
+        }
+    }
+
+    fn record_trade(&mut self, trade: PublicTrade) {
+        if self.high == 0.0 || trade.price > self.high {
+            self.high = trade.price;
+        }
+        if self.low == 0.0 || trade.price < self.low {
+            self.low = trade.price;
+        }
+        self.volume += trade.qty;
+        self.turnover += trade.qty * trade.price;
+        self.last_price = trade.price;
+        self.trades.push_front(trade);
+        self.trades.truncate(RECENT_TRADES);
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PublicTrade {
+    id: String,
+    symbol: String,
+    side: Side,
+    price: f64,
+    qty: f64,
+    tick: &'static str,
+    time: u128,
+}
+
+impl PublicTrade {
+    fn ws_json(&self) -> Value {
+        json!({
+            "T": self.time as i64,
+            "s": self.symbol,
+            "S": self.side,
+            "v": fmt(self.qty),
+            "p": fmt(self.price),
+            "L": self.tick,
+            "i": self.id,
+            "BT": false,
+        })
+    }
+
+    fn rest_json(&self) -> Value {
+        json!({
+            "execId": self.id,
+            "symbol": self.symbol,
+            "price": fmt(self.price),
+            "size": fmt(self.qty),
+            "side": self.side,
+            "time": self.time.to_string(),
+            "isBlockTrade": false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Order {
+    id: String,
+    link_id: String,
+    category: Category,
+    symbol: String,
+    side: Side,
+    market: bool,
+    time_in_force: String,
+    price: f64,
+    qty: f64,
+    filled: f64,
+    value: f64,
+    fee: f64,
+    status: &'static str,
+    reject_reason: &'static str,
+    reduce_only: bool,
+    created: u128,
+    updated: u128,
+}
+
+impl Order {
+    fn is_open(&self) -> bool {
+        matches!(self.status, "New" | "PartiallyFilled")
+    }
+
+    fn leaves(&self) -> f64 {
+        if self.is_open() || self.status == "Created" {
+            (self.qty - self.filled).max(0.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn crosses(&self, price: f64) -> bool {
+        self.market
+            || match self.side {
+                Side::Buy => price <= self.price + EPS,
+                Side::Sell => price >= self.price - EPS,
+            }
+    }
+
+    /// Close the order after its immediate matching phase.
+    fn cancel_rest(&mut self) {
+        self.status = if self.filled <= EPS {
+            "Cancelled"
+        } else if self.category == Category::Spot {
+            "PartiallyFilledCanceled"
+        } else {
+            "Cancelled"
+        };
+    }
+
+    fn json(&self) -> Value {
+        let avg = if self.filled > EPS {
+            fmt(self.value / self.filled)
+        } else {
+            String::new()
+        };
+        let leaves = self.leaves();
+        let mut order = json!({
+            "category": self.category,
+            "orderId": self.id,
+            "orderLinkId": self.link_id,
+            "blockTradeId": "",
+            "symbol": self.symbol,
+            "price": fmt(self.price),
+            "qty": fmt(self.qty),
+            "side": self.side,
+            "isLeverage": "",
+            "positionIdx": 0,
+            "orderStatus": self.status,
+            "cancelType": if self.status.contains("Cancel") { "CancelByUser" } else { "UNKNOWN" },
+            "rejectReason": self.reject_reason,
+            "avgPrice": avg,
+            "leavesQty": fmt(leaves),
+            "leavesValue": fmt(leaves * self.price),
+            "cumExecQty": fmt(self.filled),
+            "cumExecValue": fmt(self.value),
+            "cumExecFee": fmt(self.fee),
+        });
+        let rest = json!({
+            "timeInForce": self.time_in_force,
+            "orderType": if self.market { "Market" } else { "Limit" },
+            "stopOrderType": "",
+            "orderIv": "",
+            "triggerPrice": "",
+            "takeProfit": "",
+            "stopLoss": "",
+            "tpslMode": "",
+            "ocoTriggerType": "",
+            "tpLimitPrice": "",
+            "slLimitPrice": "",
+            "tpTriggerBy": "",
+            "slTriggerBy": "",
+            "triggerDirection": 0,
+            "triggerBy": "",
+            "lastPriceOnCreated": "",
+            "reduceOnly": self.reduce_only,
+            "closeOnTrigger": false,
+            "placeType": "",
+            "smpType": "None",
+            "smpGroup": 0,
+            "smpOrderId": "",
+            "createdTime": self.created.to_string(),
+            "updatedTime": self.updated.to_string(),
+        });
+        // Two halves: the whole object exceeds `json!`'s recursion limit
+        if let (Some(order), Value::Object(rest)) = (order.as_object_mut(), rest) {
+            order.extend(rest);
+        }
+        order
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Position {
+    /// Signed: positive long, negative short
+    size: f64,
+    entry: f64,
+    realised: f64,
+    created: u128,
+    updated: u128,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Balance {
+    wallet: f64,
+    locked: f64,
+}
+
+/// Order, position and balance state of a simulated account.
+#[derive(Debug)]
+pub(crate) struct Engine {
+    maker_fee: f64,
+    taker_fee: f64,
+    /// Per-symbol `(maker, taker)` overrides
+    symbol_fees: HashMap<(Category, String), (f64, f64)>,
+    books: HashMap<(Category, String), Book>,
+    orders: Vec<Order>,
+    executions: Vec<Value>,
+    positions: BTreeMap<String, Position>,
+    balances: BTreeMap<String, Balance>,
+    seq: u64,
+}
+
+impl Engine {
+    pub(crate) fn new(maker_fee: f64, taker_fee: f64) -> Self {
+        Engine {
+            maker_fee,
+            taker_fee,
+            symbol_fees: HashMap::new(),
+            books: HashMap::new(),
+            orders: Vec::new(),
+            executions: Vec::new(),
+            positions: BTreeMap::new(),
+            balances: BTreeMap::new(),
+            seq: 0,
+        }
+    }
+
+    pub(crate) fn fee_rates(&self) -> (f64, f64) {
+        (self.maker_fee, self.taker_fee)
+    }
+
+    pub(crate) fn set_fee_rates(&mut self, maker: f64, taker: f64) {
+        self.maker_fee = maker;
+        self.taker_fee = taker;
+    }
+
+    /// Override the fee rates of one symbol.
+    pub(crate) fn set_symbol_fee_rates(
+        &mut self,
+        category: Category,
+        symbol: &str,
+        maker: f64,
+        taker: f64,
+    ) {
+        self.symbol_fees
+            .insert((category, symbol.to_string()), (maker, taker));
+    }
+
+    pub(crate) fn deposit(&mut self, coin: &str, amount: f64) {
+        self.balances.entry(coin.to_string()).or_default().wallet += amount;
+    }
+
+    pub(crate) fn wallet_balance(&self, coin: &str) -> f64 {
+        self.balances.get(coin).map(|b| b.wallet).unwrap_or(0.0)
+    }
+
+    fn available(&self, coin: &str) -> f64 {
+        self.balances
+            .get(coin)
+            .map(|b| b.wallet - b.locked)
+            .unwrap_or(0.0)
+    }
+
+    fn lock(&mut self, coin: &str, amount: f64) {
+        let balance = self.balances.entry(coin.to_string()).or_default();
+        balance.locked = (balance.locked + amount).max(0.0);
+    }
+
+    fn next_seq(&mut self) -> u64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    // ── Market data ────────────────────────────────────────────
+
+    /// Replace the scripted book of a symbol and fill resting orders it crosses.
+    pub(crate) fn set_book(
+        &mut self,
+        category: Category,
+        symbol: &str,
+        bids: &[(f64, f64)],
+        asks: &[(f64, f64)],
+        events: &mut Vec<Event>,
+    ) {
+        self.books
+            .entry((category, symbol.to_string()))
+            .or_default()
+            .set_levels(bids, asks);
+
+        let mut orders = std::mem::take(&mut self.orders);
+        let mut touched = Vec::new();
+        for (n, order) in orders.iter_mut().enumerate() {
+            if order.category != category || order.symbol != symbol || !order.is_open() {
+                continue;
+            }
+            let fills = self.take_liquidity(order);
+            if fills.is_empty() {
+                continue;
+            }
+            for (price, qty) in fills {
+                self.execute(order, order.price, qty, true, events);
+                self.public_trade(category, symbol, opposite(order.side), price, qty, events);
+            }
+            touched.push(n);
+        }
+        self.finish(&orders, &touched, events);
+        self.orders = orders;
+        events.push(Event::Book {
+            category,
+            symbol: symbol.to_string(),
+        });
+        events.push(Event::Ticker {
+            category,
+            symbol: symbol.to_string(),
+        });
+    }
+
+    /// Print a public trade and fill resting orders on the other side of it.
+    pub(crate) fn trade(
+        &mut self,
+        category: Category,
+        symbol: &str,
+        side: Side,
+        price: f64,
+        qty: f64,
+        events: &mut Vec<Event>,
+    ) {
+        self.public_trade(category, symbol, side, price, qty, events);
+
+        let mut orders = std::mem::take(&mut self.orders);
+        // Best price first, then time priority
+        let mut resting: Vec<usize> = (0..orders.len())
+            .filter(|&n| {
+                let o = &orders[n];
+                o.category == category
+                    && o.symbol == symbol
+                    && o.is_open()
+                    && o.side == opposite(side)
+                    && o.crosses(price)
+            })
+            .collect();
+        resting.sort_by(|&a, &b| match side {
+            Side::Sell => orders[b].price.total_cmp(&orders[a].price),
+            Side::Buy => orders[a].price.total_cmp(&orders[b].price),
+        });
+
+        let mut remaining = qty;
+        let mut touched = Vec::new();
+        for n in resting {
+            if remaining <= EPS {
+                break;
+            }
+            let order = &mut orders[n];
+            let fill = order.leaves().min(remaining);
+            remaining -= fill;
+            self.execute(order, order.price, fill, true, events);
+            touched.push(n);
+        }
+        self.finish(&orders, &touched, events);
+        self.orders = orders;
+        events.push(Event::Ticker {
+            category,
+            symbol: symbol.to_string(),
+        });
+    }
+
+    fn public_trade(
+        &mut self,
+        category: Category,
+        symbol: &str,
+        side: Side,
+        price: f64,
+        qty: f64,
+        events: &mut Vec<Event>,
+    ) {
+        let book = self
+            .books
+            .entry((category, symbol.to_string()))
+            .or_default();
+        let tick = if price > book.last_price {
+            "PlusTick"
+        } else if price < book.last_price {
+            "MinusTick"
+        } else {
+            "ZeroPlusTick"
+        };
+        let trade = PublicTrade {
+            id: uuid::Uuid::new_v4().to_string(),
+            symbol: symbol.to_string(),
+            side,
+            price,
+            qty,
+            tick,
+            time: millis(),
+        };
+        events.push(Event::Trade {
+            category,
+            data: trade.ws_json(),
+        });
+        book.record_trade(trade);
+    }
+
+    pub(crate) fn has_symbol(&self, category: Category, symbol: &str) -> bool {
+        self.books.contains_key(&(category, symbol.to_string()))
+    }
+
+    /// `orderbook` REST result / WS data, limited to `depth` levels per side.
+    pub(crate) fn orderbook_json(&self, category: Category, symbol: &str, depth: usize) -> Value {
+        let levels = |side: &[(f64, f64)]| -> Vec<[String; 2]> {
+            side.iter()
+                .take(depth)
+                .map(|(p, q)| [fmt(*p), fmt(*q)])
+                .collect()
+        };
+        let (bids, asks, u) = match self.books.get(&(category, symbol.to_string())) {
+            Some(book) => (levels(&book.bids), levels(&book.asks), book.update_id),
+            None => (Vec::new(), Vec::new(), 0),
+        };
+        json!({
+            "s": symbol,
+            "b": bids,
+            "a": asks,
+            "ts": millis() as i64,
+            "u": u,
+            "seq": u,
+        })
+    }
+
+    pub(crate) fn ticker_json(&self, category: Category, symbol: &str) -> Option<Value> {
+        let book = self.books.get(&(category, symbol.to_string()))?;
+        let price = |l: Option<&(f64, f64)>| l.map(|l| fmt(l.0)).unwrap_or_default();
+        let size = |l: Option<&(f64, f64)>| l.map(|l| fmt(l.1)).unwrap_or_default();
+        let pcnt = if book.prev_price > 0.0 {
+            fmt((book.last_price - book.prev_price) / book.prev_price)
+        } else {
+            "0".to_string()
+        };
+        let mut ticker = json!({
+            "symbol": symbol,
+            "bid1Price": price(book.bids.first()),
+            "bid1Size": size(book.bids.first()),
+            "ask1Price": price(book.asks.first()),
+            "ask1Size": size(book.asks.first()),
+            "lastPrice": fmt(book.last_price),
+            "prevPrice24h": fmt(book.prev_price),
+            "price24hPcnt": pcnt,
+            "highPrice24h": fmt(book.high.max(book.last_price)),
+            "lowPrice24h": fmt(if book.low > 0.0 { book.low } else { book.last_price }),
+            "turnover24h": fmt(book.turnover),
+            "volume24h": fmt(book.volume),
+        });
+        if category != Category::Spot {
+            ticker["markPrice"] = json!(fmt(book.last_price));
+            ticker["indexPrice"] = json!(fmt(book.last_price));
+            ticker["fundingRate"] = json!("0");
+            ticker["openInterest"] = json!("0");
+        }
+        Some(ticker)
+    }
+
+    pub(crate) fn tickers_json(&self, category: Category, symbol: Option<&str>) -> Vec<Value> {
+        self.symbols(category)
+            .into_iter()
+            .filter(|s| symbol.is_none_or(|x| x == s))
+            .filter_map(|s| self.ticker_json(category, &s))
+            .collect()
+    }
+
+    pub(crate) fn recent_trades_json(
+        &self,
+        category: Category,
+        symbol: &str,
+        limit: usize,
+    ) -> Vec<Value> {
+        self.books
+            .get(&(category, symbol.to_string()))
+            .map(|book| {
+                book.trades
+                    .iter()
+                    .take(limit)
+                    .map(PublicTrade::rest_json)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn symbols(&self, category: Category) -> Vec<String> {
+        let mut symbols: Vec<String> = self
+            .books
+            .keys()
+            .filter(|(c, _)| *c == category)
+            .map(|(_, s)| s.clone())
+            .collect();
+        symbols.sort();
+        symbols
+    }
+
+    fn last_price(&self, category: Category, symbol: &str) -> f64 {
+        self.books
+            .get(&(category, symbol.to_string()))
+            .map(|b| b.last_price)
+            .unwrap_or(0.0)
+    }
+
+    // ── Orders ─────────────────────────────────────────────────
+
+    /// `order.create`: match against the book, rest the remainder.
+    pub(crate) fn place_order(
+        &mut self,
+        params: &Value,
+        events: &mut Vec<Event>,
+    ) -> Result<Value, Reject> {
+        let category = category_param(params)?;
+        if !matches!(category, Category::Spot | Category::Linear) {
+            return reject(10001, "Only spot and linear are simulated");
+        }
+        let symbol = param(params, "symbol").unwrap_or_default();
+        if !self.has_symbol(category, &symbol) {
+            return reject(10001, format!("params error: symbol invalid: {}", symbol));
+        }
+        let side: Side = serde_json::from_value(params.get("side").cloned().unwrap_or_default())
+            .or_else(|_| reject(10001, "params error: side invalid"))?;
+        let market = match param(params, "orderType").as_deref() {
+            Some("Market") => true,
+            Some("Limit") => false,
+            _ => return reject(10001, "params error: orderType invalid"),
+        };
+        let qty = num_param(params, "qty")?.unwrap_or(0.0);
+        if qty <= 0.0 {
+            return reject(10001, "params error: qty invalid");
+        }
+        let price = num_param(params, "price")?.unwrap_or(0.0);
+        if !market && price <= 0.0 {
+            return reject(10001, "params error: price invalid");
+        }
+        let time_in_force = match param(params, "timeInForce") {
+            _ if market => "IOC".to_string(),
+            Some(tif) if matches!(tif.as_str(), "GTC" | "IOC" | "FOK" | "PostOnly") => tif,
+            Some(_) => return reject(10001, "params error: timeInForce invalid"),
+            None => "GTC".to_string(),
+        };
+        let link_id = param(params, "orderLinkId").unwrap_or_default();
+        if !link_id.is_empty() && self.orders.iter().any(|o| o.link_id == link_id) {
+            return reject(110072, "OrderLinkedID is duplicate");
+        }
+        let reduce_only = params
+            .get("reduceOnly")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if reduce_only {
+            let size = self.positions.get(&symbol).map(|p| p.size).unwrap_or(0.0);
+            let reduces = match side {
+                Side::Buy => size < 0.0,
+                Side::Sell => size > 0.0,
+            };
+            if category != Category::Linear || !reduces || qty > size.abs() + EPS {
+                return reject(110017, "Reduce-only rule not satisfied");
+            }
+        }
+
+        let now = millis();
+        let mut order = Order {
+            id: uuid::Uuid::new_v4().to_string(),
+            link_id,
+            category,
+            symbol: symbol.clone(),
+            side,
+            market,
+            time_in_force,
+            price: if market { 0.0 } else { price },
+            qty,
+            filled: 0.0,
+            value: 0.0,
+            fee: 0.0,
+            status: "Created",
+            reject_reason: "EC_NoError",
+            reduce_only,
+            created: now,
+            updated: now,
+        };
+        if category == Category::Spot {
+            self.check_spot_funds(&order)?;
+        }
+
+        let fills = self.preview_fills(&order);
+        let liquidity: f64 = fills.iter().map(|f| f.1).sum();
+        if order.time_in_force == "PostOnly" && !fills.is_empty() {
+            order.status = "Cancelled";
+            order.reject_reason = "EC_PostOnlyWillTakeLiquidity";
+        } else if order.time_in_force == "FOK" && liquidity < qty - EPS {
+            order.status = "Cancelled";
+            order.reject_reason = "EC_CancelForNoFullFill";
+        } else {
+            order.status = "New";
+            let fills = self.take_liquidity(&mut order);
+            for &(price, fill) in &fills {
+                self.execute(&mut order, price, fill, false, events);
+                self.public_trade(category, &symbol, side, price, fill, events);
+            }
+            if order.is_open()
+                && (market || !matches!(order.time_in_force.as_str(), "GTC" | "PostOnly"))
+            {
+                order.cancel_rest();
+            }
+            self.lock_resting(&order);
+            if !fills.is_empty() {
+                events.push(Event::Book {
+                    category,
+                    symbol: symbol.clone(),
+                });
+                events.push(Event::Ticker {
+                    category,
+                    symbol: symbol.clone(),
+                });
+            }
+        }
+
+        let result = json!({"orderId": order.id, "orderLinkId": order.link_id});
+        self.orders.push(order);
+        self.finish_last(events);
+        Ok(result)
+    }
+
+    /// [`finish`](Self::finish) for the order just pushed.
+    fn finish_last(&mut self, events: &mut Vec<Event>) {
+        let orders = std::mem::take(&mut self.orders);
+        self.finish(&orders, &[orders.len() - 1], events);
+        self.orders = orders;
+    }
+
+    fn check_spot_funds(&self, order: &Order) -> Result<(), Reject> {
+        let (base, quote) = split_symbol(&order.symbol);
+        let (coin, needed) = match order.side {
+            Side::Buy => {
+                let cost = if order.market {
+                    self.preview_fills(order).iter().map(|(p, q)| p * q).sum()
+                } else {
+                    order.price * order.qty
+                };
+                (quote, cost)
+            }
+            Side::Sell => (base, order.qty),
+        };
+        if self.available(&coin) + EPS < needed {
+            return reject(170131, "Insufficient balance.");
+        }
+        Ok(())
+    }
+
+    /// Book levels an order would take, without consuming them.
+    fn preview_fills(&self, order: &Order) -> Vec<(f64, f64)> {
+        let Some(book) = self.books.get(&(order.category, order.symbol.clone())) else {
+            return Vec::new();
+        };
+        let levels = match order.side {
+            Side::Buy => &book.asks,
+            Side::Sell => &book.bids,
+        };
+        let mut left = order.leaves();
+        let mut fills = Vec::new();
+        for &(price, size) in levels {
+            if left <= EPS || !order.crosses(price) {
+                break;
+            }
+            let fill = size.min(left);
+            left -= fill;
+            fills.push((price, fill));
+        }
+        fills
+    }
+
+    /// Consume the book levels an order crosses.
+    fn take_liquidity(&mut self, order: &mut Order) -> Vec<(f64, f64)> {
+        let fills = self.preview_fills(order);
+        if let Some(book) = self.books.get_mut(&(order.category, order.symbol.clone())) {
+            let levels = match order.side {
+                Side::Buy => &mut book.asks,
+                Side::Sell => &mut book.bids,
+            };
+            for (_, fill) in &fills {
+                levels[0].1 -= fill;
+                if levels[0].1 <= EPS {
+                    levels.remove(0);
+                }
+            }
+            if !fills.is_empty() {
+                book.update_id += 1;
+            }
+        }
+        fills
+    }
+
+    /// Apply one fill to an order, balances and position.
+    fn execute(
+        &mut self,
+        order: &mut Order,
+        price: f64,
+        qty: f64,
+        maker: bool,
+        events: &mut Vec<Event>,
+    ) {
+        let (maker_fee, taker_fee) = self
+            .symbol_fees
+            .get(&(order.category, order.symbol.clone()))
+            .copied()
+            .unwrap_or((self.maker_fee, self.taker_fee));
+        let fee_rate = if maker { maker_fee } else { taker_fee };
+        let value = price * qty;
+        let (base, quote) = split_symbol(&order.symbol);
+        // Makers are always resting orders, whose funds are locked
+        let (fee, fee_coin) = match order.category {
+            Category::Spot => {
+                let fee = match order.side {
+                    Side::Buy => qty * fee_rate,
+                    Side::Sell => value * fee_rate,
+                };
+                match order.side {
+                    Side::Buy => {
+                        if maker {
+                            self.lock(&quote, -order.price * qty);
+                        }
+                        self.deposit(&quote, -value);
+                        self.deposit(&base, qty - fee);
+                        (fee, base.clone())
+                    }
+                    Side::Sell => {
+                        if maker {
+                            self.lock(&base, -qty);
+                        }
+                        self.deposit(&base, -qty);
+                        self.deposit(&quote, value - fee);
+                        (fee, quote.clone())
+                    }
+                }
+            }
+            _ => {
+                let fee = value * fee_rate;
+                self.deposit(&quote, -fee);
+                self.update_position(&order.symbol, order.side, price, qty, fee);
+                (fee, quote.clone())
+            }
+        };
+
+        let now = millis();
+        order.filled += qty;
+        order.value += value;
+        order.fee += fee;
+        order.updated = now;
+        order.status = if order.filled >= order.qty - EPS {
+            "Filled"
+        } else {
+            "PartiallyFilled"
+        };
+
+        let seq = self.next_seq();
+        let mark = match self.last_price(order.category, &order.symbol) {
+            last if last > 0.0 => last,
+            _ => price,
+        };
+        let execution = json!({
+            "category": order.category,
+            "symbol": order.symbol,
+            "orderId": order.id,
+            "orderLinkId": order.link_id,
+            "side": order.side,
+            "orderPrice": fmt(order.price),
+            "orderQty": fmt(order.qty),
+            "leavesQty": fmt(order.leaves()),
+            "orderType": if order.market { "Market" } else { "Limit" },
+            "stopOrderType": "",
+            "execFee": fmt(fee),
+            "feeCurrency": fee_coin,
+            "execId": uuid::Uuid::new_v4().to_string(),
+            "execPrice": fmt(price),
+            "execQty": fmt(qty),
+            "execType": "Trade",
+            "execValue": fmt(value),
+            "execTime": now.to_string(),
+            "isMaker": maker,
+            "isLeverage": "",
+            "feeRate": fmt(fee_rate),
+            "tradeIv": "",
+            "markIv": "",
+            "markPrice": fmt(mark),
+            "indexPrice": "",
+            "underlyingPrice": "",
+            "blockTradeId": "",
+            "closedSize": "",
+            "seq": seq,
+        });
+        events.push(Event::Execution(execution.clone()));
+        self.executions.push(execution);
+    }
+
+    fn update_position(&mut self, symbol: &str, side: Side, price: f64, qty: f64, fee: f64) {
+        let now = millis();
+        let position = self
+            .positions
+            .entry(symbol.to_string())
+            .or_insert_with(|| Position {
+                created: now,
+                ..Default::default()
+            });
+        let signed = match side {
+            Side::Buy => qty,
+            Side::Sell => -qty,
+        };
+        let mut pnl = 0.0;
+        if position.size.abs() <= EPS || position.size.signum() == signed.signum() {
+            let size = position.size.abs();
+            position.entry = (position.entry * size + price * qty) / (size + qty);
+            position.size += signed;
+        } else {
+            let closing = position.size.abs().min(qty);
+            pnl += closing * (price - position.entry) * position.size.signum();
+            let before = position.size;
+            position.size += signed;
+            if position.size.abs() <= EPS {
+                position.size = 0.0;
+                position.entry = 0.0;
+            } else if position.size.signum() != before.signum() {
+                position.entry = price;
+            }
+        }
+        position.realised += pnl - fee;
+        position.updated = now;
+        let (_, quote) = split_symbol(symbol);
+        self.deposit(&quote, pnl);
+    }
+
+    /// Emit order events for the touched orders, then position and wallet
+    /// events.
+    fn finish(&mut self, orders: &[Order], touched: &[usize], events: &mut Vec<Event>) {
+        let mut positions = Vec::new();
+        for &n in touched {
+            let order = &orders[n];
+            events.push(Event::Order(order.json()));
+            if order.category == Category::Linear && !positions.contains(&order.symbol) {
+                positions.push(order.symbol.clone());
+            }
+        }
+        for symbol in positions {
+            if let Some(position) = self.position_json(&symbol) {
+                events.push(Event::Position(position));
+            }
+        }
+        if !touched.is_empty() {
+            events.push(Event::Wallet(self.wallet_json(None)));
+        }
+    }
+
+    /// Lock spot funds for the resting part of a newly accepted order.
+    fn lock_resting(&mut self, order: &Order) {
+        if order.category != Category::Spot || !order.is_open() {
+            return;
+        }
+        let (base, quote) = split_symbol(&order.symbol);
+        match order.side {
+            Side::Buy => self.lock(&quote, order.price * order.leaves()),
+            Side::Sell => self.lock(&base, order.leaves()),
+        }
+    }
+
+    fn unlock_resting(&mut self, order: &Order) {
+        if order.category != Category::Spot || !order.is_open() {
+            return;
+        }
+        let (base, quote) = split_symbol(&order.symbol);
+        match order.side {
+            Side::Buy => self.lock(&quote, -order.price * order.leaves()),
+            Side::Sell => self.lock(&base, -order.leaves()),
+        }
+    }
+
+    fn find_order(&self, params: &Value) -> Result<usize, Reject> {
+        let order_id = param(params, "orderId");
+        let link_id = param(params, "orderLinkId");
+        if order_id.is_none() && link_id.is_none() {
+            return reject(10001, "params error: orderId or orderLinkId is required");
+        }
+        self.orders
+            .iter()
+            .position(|o| {
+                o.is_open()
+                    && order_id.as_ref().is_none_or(|id| *id == o.id)
+                    && link_id.as_ref().is_none_or(|id| *id == o.link_id)
+            })
+            .ok_or((110001, "Order does not exist".to_string()))
+    }
+
+    /// `order.amend`: change price and/or qty of an open limit order.
+    pub(crate) fn amend_order(
+        &mut self,
+        params: &Value,
+        events: &mut Vec<Event>,
+    ) -> Result<Value, Reject> {
+        let n = self.find_order(params)?;
+        let qty = num_param(params, "qty")?;
+        let price = num_param(params, "price")?;
+        let mut order = self.orders.remove(n);
+        if qty.is_some_and(|q| q <= order.filled + EPS) || price.is_some_and(|p| p <= 0.0) {
+            self.orders.insert(n, order);
+            return reject(10001, "params error: qty or price invalid");
+        }
+        self.unlock_resting(&order);
+        if let Some(qty) = qty {
+            order.qty = qty;
+        }
+        if let Some(price) = price {
+            order.price = price;
+        }
+        order.updated = millis();
+        let (category, symbol) = (order.category, order.symbol.clone());
+        let fills = self.take_liquidity(&mut order);
+        for &(price, fill) in &fills {
+            self.execute(&mut order, price, fill, false, events);
+            self.public_trade(category, &symbol, order.side, price, fill, events);
+        }
+        if !fills.is_empty() {
+            events.push(Event::Book {
+                category,
+                symbol: symbol.clone(),
+            });
+            events.push(Event::Ticker { category, symbol });
+        }
+        self.lock_resting(&order);
+        let result = json!({"orderId": order.id, "orderLinkId": order.link_id});
+        self.orders.insert(n, order);
+        let orders = std::mem::take(&mut self.orders);
+        self.finish(&orders, &[n], events);
+        self.orders = orders;
+        Ok(result)
+    }
+
+    /// `order.cancel`
+    pub(crate) fn cancel_order(
+        &mut self,
+        params: &Value,
+        events: &mut Vec<Event>,
+    ) -> Result<Value, Reject> {
+        let n = self.find_order(params)?;
+        let mut orders = std::mem::take(&mut self.orders);
+        self.unlock_resting(&orders[n]);
+        orders[n].cancel_rest();
+        orders[n].updated = millis();
+        self.finish(&orders, &[n], events);
+        let result = json!({"orderId": orders[n].id, "orderLinkId": orders[n].link_id});
+        self.orders = orders;
+        Ok(result)
+    }
+
+    /// `POST /v5/order/cancel-all`
+    pub(crate) fn cancel_all(
+        &mut self,
+        params: &Value,
+        events: &mut Vec<Event>,
+    ) -> Result<Value, Reject> {
+        let category = category_param(params)?;
+        let symbol = param(params, "symbol");
+        let mut orders = std::mem::take(&mut self.orders);
+        let mut touched = Vec::new();
+        for (n, order) in orders.iter_mut().enumerate() {
+            if order.category == category
+                && order.is_open()
+                && symbol.as_ref().is_none_or(|s| *s == order.symbol)
+            {
+                self.unlock_resting(order);
+                order.cancel_rest();
+                order.updated = millis();
+                touched.push(n);
+            }
+        }
+        self.finish(&orders, &touched, events);
+        let list: Vec<Value> = touched
+            .iter()
+            .map(|&n| json!({"orderId": orders[n].id, "orderLinkId": orders[n].link_id}))
+            .collect();
+        self.orders = orders;
+        Ok(json!({"list": list, "success": "1"}))
+    }
+
+    /// Orders of a category, newest first. `open_only` keeps New/PartiallyFilled.
+    pub(crate) fn orders_json(&self, params: &Value, open_only: bool) -> Result<Value, Reject> {
+        let category = category_param(params)?;
+        let symbol = param(params, "symbol");
+        let order_id = param(params, "orderId");
+        let link_id = param(params, "orderLinkId");
+        let limit = param(params, "limit")
+            .and_then(|l| l.parse().ok())
+            .unwrap_or(20);
+        let list: Vec<Value> = self
+            .orders
+            .iter()
+            .rev()
+            .filter(|o| {
+                o.category == category
+                    && symbol.as_ref().is_none_or(|s| *s == o.symbol)
+                    && order_id.as_ref().is_none_or(|id| *id == o.id)
+                    && link_id.as_ref().is_none_or(|id| *id == o.link_id)
+                    && (!open_only || o.is_open() || order_id.is_some() || link_id.is_some())
+            })
+            .take(limit)
+            .map(Order::json)
+            .collect();
+        Ok(json!({"category": category, "list": list, "nextPageCursor": ""}))
+    }
+
+    /// Every open order of a category, oldest first.
+    pub(crate) fn open_orders(&self, category: Category, symbol: Option<&str>) -> Vec<Value> {
+        self.orders
+            .iter()
+            .filter(|o| {
+                o.category == category && o.is_open() && symbol.is_none_or(|s| s == o.symbol)
+            })
+            .map(Order::json)
+            .collect()
+    }
+
+    pub(crate) fn all_orders(&self) -> Vec<Value> {
+        self.orders.iter().map(Order::json).collect()
+    }
+
+    pub(crate) fn all_executions(&self) -> Vec<Value> {
+        self.executions.clone()
+    }
+
+    /// `GET /v5/execution/list`, newest first.
+    pub(crate) fn executions_json(&self, params: &Value) -> Result<Value, Reject> {
+        let category = category_param(params)?;
+        let category_str = category.to_string();
+        let symbol = param(params, "symbol");
+        let order_id = param(params, "orderId");
+        let limit = param(params, "limit")
+            .and_then(|l| l.parse().ok())
+            .unwrap_or(50);
+        let list: Vec<Value> = self
+            .executions
+            .iter()
+            .rev()
+            .filter(|e| {
+                e["category"] == category_str.as_str()
+                    && symbol.as_ref().is_none_or(|s| e["symbol"] == s.as_str())
+                    && order_id
+                        .as_ref()
+                        .is_none_or(|id| e["orderId"] == id.as_str())
+            })
+            .take(limit)
+            .cloned()
+            .collect();
+        Ok(json!({"category": category, "list": list, "nextPageCursor": ""}))
+    }
+
+    // ── Positions and balances ─────────────────────────────────
+
+    fn position_json(&self, symbol: &str) -> Option<Value> {
+        let position = self.positions.get(symbol)?;
+        let mark = self.last_price(Category::Linear, symbol);
+        let size = position.size.abs();
+        let side = if position.size > EPS {
+            "Buy"
+        } else if position.size < -EPS {
+            "Sell"
+        } else {
+            ""
+        };
+        let entry = fmt(position.entry);
+        Some(json!({
+            "category": "linear",
+            "symbol": symbol,
+            "side": side,
+            "size": fmt(size),
+            "avgPrice": entry,
+            "entryPrice": entry,
+            "positionValue": fmt(size * position.entry),
+            "leverage": LEVERAGE,
+            "markPrice": fmt(mark),
+            "unrealisedPnl": fmt(position.size * (mark - position.entry)),
+            "curRealisedPnl": fmt(position.realised),
+            "cumRealisedPnl": fmt(position.realised),
+            "positionIdx": 0,
+            "tradeMode": 0,
+            "riskId": 1,
+            "autoAddMargin": 0,
+            "positionStatus": "Normal",
+            "liqPrice": "",
+            "bustPrice": "",
+            "takeProfit": "",
+            "stopLoss": "",
+            "trailingStop": "0",
+            "positionIM": fmt(size * position.entry / 10.0),
+            "positionMM": "0",
+            "createdTime": position.created.to_string(),
+            "updatedTime": position.updated.to_string(),
+            "seq": self.seq,
+        }))
+    }
+
+    pub(crate) fn all_positions(&self) -> Vec<Value> {
+        self.positions
+            .keys()
+            .filter_map(|symbol| self.position_json(symbol))
+            .collect()
+    }
+
+    /// `GET /v5/position/list`
+    pub(crate) fn positions_json(&self, params: &Value) -> Result<Value, Reject> {
+        let category = category_param(params)?;
+        let symbol = param(params, "symbol");
+        let settle_coin = param(params, "settleCoin");
+        if symbol.is_none() && settle_coin.is_none() {
+            return reject(10001, "params error: symbol or settleCoin is required");
+        }
+        let list: Vec<Value> = if category == Category::Linear {
+            self.all_positions()
+                .into_iter()
+                .filter(|p| symbol.as_ref().is_none_or(|s| p["symbol"] == s.as_str()))
+                .filter(|p| {
+                    settle_coin.as_ref().is_none_or(|c| {
+                        split_symbol(p["symbol"].as_str().unwrap_or_default()).1 == *c
+                    })
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Ok(json!({"category": category, "list": list, "nextPageCursor": ""}))
+    }
+
+    fn usd_price(&self, coin: &str) -> f64 {
+        if matches!(coin, "USDT" | "USDC" | "DAI") {
+            return 1.0;
+        }
+        let symbol = format!("{}USDT", coin);
+        [Category::Spot, Category::Linear]
+            .iter()
+            .map(|c| self.last_price(*c, &symbol))
+            .find(|p| *p > 0.0)
+            .unwrap_or(0.0)
+    }
+
+    /// Unified account wallet, optionally limited to some coins.
+    pub(crate) fn wallet_json(&self, coins: Option<&[String]>) -> Value {
+        let mut list = Vec::new();
+        let mut total_equity = 0.0;
+        let mut total_upl = 0.0;
+        let mut total_wallet = 0.0;
+        for (coin, balance) in &self.balances {
+            if coins.is_some_and(|c| !c.contains(coin)) {
+                continue;
+            }
+            let (upl, realised) = self
+                .positions
+                .iter()
+                .filter(|(symbol, _)| split_symbol(symbol).1 == *coin)
+                .fold((0.0, 0.0), |(upl, realised), (symbol, p)| {
+                    let mark = self.last_price(Category::Linear, symbol);
+                    (upl + p.size * (mark - p.entry), realised + p.realised)
+                });
+            let equity = balance.wallet + upl;
+            let usd = self.usd_price(coin);
+            total_equity += equity * usd;
+            total_wallet += balance.wallet * usd;
+            total_upl += upl * usd;
+            list.push(json!({
+                "coin": coin,
+                "equity": fmt(equity),
+                "usdValue": fmt(equity * usd),
+                "walletBalance": fmt(balance.wallet),
+                "locked": fmt(balance.locked),
+                "availableToWithdraw": fmt(balance.wallet - balance.locked),
+                "availableToBorrow": "",
+                "borrowAmount": "0",
+                "accruedInterest": "0",
+                "bonus": "0",
+                "totalOrderIM": "0",
+                "totalPositionIM": "0",
+                "totalPositionMM": "0",
+                "unrealisedPnl": fmt(upl),
+                "cumRealisedPnl": fmt(realised),
+                "spotHedgingQty": "0",
+                "collateralSwitch": true,
+                "marginCollateral": true,
+            }));
+        }
+        json!({
+            "accountType": "UNIFIED",
+            "accountIMRate": "0",
+            "accountMMRate": "0",
+            "accountLTV": "0",
+            "totalEquity": fmt(total_equity),
+            "totalWalletBalance": fmt(total_wallet),
+            "totalMarginBalance": fmt(total_equity),
+            "totalAvailableBalance": fmt(total_equity),
+            "totalPerpUPL": fmt(total_upl),
+            "totalInitialMargin": "0",
+            "totalMaintenanceMargin": "0",
+            "coin": list,
+        })
+    }
+}
+
+fn opposite(side: Side) -> Side {
+    match side {
+        Side::Buy => Side::Sell,
+        Side::Sell => Side::Buy,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine() -> Engine {
+        let mut engine = Engine::new(0.0, 0.001);
+        engine.deposit("USDT", 100_000.0);
+        let mut events = Vec::new();
+        engine.set_book(
+            Category::Spot,
+            "BTCUSDT",
+            &[(29_990.0, 1.0), (29_980.0, 2.0)],
+            &[(30_010.0, 0.5), (30_020.0, 1.0)],
+            &mut events,
+        );
+        engine.set_book(
+            Category::Linear,
+            "BTCUSDT",
+            &[(29_990.0, 5.0)],
+            &[(30_010.0, 5.0)],
+            &mut events,
+        );
+        engine
+    }
+
+    fn order(
+        category: &str,
+        side: &str,
+        order_type: &str,
+        qty: &str,
+        price: Option<&str>,
+    ) -> Value {
+        let mut params = json!({
+            "category": category,
+            "symbol": "BTCUSDT",
+            "side": side,
+            "orderType": order_type,
+            "qty": qty,
+        });
+        if let Some(price) = price {
+            params["price"] = json!(price);
+        }
+        params
+    }
+
+    fn status(engine: &Engine, n: usize) -> String {
+        engine.all_orders()[n]["orderStatus"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_limit_order_walks_book_and_rests() {
+        let mut engine = engine();
+        let mut events = Vec::new();
+        engine
+            .place_order(
+                &order("spot", "Buy", "Limit", "1", Some("30015")),
+                &mut events,
+            )
+            .unwrap();
+        let o = &engine.all_orders()[0];
+        assert_eq!(o["orderStatus"], "PartiallyFilled");
+        assert_eq!(o["cumExecQty"], "0.5");
+        assert_eq!(o["leavesQty"], "0.5");
+        // 0.5 BTC bought at 30010, 0.1% taker fee taken in BTC
+        assert_eq!(engine.wallet_balance("BTC"), 0.4995);
+        assert_eq!(engine.wallet_balance("USDT"), 100_000.0 - 15_005.0);
+        assert_eq!(engine.available("USDT"), 100_000.0 - 15_005.0 - 15_007.5);
+        assert!(events.iter().any(|e| matches!(e, Event::Execution(_))));
+        assert!(events.iter().any(|e| matches!(e, Event::Trade { .. })));
+
+        // A sell print through the resting price fills the rest as maker
+        let mut events = Vec::new();
+        engine.trade(
+            Category::Spot,
+            "BTCUSDT",
+            Side::Sell,
+            30_000.0,
+            2.0,
+            &mut events,
+        );
+        assert_eq!(status(&engine, 0), "Filled");
+        assert_eq!(engine.available("USDT"), engine.wallet_balance("USDT"));
+        let execs = engine.all_executions();
+        assert_eq!(execs.len(), 2);
+        assert_eq!(execs[1]["isMaker"], true);
+        assert_eq!(execs[1]["execPrice"], "30015");
+    }
+
+    #[test]
+    fn test_time_in_force_rules() {
+        let mut engine = engine();
+        let mut events = Vec::new();
+        engine
+            .place_order(
+                &json!({"category": "spot", "symbol": "BTCUSDT", "side": "Buy", "orderType": "Limit",
+                        "qty": "0.1", "price": "30010", "timeInForce": "PostOnly"}),
+                &mut events,
+            )
+            .unwrap();
+        assert_eq!(status(&engine, 0), "Cancelled");
+
+        engine
+            .place_order(
+                &json!({"category": "spot", "symbol": "BTCUSDT", "side": "Buy", "orderType": "Limit",
+                        "qty": "2", "price": "30020", "timeInForce": "FOK"}),
+                &mut events,
+            )
+            .unwrap();
+        assert_eq!(status(&engine, 1), "Cancelled");
+        assert_eq!(engine.wallet_balance("BTC"), 0.0);
+
+        engine
+            .place_order(
+                &json!({"category": "spot", "symbol": "BTCUSDT", "side": "Buy", "orderType": "Limit",
+                        "qty": "1", "price": "30010", "timeInForce": "IOC"}),
+                &mut events,
+            )
+            .unwrap();
+        assert_eq!(status(&engine, 2), "PartiallyFilledCanceled");
+        assert_eq!(engine.available("USDT"), engine.wallet_balance("USDT"));
+    }
+
+    #[test]
+    fn test_spot_rejects_insufficient_balance() {
+        let mut engine = engine();
+        let err = engine
+            .place_order(&order("spot", "Sell", "Market", "1", None), &mut Vec::new())
+            .unwrap_err();
+        assert_eq!(err.0, 170131);
+        let err = engine
+            .place_order(
+                &order("spot", "Buy", "Limit", "10", Some("30000")),
+                &mut Vec::new(),
+            )
+            .unwrap_err();
+        assert_eq!(err.0, 170131);
+    }
+
+    #[test]
+    fn test_linear_position_and_realised_pnl() {
+        let mut engine = Engine::new(0.0, 0.0);
+        engine.deposit("USDT", 10_000.0);
+        let mut events = Vec::new();
+        engine.set_book(
+            Category::Linear,
+            "BTCUSDT",
+            &[(29_990.0, 5.0)],
+            &[(30_000.0, 5.0)],
+            &mut events,
+        );
+        engine
+            .place_order(&order("linear", "Buy", "Market", "1", None), &mut events)
+            .unwrap();
+        let position = &engine.all_positions()[0];
+        assert_eq!(position["side"], "Buy");
+        assert_eq!(position["avgPrice"], "30000");
+        assert!(events.iter().any(|e| matches!(e, Event::Position(_))));
+
+        engine.set_book(
+            Category::Linear,
+            "BTCUSDT",
+            &[(31_000.0, 5.0)],
+            &[(31_010.0, 5.0)],
+            &mut events,
+        );
+        let err = engine
+            .place_order(
+                &json!({"category": "linear", "symbol": "BTCUSDT", "side": "Sell", "orderType": "Market",
+                        "qty": "2", "reduceOnly": true}),
+                &mut events,
+            )
+            .unwrap_err();
+        assert_eq!(err.0, 110017);
+        engine
+            .place_order(&order("linear", "Sell", "Market", "1", None), &mut events)
+            .unwrap();
+        let position = &engine.all_positions()[0];
+        assert_eq!(position["size"], "0");
+        assert_eq!(position["cumRealisedPnl"], "1000");
+        assert_eq!(engine.wallet_balance("USDT"), 11_000.0);
+    }
+
+    #[test]
+    fn test_amend_and_cancel() {
+        let mut engine = engine();
+        let mut events = Vec::new();
+        let placed = engine
+            .place_order(
+                &order("spot", "Buy", "Limit", "1", Some("29000")),
+                &mut events,
+            )
+            .unwrap();
+        assert_eq!(engine.available("USDT"), 71_000.0);
+        engine
+            .amend_order(
+                &json!({"category": "spot", "symbol": "BTCUSDT", "orderId": placed["orderId"], "price": "28000"}),
+                &mut events,
+            )
+            .unwrap();
+        assert_eq!(engine.available("USDT"), 72_000.0);
+        engine
+            .cancel_order(
+                &json!({"category": "spot", "orderId": placed["orderId"]}),
+                &mut events,
+            )
+            .unwrap();
+        assert_eq!(status(&engine, 0), "Cancelled");
+        assert_eq!(engine.available("USDT"), 100_000.0);
+        let err = engine
+            .cancel_order(
+                &json!({"category": "spot", "orderId": placed["orderId"]}),
+                &mut events,
+            )
+            .unwrap_err();
+        assert_eq!(err.0, 110001);
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(fmt(30_000.0), "30000");
+        assert_eq!(fmt(0.1 + 0.2), "0.3");
+        assert_eq!(fmt(-0.0), "0");
+        assert_eq!(
+            split_symbol("ETHBTC"),
+            ("ETH".to_string(), "BTC".to_string())
+        );
+    }
+}
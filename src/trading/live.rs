@@ -0,0 +1,130 @@
+//! [`LiveTrader`]: [`TradingApi`] over the REST clients.
+
+use super::TradingApi;
+use crate::rest::account::dto::{GetWalletBalanceParams, WalletBalanceDetails};
+use crate::rest::enums::{AccountType, Category};
+use crate::rest::errors::{BybitError, BybitResult};
+use crate::rest::order::dto::{
+    AmendOrderRequest, AmendOrderResponse, CancelOrderRequest, CancelOrderResponse, OrderInfo,
+    PlaceOrderRequest, PlaceOrderResponse,
+};
+use crate::rest::position::dto::{GetPositionInfoParams, PositionInfo};
+use crate::rest::{AccountClient, OrderClient, PositionClient, RestClient};
+
+/// Settle coin queried when a linear request has no symbol.
+const DEFAULT_SETTLE_COIN: &str = "USDT";
+
+/// Page size of open order and position queries.
+const PAGE_LIMIT: i32 = 50;
+
+/// Trades on the exchange through [`OrderClient`], [`PositionClient`] and
+/// [`AccountClient`].
+#[derive(Clone)]
+pub struct LiveTrader {
+    orders: OrderClient,
+    positions: PositionClient,
+    account: AccountClient,
+}
+
+impl LiveTrader {
+    pub fn new(client: RestClient) -> Self {
+        LiveTrader {
+            orders: OrderClient::new(client.clone()),
+            positions: PositionClient::new(client.clone()),
+            account: AccountClient::new(client),
+        }
+    }
+}
+
+/// Linear queries need a symbol or settle coin.
+fn settle_coin(category: Category, symbol: Option<&str>) -> Option<&'static str> {
+    (symbol.is_none() && category == Category::Linear).then_some(DEFAULT_SETTLE_COIN)
+}
+
+impl TradingApi for LiveTrader {
+    async fn place_order(&self, request: PlaceOrderRequest) -> BybitResult<PlaceOrderResponse> {
+        Ok(self.orders.place_order(request).await?.result)
+    }
+
+    async fn amend_order(&self, request: AmendOrderRequest) -> BybitResult<AmendOrderResponse> {
+        Ok(self.orders.amend_order(request).await?.result)
+    }
+
+    async fn cancel_order(&self, request: CancelOrderRequest) -> BybitResult<CancelOrderResponse> {
+        Ok(self.orders.cancel_order(request).await?.result)
+    }
+
+    async fn open_orders(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> BybitResult<Vec<OrderInfo>> {
+        let mut orders = Vec::new();
+        let mut cursor = String::new();
+        loop {
+            let page = self
+                .orders
+                .get_open_orders(
+                    category,
+                    symbol,
+                    None,
+                    settle_coin(category, symbol),
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(PAGE_LIMIT),
+                    (!cursor.is_empty()).then_some(cursor.as_str()),
+                )
+                .await?
+                .result;
+            orders.extend(page.list);
+            if page.next_page_cursor.is_empty() || page.next_page_cursor == cursor {
+                return Ok(orders);
+            }
+            cursor = page.next_page_cursor;
+        }
+    }
+
+    async fn positions(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> BybitResult<Vec<PositionInfo>> {
+        let mut positions = Vec::new();
+        let mut cursor = String::new();
+        loop {
+            let page = self
+                .positions
+                .get_position_info(GetPositionInfoParams {
+                    category,
+                    symbol: symbol.map(str::to_string),
+                    settle_coin: settle_coin(category, symbol).map(str::to_string),
+                    limit: Some(PAGE_LIMIT),
+                    cursor: (!cursor.is_empty()).then(|| cursor.clone()),
+                    ..Default::default()
+                })
+                .await?
+                .result;
+            positions.extend(page.list);
+            if page.next_page_cursor.is_empty() || page.next_page_cursor == cursor {
+                return Ok(positions);
+            }
+            cursor = page.next_page_cursor;
+        }
+    }
+
+    async fn wallet(&self) -> BybitResult<WalletBalanceDetails> {
+        self.account
+            .get_wallet_balance(GetWalletBalanceParams {
+                account_type: AccountType::UNIFIED,
+                coin: None,
+            })
+            .await?
+            .result
+            .list
+            .into_iter()
+            .next()
+            .ok_or_else(|| BybitError::Internal("No unified account wallet".to_string()))
+    }
+}
@@ -0,0 +1,162 @@
+//! Execution backends behind one trading interface.
+//!
+//! [`TradingApi`] covers what a strategy needs to trade: place, amend and
+//! cancel orders, and read open orders, positions and the wallet. It is
+//! implemented by
+//!
+//! - [`LiveTrader`]: the real `OrderClient` / `PositionClient` / `AccountClient`
+//! - [`PaperTrader`]: simulated fills against live public `orderbook` and
+//!   `publicTrade` streams, charging the account's fee rates
+//! - [`Trader`]: either of the two, chosen at runtime
+//!
+//! Strategy code written against `TradingApi` switches between paper and
+//! live trading by configuration only.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bybit_rust_api::trading::{LiveTrader, PaperTrader, Trader, TradingApi};
+//! use bybit_rust_api::{AccountClient, ApiKeyPair, BybitEnv, Category, RestClient};
+//!
+//! # async fn run(paper: bool) -> anyhow::Result<()> {
+//! let rest = RestClient::for_env(ApiKeyPair::from_env()?, BybitEnv::Mainnet);
+//! let trader = if paper {
+//!     let paper = PaperTrader::new().with_balance("USDT", 10_000.0);
+//!     paper
+//!         .fetch_fee_rates(&AccountClient::new(rest), Category::Linear, "BTCUSDT")
+//!         .await?;
+//!     paper.track(BybitEnv::Mainnet, Category::Linear, "BTCUSDT").await?;
+//!     Trader::Paper(paper)
+//! } else {
+//!     Trader::Live(LiveTrader::new(rest))
+//! };
+//! let orders = trader.open_orders(Category::Linear, Some("BTCUSDT")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// Rendering and event payloads are only read by `testing::MockExchange`.
+#[cfg_attr(not(feature = "testing"), allow(dead_code))]
+pub(crate) mod engine;
+mod live;
+mod paper;
+
+pub use live::LiveTrader;
+pub use paper::PaperTrader;
+
+use crate::rest::account::dto::WalletBalanceDetails;
+use crate::rest::enums::Category;
+use crate::rest::errors::BybitResult;
+use crate::rest::order::dto::{
+    AmendOrderRequest, AmendOrderResponse, CancelOrderRequest, CancelOrderResponse, OrderInfo,
+    PlaceOrderRequest, PlaceOrderResponse,
+};
+use crate::rest::position::dto::PositionInfo;
+use std::future::Future;
+
+/// Order entry and account state, independent of the execution backend.
+///
+/// Without a symbol, linear queries cover USDT-settled contracts.
+pub trait TradingApi: Send + Sync {
+    /// Place an order.
+    fn place_order(
+        &self,
+        request: PlaceOrderRequest,
+    ) -> impl Future<Output = BybitResult<PlaceOrderResponse>> + Send;
+
+    /// Amend an open order.
+    fn amend_order(
+        &self,
+        request: AmendOrderRequest,
+    ) -> impl Future<Output = BybitResult<AmendOrderResponse>> + Send;
+
+    /// Cancel an open order.
+    fn cancel_order(
+        &self,
+        request: CancelOrderRequest,
+    ) -> impl Future<Output = BybitResult<CancelOrderResponse>> + Send;
+
+    /// All open orders of a category, optionally for one symbol.
+    fn open_orders(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> impl Future<Output = BybitResult<Vec<OrderInfo>>> + Send;
+
+    /// Open positions of a category, optionally for one symbol.
+    fn positions(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> impl Future<Output = BybitResult<Vec<PositionInfo>>> + Send;
+
+    /// The unified trading account wallet.
+    fn wallet(&self) -> impl Future<Output = BybitResult<WalletBalanceDetails>> + Send;
+}
+
+/// A live or paper backend, selected at runtime.
+// One per strategy; boxing the live clients would buy nothing
+#[allow(clippy::large_enum_variant)]
+pub enum Trader {
+    Live(LiveTrader),
+    Paper(PaperTrader),
+}
+
+impl Trader {
+    /// True for [`Trader::Paper`].
+    pub fn is_paper(&self) -> bool {
+        matches!(self, Trader::Paper(_))
+    }
+}
+
+impl TradingApi for Trader {
+    async fn place_order(&self, request: PlaceOrderRequest) -> BybitResult<PlaceOrderResponse> {
+        match self {
+            Trader::Live(t) => t.place_order(request).await,
+            Trader::Paper(t) => t.place_order(request).await,
+        }
+    }
+
+    async fn amend_order(&self, request: AmendOrderRequest) -> BybitResult<AmendOrderResponse> {
+        match self {
+            Trader::Live(t) => t.amend_order(request).await,
+            Trader::Paper(t) => t.amend_order(request).await,
+        }
+    }
+
+    async fn cancel_order(&self, request: CancelOrderRequest) -> BybitResult<CancelOrderResponse> {
+        match self {
+            Trader::Live(t) => t.cancel_order(request).await,
+            Trader::Paper(t) => t.cancel_order(request).await,
+        }
+    }
+
+    async fn open_orders(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> BybitResult<Vec<OrderInfo>> {
+        match self {
+            Trader::Live(t) => t.open_orders(category, symbol).await,
+            Trader::Paper(t) => t.open_orders(category, symbol).await,
+        }
+    }
+
+    async fn positions(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> BybitResult<Vec<PositionInfo>> {
+        match self {
+            Trader::Live(t) => t.positions(category, symbol).await,
+            Trader::Paper(t) => t.positions(category, symbol).await,
+        }
+    }
+
+    async fn wallet(&self) -> BybitResult<WalletBalanceDetails> {
+        match self {
+            Trader::Live(t) => t.wallet().await,
+            Trader::Paper(t) => t.wallet().await,
+        }
+    }
+}
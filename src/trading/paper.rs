@@ -0,0 +1,458 @@
+//! [`PaperTrader`]: simulated execution against live market data.
+//!
+//! Orders are matched by the in-memory engine in `trading::engine` (also
+//! behind `testing::MockExchange`), fed from public `orderbook` and
+//! `publicTrade` messages instead of scripted books:
+//!
+//! - marketable orders take liquidity from the latest book, as taker
+//! - resting orders fill as maker when the book crosses them or a public
+//!   trade prints at or through their price
+//!
+//! Only spot and linear symbols are simulated. Liquidity taken by paper
+//! orders is not removed from the exchange, so it reappears with the next
+//! book update.
+
+use super::engine::{Engine, Event, Reject};
+use super::TradingApi;
+use crate::environment::BybitEnv;
+use crate::rest::account::dto::WalletBalanceDetails;
+use crate::rest::enums::{Category, Side};
+use crate::rest::errors::{BybitError, BybitResult, ErrorCodes};
+use crate::rest::order::dto::{
+    AmendOrderRequest, AmendOrderResponse, CancelOrderRequest, CancelOrderResponse, OrderInfo,
+    PlaceOrderRequest, PlaceOrderResponse, TradeHistory,
+};
+use crate::rest::position::dto::PositionInfo;
+use crate::rest::AccountClient;
+use crate::ws::{topics, WsClient, WsMessage};
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::task::JoinHandle;
+
+/// Order book depth subscribed to by [`PaperTrader::track`].
+const ORDERBOOK_DEPTH: u16 = 50;
+
+/// Local order book rebuilt from `orderbook.*` snapshots and deltas.
+#[derive(Debug, Default)]
+struct LocalBook {
+    bids: HashMap<String, f64>,
+    asks: HashMap<String, f64>,
+}
+
+impl LocalBook {
+    fn apply(&mut self, snapshot: bool, data: &Value) {
+        if snapshot {
+            self.bids.clear();
+            self.asks.clear();
+        }
+        Self::apply_side(&mut self.bids, &data["b"]);
+        Self::apply_side(&mut self.asks, &data["a"]);
+    }
+
+    fn apply_side(side: &mut HashMap<String, f64>, levels: &Value) {
+        for level in levels.as_array().into_iter().flatten() {
+            let (Some(price), Some(size)) = (level[0].as_str(), level[1].as_str()) else {
+                continue;
+            };
+            match size.parse::<f64>() {
+                Ok(size) if size > 0.0 => {
+                    side.insert(price.to_string(), size);
+                }
+                _ => {
+                    side.remove(price);
+                }
+            }
+        }
+    }
+
+    fn levels(side: &HashMap<String, f64>) -> Vec<(f64, f64)> {
+        side.iter()
+            .filter_map(|(price, size)| Some((price.parse().ok()?, *size)))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    engine: Engine,
+    books: HashMap<(Category, String), LocalBook>,
+}
+
+/// Simulated trading account fed by public market data.
+///
+/// Start one with [`new`](Self::new) and `with_*` builders, then feed it with
+/// [`track`](Self::track) (own WebSocket connection per symbol) or
+/// [`apply`](Self::apply) (messages from any source, e.g. a
+/// [`ReplayWsSource`](crate::ws::ReplayWsSource)). Orders for a symbol are
+/// rejected until its first order book snapshot arrives.
+pub struct PaperTrader {
+    state: Arc<Mutex<State>>,
+    feeds: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl Default for PaperTrader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaperTrader {
+    /// An empty account with 0.02% maker / 0.055% taker fees.
+    pub fn new() -> Self {
+        PaperTrader {
+            state: Arc::new(Mutex::new(State {
+                engine: Engine::new(0.0002, 0.00055),
+                books: HashMap::new(),
+            })),
+            feeds: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Credit `amount` of `coin` to the wallet.
+    pub fn with_balance(self, coin: &str, amount: f64) -> Self {
+        self.state().engine.deposit(coin, amount);
+        self
+    }
+
+    /// Default maker and taker fee rates, e.g. `0.0002` for 0.02%.
+    pub fn with_fee_rates(self, maker: f64, taker: f64) -> Self {
+        self.state().engine.set_fee_rates(maker, taker);
+        self
+    }
+
+    /// Charge a symbol the account's real fee rates, from
+    /// [`AccountClient::get_fee_rate`].
+    pub async fn fetch_fee_rates(
+        &self,
+        account: &AccountClient,
+        category: Category,
+        symbol: &str,
+    ) -> BybitResult<()> {
+        let rates = account
+            .get_fee_rate(&category.to_string(), Some(symbol), None)
+            .await?
+            .result;
+        let rate = rates
+            .list
+            .iter()
+            .find(|r| r.symbol == symbol)
+            .ok_or_else(|| BybitError::Internal(format!("No fee rate for {}", symbol)))?;
+        let parse = |rate: &str| {
+            rate.parse::<f64>()
+                .map_err(|_| BybitError::Internal(format!("Invalid fee rate: {}", rate)))
+        };
+        let (maker, taker) = (parse(&rate.maker_fee_rate)?, parse(&rate.taker_fee_rate)?);
+        self.state()
+            .engine
+            .set_symbol_fee_rates(category, symbol, maker, taker);
+        Ok(())
+    }
+
+    /// Open a public connection for `symbol` and feed its order book and
+    /// trades into the simulation until this trader is dropped.
+    pub async fn track(&self, env: BybitEnv, category: Category, symbol: &str) -> BybitResult<()> {
+        let client = WsClient::public(env, category).await?;
+        self.track_with(client, category, symbol).await
+    }
+
+    /// [`track`](Self::track) over an already connected public client.
+    pub async fn track_with(
+        &self,
+        mut client: WsClient,
+        category: Category,
+        symbol: &str,
+    ) -> BybitResult<()> {
+        client
+            .subscribe(vec![
+                topics::orderbook(ORDERBOOK_DEPTH, symbol),
+                topics::trade(symbol),
+            ])
+            .await?;
+        let state = self.state.clone();
+        let feed = tokio::spawn(async move {
+            while let Some(msg) = client.next().await {
+                apply(&mut lock(&state), category, &msg);
+            }
+        });
+        self.feeds
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(feed);
+        Ok(())
+    }
+
+    /// Feed one public message (`orderbook.*` or `publicTrade.*`) of a
+    /// category. Other messages are ignored.
+    pub fn apply(&self, category: Category, msg: &WsMessage) {
+        apply(&mut self.state(), category, msg);
+    }
+
+    /// Whether the order book of a symbol has arrived, so it can be traded.
+    pub fn has_book(&self, category: Category, symbol: &str) -> bool {
+        self.state().engine.has_symbol(category, symbol)
+    }
+
+    /// Every simulated fill, oldest first.
+    pub fn executions(&self) -> BybitResult<Vec<TradeHistory>> {
+        let executions = self.state().engine.all_executions();
+        Ok(serde_json::from_value(Value::Array(executions))?)
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        lock(&self.state)
+    }
+
+    /// Run an engine request with the request serialized as Bybit would
+    /// receive it.
+    fn request<R: DeserializeOwned>(
+        &self,
+        request: &impl serde::Serialize,
+        f: impl FnOnce(&mut Engine, &Value, &mut Vec<Event>) -> Result<Value, Reject>,
+    ) -> BybitResult<R> {
+        let params = serde_json::to_value(request)?;
+        let result = f(&mut self.state().engine, &params, &mut Vec::new());
+        Ok(serde_json::from_value(result.map_err(api_error)?)?)
+    }
+}
+
+impl Drop for PaperTrader {
+    fn drop(&mut self) {
+        for feed in self.feeds.get_mut().unwrap_or_else(|e| e.into_inner()) {
+            feed.abort();
+        }
+    }
+}
+
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    // A panicking feed must not wedge the trader
+    state.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A rejection, as the REST client would report it.
+fn api_error((code, msg): Reject) -> BybitError {
+    log::debug!("PaperTrader rejected request: {} {}", code, msg);
+    BybitError::Api(serde_json::from_value(json!(code.to_string())).unwrap_or(ErrorCodes::E10001))
+}
+
+fn apply(state: &mut State, category: Category, msg: &WsMessage) {
+    let WsMessage::Data(msg) = msg else {
+        return;
+    };
+    let (Some(topic), Some(data)) = (msg.topic.as_deref(), msg.data.as_ref()) else {
+        return;
+    };
+    let mut events = Vec::new();
+    match topic.split('.').collect::<Vec<_>>().as_slice() {
+        ["orderbook", _, symbol] => {
+            let book = state
+                .books
+                .entry((category, symbol.to_string()))
+                .or_default();
+            book.apply(msg.msg_type.as_deref() == Some("snapshot"), data);
+            let (bids, asks) = (LocalBook::levels(&book.bids), LocalBook::levels(&book.asks));
+            state
+                .engine
+                .set_book(category, symbol, &bids, &asks, &mut events);
+        }
+        ["publicTrade", symbol] => {
+            // Trades before the first book snapshot have nothing to fill
+            if !state.engine.has_symbol(category, symbol) {
+                return;
+            }
+            for trade in data.as_array().into_iter().flatten() {
+                let side = serde_json::from_value::<Side>(trade["S"].clone());
+                let price = trade["p"].as_str().and_then(|p| p.parse().ok());
+                let qty = trade["v"].as_str().and_then(|v| v.parse().ok());
+                if let (Ok(side), Some(price), Some(qty)) = (side, price, qty) {
+                    state
+                        .engine
+                        .trade(category, symbol, side, price, qty, &mut events);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+impl TradingApi for PaperTrader {
+    async fn place_order(&self, request: PlaceOrderRequest) -> BybitResult<PlaceOrderResponse> {
+        self.request(&request, Engine::place_order)
+    }
+
+    async fn amend_order(&self, request: AmendOrderRequest) -> BybitResult<AmendOrderResponse> {
+        self.request(&request, Engine::amend_order)
+    }
+
+    async fn cancel_order(&self, request: CancelOrderRequest) -> BybitResult<CancelOrderResponse> {
+        self.request(&request, Engine::cancel_order)
+    }
+
+    async fn open_orders(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> BybitResult<Vec<OrderInfo>> {
+        let orders = self.state().engine.open_orders(category, symbol);
+        Ok(serde_json::from_value(Value::Array(orders))?)
+    }
+
+    async fn positions(
+        &self,
+        category: Category,
+        symbol: Option<&str>,
+    ) -> BybitResult<Vec<PositionInfo>> {
+        if category != Category::Linear {
+            return Ok(Vec::new());
+        }
+        let positions: Vec<Value> = self
+            .state()
+            .engine
+            .all_positions()
+            .into_iter()
+            .filter(|p| symbol.is_none_or(|s| p["symbol"] == s))
+            .collect();
+        Ok(serde_json::from_value(Value::Array(positions))?)
+    }
+
+    async fn wallet(&self) -> BybitResult<WalletBalanceDetails> {
+        Ok(serde_json::from_value(
+            self.state().engine.wallet_json(None),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::enums::{OrderType, TimeInForce};
+
+    fn push(topic: &str, msg_type: &str, data: Value) -> WsMessage {
+        serde_json::from_value(json!({"topic": topic, "type": msg_type, "ts": 1, "data": data}))
+            .unwrap()
+    }
+
+    fn book_snapshot() -> WsMessage {
+        push(
+            "orderbook.50.BTCUSDT",
+            "snapshot",
+            json!({"s": "BTCUSDT", "b": [["29990", "1"]], "a": [["30010", "1"], ["30020", "2"]], "u": 1}),
+        )
+    }
+
+    fn order(
+        side: Side,
+        order_type: OrderType,
+        qty: &str,
+        price: Option<&str>,
+    ) -> PlaceOrderRequest {
+        PlaceOrderRequest {
+            category: Category::Linear,
+            symbol: "BTCUSDT".to_string(),
+            side,
+            order_type,
+            qty: qty.to_string(),
+            price: price.map(str::to_string),
+            time_in_force: price.map(|_| TimeInForce::GTC),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejects_until_book_arrives() {
+        let trader = PaperTrader::new().with_balance("USDT", 1000.0);
+        let err = trader
+            .place_order(order(Side::Buy, OrderType::Market, "0.01", None))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BybitError::Api(ErrorCodes::E10001)));
+    }
+
+    #[tokio::test]
+    async fn test_market_order_walks_book_with_fees() {
+        let trader = PaperTrader::new()
+            .with_balance("USDT", 100_000.0)
+            .with_fee_rates(0.0, 0.001);
+        trader.apply(Category::Linear, &book_snapshot());
+
+        trader
+            .place_order(order(Side::Buy, OrderType::Market, "1.5", None))
+            .await
+            .unwrap();
+        let fills = trader.executions().unwrap();
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].exec_price, "30010");
+        assert_eq!(fills[1].exec_price, "30020");
+
+        let positions = trader.positions(Category::Linear, None).await.unwrap();
+        assert_eq!(positions[0].size, "1.5");
+        // 0.1% taker fee on 30010 + 0.5 * 30020
+        let wallet = trader.wallet().await.unwrap();
+        assert_eq!(wallet.coin[0].wallet_balance, "99954.98");
+    }
+
+    #[tokio::test]
+    async fn test_resting_order_fills_on_delta_and_trade() {
+        let trader = PaperTrader::new().with_balance("USDT", 100_000.0);
+        trader.apply(Category::Linear, &book_snapshot());
+        let bid = trader
+            .place_order(order(Side::Buy, OrderType::Limit, "1", Some("29950")))
+            .await
+            .unwrap();
+        let ask = trader
+            .place_order(order(Side::Sell, OrderType::Limit, "1", Some("30100")))
+            .await
+            .unwrap();
+        assert_eq!(
+            trader
+                .open_orders(Category::Linear, None)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // The best ask drops through the bid
+        trader.apply(
+            Category::Linear,
+            &push(
+                "orderbook.50.BTCUSDT",
+                "delta",
+                json!({"s": "BTCUSDT", "b": [], "a": [["29940", "3"]], "u": 2}),
+            ),
+        );
+        // A buy prints through the ask
+        trader.apply(
+            Category::Linear,
+            &push(
+                "publicTrade.BTCUSDT",
+                "snapshot",
+                json!([{"s": "BTCUSDT", "S": "Buy", "p": "30150", "v": "2", "T": 1, "i": "x"}]),
+            ),
+        );
+
+        assert!(trader
+            .open_orders(Category::Linear, None)
+            .await
+            .unwrap()
+            .is_empty());
+        let fills = trader.executions().unwrap();
+        assert_eq!(fills[0].order_id, bid.order_id);
+        assert_eq!(fills[0].exec_price, "29950");
+        assert!(fills[0].is_maker);
+        assert_eq!(fills[1].order_id, ask.order_id);
+        assert_eq!(fills[1].exec_price, "30100");
+        let cancel = trader
+            .cancel_order(CancelOrderRequest {
+                category: Category::Linear,
+                symbol: "BTCUSDT".to_string(),
+                order_id: Some(ask.order_id),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(cancel, BybitError::Api(ErrorCodes::E110001)));
+    }
+}
@@ -3,7 +3,7 @@
 //! Authentication is required for private WebSocket topics (position, execution,
 //! order, wallet). The process:
 //! 1. Generate an `expires` timestamp (current ms + 10 seconds)
-//! 2. Sign the expires value with HMAC-SHA256 using the API secret
+//! 2. Sign `GET/realtime{expires}` with HMAC-SHA256 using the API secret
 //! 3. Send `{"op": "auth", "args": [api_key, expires, signature]}`
 
 use crate::utils;
@@ -22,11 +22,15 @@ use crate::utils;
 /// ```
 pub fn generate_auth_params(secret: &str) -> (u64, String) {
     let expires = utils::millis() as u64 + 10_000; // 10 seconds from now
-    let expires_str = expires.to_string();
-    let signature = utils::sign(secret, &expires_str);
+    let signature = utils::sign(secret, &auth_payload(expires));
     (expires, signature)
 }
 
+/// The string Bybit expects to be signed for a WebSocket `auth` request.
+pub fn auth_payload(expires: u64) -> String {
+    format!("GET/realtime{}", expires)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,4 +54,29 @@ mod tests {
         // signatures should differ because expires differs
         assert_ne!(s1, s2);
     }
+
+    #[test]
+    fn test_auth_signs_realtime_payload() {
+        let (expires, sig) = generate_auth_params("secret");
+        assert_eq!(
+            sig,
+            utils::sign("secret", &format!("GET/realtime{}", expires))
+        );
+    }
+
+    #[test]
+    fn test_auth_payload_known_signature() {
+        // Signing the bare expires value (the old payload) is rejected by
+        // Bybit with "Params Error".
+        let payload = auth_payload(1662350400000);
+        assert_eq!(payload, "GET/realtime1662350400000");
+        assert_eq!(
+            utils::sign("secret", &payload),
+            "d7ca36fea9ef1287007fd4b15af961e91d419a3d3f3ccbdf23585170ac116cd4"
+        );
+        assert_ne!(
+            utils::sign("secret", "1662350400000"),
+            utils::sign("secret", &payload)
+        );
+    }
 }
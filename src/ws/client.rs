@@ -7,6 +7,9 @@
 //! - Automatic reconnection with exponential backoff
 //! - Automatic re-subscription after reconnect
 //! - Implements `futures::Stream` for async iteration
+//! - Proxy, TLS and socket options via [`ClientConfig`]
+//! - Raw frame taps (see [`WsClient::add_raw_tap`]) for recording
+//! - Maintenance-aware reconnects via [`ClientConfig::with_maintenance_guard`]
 //!
 //! # Example
 //!
@@ -21,21 +24,24 @@
 //! }
 //! ```
 
+use crate::config::ClientConfig;
+use crate::environment::BybitEnv;
+use crate::maintenance::MaintenanceGuard;
+use crate::rest::enums::category::Category;
 use crate::rest::errors::{BybitError, BybitResult};
+use crate::utils;
+use crate::ws::connector::{self, WsStream};
 use crate::ws::messages::{WsMessage, WsRequest};
+use crate::ws::recorder::RawFrame;
 use futures_util::stream::SplitSink;
 use futures_util::{SinkExt, Stream, StreamExt};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Duration;
-use tokio::net::TcpStream;
 use tokio::sync::{mpsc, Mutex};
 use tokio::time::{interval, sleep};
 use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
-
-type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
 /// Maximum reconnection attempts before giving up.
 const MAX_RECONNECT_ATTEMPTS: u32 = 10;
@@ -46,6 +52,9 @@ const RECONNECT_MAX_DELAY_MS: u64 = 30_000;
 /// Ping interval in seconds.
 const PING_INTERVAL_SECS: u64 = 20;
 
+/// Callback receiving every raw text frame, before parsing.
+pub type RawTap = Arc<dyn Fn(&RawFrame) + Send + Sync>;
+
 /// Authentication parameters stored for reconnection.
 #[derive(Clone)]
 struct AuthParams {
@@ -66,6 +75,8 @@ pub struct WsClient {
     url: String,
     /// Currently subscribed topics (for resubscribe on reconnect)
     subscribed_topics: Arc<Mutex<Vec<String>>>,
+    /// Raw frame observers
+    taps: Arc<std::sync::Mutex<Vec<RawTap>>>,
 }
 
 enum Command {
@@ -76,6 +87,7 @@ enum Command {
         expires: u64,
         signature: String,
     },
+    Send(String),
 }
 
 impl WsClient {
@@ -84,15 +96,38 @@ impl WsClient {
     /// Spawns a background task that manages the connection lifecycle
     /// including automatic reconnection with exponential backoff.
     pub async fn connect(url: &str) -> BybitResult<Self> {
+        Self::connect_with_config(url, ClientConfig::default()).await
+    }
+
+    /// Connect to a Bybit WebSocket endpoint using custom transport settings.
+    ///
+    /// The config is applied on every (re)connect: proxy tunnel, TLS roots,
+    /// local bind address, keep-alive, `TCP_NODELAY`, connect timeout and
+    /// default headers (sent with the upgrade request).
+    pub async fn connect_with_config(url: &str, config: ClientConfig) -> BybitResult<Self> {
+        // Surface invalid headers immediately instead of on every reconnect
+        config.header_map()?;
+        if config.demo_only && !url.contains("/v5/public/") && !BybitEnv::is_demo_url(url) {
+            return Err(BybitError::Config(format!(
+                "Demo-only client refuses non-demo host {}",
+                url
+            )));
+        }
+
         let (command_tx, command_rx) = mpsc::unbounded_channel();
         let (message_tx, message_rx) = mpsc::unbounded_channel();
 
         let subscribed_topics = Arc::new(Mutex::new(Vec::new()));
         let topics = subscribed_topics.clone();
+        let taps = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let loop_taps = taps.clone();
         let url_owned = url.to_string();
 
         let handle = tokio::spawn(async move {
-            run_connection_loop(&url_owned, command_rx, message_tx, topics).await;
+            run_connection_loop(
+                &url_owned, &config, command_rx, message_tx, topics, loop_taps,
+            )
+            .await;
         });
 
         Ok(WsClient {
@@ -101,9 +136,37 @@ impl WsClient {
             _handle: Some(handle),
             url: url.to_string(),
             subscribed_topics,
+            taps,
         })
     }
 
+    /// Connect to the public stream of `category` in the given environment.
+    pub async fn public(env: BybitEnv, category: Category) -> BybitResult<Self> {
+        Self::connect(&env.public_ws_url(category)).await
+    }
+
+    /// Connect to the public spread trading stream of the given environment.
+    ///
+    /// Spread order and execution updates arrive on the private stream.
+    pub async fn spread(env: BybitEnv) -> BybitResult<Self> {
+        Self::connect(&env.spread_ws_url()).await
+    }
+
+    /// Connect to the maintenance announcement stream of the given environment.
+    ///
+    /// Subscribe to [`topics::system_status`](crate::ws::topics::system_status).
+    pub async fn system_status(env: BybitEnv) -> BybitResult<Self> {
+        Self::connect(&env.system_status_ws_url()).await
+    }
+
+    /// Connect to the private stream of the given environment.
+    ///
+    /// Call [`authenticate`](Self::authenticate) before subscribing.
+    pub async fn private(env: BybitEnv) -> BybitResult<Self> {
+        let config = ClientConfig::default().with_demo_only(env.is_demo());
+        Self::connect_with_config(&env.private_ws_url(), config).await
+    }
+
     /// Subscribe to one or more topics.
     ///
     /// Topics are remembered for automatic re-subscription on reconnect.
@@ -157,6 +220,31 @@ impl WsClient {
         Ok(())
     }
 
+    /// Send an arbitrary request, e.g. a
+    /// [`WsTradeRequest`](crate::ws::trade::WsTradeRequest) on the trade
+    /// stream. Responses arrive on the stream as [`WsMessage::Op`].
+    ///
+    /// Unlike subscriptions, sent requests are not replayed on reconnect.
+    pub fn send<T: serde::Serialize>(&self, request: &T) -> BybitResult<()> {
+        let json = serde_json::to_string(request)?;
+        self.command_tx
+            .send(Command::Send(json))
+            .map_err(|e| BybitError::Internal(format!("Send channel closed: {}", e)))?;
+        Ok(())
+    }
+
+    /// Observe every raw text frame received on this client.
+    ///
+    /// The tap is called from the connection task, before the frame is
+    /// parsed, with the local receive time and the id of the (re)connection
+    /// that delivered it. Keep it cheap — e.g. forward to a channel, as
+    /// [`WsRecorder::sink`](crate::ws::recorder::WsRecorder::sink) does.
+    pub fn add_raw_tap(&self, tap: impl Fn(&RawFrame) + Send + Sync + 'static) {
+        if let Ok(mut taps) = self.taps.lock() {
+            taps.push(Arc::new(tap));
+        }
+    }
+
     /// Get the WebSocket endpoint URL.
     pub fn url(&self) -> &str {
         &self.url
@@ -191,35 +279,55 @@ impl Stream for WsClient {
 /// Connection loop: connects, handles messages, reconnects on failure.
 async fn run_connection_loop(
     url: &str,
+    config: &ClientConfig,
     mut command_rx: mpsc::UnboundedReceiver<Command>,
     message_tx: mpsc::UnboundedSender<WsMessage>,
     subscribed_topics: Arc<Mutex<Vec<String>>>,
+    taps: Arc<std::sync::Mutex<Vec<RawTap>>>,
 ) {
     let mut auth_params: Option<AuthParams> = None;
     let mut attempt = 0;
+    let category = stream_category(url);
 
     loop {
+        // Announced maintenance drops connections repeatedly: keep retrying
+        // through the window rather than exhausting the attempt budget.
+        let in_maintenance = attempt > 0
+            && config
+                .maintenance
+                .as_ref()
+                .is_some_and(|guard| guard.record_reconnect(category));
+
         if attempt > 0 {
             let delay_ms =
                 (RECONNECT_BASE_DELAY_MS * 2_u64.pow(attempt.min(6))).min(RECONNECT_MAX_DELAY_MS);
-            log::warn!(
-                "Reconnecting in {}ms (attempt {}/{})...",
-                delay_ms,
-                attempt,
-                MAX_RECONNECT_ATTEMPTS
-            );
+            if in_maintenance {
+                log::info!(
+                    "Reconnecting in {}ms during announced maintenance (attempt {})...",
+                    delay_ms,
+                    attempt
+                );
+            } else {
+                log::warn!(
+                    "Reconnecting in {}ms (attempt {}/{})...",
+                    delay_ms,
+                    attempt,
+                    MAX_RECONNECT_ATTEMPTS
+                );
+            }
             sleep(Duration::from_millis(delay_ms)).await;
         }
 
-        if attempt >= MAX_RECONNECT_ATTEMPTS {
+        if attempt >= MAX_RECONNECT_ATTEMPTS && !in_maintenance {
             log::error!("Max reconnect attempts reached. Giving up.");
             break;
         }
 
-        match connect_async(url).await {
-            Ok((ws_stream, _)) => {
+        match connector::connect(url, config).await {
+            Ok(ws_stream) => {
                 log::info!("WebSocket connected to {}", url);
                 attempt = 0; // reset on successful connection
+                let conn_id = uuid::Uuid::new_v4().simple().to_string();
 
                 let (ws_write, ws_read) = ws_stream.split();
                 let ws_write = Arc::new(Mutex::new(ws_write));
@@ -240,12 +348,19 @@ async fn run_connection_loop(
                 }
 
                 // Run the connection until it fails
+                let tap = FrameTap {
+                    url,
+                    conn_id: &conn_id,
+                    taps: &taps,
+                };
                 run_connection(
                     ws_read,
                     ws_write,
                     &mut command_rx,
                     &message_tx,
                     &mut auth_params,
+                    &tap,
+                    config.maintenance.as_ref(),
                 )
                 .await;
             }
@@ -259,6 +374,19 @@ async fn run_connection_loop(
 }
 
 /// Send a WS request through the writer.
+/// Product category served by a public stream URL (`.../v5/public/linear`).
+///
+/// Private, trade and spread streams serve every category and yield `None`.
+fn stream_category(url: &str) -> Option<Category> {
+    match url.trim_end_matches('/').rsplit_once("/v5/public/")?.1 {
+        "spot" => Some(Category::Spot),
+        "linear" => Some(Category::Linear),
+        "inverse" => Some(Category::Inverse),
+        "option" => Some(Category::Option),
+        _ => None,
+    }
+}
+
 async fn send_command(writer: &Arc<Mutex<SplitSink<WsStream, Message>>>, req: &WsRequest) {
     if let Ok(json) = serde_json::to_string(req) {
         if let Ok(mut w) = writer.try_lock() {
@@ -267,6 +395,31 @@ async fn send_command(writer: &Arc<Mutex<SplitSink<WsStream, Message>>>, req: &W
     }
 }
 
+/// Raw taps of one connection, with what they need to build a [`RawFrame`].
+struct FrameTap<'a> {
+    url: &'a str,
+    conn_id: &'a str,
+    taps: &'a std::sync::Mutex<Vec<RawTap>>,
+}
+
+impl FrameTap<'_> {
+    fn send(&self, text: &str) {
+        let taps = match self.taps.lock() {
+            Ok(taps) if !taps.is_empty() => taps.clone(),
+            _ => return,
+        };
+        let frame = RawFrame {
+            recv_ts: utils::millis() as i64,
+            conn_id: self.conn_id.to_string(),
+            url: self.url.to_string(),
+            frame: text.to_string(),
+        };
+        for tap in taps {
+            tap(&frame);
+        }
+    }
+}
+
 /// Core connection handler: reads WS messages, processes commands, sends pings.
 async fn run_connection(
     mut ws_read: futures_util::stream::SplitStream<WsStream>,
@@ -274,6 +427,8 @@ async fn run_connection(
     command_rx: &mut mpsc::UnboundedReceiver<Command>,
     message_tx: &mpsc::UnboundedSender<WsMessage>,
     auth_params: &mut Option<AuthParams>,
+    tap: &FrameTap<'_>,
+    maintenance: Option<&MaintenanceGuard>,
 ) {
     let mut ping_interval = interval(Duration::from_secs(PING_INTERVAL_SECS));
 
@@ -299,6 +454,10 @@ async fn run_connection(
                         let req = WsRequest::auth(&api_key, expires, &signature);
                         send_command(&ws_write, &req).await;
                     }
+                    Some(Command::Send(json)) => {
+                        let mut writer = ws_write.lock().await;
+                        let _ = writer.send(Message::Text(json.into())).await;
+                    }
                     None => {
                         // Command channel closed
                         break;
@@ -316,8 +475,12 @@ async fn run_connection(
             msg = ws_read.next() => {
                 match msg {
                     Some(Ok(Message::Text(text))) => {
+                        tap.send(&text);
                         match serde_json::from_str::<WsMessage>(&text) {
                             Ok(parsed) => {
+                                if let Some(guard) = maintenance {
+                                    guard.apply_message(&parsed);
+                                }
                                 if message_tx.send(parsed).is_err() {
                                     break; // receiver dropped
                                 }
@@ -355,3 +518,19 @@ async fn run_connection(
 
     log::info!("WebSocket connection handler exited");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_category() {
+        let env = BybitEnv::Mainnet;
+        assert_eq!(
+            stream_category(&env.public_ws_url(Category::Option)),
+            Some(Category::Option)
+        );
+        assert_eq!(stream_category(&env.spread_ws_url()), None);
+        assert_eq!(stream_category(&env.private_ws_url()), None);
+    }
+}
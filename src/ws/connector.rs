@@ -0,0 +1,317 @@
+//! Low-level WebSocket transport setup driven by [`ClientConfig`].
+//!
+//! Opens the TCP socket (optionally bound to a local address, with
+//! keep-alive / nodelay applied), tunnels through an HTTP or SOCKS5 proxy
+//! when configured, then performs the TLS and WebSocket handshakes.
+
+use crate::config::ClientConfig;
+use crate::rest::errors::{BybitError, BybitResult};
+use base64::Engine;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{lookup_host, TcpSocket, TcpStream};
+use tokio_socks::tcp::Socks5Stream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::{client_async_tls_with_config, Connector, MaybeTlsStream, WebSocketStream};
+
+pub(crate) type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Maximum size of an HTTP CONNECT response header we are willing to read.
+const MAX_CONNECT_RESPONSE: usize = 8 * 1024;
+
+/// Connect to `url` applying every WS-relevant setting of `config`.
+pub(crate) async fn connect(url: &str, config: &ClientConfig) -> BybitResult<WsStream> {
+    match config.connect_timeout {
+        Some(timeout) => tokio::time::timeout(timeout, connect_inner(url, config))
+            .await
+            .map_err(|_| BybitError::Internal(format!("WebSocket connect to {} timed out", url)))?,
+        None => connect_inner(url, config).await,
+    }
+}
+
+async fn connect_inner(url: &str, config: &ClientConfig) -> BybitResult<WsStream> {
+    let mut request = url
+        .into_client_request()
+        .map_err(|e| BybitError::Config(format!("Invalid WebSocket URL {}: {}", url, e)))?;
+    request.headers_mut().extend(config.header_map()?);
+
+    let host = request
+        .uri()
+        .host()
+        .ok_or_else(|| BybitError::Config(format!("WebSocket URL has no host: {}", url)))?
+        .to_string();
+    let port = request
+        .uri()
+        .port_u16()
+        .unwrap_or(match request.uri().scheme_str() {
+            Some("wss") => 443,
+            _ => 80,
+        });
+
+    let stream = match config.proxy {
+        Some(ref proxy) => connect_via_proxy(proxy, &host, port, config).await?,
+        None => open_tcp(&host, port, config).await?,
+    };
+
+    let (ws_stream, _) =
+        client_async_tls_with_config(request, stream, None, tls_connector(config)?)
+            .await
+            .map_err(|e| BybitError::Internal(format!("WebSocket handshake failed: {}", e)))?;
+    Ok(ws_stream)
+}
+
+/// Resolve `host` and open a TCP connection with the configured socket options.
+async fn open_tcp(host: &str, port: u16, config: &ClientConfig) -> BybitResult<TcpStream> {
+    let addrs = lookup_host((host, port))
+        .await
+        .map_err(|e| BybitError::Internal(format!("Failed to resolve {}: {}", host, e)))?;
+
+    let mut last_err = None;
+    for addr in addrs {
+        if let Some(local) = config.local_address {
+            if local.is_ipv4() != addr.is_ipv4() {
+                continue;
+            }
+        }
+        match open_tcp_addr(addr, config).await {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_err = Some(e),
+        }
+    }
+
+    Err(BybitError::Internal(match last_err {
+        Some(e) => format!("Failed to connect to {}:{}: {}", host, port, e),
+        None => format!("No usable address for {}:{}", host, port),
+    }))
+}
+
+async fn open_tcp_addr(addr: SocketAddr, config: &ClientConfig) -> std::io::Result<TcpStream> {
+    let socket = if addr.is_ipv4() {
+        TcpSocket::new_v4()?
+    } else {
+        TcpSocket::new_v6()?
+    };
+    if let Some(local) = config.local_address {
+        socket.bind(SocketAddr::new(local, 0))?;
+    }
+
+    let stream = socket.connect(addr).await?;
+    stream.set_nodelay(config.tcp_nodelay)?;
+    if let Some(idle) = config.tcp_keepalive {
+        let keepalive = socket2::TcpKeepalive::new().with_time(idle);
+        socket2::SockRef::from(&stream).set_tcp_keepalive(&keepalive)?;
+    }
+    Ok(stream)
+}
+
+/// Establish a tunnel to `host:port` through the configured proxy.
+async fn connect_via_proxy(
+    proxy: &str,
+    host: &str,
+    port: u16,
+    config: &ClientConfig,
+) -> BybitResult<TcpStream> {
+    let proxy_url = url::Url::parse(proxy)
+        .map_err(|e| BybitError::Config(format!("Invalid proxy URL {}: {}", proxy, e)))?;
+    let proxy_host = proxy_url
+        .host_str()
+        .ok_or_else(|| BybitError::Config(format!("Proxy URL has no host: {}", proxy)))?;
+    let proxy_port = proxy_url.port_or_known_default().unwrap_or(1080);
+    let credentials = match proxy_url.username() {
+        "" => None,
+        user => Some((
+            user.to_string(),
+            proxy_url.password().unwrap_or("").to_string(),
+        )),
+    };
+
+    if !matches!(proxy_url.scheme(), "http" | "socks5" | "socks5h") {
+        return Err(BybitError::Config(format!(
+            "Unsupported proxy scheme for WebSocket: {}",
+            proxy_url.scheme()
+        )));
+    }
+
+    let stream = open_tcp(proxy_host, proxy_port, config).await?;
+    match proxy_url.scheme() {
+        "http" => http_connect(stream, host, port, credentials.as_ref()).await,
+        "socks5" => {
+            // Resolve locally; the proxy only sees the IP address
+            let target = lookup_host((host, port))
+                .await
+                .ok()
+                .and_then(|mut addrs| addrs.next())
+                .ok_or_else(|| BybitError::Internal(format!("Failed to resolve {}", host)))?;
+            socks5_connect(stream, target, credentials.as_ref()).await
+        }
+        _ => socks5_connect(stream, (host, port), credentials.as_ref()).await,
+    }
+}
+
+async fn socks5_connect<'t, T>(
+    stream: TcpStream,
+    target: T,
+    credentials: Option<&(String, String)>,
+) -> BybitResult<TcpStream>
+where
+    T: tokio_socks::IntoTargetAddr<'t>,
+{
+    let result = match credentials {
+        Some((user, pass)) => {
+            Socks5Stream::connect_with_password_and_socket(stream, target, user, pass).await
+        }
+        None => Socks5Stream::connect_with_socket(stream, target).await,
+    };
+    result
+        .map(Socks5Stream::into_inner)
+        .map_err(|e| BybitError::Internal(format!("SOCKS5 proxy error: {}", e)))
+}
+
+/// Open an HTTP `CONNECT` tunnel over an already connected proxy socket.
+async fn http_connect(
+    mut stream: TcpStream,
+    host: &str,
+    port: u16,
+    credentials: Option<&(String, String)>,
+) -> BybitResult<TcpStream> {
+    let mut request = format!(
+        "CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n",
+        host = host,
+        port = port
+    );
+    if let Some((user, pass)) = credentials {
+        let token = base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", user, pass));
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", token));
+    }
+    request.push_str("\r\n");
+
+    let io_err = |e: std::io::Error| BybitError::Internal(format!("HTTP proxy error: {}", e));
+    stream.write_all(request.as_bytes()).await.map_err(io_err)?;
+
+    // Read byte-by-byte so nothing past the header (i.e. TLS data) is consumed.
+    let mut response = Vec::with_capacity(256);
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_CONNECT_RESPONSE {
+            return Err(BybitError::Internal(
+                "HTTP proxy response header too large".to_string(),
+            ));
+        }
+        response.push(stream.read_u8().await.map_err(io_err)?);
+    }
+
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some(code) if code.starts_with('2') => Ok(stream),
+        _ => Err(BybitError::Internal(format!(
+            "HTTP proxy refused CONNECT: {}",
+            status_line
+        ))),
+    }
+}
+
+/// Build a rustls connector when custom roots are configured.
+///
+/// Returns `None` for the default trust configuration so that
+/// tokio-tungstenite uses its own native-roots setup.
+fn tls_connector(config: &ClientConfig) -> BybitResult<Option<Connector>> {
+    use rustls::pki_types::pem::PemObject;
+    use rustls::pki_types::CertificateDer;
+
+    if config.root_certificates.is_empty() && config.built_in_roots {
+        return Ok(None);
+    }
+
+    let mut roots = rustls::RootCertStore::empty();
+    if config.built_in_roots {
+        let native = rustls_native_certs::load_native_certs();
+        roots.add_parsable_certificates(native.certs);
+    }
+    for pem in &config.root_certificates {
+        for cert in CertificateDer::pem_slice_iter(pem) {
+            let cert =
+                cert.map_err(|e| BybitError::Config(format!("Invalid root certificate: {}", e)))?;
+            roots
+                .add(cert)
+                .map_err(|e| BybitError::Config(format!("Invalid root certificate: {}", e)))?;
+        }
+    }
+
+    let tls = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Ok(Some(Connector::Rustls(Arc::new(tls))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_http_connect_tunnel() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_addr = listener.local_addr().unwrap();
+
+        let proxy = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 1024];
+            let n = socket.read(&mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..n]).to_string();
+            socket
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\nhello")
+                .await
+                .unwrap();
+            request
+        });
+
+        let stream = TcpStream::connect(proxy_addr).await.unwrap();
+        let creds = ("user".to_string(), "pass".to_string());
+        let mut tunnel = http_connect(stream, "stream.bybit.com", 443, Some(&creds))
+            .await
+            .unwrap();
+
+        // Bytes after the header must remain unread for the TLS layer
+        let mut rest = [0u8; 5];
+        tunnel.read_exact(&mut rest).await.unwrap();
+        assert_eq!(&rest, b"hello");
+
+        let request = proxy.await.unwrap();
+        assert!(request.starts_with("CONNECT stream.bybit.com:443 HTTP/1.1\r\n"));
+        assert!(request.contains("Proxy-Authorization: Basic dXNlcjpwYXNz\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_http_connect_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 1024];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let stream = TcpStream::connect(proxy_addr).await.unwrap();
+        let result = http_connect(stream, "stream.bybit.com", 443, None).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_default_config_uses_library_tls() {
+        assert!(tls_connector(&ClientConfig::default()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_unsupported_proxy_scheme() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let config = ClientConfig::new().with_proxy("ftp://127.0.0.1:21");
+        let result = rt.block_on(connect("wss://stream.bybit.com/v5/public/linear", &config));
+        assert!(matches!(result, Err(BybitError::Config(_))));
+    }
+}
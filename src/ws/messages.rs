@@ -69,7 +69,7 @@ impl WsRequest {
 }
 
 /// Response type from Bybit WS (topic-based data)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WsResponse {
     /// Topic name (e.g. "orderbook.1.BTCUSDT")
     #[serde(default)]
@@ -87,7 +87,7 @@ pub struct WsResponse {
 }
 
 /// Operation-level response (subscribe success/error)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WsOpResponse {
     /// "subscribe", "unsubscribe", "auth", "pong"
     #[serde(default)]
@@ -96,18 +96,24 @@ pub struct WsOpResponse {
     #[serde(default)]
     pub success: Option<bool>,
     /// Return message
-    #[serde(default)]
+    #[serde(default, alias = "retMsg")]
     pub ret_msg: Option<String>,
     /// Connection ID
-    #[serde(default)]
+    #[serde(default, alias = "connId")]
     pub conn_id: Option<String>,
     /// Request arguments echoed back
-    #[serde(default)]
+    #[serde(default, alias = "reqId")]
     pub req_id: Option<String>,
+    /// Return code of trade operations (0 = success)
+    #[serde(default, rename = "retCode", skip_serializing_if = "Option::is_none")]
+    pub ret_code: Option<i32>,
+    /// Result of trade operations (`orderId`, `orderLinkId`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
 }
 
 /// Combined response enum to handle both topic messages and op responses
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum WsMessage {
     /// Topic-based data push
@@ -116,6 +122,24 @@ pub enum WsMessage {
     Op(WsOpResponse),
 }
 
+impl<'de> Deserialize<'de> for WsMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Every field of both shapes is optional, so dispatch on the keys
+        // only op responses carry instead of trying each variant in turn.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let is_op = value.get("op").is_some() || value.get("success").is_some();
+        if is_op {
+            serde_json::from_value(value)
+                .map(WsMessage::Op)
+                .map_err(serde::de::Error::custom)
+        } else {
+            serde_json::from_value(value)
+                .map(WsMessage::Data)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
 impl WsMessage {
     /// Returns true if this is a successful subscription confirmation
     pub fn is_subscribe_success(&self) -> bool {
@@ -190,14 +214,49 @@ pub mod topics {
 
     /// Build kline topic: kline.{interval}.{symbol}
     pub fn kline(interval: &str, symbol: &str) -> String {
-        format!("kline.{}.{}", symbol, interval)
+        format!("kline.{}.{}", interval, symbol)
     }
 
     /// Build liquidation topic
+    ///
+    /// Deprecated by Bybit: pushes at most one liquidation per second.
+    /// Prefer [`all_liquidation`].
     pub fn liquidation(symbol: &str) -> String {
         format!("liquidation.{}", symbol)
     }
 
+    /// Build all-liquidation topic: allLiquidation.{symbol}
+    pub fn all_liquidation(symbol: &str) -> String {
+        format!("allLiquidation.{}", symbol)
+    }
+
+    /// Build insurance pool topic: insurance.{coin}
+    ///
+    /// `coin` is the settle coin (`USDT`, `USDC`) or `inverse`.
+    pub fn insurance(coin: &str) -> String {
+        format!("insurance.{}", coin)
+    }
+
+    /// Build RPI orderbook topic: orderbook.rpi.{symbol}
+    pub fn rpi_orderbook(symbol: &str) -> String {
+        format!("orderbook.rpi.{}", symbol)
+    }
+
+    /// Build order price limit topic: priceLimit.{symbol}
+    pub fn price_limit(symbol: &str) -> String {
+        format!("priceLimit.{}", symbol)
+    }
+
+    /// Build ADL alert topic: adlAlert.{coin}, per insurance pool settle coin
+    pub fn adl_alert(coin: &str) -> String {
+        format!("adlAlert.{}", coin)
+    }
+
+    /// Build system maintenance topic, served on the misc/status stream
+    pub fn system_status() -> String {
+        "system.status".to_string()
+    }
+
     // --- Private topics ---
 
     /// Private position topic
@@ -245,4 +304,166 @@ pub mod topics {
             "wallet.linear".to_string()
         }
     }
+
+    /// Spread trading topics
+    ///
+    /// `orderbook`, `trade` and `ticker` are served on the public spread
+    /// stream; `order` and `execution` on the private stream.
+    pub mod spread {
+        /// Spread orderbook: orderbook.25.{symbol}, the only depth offered
+        pub fn orderbook(symbol: &str) -> String {
+            format!("orderbook.25.{}", symbol)
+        }
+        pub fn trade(symbol: &str) -> String {
+            format!("publicTrade.{}", symbol)
+        }
+        pub fn ticker(symbol: &str) -> String {
+            format!("tickers.{}", symbol)
+        }
+        pub fn order() -> String {
+            "spread.order".to_string()
+        }
+        pub fn execution() -> String {
+            "spread.execution".to_string()
+        }
+    }
+
+    /// Private RFQ topics
+    pub mod rfq {
+        /// RFQs sent or received
+        pub fn rfqs() -> String {
+            "rfq.open.rfqs".to_string()
+        }
+        /// Quotes sent or received
+        pub fn quotes() -> String {
+            "rfq.open.quotes".to_string()
+        }
+        /// Own block trades
+        pub fn trades() -> String {
+            "rfq.open.trades".to_string()
+        }
+        /// All public block trades
+        pub fn public_trades() -> String {
+            "rfq.open.public.trades".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kline_topic_puts_interval_first() {
+        // Bybit's topic is kline.{interval}.{symbol}; the builder used to
+        // emit kline.{symbol}.{interval}, which the server rejects.
+        assert_eq!(topics::kline("5", "BTCUSDT"), "kline.5.BTCUSDT");
+        assert_eq!(topics::kline("D", "ETHUSDT"), "kline.D.ETHUSDT");
+    }
+
+    #[test]
+    fn test_topic_builders() {
+        assert_eq!(topics::all_liquidation("BTCUSDT"), "allLiquidation.BTCUSDT");
+        assert_eq!(topics::insurance("USDT"), "insurance.USDT");
+        assert_eq!(topics::rpi_orderbook("BTCUSDT"), "orderbook.rpi.BTCUSDT");
+    }
+
+    #[test]
+    fn test_op_responses_are_not_data() {
+        let sub: WsMessage = serde_json::from_str(
+            r#"{"success":true,"ret_msg":"","conn_id":"c1","req_id":"","op":"subscribe"}"#,
+        )
+        .unwrap();
+        assert!(sub.is_subscribe_success());
+
+        let pong: WsMessage = serde_json::from_str(
+            r#"{"req_id":"","op":"pong","args":["1675418560633"],"conn_id":"c1"}"#,
+        )
+        .unwrap();
+        assert!(pong.is_pong());
+
+        let data: WsMessage = serde_json::from_str(
+            r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[]}"#,
+        )
+        .unwrap();
+        assert_eq!(data.topic(), Some("publicTrade.BTCUSDT"));
+    }
+
+    #[test]
+    fn test_untagged_derive_misroutes_op_responses() {
+        // The previous `#[derive(Deserialize)] #[serde(untagged)]` tried
+        // `Data` first; all of `WsResponse`'s fields are optional, so every
+        // op response matched it and the `is_*` helpers never fired.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        #[allow(dead_code)]
+        enum Untagged {
+            Data(WsResponse),
+            Op(WsOpResponse),
+        }
+
+        let frame = r#"{"success":true,"ret_msg":"","conn_id":"c1","op":"auth"}"#;
+        let untagged: Untagged = serde_json::from_str(frame).unwrap();
+        assert!(matches!(untagged, Untagged::Data(_)));
+
+        let msg: WsMessage = serde_json::from_str(frame).unwrap();
+        assert!(msg.is_auth_success());
+        assert_eq!(msg.topic(), None);
+    }
+
+    #[test]
+    fn test_ws_message_round_trips_variant() {
+        let frames = [
+            r#"{"success":false,"ret_msg":"error:handler not found","op":"subscribe"}"#,
+            r#"{"topic":"tickers.BTCUSDT","type":"snapshot","ts":1673853746003,"data":{}}"#,
+        ];
+        for frame in frames {
+            let msg: WsMessage = serde_json::from_str(frame).unwrap();
+            let again: WsMessage =
+                serde_json::from_str(&serde_json::to_string(&msg).unwrap()).unwrap();
+            assert_eq!(
+                matches!(msg, WsMessage::Op(_)),
+                matches!(again, WsMessage::Op(_))
+            );
+        }
+    }
+
+    #[test]
+    fn test_op_response_fields() {
+        // Trade-stream replies use camelCase keys and carry retCode/data;
+        // subscribe/auth replies use snake_case and have neither.
+        let op: WsOpResponse = serde_json::from_str(
+            r#"{"reqId":"r2","retCode":10001,"retMsg":"params error","op":"order.amend","data":{},"connId":"c2"}"#,
+        )
+        .unwrap();
+        assert_eq!(op.req_id.as_deref(), Some("r2"));
+        assert_eq!(op.conn_id.as_deref(), Some("c2"));
+        assert_eq!(op.ret_msg.as_deref(), Some("params error"));
+        assert_eq!(op.ret_code, Some(10001));
+
+        let sub: WsOpResponse = serde_json::from_str(
+            r#"{"success":true,"ret_msg":"","conn_id":"c1","op":"subscribe"}"#,
+        )
+        .unwrap();
+        assert_eq!(sub.ret_code, None);
+        let json = serde_json::to_value(&sub).unwrap();
+        assert!(json.get("retCode").is_none());
+        assert!(json.get("data").is_none());
+    }
+
+    #[test]
+    fn test_trade_op_response() {
+        let msg: WsMessage = serde_json::from_str(
+            r#"{"reqId":"r1","retCode":0,"retMsg":"OK","op":"order.create","data":{"orderId":"o1","orderLinkId":""},"connId":"c1"}"#,
+        )
+        .unwrap();
+        match msg {
+            WsMessage::Op(op) => {
+                assert_eq!(op.req_id.as_deref(), Some("r1"));
+                assert_eq!(op.ret_code, Some(0));
+                assert_eq!(op.data.unwrap()["orderId"], "o1");
+            }
+            WsMessage::Data(_) => panic!("expected op response"),
+        }
+    }
 }
@@ -1,7 +1,8 @@
 //! WebSocket module for Bybit V5 real-time data streams.
 //!
 //! Provides connection management, authentication, and typed data
-//! structures for all Bybit WebSocket channels.
+//! structures for all Bybit WebSocket channels, plus a raw frame
+//! [`recorder`] and a [`replay`] source for offline reproduction.
 //!
 //! # Quick Start
 //!
@@ -30,12 +31,17 @@
 
 pub mod auth;
 pub mod client;
+mod connector;
 pub mod messages;
 pub mod private;
 pub mod public;
+pub mod recorder;
+pub mod replay;
 pub mod trade;
 
 // Re-export key types
 pub use auth::generate_auth_params;
-pub use client::WsClient;
+pub use client::{RawTap, WsClient};
 pub use messages::{topics, WsMessage, WsOpResponse, WsRequest, WsResponse};
+pub use recorder::{RawFrame, RecorderConfig, WsRecorder};
+pub use replay::{ReplaySpeed, ReplayStream, ReplayWsSource};
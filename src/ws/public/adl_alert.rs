@@ -0,0 +1,81 @@
+//! ADL alert stream — insurance pool levels that drive auto-deleveraging.
+//!
+//! # Topic
+//! `adlAlert.{coin}` — e.g. `adlAlert.USDT`, per insurance pool settle coin
+//!
+//! Same figures as `GET /v5/market/adlAlert`, pushed with abbreviated keys.
+
+use serde::Deserialize;
+
+/// ADL alert for one contract.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdlAlertData {
+    /// Settle coin of the insurance pool
+    #[serde(rename = "c", alias = "coin")]
+    #[serde(default)]
+    pub coin: Option<String>,
+    /// Contract name
+    #[serde(rename = "s", alias = "symbol")]
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Insurance pool balance
+    #[serde(rename = "b", alias = "balance")]
+    #[serde(default)]
+    pub balance: Option<String>,
+    /// Maximum pool balance in the last 8 hours
+    #[serde(rename = "mb", alias = "maxBalance")]
+    #[serde(default)]
+    pub max_balance: Option<String>,
+    /// PnL ratio that triggers ADL
+    #[serde(rename = "i_pr", alias = "insurancePnlRatio")]
+    #[serde(default)]
+    pub insurance_pnl_ratio: Option<String>,
+    /// Current PnL ratio of the pool
+    #[serde(rename = "pr", alias = "pnlRatio")]
+    #[serde(default)]
+    pub pnl_ratio: Option<String>,
+    /// Pool balance below which ADL may trigger
+    #[serde(rename = "adl_tt", alias = "adlTriggerThreshold")]
+    #[serde(default)]
+    pub adl_trigger_threshold: Option<String>,
+    /// PnL ratio at which ADL stops
+    #[serde(rename = "adl_sr", alias = "adlStopRatio")]
+    #[serde(default)]
+    pub adl_stop_ratio: Option<String>,
+}
+
+/// Typed wrapper for ADL alert stream data.
+pub struct AdlAlertStream;
+
+impl AdlAlertStream {
+    pub fn parse(data: &serde_json::Value) -> serde_json::Result<Vec<AdlAlertData>> {
+        serde_json::from_value(data.clone())
+    }
+
+    pub fn matches_topic(topic: &str) -> bool {
+        topic.starts_with("adlAlert.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_adl_alert() {
+        let json = serde_json::json!([{
+            "c": "USDT",
+            "s": "BTCUSDT",
+            "b": "73412365.12",
+            "mb": "73412365.12",
+            "i_pr": "0.01",
+            "pr": "-0.02",
+            "adl_tt": "10000",
+            "adl_sr": "-0.3"
+        }]);
+
+        let alerts = AdlAlertStream::parse(&json).unwrap();
+        assert_eq!(alerts[0].symbol.as_deref(), Some("BTCUSDT"));
+        assert_eq!(alerts[0].adl_stop_ratio.as_deref(), Some("-0.3"));
+    }
+}
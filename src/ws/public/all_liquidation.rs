@@ -0,0 +1,73 @@
+//! All-liquidation stream — every forced liquidation on a symbol.
+//!
+//! Replaces the deprecated single-order `liquidation.{symbol}` topic, which
+//! only pushed one liquidation per second.
+//!
+//! # Topic
+//! `allLiquidation.{symbol}` — e.g. `allLiquidation.BTCUSDT`
+
+use serde::Deserialize;
+
+/// A single liquidated order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AllLiquidationData {
+    /// Updated timestamp in ms
+    #[serde(rename = "T")]
+    #[serde(default)]
+    pub updated_time: Option<i64>,
+    /// Symbol
+    #[serde(rename = "s")]
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Position side: "Buy" means a long position was liquidated
+    #[serde(rename = "S")]
+    #[serde(default)]
+    pub side: Option<String>,
+    /// Executed size
+    #[serde(rename = "v")]
+    #[serde(default)]
+    pub size: Option<String>,
+    /// Bankruptcy price
+    #[serde(rename = "p")]
+    #[serde(default)]
+    pub price: Option<String>,
+}
+
+/// Typed wrapper for all-liquidation stream data.
+///
+/// Bybit sends an array of liquidations in each message.
+pub struct AllLiquidationStream;
+
+impl AllLiquidationStream {
+    pub fn parse(data: &serde_json::Value) -> serde_json::Result<Vec<AllLiquidationData>> {
+        serde_json::from_value(data.clone())
+    }
+
+    pub fn matches_topic(topic: &str) -> bool {
+        topic.starts_with("allLiquidation.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_all_liquidation() {
+        let json = serde_json::json!([{
+            "T": 1739502302929_i64,
+            "s": "ROSEUSDT",
+            "S": "Sell",
+            "v": "20000",
+            "p": "0.04499"
+        }]);
+
+        let list = AllLiquidationStream::parse(&json).unwrap();
+        assert_eq!(list[0].symbol.as_deref(), Some("ROSEUSDT"));
+        assert_eq!(list[0].price.as_deref(), Some("0.04499"));
+        assert!(AllLiquidationStream::matches_topic(
+            "allLiquidation.ROSEUSDT"
+        ));
+        assert!(!AllLiquidationStream::matches_topic("liquidation.ROSEUSDT"));
+    }
+}
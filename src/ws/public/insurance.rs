@@ -0,0 +1,73 @@
+//! Insurance pool stream — balance changes of the insurance fund.
+//!
+//! # Topic
+//! `insurance.{coin}` — e.g. `insurance.USDT` for USDT contracts,
+//! `insurance.USDC` for USDC contracts, `insurance.inverse` for inverse.
+//!
+//! Isolated pools are pushed per symbol; shared pools list every symbol
+//! they cover in `symbols`.
+
+use serde::Deserialize;
+
+/// Insurance pool balance update.
+#[derive(Debug, Clone, Deserialize)]
+pub struct InsuranceData {
+    /// Settle coin of the pool
+    #[serde(rename = "coin")]
+    #[serde(default)]
+    pub coin: Option<String>,
+    /// Comma-separated symbols sharing the pool
+    #[serde(rename = "symbols")]
+    #[serde(default)]
+    pub symbols: Option<String>,
+    /// Pool balance
+    #[serde(rename = "balance")]
+    #[serde(default)]
+    pub balance: Option<String>,
+    /// Updated timestamp in ms
+    #[serde(rename = "updateTime")]
+    #[serde(default)]
+    pub update_time: Option<String>,
+}
+
+impl InsuranceData {
+    /// Symbols covered by this pool.
+    pub fn symbol_list(&self) -> Vec<&str> {
+        self.symbols
+            .as_deref()
+            .map(|s| s.split(',').filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Typed wrapper for insurance pool stream data.
+pub struct InsuranceStream;
+
+impl InsuranceStream {
+    pub fn parse(data: &serde_json::Value) -> serde_json::Result<Vec<InsuranceData>> {
+        serde_json::from_value(data.clone())
+    }
+
+    pub fn matches_topic(topic: &str) -> bool {
+        topic.starts_with("insurance.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_insurance() {
+        let json = serde_json::json!([{
+            "coin": "USDT",
+            "symbols": "GRIFFAINUSDT,BTCUSDT",
+            "balance": "25193.22440897",
+            "updateTime": "1739502302929"
+        }]);
+
+        let pools = InsuranceStream::parse(&json).unwrap();
+        assert_eq!(pools[0].symbol_list(), vec!["GRIFFAINUSDT", "BTCUSDT"]);
+        assert!(InsuranceStream::matches_topic("insurance.USDT"));
+    }
+}
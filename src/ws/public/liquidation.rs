@@ -1,5 +1,8 @@
 //! Liquidation stream — real-time forced liquidation orders.
 //!
+//! Deprecated by Bybit in favour of `allLiquidation.{symbol}`
+//! (see [`super::all_liquidation`]), which pushes every liquidation.
+//!
 //! # Topic
 //! `liquidation.{symbol}` — e.g. `liquidation.BTCUSDT`
 
@@ -3,14 +3,26 @@
 //! Each channel provides a typed stream wrapper that yields
 //! deserialized data structures for real-time market data.
 
+pub mod adl_alert;
+pub mod all_liquidation;
+pub mod insurance;
 pub mod kline;
 pub mod liquidation;
 pub mod orderbook;
+pub mod price_limit;
+pub mod rpi_orderbook;
+pub mod system_status;
 pub mod ticker;
 pub mod trade;
 
+pub use adl_alert::{AdlAlertData, AdlAlertStream};
+pub use all_liquidation::{AllLiquidationData, AllLiquidationStream};
+pub use insurance::{InsuranceData, InsuranceStream};
 pub use kline::{KlineData, KlineStream};
 pub use liquidation::{LiquidationData, LiquidationStream};
 pub use orderbook::{OrderBookData, OrderBookStream};
+pub use price_limit::{PriceLimitData, PriceLimitStream};
+pub use rpi_orderbook::{RpiOrderBookData, RpiOrderBookLevel, RpiOrderBookStream};
+pub use system_status::SystemStatusStream;
 pub use ticker::{TickerData, TickerStream};
 pub use trade::{PublicTrade, TradeStream};
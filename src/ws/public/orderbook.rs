@@ -12,6 +12,7 @@
 //! - Spot: 1, 50, 200, 1000
 //! - Option: 25, 100
 
+use crate::rest::enums::category::Category;
 use serde::Deserialize;
 
 /// A single price level in the orderbook.
@@ -49,7 +50,11 @@ pub struct OrderBookData {
     #[serde(default)]
     pub cts: Option<u64>,
     /// Message type: "snapshot" or "delta"
+    ///
+    /// Bybit sends the type on the message envelope (`WsResponse::msg_type`),
+    /// not inside `data`; this is only set when it is embedded there.
     #[serde(rename = "type")]
+    #[serde(default)]
     pub msg_type: String,
 }
 
@@ -69,8 +74,18 @@ impl OrderBookStream {
     }
 
     /// Check if the given topic matches an orderbook channel.
+    ///
+    /// RPI orderbooks have three values per level; see `RpiOrderBookStream`.
     pub fn matches_topic(topic: &str) -> bool {
-        topic.starts_with("orderbook.")
+        topic.starts_with("orderbook.") && !topic.starts_with("orderbook.rpi.")
+    }
+
+    /// Depths Bybit serves for `category`.
+    pub fn depths(category: Category) -> &'static [u16] {
+        match category {
+            Category::Option => &[25, 100],
+            Category::Spot | Category::Linear | Category::Inverse => &[1, 50, 200, 1000],
+        }
     }
 }
 
@@ -78,6 +93,13 @@ impl OrderBookStream {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_depths() {
+        assert!(OrderBookStream::depths(Category::Linear).contains(&50));
+        assert!(!OrderBookStream::depths(Category::Option).contains(&50));
+        assert_eq!(OrderBookStream::depths(Category::Option), &[25, 100]);
+    }
+
     #[test]
     fn test_parse_snapshot() {
         let json = serde_json::json!({
@@ -115,8 +137,24 @@ mod tests {
         assert_eq!(data.msg_type, "delta");
     }
 
+    #[test]
+    fn test_parse_without_embedded_type() {
+        let json = serde_json::json!({
+            "s": "BTCUSDT",
+            "b": [["50000.00", "1.5"]],
+            "a": [],
+            "u": 7,
+            "seq": 107
+        });
+
+        let data = OrderBookStream::parse(&json).unwrap();
+        assert_eq!(data.update_id, 7);
+        assert_eq!(data.msg_type, "");
+    }
+
     #[test]
     fn test_matches_topic() {
+        assert!(!OrderBookStream::matches_topic("orderbook.rpi.BTCUSDT"));
         assert!(OrderBookStream::matches_topic("orderbook.1.BTCUSDT"));
         assert!(OrderBookStream::matches_topic("orderbook.50.ETHUSDT"));
         assert!(!OrderBookStream::matches_topic("publicTrade.BTCUSDT"));
@@ -0,0 +1,36 @@
+//! Order price limit stream — the current buy/sell price bounds of a symbol.
+//!
+//! # Topic
+//! `priceLimit.{symbol}` — e.g. `priceLimit.BTCUSDT`
+
+use serde::Deserialize;
+
+/// Order price limits for a symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceLimitData {
+    /// Symbol
+    #[serde(rename = "symbol")]
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Highest price a buy order may be placed at
+    #[serde(rename = "buyLmt")]
+    #[serde(default)]
+    pub buy_lmt: Option<String>,
+    /// Lowest price a sell order may be placed at
+    #[serde(rename = "sellLmt")]
+    #[serde(default)]
+    pub sell_lmt: Option<String>,
+}
+
+/// Typed wrapper for price limit stream data.
+pub struct PriceLimitStream;
+
+impl PriceLimitStream {
+    pub fn parse(data: &serde_json::Value) -> serde_json::Result<PriceLimitData> {
+        serde_json::from_value(data.clone())
+    }
+
+    pub fn matches_topic(topic: &str) -> bool {
+        topic.starts_with("priceLimit.")
+    }
+}
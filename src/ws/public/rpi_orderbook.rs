@@ -0,0 +1,79 @@
+//! RPI orderbook stream — depth split into regular and RPI liquidity.
+//!
+//! Retail Price Improvement (RPI) orders only match against retail flow.
+//! Each level carries the non-RPI and RPI size separately.
+//!
+//! # Topic
+//! `orderbook.rpi.{symbol}` — e.g. `orderbook.rpi.BTCUSDT`, 50 levels
+//!
+//! As with the regular orderbook, a `snapshot` is followed by `delta`
+//! updates; the type is on the message envelope.
+
+use serde::Deserialize;
+
+/// A single RPI orderbook level.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RpiOrderBookLevel {
+    /// Price
+    pub price: String,
+    /// Size of regular (non-RPI) orders
+    pub non_rpi_size: String,
+    /// Size of RPI orders
+    pub rpi_size: String,
+}
+
+/// RPI orderbook snapshot or delta.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RpiOrderBookData {
+    /// Symbol
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Bids: [price, non-RPI size, RPI size]
+    #[serde(rename = "b")]
+    pub bids: Vec<RpiOrderBookLevel>,
+    /// Asks: [price, non-RPI size, RPI size]
+    #[serde(rename = "a")]
+    pub asks: Vec<RpiOrderBookLevel>,
+    /// Update ID
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    /// Cross sequence
+    #[serde(rename = "seq")]
+    #[serde(default)]
+    pub seq: Option<u64>,
+}
+
+/// Typed wrapper for RPI orderbook stream data.
+pub struct RpiOrderBookStream;
+
+impl RpiOrderBookStream {
+    pub fn parse(data: &serde_json::Value) -> serde_json::Result<RpiOrderBookData> {
+        serde_json::from_value(data.clone())
+    }
+
+    pub fn matches_topic(topic: &str) -> bool {
+        topic.starts_with("orderbook.rpi.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rpi_orderbook() {
+        let json = serde_json::json!({
+            "s": "BTCUSDT",
+            "b": [["116760.3", "2.331", "0.127"]],
+            "a": [["116760.4", "3.018", "0"]],
+            "u": 42390184,
+            "seq": 81298710374_u64,
+            "cts": 1758183221328_i64
+        });
+
+        let book = RpiOrderBookStream::parse(&json).unwrap();
+        assert_eq!(book.bids[0].rpi_size, "0.127");
+        assert_eq!(book.asks[0].non_rpi_size, "3.018");
+        assert!(RpiOrderBookStream::matches_topic("orderbook.rpi.BTCUSDT"));
+    }
+}
@@ -0,0 +1,23 @@
+//! System status stream — platform maintenance announcements.
+//!
+//! # Topic
+//! `system.status` on `wss://stream.bybit.com/v5/public/misc/status`
+//!
+//! Each push carries the same entries as `GET /v5/system/status`, so they
+//! parse into [`SystemMaintenance`] and can be fed to a
+//! [`MaintenanceGuard`](crate::maintenance::MaintenanceGuard).
+
+use crate::rest::announcements::dto::SystemMaintenance;
+
+pub struct SystemStatusStream;
+
+impl SystemStatusStream {
+    /// Parse raw WS data into the announced maintenance windows.
+    pub fn parse(data: &serde_json::Value) -> serde_json::Result<Vec<SystemMaintenance>> {
+        serde_json::from_value(data.clone())
+    }
+
+    pub fn matches_topic(topic: &str) -> bool {
+        topic == "system.status"
+    }
+}
@@ -3,7 +3,13 @@
 //! # Topic format
 //! `tickers.{symbol}` — e.g. `tickers.BTCUSDT`
 //!
-//! Works for all categories: linear, inverse, spot, option.
+//! Works for all categories: linear, inverse, spot, option. The shapes
+//! differ per category and all map onto [`TickerData`]:
+//! - linear/inverse: derivatives fields (funding, open interest, ...);
+//!   `delta` pushes only carry the fields that changed
+//! - spot: 24h stats plus `usdIndexPrice`, always a snapshot
+//! - option: `bidPrice`/`askPrice` (mapped to `bid1_price`/`ask1_price`),
+//!   implied volatilities and greeks
 
 use serde::Deserialize;
 
@@ -66,14 +72,78 @@ pub struct TickerData {
     #[serde(rename = "nextFundingTime")]
     #[serde(default)]
     pub next_funding_time: Option<String>,
-    /// Best bid price
-    #[serde(rename = "bid1Price")]
+    /// Best bid price (`bidPrice` for options)
+    #[serde(rename = "bid1Price", alias = "bidPrice")]
     #[serde(default)]
     pub bid1_price: Option<String>,
-    /// Best ask price
-    #[serde(rename = "ask1Price")]
+    /// Best bid size (`bidSize` for options)
+    #[serde(rename = "bid1Size", alias = "bidSize")]
+    #[serde(default)]
+    pub bid1_size: Option<String>,
+    /// Best ask price (`askPrice` for options)
+    #[serde(rename = "ask1Price", alias = "askPrice")]
     #[serde(default)]
     pub ask1_price: Option<String>,
+    /// Best ask size (`askSize` for options)
+    #[serde(rename = "ask1Size", alias = "askSize")]
+    #[serde(default)]
+    pub ask1_size: Option<String>,
+    /// Tick direction (derivatives)
+    #[serde(rename = "tickDirection")]
+    #[serde(default)]
+    pub tick_direction: Option<String>,
+    /// USD index price (spot)
+    #[serde(rename = "usdIndexPrice")]
+    #[serde(default)]
+    pub usd_index_price: Option<String>,
+    /// Best bid implied volatility (option)
+    #[serde(rename = "bidIv")]
+    #[serde(default)]
+    pub bid_iv: Option<String>,
+    /// Best ask implied volatility (option)
+    #[serde(rename = "askIv")]
+    #[serde(default)]
+    pub ask_iv: Option<String>,
+    /// Mark price implied volatility (option)
+    #[serde(rename = "markPriceIv")]
+    #[serde(default)]
+    pub mark_price_iv: Option<String>,
+    /// Underlying price (option)
+    #[serde(rename = "underlyingPrice")]
+    #[serde(default)]
+    pub underlying_price: Option<String>,
+    /// Total volume (option)
+    #[serde(rename = "totalVolume")]
+    #[serde(default)]
+    pub total_volume: Option<String>,
+    /// Total turnover (option)
+    #[serde(rename = "totalTurnover")]
+    #[serde(default)]
+    pub total_turnover: Option<String>,
+    /// Delta (option)
+    #[serde(rename = "delta")]
+    #[serde(default)]
+    pub delta: Option<String>,
+    /// Gamma (option)
+    #[serde(rename = "gamma")]
+    #[serde(default)]
+    pub gamma: Option<String>,
+    /// Vega (option)
+    #[serde(rename = "vega")]
+    #[serde(default)]
+    pub vega: Option<String>,
+    /// Theta (option)
+    #[serde(rename = "theta")]
+    #[serde(default)]
+    pub theta: Option<String>,
+    /// Predicted delivery price (option, dated futures)
+    #[serde(rename = "predictedDeliveryPrice")]
+    #[serde(default)]
+    pub predicted_delivery_price: Option<String>,
+    /// 24hr price change (option)
+    #[serde(rename = "change24h")]
+    #[serde(default)]
+    pub change_24h: Option<String>,
     /// Category: "spot", "linear", "inverse", "option"
     #[serde(rename = "category")]
     #[serde(default)]
@@ -118,6 +188,56 @@ mod tests {
         assert_eq!(ticker.category.as_deref(), Some("linear"));
     }
 
+    #[test]
+    fn test_parse_spot_ticker() {
+        let json = serde_json::json!({
+            "symbol": "BTCUSDT",
+            "lastPrice": "21109.77",
+            "highPrice24h": "21426.99",
+            "lowPrice24h": "20575",
+            "prevPrice24h": "20704.93",
+            "volume24h": "6780.866843",
+            "turnover24h": "141946527.22907118",
+            "price24hPcnt": "0.0196",
+            "usdIndexPrice": "21120.2400136"
+        });
+
+        let ticker = TickerStream::parse(&json).unwrap();
+        assert_eq!(ticker.usd_index_price.as_deref(), Some("21120.2400136"));
+        assert!(ticker.mark_price.is_none());
+    }
+
+    #[test]
+    fn test_parse_option_ticker() {
+        let json = serde_json::json!({
+            "symbol": "BTC-6JAN23-17500-C",
+            "bidPrice": "0",
+            "bidSize": "0",
+            "bidIv": "0",
+            "askPrice": "10",
+            "askSize": "5.1",
+            "askIv": "0.514",
+            "lastPrice": "10",
+            "markPrice": "7.86976724",
+            "indexPrice": "16823.73",
+            "markPriceIv": "0.4896",
+            "underlyingPrice": "16815.1",
+            "openInterest": "8.85",
+            "totalVolume": "89",
+            "delta": "0.00897014",
+            "gamma": "0.00015545",
+            "vega": "0.39308426",
+            "theta": "-2.47101766",
+            "change24h": "-0.08108108"
+        });
+
+        let ticker = TickerStream::parse(&json).unwrap();
+        assert_eq!(ticker.ask1_price.as_deref(), Some("10"));
+        assert_eq!(ticker.ask1_size.as_deref(), Some("5.1"));
+        assert_eq!(ticker.mark_price_iv.as_deref(), Some("0.4896"));
+        assert_eq!(ticker.delta.as_deref(), Some("0.00897014"));
+    }
+
     #[test]
     fn test_matches_topic() {
         assert!(TickerStream::matches_topic("tickers.BTCUSDT"));
@@ -0,0 +1,391 @@
+//! Raw WebSocket frame recorder.
+//!
+//! [`WsRecorder`] writes every frame it is given to NDJSON files, one
+//! [`RawFrame`] per line, gzip-compressed by default and rotated by size
+//! and age. Attach it to any number of [`WsClient`](crate::ws::WsClient)s
+//! with [`WsRecorder::attach`]; the files can be read back with
+//! [`read_frames`] or replayed with
+//! [`ReplayWsSource`](crate::ws::replay::ReplayWsSource).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use bybit_rust_api::ws::recorder::{RecorderConfig, WsRecorder};
+//!
+//! let recorder = WsRecorder::start(RecorderConfig::new("data/linear"))?;
+//! let client = WsClient::connect(WS_LINEAR_MAINNET).await?;
+//! recorder.attach(&client);
+//! client.subscribe(vec![topics::orderbook(50, "BTCUSDT")]).await?;
+//! // ...
+//! let files = recorder.finish()?;
+//! ```
+//!
+//! # File format
+//!
+//! ```json
+//! {"recv_ts":1672304486868,"conn_id":"9f1c...","url":"wss://stream.bybit.com/v5/public/linear","frame":"{\"topic\":\"publicTrade.BTCUSDT\",...}"}
+//! ```
+
+use crate::rest::errors::{BybitError, BybitResult};
+use crate::ws::client::WsClient;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// How often buffered frames are flushed while the stream is idle.
+const FLUSH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// One text frame as received from the exchange.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RawFrame {
+    /// Local receive time (ms since the Unix epoch)
+    pub recv_ts: i64,
+    /// Id of the (re)connection that delivered the frame
+    pub conn_id: String,
+    /// Endpoint the connection was opened to
+    pub url: String,
+    /// The frame text, unparsed
+    pub frame: String,
+}
+
+/// Where and how a [`WsRecorder`] writes.
+#[derive(Debug, Clone)]
+pub struct RecorderConfig {
+    pub(crate) dir: PathBuf,
+    pub(crate) prefix: String,
+    pub(crate) compress: bool,
+    pub(crate) max_file_bytes: Option<u64>,
+    pub(crate) max_file_age: Option<Duration>,
+}
+
+impl RecorderConfig {
+    /// Record into `dir` as `bybit-<timestamp>-<n>.ndjson.gz`, rotating
+    /// every 256 MiB (uncompressed) or every hour.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        RecorderConfig {
+            dir: dir.into(),
+            prefix: "bybit".to_string(),
+            compress: true,
+            max_file_bytes: Some(256 * 1024 * 1024),
+            max_file_age: Some(Duration::from_secs(3600)),
+        }
+    }
+
+    /// File name prefix.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Gzip the files (default) or write plain `.ndjson`.
+    pub fn with_compression(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Start a new file after this many uncompressed bytes (`None`: never).
+    pub fn with_max_file_bytes(mut self, bytes: Option<u64>) -> Self {
+        self.max_file_bytes = bytes;
+        self
+    }
+
+    /// Start a new file after this long (`None`: never).
+    pub fn with_max_file_age(mut self, age: Option<Duration>) -> Self {
+        self.max_file_age = age;
+        self
+    }
+}
+
+/// Background writer for [`RawFrame`]s.
+///
+/// Frames are handed to a dedicated thread over a channel, so taps never
+/// block the connection task on file I/O.
+pub struct WsRecorder {
+    tx: mpsc::Sender<RawFrame>,
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<BybitResult<Vec<PathBuf>>>,
+}
+
+impl WsRecorder {
+    /// Create the output directory and start the writer thread.
+    pub fn start(config: RecorderConfig) -> BybitResult<Self> {
+        std::fs::create_dir_all(&config.dir).map_err(|e| {
+            BybitError::Config(format!(
+                "Cannot create recording directory {}: {}",
+                config.dir.display(),
+                e
+            ))
+        })?;
+        let (tx, rx) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let handle = std::thread::Builder::new()
+            .name("bybit-ws-recorder".to_string())
+            .spawn(move || write_loop(config, rx, thread_stop))
+            .map_err(|e| BybitError::Internal(format!("Cannot start recorder: {}", e)))?;
+        Ok(WsRecorder { tx, stop, handle })
+    }
+
+    /// Record every frame `client` receives from now on.
+    pub fn attach(&self, client: &WsClient) {
+        client.add_raw_tap(self.sink());
+    }
+
+    /// A tap that forwards frames to this recorder.
+    pub fn sink(&self) -> impl Fn(&RawFrame) + Send + Sync + 'static {
+        let tx = std::sync::Mutex::new(self.tx.clone());
+        let stop = self.stop.clone();
+        move |frame: &RawFrame| {
+            if stop.load(Ordering::SeqCst) {
+                return;
+            }
+            if let Ok(tx) = tx.lock() {
+                let _ = tx.send(frame.clone());
+            }
+        }
+    }
+
+    /// Record a frame directly.
+    pub fn record(&self, frame: RawFrame) {
+        let _ = self.tx.send(frame);
+    }
+
+    /// Write out everything received so far, close the current file and
+    /// return the paths of all files written.
+    ///
+    /// Frames arriving from attached clients after this are dropped.
+    pub fn finish(self) -> BybitResult<Vec<PathBuf>> {
+        self.stop.store(true, Ordering::SeqCst);
+        drop(self.tx);
+        self.handle
+            .join()
+            .map_err(|_| BybitError::Internal("Recorder thread panicked".to_string()))?
+    }
+}
+
+/// The file currently being written.
+struct Segment {
+    writer: SegmentWriter,
+    opened: Instant,
+    bytes: u64,
+}
+
+enum SegmentWriter {
+    Gzip(GzEncoder<BufWriter<File>>),
+    Plain(BufWriter<File>),
+}
+
+impl SegmentWriter {
+    fn writer(&mut self) -> &mut dyn Write {
+        match self {
+            SegmentWriter::Gzip(w) => w,
+            SegmentWriter::Plain(w) => w,
+        }
+    }
+
+    /// Flush, writing the gzip trailer if compressed.
+    fn finish(self) -> std::io::Result<()> {
+        match self {
+            SegmentWriter::Gzip(w) => w.finish()?.flush(),
+            SegmentWriter::Plain(mut w) => w.flush(),
+        }
+    }
+}
+
+fn write_loop(
+    config: RecorderConfig,
+    rx: mpsc::Receiver<RawFrame>,
+    stop: Arc<AtomicBool>,
+) -> BybitResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut segment: Option<Segment> = None;
+
+    loop {
+        // Once stopped, write whatever is already queued and exit
+        let frame = if stop.load(Ordering::SeqCst) {
+            match rx.try_recv() {
+                Ok(frame) => frame,
+                Err(_) => break,
+            }
+        } else {
+            match rx.recv_timeout(FLUSH_INTERVAL) {
+                Ok(frame) => frame,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if let Some(ref mut s) = segment {
+                        s.writer.writer().flush().map_err(io_error)?;
+                    }
+                    continue;
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        };
+
+        let rotate = segment.as_ref().is_some_and(|s| {
+            config.max_file_bytes.is_some_and(|max| s.bytes >= max)
+                || config
+                    .max_file_age
+                    .is_some_and(|age| s.opened.elapsed() >= age)
+        });
+        if rotate {
+            if let Some(s) = segment.take() {
+                s.writer.finish().map_err(io_error)?;
+            }
+        }
+        let current = match segment {
+            Some(ref mut s) => s,
+            None => {
+                let path = next_path(&config, files.len());
+                let file = File::create(&path).map_err(io_error)?;
+                files.push(path);
+                let writer = if config.compress {
+                    SegmentWriter::Gzip(GzEncoder::new(BufWriter::new(file), Compression::fast()))
+                } else {
+                    SegmentWriter::Plain(BufWriter::new(file))
+                };
+                segment.insert(Segment {
+                    writer,
+                    opened: Instant::now(),
+                    bytes: 0,
+                })
+            }
+        };
+
+        let mut line = serde_json::to_vec(&frame)?;
+        line.push(b'\n');
+        current.writer.writer().write_all(&line).map_err(io_error)?;
+        current.bytes += line.len() as u64;
+    }
+
+    if let Some(s) = segment.take() {
+        s.writer.finish().map_err(io_error)?;
+    }
+    Ok(files)
+}
+
+fn next_path(config: &RecorderConfig, n: usize) -> PathBuf {
+    let extension = if config.compress {
+        "ndjson.gz"
+    } else {
+        "ndjson"
+    };
+    config.dir.join(format!(
+        "{}-{}-{:04}.{}",
+        config.prefix,
+        chrono::Utc::now().format("%Y%m%dT%H%M%S"),
+        n,
+        extension
+    ))
+}
+
+fn io_error(e: std::io::Error) -> BybitError {
+    BybitError::Internal(format!("Recorder I/O error: {}", e))
+}
+
+/// Read the frames of one recording file (`.gz` files are decompressed).
+pub fn read_frames(path: &Path) -> BybitResult<impl Iterator<Item = BybitResult<RawFrame>>> {
+    let file = File::open(path).map_err(|e| {
+        BybitError::Config(format!("Cannot open recording {}: {}", path.display(), e))
+    })?;
+    let reader: Box<dyn BufRead + Send> = if path.extension().is_some_and(|e| e == "gz") {
+        Box::new(BufReader::new(GzDecoder::new(file)))
+    } else {
+        Box::new(BufReader::new(file))
+    };
+    Ok(reader
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |l| !l.trim().is_empty()))
+        .map(|line| Ok(serde_json::from_str(&line.map_err(io_error)?)?)))
+}
+
+/// Recording files in `dir` (`.ndjson` / `.ndjson.gz`), in name order,
+/// which is recording order for files written by [`WsRecorder`].
+pub fn recording_files(dir: &Path) -> BybitResult<Vec<PathBuf>> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        BybitError::Config(format!(
+            "Cannot read recording directory {}: {}",
+            dir.display(),
+            e
+        ))
+    })?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            let name = p.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            name.ends_with(".ndjson") || name.ends_with(".ndjson.gz")
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(ts: i64, n: usize) -> RawFrame {
+        RawFrame {
+            recv_ts: ts,
+            conn_id: "c1".to_string(),
+            url: "wss://stream.bybit.com/v5/public/linear".to_string(),
+            frame: format!(
+                r#"{{"topic":"publicTrade.BTCUSDT","ts":{},"data":[{{"i":"{}"}}]}}"#,
+                ts, n
+            ),
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("bybit-recorder-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_record_and_read_back_gzip() {
+        let dir = temp_dir("gzip");
+        let recorder = WsRecorder::start(RecorderConfig::new(&dir)).unwrap();
+        let sink = recorder.sink();
+        for n in 0..10 {
+            sink(&frame(1_000 + n as i64, n));
+        }
+        let files = recorder.finish().unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].to_string_lossy().ends_with(".ndjson.gz"));
+
+        let frames: Vec<RawFrame> = read_frames(&files[0])
+            .unwrap()
+            .collect::<BybitResult<_>>()
+            .unwrap();
+        assert_eq!(frames.len(), 10);
+        assert_eq!(frames[3], frame(1_003, 3));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rotation_by_size() {
+        let dir = temp_dir("rotate");
+        let config = RecorderConfig::new(&dir)
+            .with_compression(false)
+            .with_max_file_bytes(Some(400));
+        let recorder = WsRecorder::start(config).unwrap();
+        for n in 0..10 {
+            recorder.record(frame(n as i64, n));
+        }
+        let files = recorder.finish().unwrap();
+        assert!(files.len() > 1);
+        assert_eq!(recording_files(&dir).unwrap(), files);
+
+        let total: usize = files.iter().map(|f| read_frames(f).unwrap().count()).sum();
+        assert_eq!(total, 10);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
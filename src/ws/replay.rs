@@ -0,0 +1,274 @@
+//! Deterministic replay of recorded WebSocket frames.
+//!
+//! [`ReplayWsSource`] reads files written by
+//! [`WsRecorder`](crate::ws::recorder::WsRecorder) and yields the frames as
+//! [`WsMessage`]s, in recording order, through the same `Stream` interface
+//! as [`WsClient`](crate::ws::WsClient). Code consuming a live client can
+//! consume a replay unchanged, without network access.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use bybit_rust_api::ws::replay::{ReplaySpeed, ReplayWsSource};
+//! use bybit_rust_api::ws::public::OrderBookStream;
+//! use futures_util::StreamExt;
+//!
+//! let mut replay = ReplayWsSource::from_dir("data/linear")?
+//!     .with_speed(ReplaySpeed::Accelerated(10.0))
+//!     .with_topics(vec!["orderbook.50.BTCUSDT".to_string()])
+//!     .start()?;
+//! while let Some(msg) = replay.next().await {
+//!     // same handling as for a live WsClient
+//! }
+//! ```
+
+use crate::rest::errors::BybitResult;
+use crate::ws::messages::WsMessage;
+use crate::ws::recorder::{read_frames, recording_files, RawFrame};
+use futures_util::Stream;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Messages buffered between the reader thread and the consumer.
+const REPLAY_BUFFER: usize = 1024;
+
+/// How fast recorded time passes during a replay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// Keep the recorded gaps between frames
+    RealTime,
+    /// Recorded gaps divided by the factor (2.0 = twice as fast)
+    Accelerated(f64),
+    /// No pauses at all
+    Max,
+}
+
+/// A recording, ready to be replayed.
+#[derive(Debug, Clone)]
+pub struct ReplayWsSource {
+    files: Vec<PathBuf>,
+    speed: ReplaySpeed,
+    topics: Option<Vec<String>>,
+}
+
+impl ReplayWsSource {
+    /// Replay the given files, in the order given.
+    pub fn from_files(files: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        ReplayWsSource {
+            files: files.into_iter().map(Into::into).collect(),
+            speed: ReplaySpeed::Max,
+            topics: None,
+        }
+    }
+
+    /// Replay every recording file in `dir`, in name order.
+    pub fn from_dir(dir: impl AsRef<Path>) -> BybitResult<Self> {
+        Ok(Self::from_files(recording_files(dir.as_ref())?))
+    }
+
+    /// Set the replay speed (default: [`ReplaySpeed::Max`]).
+    pub fn with_speed(mut self, speed: ReplaySpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Only yield data messages for these topics (op responses are dropped
+    /// as well).
+    pub fn with_topics(mut self, topics: Vec<String>) -> Self {
+        self.topics = Some(topics);
+        self
+    }
+
+    /// Start replaying on a background thread.
+    ///
+    /// Fails if the reader thread cannot be spawned.
+    pub fn start(self) -> io::Result<ReplayStream> {
+        let (tx, rx) = mpsc::channel(REPLAY_BUFFER);
+        let handle = std::thread::Builder::new()
+            .name("bybit-ws-replay".to_string())
+            .spawn(move || self.run(tx))?;
+        Ok(ReplayStream {
+            rx,
+            _handle: handle,
+        })
+    }
+
+    /// Every frame of the recording, in order, without pacing or parsing.
+    pub fn frames(&self) -> impl Iterator<Item = BybitResult<RawFrame>> + '_ {
+        self.files
+            .iter()
+            .flat_map(|path| -> Box<dyn Iterator<Item = _>> {
+                match read_frames(path) {
+                    Ok(frames) => Box::new(frames),
+                    Err(e) => Box::new(std::iter::once(Err(e))),
+                }
+            })
+    }
+
+    fn run(self, tx: mpsc::Sender<WsMessage>) {
+        let mut clock = ReplayClock::new(self.speed);
+        for frame in self.frames() {
+            let frame = match frame {
+                Ok(frame) => frame,
+                Err(e) => {
+                    log::error!("Replay stopped: {}", e);
+                    return;
+                }
+            };
+            let msg = match serde_json::from_str::<WsMessage>(&frame.frame) {
+                Ok(msg) => msg,
+                Err(e) => {
+                    log::warn!(
+                        "Failed to parse recorded frame: {} -- raw: {}",
+                        e,
+                        frame.frame
+                    );
+                    continue;
+                }
+            };
+            if let Some(ref topics) = self.topics {
+                if !msg.topic().is_some_and(|t| topics.iter().any(|x| x == t)) {
+                    continue;
+                }
+            }
+            if let Some(delay) = clock.delay(frame.recv_ts) {
+                std::thread::sleep(delay);
+            }
+            if tx.blocking_send(msg).is_err() {
+                return; // consumer dropped
+            }
+        }
+    }
+}
+
+/// Maps recorded receive times onto wall-clock time.
+struct ReplayClock {
+    speed: ReplaySpeed,
+    start: Option<(i64, Instant)>,
+}
+
+impl ReplayClock {
+    fn new(speed: ReplaySpeed) -> Self {
+        ReplayClock { speed, start: None }
+    }
+
+    /// How long to wait before emitting a frame received at `recv_ts`.
+    fn delay(&mut self, recv_ts: i64) -> Option<Duration> {
+        self.delay_at(recv_ts, Instant::now())
+    }
+
+    /// [`delay`](Self::delay) as seen at `now`; `None` if the frame is due.
+    fn delay_at(&mut self, recv_ts: i64, now: Instant) -> Option<Duration> {
+        let factor = match self.speed {
+            ReplaySpeed::Max => return None,
+            ReplaySpeed::RealTime => 1.0,
+            ReplaySpeed::Accelerated(factor) if factor > 0.0 => factor,
+            ReplaySpeed::Accelerated(_) => return None,
+        };
+        let (first_ts, started) = *self.start.get_or_insert((recv_ts, now));
+        let offset_ms = (recv_ts - first_ts).max(0) as f64 / factor;
+        let due = started + Duration::from_secs_f64(offset_ms / 1000.0);
+        due.checked_duration_since(now).filter(|d| !d.is_zero())
+    }
+}
+
+/// Recorded messages, as a `Stream` like [`WsClient`](crate::ws::WsClient).
+pub struct ReplayStream {
+    rx: mpsc::Receiver<WsMessage>,
+    _handle: std::thread::JoinHandle<()>,
+}
+
+impl Stream for ReplayStream {
+    type Item = WsMessage;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws::public::TradeStream;
+    use crate::ws::recorder::{RecorderConfig, WsRecorder};
+    use futures_util::StreamExt;
+
+    fn record(dir: &Path) {
+        let _ = std::fs::remove_dir_all(dir);
+        let recorder = WsRecorder::start(RecorderConfig::new(dir)).unwrap();
+        let frames = [
+            r#"{"success":true,"ret_msg":"","conn_id":"x","op":"subscribe"}"#,
+            r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[{"T":1672304486865,"s":"BTCUSDT","S":"Buy","v":"0.001","p":"16578.50","L":"PlusTick","i":"a","BT":false}]}"#,
+            r#"{"topic":"tickers.BTCUSDT","type":"delta","ts":1672304486900,"data":{"symbol":"BTCUSDT","lastPrice":"16578.50"}}"#,
+            r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304487000,"data":[{"T":1672304486990,"s":"BTCUSDT","S":"Sell","v":"0.002","p":"16578.00","L":"MinusTick","i":"b","BT":false}]}"#,
+        ];
+        for (n, frame) in frames.iter().enumerate() {
+            recorder.record(RawFrame {
+                recv_ts: 1_000 + 100 * n as i64,
+                conn_id: "c1".to_string(),
+                url: "wss://stream.bybit.com/v5/public/linear".to_string(),
+                frame: frame.to_string(),
+            });
+        }
+        recorder.finish().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_through_typed_parsers() {
+        let dir = std::env::temp_dir().join(format!("bybit-replay-{}", std::process::id()));
+        record(&dir);
+
+        let source = ReplayWsSource::from_dir(&dir).unwrap();
+        assert_eq!(source.frames().count(), 4);
+
+        let messages: Vec<WsMessage> = source.clone().start().unwrap().collect().await;
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].is_subscribe_success());
+
+        let trades: Vec<WsMessage> = source
+            .with_topics(vec!["publicTrade.BTCUSDT".to_string()])
+            .start()
+            .unwrap()
+            .collect()
+            .await;
+        let sides: Vec<String> = trades
+            .iter()
+            .map(|m| match m {
+                WsMessage::Data(d) => TradeStream::parse(d.data.as_ref().unwrap()).unwrap()[0]
+                    .side
+                    .clone()
+                    .unwrap(),
+                WsMessage::Op(_) => unreachable!(),
+            })
+            .collect();
+        assert_eq!(sides, vec!["Buy", "Sell"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_replay_clock() {
+        let mut max = ReplayClock::new(ReplaySpeed::Max);
+        assert_eq!(max.delay(0), None);
+        assert_eq!(max.delay(60_000), None);
+
+        let start = Instant::now();
+        let mut fast = ReplayClock::new(ReplaySpeed::Accelerated(1000.0));
+        assert_eq!(fast.delay_at(5_000, start), None);
+        assert_eq!(
+            fast.delay_at(15_000, start),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(
+            fast.delay_at(15_000, start + Duration::from_millis(4)),
+            Some(Duration::from_millis(6))
+        );
+        assert_eq!(
+            fast.delay_at(15_000, start + Duration::from_millis(20)),
+            None
+        );
+    }
+}
@@ -21,11 +21,13 @@ use serde_json::Value;
 // ── Request Types ──────────────────────────────────────────────
 
 /// Order operation types for WebSocket.
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum TradeOp {
+    #[serde(rename = "order.create")]
     OrderCreate,
+    #[serde(rename = "order.amend")]
     OrderAmend,
+    #[serde(rename = "order.cancel")]
     OrderCancel,
 }
 
@@ -35,8 +37,10 @@ pub struct WsTradeRequest {
     /// Request ID (unique per request, echoed in response)
     #[serde(rename = "reqId")]
     pub req_id: String,
-    /// Header with operation type
+    /// Request headers (timestamp, recv window, key, signature)
     pub header: TradeHeader,
+    /// Operation: `order.create`, `order.amend` or `order.cancel`
+    pub op: TradeOp,
     /// Request parameters (order details)
     pub args: Vec<Value>,
 }
@@ -155,6 +159,7 @@ impl WsTradeRequest {
                 api_key: api_key.to_string(),
                 signature,
             },
+            op: TradeOp::OrderCreate,
             args: vec![body],
         }
     }
@@ -186,6 +191,7 @@ impl WsTradeRequest {
                 api_key: api_key.to_string(),
                 signature,
             },
+            op: TradeOp::OrderAmend,
             args: vec![body],
         }
     }
@@ -217,6 +223,7 @@ impl WsTradeRequest {
                 api_key: api_key.to_string(),
                 signature,
             },
+            op: TradeOp::OrderCancel,
             args: vec![body],
         }
     }
@@ -244,6 +251,33 @@ mod tests {
         let req = WsTradeRequest::create_order(order, "key", "secret", 5000);
         assert!(req.req_id.starts_with("ws-"));
         assert_eq!(req.args.len(), 1);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["op"], "order.create");
+    }
+
+    #[test]
+    fn test_trade_request_carries_op() {
+        // Without `op` the trade stream rejects the frame, and the old
+        // snake_case names ("order_create") are not valid ops either.
+        let cancel = CancelOrderRequest {
+            category: Category::Linear,
+            symbol: "BTCUSDT".to_string(),
+            order_id: Some("o1".to_string()),
+            ..Default::default()
+        };
+        let req = WsTradeRequest::cancel_order(cancel, "key", "secret", 5000);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["op"], "order.cancel");
+        assert_eq!(json["args"][0]["orderId"], "o1");
+
+        assert_eq!(
+            serde_json::to_value(TradeOp::OrderAmend).unwrap(),
+            "order.amend"
+        );
+        assert_eq!(
+            serde_json::from_str::<TradeOp>(r#""order.create""#).unwrap(),
+            TradeOp::OrderCreate
+        );
     }
 
     #[test]
@@ -0,0 +1,374 @@
+//! End-to-end tests against the in-process `MockExchange`.
+//!
+//! Unlike the wiremock tests, these drive the real REST and WebSocket
+//! clients through signing, order matching and private event pushes.
+
+use bybit_rust_api::dto::{CancelOrderRequest, GetWalletBalanceParams, PlaceOrderRequest};
+use bybit_rust_api::enums::AccountType;
+use bybit_rust_api::rest::errors::{BybitError, ErrorCodes};
+use bybit_rust_api::testing::{MockExchange, MockExchangeConfig};
+use bybit_rust_api::ws::private::{ExecutionData, OrderData};
+use bybit_rust_api::ws::public::TradeStream;
+use bybit_rust_api::ws::trade::WsTradeRequest;
+use bybit_rust_api::ws::{generate_auth_params, topics, WsClient, WsMessage};
+use bybit_rust_api::{
+    AccountClient, ApiKeyPair, Category, MarketClient, OrderClient, OrderStatus, OrderType,
+    RestClient, Side, TimeInForce,
+};
+use futures_util::StreamExt;
+use std::time::Duration;
+
+async fn start() -> MockExchange {
+    MockExchange::start(
+        MockExchangeConfig::new()
+            .with_api_key("key", "secret")
+            .with_balance("USDT", 100_000.0)
+            .with_fee_rates(0.0, 0.001)
+            .with_book(
+                Category::Spot,
+                "BTCUSDT",
+                &[(29_990.0, 1.0), (29_980.0, 2.0)],
+                &[(30_010.0, 0.5), (30_020.0, 1.0)],
+            )
+            .with_book(
+                Category::Linear,
+                "BTCUSDT",
+                &[(29_990.0, 10.0)],
+                &[(30_010.0, 10.0)],
+            ),
+    )
+    .await
+    .unwrap()
+}
+
+fn limit(category: Category, side: Side, qty: &str, price: &str) -> PlaceOrderRequest {
+    PlaceOrderRequest {
+        category,
+        symbol: "BTCUSDT".to_string(),
+        side,
+        order_type: OrderType::Limit,
+        qty: qty.to_string(),
+        price: Some(price.to_string()),
+        time_in_force: Some(TimeInForce::GTC),
+        ..Default::default()
+    }
+}
+
+/// Next data message on `topic`, skipping op responses and other topics.
+async fn next_on(client: &mut WsClient, topic: &str) -> serde_json::Value {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            match client.next().await {
+                Some(WsMessage::Data(d)) if d.topic.as_deref() == Some(topic) => {
+                    return d.data.unwrap()
+                }
+                Some(_) => continue,
+                None => panic!("stream ended"),
+            }
+        }
+    })
+    .await
+    .expect("no message on topic")
+}
+
+/// Whether `op` is a successful `auth` / `subscribe` reply.
+fn ok(op: &bybit_rust_api::ws::WsOpResponse, name: &str) -> bool {
+    op.op.as_deref() == Some(name) && op.success == Some(true)
+}
+
+/// Next op response, skipping data messages and heartbeat pongs.
+async fn next_op(client: &mut WsClient) -> bybit_rust_api::ws::WsOpResponse {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            match client.next().await {
+                Some(WsMessage::Op(op)) if op.op.as_deref() != Some("pong") => return op,
+                Some(_) => continue,
+                None => panic!("stream ended"),
+            }
+        }
+    })
+    .await
+    .expect("no op response")
+}
+
+#[tokio::test]
+async fn test_market_data() {
+    let mock = start().await;
+    let market = MarketClient::new(mock.rest_client());
+
+    let time = market.get_server_time().await.unwrap();
+    assert!(!time.result.time_second.is_empty());
+
+    let book = market
+        .get_orderbook(Category::Spot, "BTCUSDT", Some(1))
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(book.b, vec![["29990".to_string(), "1".to_string()]]);
+    assert_eq!(book.a, vec![["30010".to_string(), "0.5".to_string()]]);
+
+    let tickers = market
+        .get_tickers(Category::Linear, Some("BTCUSDT"), None, None)
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(tickers.list[0].last_price, "30000");
+}
+
+#[tokio::test]
+async fn test_signatures_are_checked() {
+    let mock = start().await;
+    let client = |key: &str, secret: &str| {
+        AccountClient::new(RestClient::new(
+            ApiKeyPair::new("t".to_string(), key.to_string(), secret.to_string()),
+            mock.rest_url(),
+        ))
+    };
+    let params = GetWalletBalanceParams {
+        account_type: AccountType::UNIFIED,
+        coin: None,
+    };
+
+    let err = client("key", "wrong")
+        .get_wallet_balance(params.clone())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BybitError::Api(ErrorCodes::E10004)));
+    let err = client("nobody", "secret")
+        .get_wallet_balance(params.clone())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BybitError::Api(ErrorCodes::E10003)));
+
+    let wallet = client("key", "secret")
+        .get_wallet_balance(params)
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(wallet.list[0].coin[0].wallet_balance, "100000");
+}
+
+#[tokio::test]
+async fn test_rest_order_lifecycle() {
+    let mock = start().await;
+    let orders = OrderClient::new(mock.rest_client());
+
+    // Rests below the book
+    let placed = orders
+        .place_order(limit(Category::Spot, Side::Buy, "1", "29000"))
+        .await
+        .unwrap()
+        .result;
+    let open = orders
+        .get_open_orders(
+            Category::Spot,
+            Some("BTCUSDT"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(open.list.len(), 1);
+    assert_eq!(open.list[0].order_id, placed.order_id);
+    assert_eq!(open.list[0].order_status, OrderStatus::New);
+
+    // A sell print through 29000 fills it as maker
+    mock.trade(Category::Spot, "BTCUSDT", Side::Sell, 28_900.0, 3.0);
+    let history = orders
+        .get_order_history(
+            Category::Spot,
+            None,
+            None,
+            None,
+            Some(&placed.order_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(history.list[0].order_status, OrderStatus::Filled);
+    assert_eq!(history.list[0].avg_price, "29000");
+
+    let fills = orders
+        .get_trade_history(
+            Category::Spot,
+            Some("BTCUSDT"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(fills.list.len(), 1);
+    assert!(fills.list[0].is_maker);
+    assert_eq!(mock.balance("BTC"), 1.0);
+    assert_eq!(mock.balance("USDT"), 71_000.0);
+
+    // Unknown orders and insufficient funds are API errors
+    let err = orders
+        .cancel_order(CancelOrderRequest {
+            category: Category::Spot,
+            symbol: "BTCUSDT".to_string(),
+            order_id: Some(placed.order_id),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BybitError::Api(ErrorCodes::E110001)));
+    let err = orders
+        .place_order(limit(Category::Spot, Side::Buy, "10", "30000"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BybitError::Api(ErrorCodes::E170131)));
+}
+
+#[tokio::test]
+async fn test_private_stream_pushes_fills() {
+    let mock = start().await;
+    let mut ws = WsClient::connect(&mock.private_ws_url()).await.unwrap();
+    let (expires, signature) = generate_auth_params("secret");
+    ws.authenticate("key", expires, &signature).await.unwrap();
+    assert!(ok(&next_op(&mut ws).await, "auth"));
+    ws.subscribe(vec![
+        topics::order::linear(),
+        topics::execution::all(),
+        topics::position::all(),
+    ])
+    .await
+    .unwrap();
+    assert!(ok(&next_op(&mut ws).await, "subscribe"));
+
+    let orders = OrderClient::new(mock.rest_client());
+    orders
+        .place_order(limit(Category::Linear, Side::Buy, "2", "30010"))
+        .await
+        .unwrap();
+
+    let execution: Vec<ExecutionData> =
+        serde_json::from_value(next_on(&mut ws, "execution").await).unwrap();
+    assert_eq!(execution[0].exec_qty.as_deref(), Some("2"));
+    assert_eq!(execution[0].exec_price.as_deref(), Some("30010"));
+    let order: Vec<OrderData> =
+        serde_json::from_value(next_on(&mut ws, "order.linear").await).unwrap();
+    assert_eq!(order[0].order_status.as_deref(), Some("Filled"));
+    let position = next_on(&mut ws, "position").await;
+    assert_eq!(position[0]["side"], "Buy");
+    assert_eq!(position[0]["size"], "2");
+    assert_eq!(mock.positions()[0].avg_price, "30010");
+}
+
+#[tokio::test]
+async fn test_private_stream_rejects_bad_auth() {
+    let mock = start().await;
+    let mut ws = WsClient::connect(&mock.private_ws_url()).await.unwrap();
+    let (expires, signature) = generate_auth_params("not-the-secret");
+    ws.authenticate("key", expires, &signature).await.unwrap();
+    let op = next_op(&mut ws).await;
+    assert_eq!(op.success, Some(false));
+
+    ws.subscribe(vec![topics::order::all()]).await.unwrap();
+    assert_eq!(next_op(&mut ws).await.success, Some(false));
+}
+
+#[tokio::test]
+async fn test_trade_stream_orders() {
+    let mock = start().await;
+    let mut ws = WsClient::connect(&mock.trade_ws_url()).await.unwrap();
+    let (expires, signature) = generate_auth_params("secret");
+    ws.authenticate("key", expires, &signature).await.unwrap();
+    assert!(ok(&next_op(&mut ws).await, "auth"));
+
+    let request = WsTradeRequest::create_order(
+        limit(Category::Spot, Side::Sell, "0.1", "31000"),
+        "key",
+        "secret",
+        5000,
+    );
+    ws.send(&request).unwrap();
+    let response = next_op(&mut ws).await;
+    assert_eq!(response.op.as_deref(), Some("order.create"));
+    assert_eq!(response.req_id.as_deref(), Some(request.req_id.as_str()));
+    // No BTC to sell yet
+    assert_eq!(response.ret_code, Some(170131));
+
+    let request = WsTradeRequest::create_order(
+        limit(Category::Spot, Side::Buy, "0.1", "29500"),
+        "key",
+        "secret",
+        5000,
+    );
+    ws.send(&request).unwrap();
+    let response = next_op(&mut ws).await;
+    assert_eq!(response.ret_code, Some(0));
+    let order_id = response.data.unwrap()["orderId"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(mock.orders()[0].order_id, order_id);
+
+    ws.send(&WsTradeRequest::cancel_order(
+        CancelOrderRequest {
+            category: Category::Spot,
+            symbol: "BTCUSDT".to_string(),
+            order_id: Some(order_id),
+            ..Default::default()
+        },
+        "key",
+        "secret",
+        5000,
+    ))
+    .unwrap();
+    assert_eq!(next_op(&mut ws).await.ret_code, Some(0));
+    assert_eq!(mock.orders()[0].order_status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_public_stream() {
+    let mock = start().await;
+    let mut ws = WsClient::connect(&mock.public_ws_url(Category::Linear))
+        .await
+        .unwrap();
+    ws.subscribe(vec![
+        topics::orderbook(50, "BTCUSDT"),
+        topics::trade("BTCUSDT"),
+    ])
+    .await
+    .unwrap();
+
+    let book = next_on(&mut ws, "orderbook.50.BTCUSDT").await;
+    assert_eq!(book["b"][0][0], "29990");
+
+    mock.trade(Category::Linear, "BTCUSDT", Side::Buy, 30_005.0, 0.25);
+    let trades = TradeStream::parse(&next_on(&mut ws, "publicTrade.BTCUSDT").await).unwrap();
+    assert_eq!(trades[0].price.as_deref(), Some("30005"));
+    assert_eq!(trades[0].side.as_deref(), Some("Buy"));
+
+    mock.set_book(
+        Category::Linear,
+        "BTCUSDT",
+        &[(30_100.0, 1.0)],
+        &[(30_200.0, 1.0)],
+    );
+    let book = next_on(&mut ws, "orderbook.50.BTCUSDT").await;
+    assert_eq!(book["a"][0][0], "30200");
+    mock.shutdown().await;
+}
@@ -0,0 +1,127 @@
+//! The same strategy code against the live and paper backends, both backed
+//! by a `MockExchange`.
+
+use bybit_rust_api::dto::PlaceOrderRequest;
+use bybit_rust_api::testing::{MockExchange, MockExchangeConfig};
+use bybit_rust_api::trading::{LiveTrader, PaperTrader, Trader, TradingApi};
+use bybit_rust_api::ws::WsClient;
+use bybit_rust_api::{AccountClient, Category, OrderType, Side, TimeInForce};
+use std::time::Duration;
+
+async fn start() -> MockExchange {
+    MockExchange::start(
+        MockExchangeConfig::new()
+            .with_api_key("key", "secret")
+            .with_balance("USDT", 10_000.0)
+            .with_fee_rates(0.0001, 0.0005)
+            .with_book(
+                Category::Linear,
+                "ETHUSDT",
+                &[(1999.0, 50.0)],
+                &[(2001.0, 50.0)],
+            ),
+    )
+    .await
+    .unwrap()
+}
+
+/// Buy at market, rest a take-profit above, report position size.
+async fn strategy(trader: &impl TradingApi) -> (String, usize) {
+    let order = |side, order_type, price: Option<&str>| PlaceOrderRequest {
+        category: Category::Linear,
+        symbol: "ETHUSDT".to_string(),
+        side,
+        order_type,
+        qty: "2".to_string(),
+        price: price.map(str::to_string),
+        time_in_force: price.map(|_| TimeInForce::GTC),
+        ..Default::default()
+    };
+    trader
+        .place_order(order(Side::Buy, OrderType::Market, None))
+        .await
+        .unwrap();
+    trader
+        .place_order(order(Side::Sell, OrderType::Limit, Some("2100")))
+        .await
+        .unwrap();
+
+    let positions = trader
+        .positions(Category::Linear, Some("ETHUSDT"))
+        .await
+        .unwrap();
+    let open = trader.open_orders(Category::Linear, None).await.unwrap();
+    (positions[0].size.clone(), open.len())
+}
+
+#[tokio::test]
+async fn test_live_and_paper_behave_alike() {
+    let mock = start().await;
+    let live = Trader::Live(LiveTrader::new(mock.rest_client()));
+    assert_eq!(strategy(&live).await, ("2".to_string(), 1));
+    assert!(!live.is_paper());
+
+    let paper = PaperTrader::new().with_balance("USDT", 10_000.0);
+    paper
+        .fetch_fee_rates(
+            &AccountClient::new(mock.rest_client()),
+            Category::Linear,
+            "ETHUSDT",
+        )
+        .await
+        .unwrap();
+    let public = WsClient::connect(&mock.public_ws_url(Category::Linear))
+        .await
+        .unwrap();
+    paper
+        .track_with(public, Category::Linear, "ETHUSDT")
+        .await
+        .unwrap();
+
+    // Orders are rejected until the first order book snapshot arrives
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !paper.has_book(Category::Linear, "ETHUSDT") {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("no order book");
+    let paper = Trader::Paper(paper);
+    assert_eq!(strategy(&paper).await, ("2".to_string(), 1));
+
+    // Both charged the 0.05% taker fee on 2 @ 2001
+    let live_wallet = live.wallet().await.unwrap();
+    let paper_wallet = paper.wallet().await.unwrap();
+    assert_eq!(live_wallet.coin[0].wallet_balance, "9997.999");
+    assert_eq!(paper_wallet.coin[0].wallet_balance, "9997.999");
+
+    // A public trade through the take-profit fills both as maker
+    mock.trade(Category::Linear, "ETHUSDT", Side::Buy, 2100.0, 10.0);
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !paper
+            .open_orders(Category::Linear, None)
+            .await
+            .unwrap()
+            .is_empty()
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("paper take-profit not filled");
+    assert!(live
+        .open_orders(Category::Linear, None)
+        .await
+        .unwrap()
+        .is_empty());
+    for trader in [&live, &paper] {
+        let positions = trader
+            .positions(Category::Linear, Some("ETHUSDT"))
+            .await
+            .unwrap();
+        assert_eq!(positions[0].size, "0");
+        assert!(positions[0].is_flat());
+        // 2 * (2100 - 2001) less 2.001 taker and 0.42 maker fees
+        assert_eq!(positions[0].cum_realised_pnl, "195.579");
+    }
+}
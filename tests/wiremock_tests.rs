@@ -5,20 +5,54 @@
 //! authentication signatures, error parsing, and edge cases
 //! without hitting the real API.
 
-use bybit_rust_api::rest::{ApiKeyPair, MarketClient, RestClient};
+use bybit_rust_api::dto::{
+    CollateralSwitch, DemoApplyMoney, DemoApplyMoneyParams, NoConvertRepayParams,
+    PlaceOrderRequest, SetCollateralSwitchBatchParams,
+};
+use bybit_rust_api::enums::{
+    AccountType, BeneficiaryWalletType, BrokerBizType, EarnCategory, FiatTradeStatus, PositionIdx,
+    QuoteStatus, RepayStatus, RfqTraderType, SmallBalanceAccountType, SwitchStatus, TpSlMode,
+    UnhostedWalletType,
+};
+use bybit_rust_api::rest::asset::dto::{
+    Beneficiary, FiatQuoteRequest, FiatTradeRequest, SmallBalanceQuoteRequest, WithdrawRequest,
+};
+use bybit_rust_api::rest::crypto_loan_new::dto::{FixedBorrowRequest, LoanCollateral};
+use bybit_rust_api::rest::earn::dto::EarnPlaceOrderRequest;
+use bybit_rust_api::rest::errors::BybitError;
+use bybit_rust_api::rest::p2p::dto::P2pSendMessageRequest;
+use bybit_rust_api::rest::position::dto::{GetPositionInfoParams, SetTradingStopRequest};
+use bybit_rust_api::rest::rfq::dto::{CreateRfqRequest, ExecuteQuoteRequest, QuoteInfo, RfqLeg};
+use bybit_rust_api::rest::spread::dto::SpreadPlaceOrderRequest;
+use bybit_rust_api::rest::{
+    AccountClient, AnnouncementsClient, ApiKeyPair, AssetClient, BrokerClient, CryptoLoanNewClient,
+    EarnClient, InstitutionalLoanClient, MarketClient, OrderClient, P2pClient, PositionClient,
+    RestClient, RfqClient, SecType, SpreadClient,
+};
+use bybit_rust_api::{Category, ClientConfig, MaintenanceGuard, OrderType, Side, TimeInForce};
+use futures_util::TryStreamExt;
 use serde_json::json;
-use wiremock::matchers::{method, path};
+use std::time::Duration;
+use wiremock::matchers::{
+    body_json, body_string_contains, header, header_regex, method, path, query_param,
+    query_param_is_missing,
+};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Helper: create a test client pointing at a mock server URL.
 fn mock_client(server: &MockServer) -> MarketClient {
+    MarketClient::new(mock_rest_client(server))
+}
+
+/// Helper: a signed `RestClient` pointing at a mock server URL, for any
+/// client type (`EarnClient::new(mock_rest_client(&server))`).
+fn mock_rest_client(server: &MockServer) -> RestClient {
     let api_key_pair = ApiKeyPair::new(
         "test".to_string(),
         "test_key".to_string(),
         "test_secret".to_string(),
     );
-    let rest_client = RestClient::new(api_key_pair, server.uri());
-    MarketClient::new(rest_client)
+    RestClient::new(api_key_pair, server.uri())
 }
 
 #[tokio::test]
@@ -247,3 +281,1324 @@ async fn test_get_orderbook_success() {
     let response = result.unwrap();
     assert_eq!(response.ret_code, 0);
 }
+
+#[tokio::test]
+async fn test_client_config_default_headers() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/market/time"))
+        .and(header("Referer", "broker-123"))
+        .and(header("User-Agent", "my-bot/1.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1672828800000_i64,
+            "result": {
+                "timeSecond": "1672828800",
+                "timeNano": "1672828800000000000"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let config = ClientConfig::new()
+        .with_timeout(Duration::from_secs(5))
+        .with_user_agent("my-bot/1.0")
+        .with_referer("broker-123");
+    let rest_client = mock_rest_client(&server).with_config(&config).unwrap();
+    let result = MarketClient::new(rest_client).get_server_time().await;
+
+    assert!(result.is_ok(), "Expected Ok, got: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_request_demo_funds() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("v5/account/demo-apply-money"))
+        .and(body_json(json!({
+            "adjustType": 0,
+            "utaDemoApplyMoney": [{ "coin": "USDT", "amountStr": "10000" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "",
+            "retExtInfo": {},
+            "time": 1711420489915_i64,
+            "result": {}
+        })))
+        .mount(&server)
+        .await;
+
+    let account = AccountClient::new(mock_rest_client(&server));
+    let params = DemoApplyMoneyParams::add(vec![DemoApplyMoney::new("USDT", "10000")]);
+    let result = account.request_demo_funds(params).await;
+
+    assert!(result.is_ok(), "Expected Ok, got: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_demo_only_refuses_non_demo_host() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/market/time"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let rest_client = mock_rest_client(&server).with_demo_only(true);
+    let result = MarketClient::new(rest_client).get_server_time().await;
+
+    assert!(matches!(result, Err(BybitError::Config(_))));
+}
+
+#[tokio::test]
+async fn test_earn_stake_flexible_savings() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/earn/product"))
+        .and(query_param("category", "FlexibleSaving"))
+        .and(query_param("coin", "USDT"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "",
+            "retExtInfo": {},
+            "time": 1739338924138_i64,
+            "result": {
+                "list": [{
+                    "category": "FlexibleSaving",
+                    "estimateApr": "3%",
+                    "coin": "USDT",
+                    "minStakeAmount": "0.001",
+                    "maxStakeAmount": "100000",
+                    "precision": "8",
+                    "productId": "428",
+                    "status": "Available"
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/earn/place-order"))
+        .and(body_json(json!({
+            "category": "FlexibleSaving",
+            "orderType": "Stake",
+            "accountType": "FUND",
+            "amount": "5000",
+            "coin": "USDT",
+            "productId": "428",
+            "orderLinkId": "park-idle-usdt"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "",
+            "retExtInfo": {},
+            "time": 1739338924138_i64,
+            "result": {
+                "orderId": "0572b030-6a0b-423f-88c4-b6ce31c0c82d",
+                "orderLinkId": "park-idle-usdt"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let earn = EarnClient::new(mock_rest_client(&server));
+    let products = earn
+        .get_products(EarnCategory::FlexibleSaving, Some("USDT"))
+        .await
+        .unwrap()
+        .result
+        .list;
+    let product = products.iter().find(|p| p.is_available()).unwrap();
+    let order = earn
+        .place_order(EarnPlaceOrderRequest::stake(
+            product.category,
+            &product.product_id,
+            "USDT",
+            "5000",
+            AccountType::FUND,
+            "park-idle-usdt",
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(order.result.order_link_id, "park-idle-usdt");
+}
+
+#[tokio::test]
+async fn test_spread_quote_inside_calendar_spread() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/spread/tickers"))
+        .and(query_param("symbol", "BTCUSDT_BTC-27JUN25"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "retExtInfo": {},
+            "time": 1744079410023_i64,
+            "result": {
+                "list": [{
+                    "symbol": "BTCUSDT_BTC-27JUN25",
+                    "bidPrice": "412.5",
+                    "bidSize": "0.05",
+                    "askPrice": "418.5",
+                    "askSize": "0.05",
+                    "lastPrice": "415",
+                    "highPrice24h": "430",
+                    "lowPrice24h": "401",
+                    "prevPrice24h": "420",
+                    "volume24h": "12.5"
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/spread/order/create"))
+        .and(body_json(json!({
+            "symbol": "BTCUSDT_BTC-27JUN25",
+            "side": "Buy",
+            "orderType": "Limit",
+            "qty": "0.05",
+            "price": "413",
+            "orderLinkId": "cal-1",
+            "timeInForce": "PostOnly"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1744079410023_i64,
+            "result": {
+                "orderId": "1b00b997-d825-465e-ad1d-80b0eb1955af",
+                "orderLinkId": "cal-1"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let spread = SpreadClient::new(mock_rest_client(&server));
+    let ticker = spread
+        .get_tickers("BTCUSDT_BTC-27JUN25")
+        .await
+        .unwrap()
+        .result
+        .list
+        .remove(0);
+    let bid: f64 = ticker.bid_price.parse().unwrap();
+    let order = spread
+        .place_order(SpreadPlaceOrderRequest {
+            symbol: ticker.symbol,
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            qty: "0.05".to_string(),
+            price: Some(format!("{}", bid + 0.5)),
+            order_link_id: Some("cal-1".to_string()),
+            time_in_force: Some(TimeInForce::PostOnly),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(order.result.order_link_id, "cal-1");
+}
+
+#[tokio::test]
+async fn test_rfq_execute_best_quote() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("v5/rfq/create-rfq"))
+        .and(body_json(json!({
+            "counterparties": ["LP4", "LP5"],
+            "rfqLinkId": "block-1",
+            "list": [{ "category": "linear", "symbol": "BTCUSDT", "side": "Buy", "qty": "25" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1757579700000_i64,
+            "result": {
+                "rfqId": "rfq-1",
+                "rfqLinkId": "block-1",
+                "status": "Active",
+                "expiresAt": "1757580000000",
+                "deskCode": "1nu9d1"
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("v5/rfq/quote-realtime"))
+        .and(query_param("rfqId", "rfq-1"))
+        .and(query_param("traderType", "request"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1757579720000_i64,
+            "result": {
+                "list": [
+                    {
+                        "rfqId": "rfq-1",
+                        "quoteId": "q-lp4",
+                        "expiresAt": "1757579760000",
+                        "deskCode": "LP4",
+                        "status": "Active",
+                        "createdAt": "1757579710000",
+                        "updatedAt": "1757579710000",
+                        "quoteBuyList": [{ "category": "linear", "symbol": "BTCUSDT", "price": "113810" }]
+                    },
+                    {
+                        "rfqId": "rfq-1",
+                        "quoteId": "q-lp5",
+                        "expiresAt": "1757579760000",
+                        "deskCode": "LP5",
+                        "status": "Active",
+                        "createdAt": "1757579712000",
+                        "updatedAt": "1757579712000",
+                        "quoteBuyList": [{ "category": "linear", "symbol": "BTCUSDT", "price": "113795" }]
+                    }
+                ]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/rfq/execute-quote"))
+        .and(body_json(
+            json!({ "rfqId": "rfq-1", "quoteId": "q-lp5", "quoteSide": "Buy" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1757579721000_i64,
+            "result": {
+                "rfqId": "rfq-1",
+                "rfqLinkId": "block-1",
+                "quoteId": "q-lp5",
+                "status": "PendingFill"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let rfq = RfqClient::new(mock_rest_client(&server));
+    let created = rfq
+        .create_rfq(CreateRfqRequest {
+            counterparties: vec!["LP4".to_string(), "LP5".to_string()],
+            rfq_link_id: Some("block-1".to_string()),
+            list: vec![RfqLeg {
+                category: Category::Linear,
+                symbol: "BTCUSDT".to_string(),
+                side: Side::Buy,
+                qty: "25".to_string(),
+            }],
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .result;
+    let quotes = rfq
+        .get_quote_realtime(
+            Some(&created.rfq_id),
+            None,
+            None,
+            Some(RfqTraderType::Request),
+        )
+        .await
+        .unwrap()
+        .result
+        .list;
+    let best = quotes
+        .iter()
+        .filter(|q| q.status == QuoteStatus::Active)
+        .min_by(|a, b| {
+            let price = |q: &QuoteInfo| q.quote_buy_list[0].price.parse::<f64>().unwrap();
+            price(a).total_cmp(&price(b))
+        })
+        .unwrap();
+    let executed = rfq
+        .execute_quote(ExecuteQuoteRequest {
+            rfq_id: created.rfq_id.clone(),
+            quote_id: best.quote_id.clone(),
+            quote_side: Side::Buy,
+        })
+        .await
+        .unwrap()
+        .result;
+
+    assert_eq!(executed.quote_id, "q-lp5");
+    assert_eq!(executed.status, QuoteStatus::PendingFill);
+}
+
+#[tokio::test]
+async fn test_account_collateral_switch_and_repay() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("v5/account/set-collateral-switch-batch"))
+        .and(body_json(json!({
+            "request": [
+                { "coin": "SOL", "collateralSwitch": "OFF" },
+                { "coin": "BTC", "collateralSwitch": "ON" }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "retExtInfo": {},
+            "time": 1739497225376_i64,
+            "result": {
+                "list": [
+                    { "coin": "SOL", "collateralSwitch": "OFF" },
+                    { "coin": "BTC", "collateralSwitch": "ON" }
+                ]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/account/no-convert-repay"))
+        .and(body_json(json!({ "coin": "USDT", "amount": "250" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1739499960126_i64,
+            "result": { "resultStatus": "P" }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/account/set-hedging-mode"))
+        .and(body_json(json!({ "setHedgingMode": "ON" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "retExtInfo": {},
+            "time": 1739499960126_i64,
+            "result": {}
+        })))
+        .mount(&server)
+        .await;
+
+    let account = AccountClient::new(mock_rest_client(&server));
+    let switched = account
+        .set_collateral_switch_batch(SetCollateralSwitchBatchParams {
+            request: vec![
+                CollateralSwitch::new("SOL", SwitchStatus::Off),
+                CollateralSwitch::new("BTC", SwitchStatus::On),
+            ],
+        })
+        .await
+        .unwrap();
+    assert_eq!(switched.result.list[0].collateral_switch, SwitchStatus::Off);
+
+    let repaid = account
+        .no_convert_repay(NoConvertRepayParams {
+            coin: "USDT".to_string(),
+            amount: Some("250".to_string()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(repaid.result.result_status, RepayStatus::Processing);
+
+    let hedging = account.set_spot_hedging(SwitchStatus::On).await.unwrap();
+    assert_eq!(hedging.ret_code, 0);
+}
+
+#[tokio::test]
+async fn test_crypto_loan_fixed_borrow_at_market_rate() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/crypto-loan-fixed/borrow-order-quote"))
+        .and(query_param("orderCurrency", "USDT"))
+        .and(query_param("orderBy", "apy"))
+        .and(query_param("term", "30"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "request.success",
+            "retExtInfo": {},
+            "time": 1750218512000_i64,
+            "result": {
+                "list": [
+                    { "orderCurrency": "USDT", "term": 30, "annualRate": "0.045", "qty": "20000" },
+                    { "orderCurrency": "USDT", "term": 30, "annualRate": "0.05", "qty": "80000" }
+                ]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/crypto-loan-fixed/borrow"))
+        .and(body_json(json!({
+            "orderCurrency": "USDT",
+            "orderAmount": "10000",
+            "annualRate": "0.045",
+            "term": "30",
+            "collateralList": [{ "currency": "BTC", "amount": "0.25" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "request.success",
+            "retExtInfo": {},
+            "time": 1750218512000_i64,
+            "result": { "orderId": "13007" }
+        })))
+        .mount(&server)
+        .await;
+
+    let loans = CryptoLoanNewClient::new(mock_rest_client(&server));
+    let quotes = loans
+        .get_fixed_borrow_quotes("USDT", "apy", Some(30), None, None)
+        .await
+        .unwrap()
+        .result
+        .list;
+    let order = loans
+        .fixed_borrow(FixedBorrowRequest {
+            order_currency: "USDT".to_string(),
+            order_amount: "10000".to_string(),
+            annual_rate: quotes[0].annual_rate.clone(),
+            term: quotes[0].term.to_string(),
+            collateral_list: Some(vec![LoanCollateral::new("BTC", "0.25")]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(order.result.order_id, "13007");
+}
+
+#[tokio::test]
+async fn test_institutional_loan_headroom() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/ins-loan/product-infos"))
+        .and(query_param("productId", "91"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1689745773187_i64,
+            "result": {
+                "marginProductInfo": [{
+                    "productId": "91",
+                    "leverage": "4.00000000",
+                    "supportSpot": 1,
+                    "supportContract": 1,
+                    "liquidationLine": "0.80000000",
+                    "stopLiquidationLine": "0.35000000"
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("v5/ins-loan/ltv-convert"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1689745773187_i64,
+            "result": {
+                "ltvInfo": [{
+                    "ltv": "0.5",
+                    "rst": "",
+                    "parentUid": "78903",
+                    "subAccountUids": ["78903"],
+                    "unpaidAmount": "500000",
+                    "unpaidInfo": [{ "token": "USDT", "unpaidQty": "500000", "unpaidInterest": "0" }],
+                    "balance": "1000000",
+                    "balanceInfo": [{ "token": "BTC", "price": "100000", "qty": "10.5", "convertedAmount": "1000000" }]
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let loans = InstitutionalLoanClient::new(mock_rest_client(&server));
+    let product = loans
+        .get_product_infos(Some("91"))
+        .await
+        .unwrap()
+        .result
+        .margin_product_info
+        .remove(0);
+    let ltv = loans
+        .get_ltv_convert()
+        .await
+        .unwrap()
+        .result
+        .ltv_info
+        .remove(0);
+    let headroom = ltv.headroom_for(&product).unwrap();
+
+    assert!((headroom.collateral_drawdown - 0.375).abs() < 1e-9);
+    assert!((headroom.debt_headroom - 300_000.0).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_broker_earning_stream_follows_cursor() {
+    let server = MockServer::start().await;
+
+    let earning = |exec_id: &str, earning: &str| {
+        json!({
+            "userId": "117894077",
+            "bizType": "SPOT",
+            "symbol": "BTCUSDT",
+            "coin": "USDT",
+            "earning": earning,
+            "markupEarning": "0",
+            "baseFeeEarning": earning,
+            "orderId": "1",
+            "execId": exec_id,
+            "execTime": "1712735720000"
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("v5/broker/earning-record"))
+        .and(query_param("bizType", "SPOT"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1712735720000_i64,
+            "result": {
+                "totalEarningCat": { "spot": [{ "coin": "USDT", "earning": "0.3" }] },
+                "details": [earning("a", "0.1"), earning("b", "0.1")],
+                "nextPageCursor": "page2"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("v5/broker/earning-record"))
+        .and(query_param("bizType", "SPOT"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1712735720000_i64,
+            "result": {
+                "totalEarningCat": { "spot": [{ "coin": "USDT", "earning": "0.3" }] },
+                "details": [earning("c", "0.1")],
+                "nextPageCursor": ""
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let broker = BrokerClient::new(mock_rest_client(&server));
+    let records: Vec<_> = broker
+        .earning_stream(Some(BrokerBizType::Spot), None, None, None)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let exec_ids: Vec<_> = records.iter().map(|r| r.exec_id.as_str()).collect();
+    assert_eq!(exec_ids, ["a", "b", "c"]);
+    assert!(records.iter().all(|r| r.biz_type == BrokerBizType::Spot));
+}
+
+#[tokio::test]
+async fn test_asset_withdraw_validates_beneficiary() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("v5/asset/withdraw/create"))
+        .and(body_json(json!({
+            "coin": "USDT",
+            "chain": "ETH",
+            "address": "0x3c5e4f8a2b1d9e7f6a5c4b3d2e1f0a9b8c7d6e5f",
+            "amount": "25",
+            "timestamp": 1734083455000_i64,
+            "beneficiary": {
+                "beneficiaryWalletType": "1",
+                "beneficiaryUnhostedWalletType": "0"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1734083455000_i64,
+            "result": { "id": "10195" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let asset = AssetClient::new(mock_rest_client(&server));
+    let mut beneficiary = Beneficiary {
+        beneficiary_wallet_type: Some(BeneficiaryWalletType::NonCustodial),
+        ..Default::default()
+    };
+    let withdraw = |beneficiary: Beneficiary| {
+        let asset = asset.clone();
+        async move {
+            asset
+                .withdraw(WithdrawRequest {
+                    coin: "USDT".to_string(),
+                    chain: "ETH".to_string(),
+                    address: "0x3c5e4f8a2b1d9e7f6a5c4b3d2e1f0a9b8c7d6e5f".to_string(),
+                    amount: "25".to_string(),
+                    timestamp: 1734083455000,
+                    beneficiary: Some(beneficiary),
+                    ..Default::default()
+                })
+                .await
+        }
+    };
+
+    // Missing the self-hosted wallet owner: rejected before any request
+    let err = withdraw(beneficiary.clone()).await.unwrap_err();
+    assert!(matches!(err, BybitError::Rejected { .. }));
+
+    beneficiary.beneficiary_unhosted_wallet_type = Some(UnhostedWalletType::Own);
+    let response = withdraw(beneficiary).await.unwrap();
+    assert_eq!(response.result["id"], "10195");
+}
+
+#[tokio::test]
+async fn test_asset_small_balance_convert() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/asset/covert/small-balance-list"))
+        .and(query_param("accountType", "eb_convert_uta"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "ok",
+            "retExtInfo": {},
+            "time": 1737022152000_i64,
+            "result": {
+                "smallAssetCoins": [
+                    { "fromCoin": "XRP", "supportConvert": 1, "availableBalance": "0.0002" },
+                    { "fromCoin": "LUNC", "supportConvert": 2, "availableBalance": "3" }
+                ],
+                "supportToCoins": ["BTC", "USDT", "MNT"],
+                "totalBaseValue": "0.00000011"
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/asset/covert/get-quote"))
+        .and(body_json(json!({
+            "accountType": "eb_convert_uta",
+            "fromCoinList": ["XRP"],
+            "toCoin": "USDT"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "ok",
+            "retExtInfo": {},
+            "time": 1737022152000_i64,
+            "result": {
+                "quoteId": "1010020692439481682687668224",
+                "toCoin": "USDT",
+                "totalToAmount": "0.0001",
+                "expiredTime": "1737022211000"
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/asset/covert/small-balance-execute"))
+        .and(body_json(
+            json!({ "quoteId": "1010020692439481682687668224" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "ok",
+            "retExtInfo": {},
+            "time": 1737022152000_i64,
+            "result": {
+                "exchangeTxId": "1010020692439483803499737088",
+                "status": "init"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let asset = AssetClient::new(mock_rest_client(&server));
+    let list = asset
+        .get_small_balance_list(SmallBalanceAccountType::Unified, None)
+        .await
+        .unwrap()
+        .result;
+    let quote = asset
+        .get_small_balance_quote(SmallBalanceQuoteRequest {
+            account_type: SmallBalanceAccountType::Unified,
+            from_coin_list: list.convertible_coins(),
+            to_coin: "USDT".to_string(),
+        })
+        .await
+        .unwrap()
+        .result;
+    let exchange = asset
+        .exchange_small_balance(&quote.quote_id)
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(exchange.exchange_tx_id, "1010020692439483803499737088");
+}
+
+#[tokio::test]
+async fn test_asset_fiat_convert_quote_execute_query() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("v5/fiat/quote-apply"))
+        .and(body_json(json!({
+            "fromCoin": "EUR",
+            "fromCoinType": "fiat",
+            "toCoin": "USDT",
+            "toCoinType": "crypto",
+            "requestAmount": "100",
+            "requestCoinType": "fiat"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1737022152000_i64,
+            "result": {
+                "quoteTxId": "Q1001",
+                "exchangeRate": "1.08",
+                "fromCoin": "EUR",
+                "fromCoinType": "fiat",
+                "toCoin": "USDT",
+                "toCoinType": "crypto",
+                "fromAmount": "100",
+                "toAmount": "108",
+                "expiredTime": "1737022182000"
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/fiat/trade-execute"))
+        .and(body_json(json!({
+            "quoteTxId": "Q1001",
+            "merchantRequestId": "my-req-1"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1737022152000_i64,
+            "result": { "tradeNo": "T2001", "merchantRequestId": "my-req-1" }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("v5/fiat/trade-query"))
+        .and(query_param("tradeNo", "T2001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "success",
+            "retExtInfo": {},
+            "time": 1737022152000_i64,
+            "result": {
+                "tradeNo": "T2001",
+                "status": "success",
+                "quoteTxId": "Q1001",
+                "exchangeRate": "1.08",
+                "fromCoin": "EUR",
+                "fromCoinType": "fiat",
+                "toCoin": "USDT",
+                "toCoinType": "crypto",
+                "fromAmount": "100",
+                "toAmount": "108",
+                "createdAt": "1737022153000"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let asset = AssetClient::new(mock_rest_client(&server));
+    let quote = asset
+        .request_fiat_quote(FiatQuoteRequest::buy_crypto("EUR", "USDT", "100"))
+        .await
+        .unwrap()
+        .result;
+    let executed = asset
+        .execute_fiat_trade(FiatTradeRequest {
+            quote_tx_id: quote.quote_tx_id,
+            merchant_request_id: Some("my-req-1".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .result;
+    let trade = asset
+        .get_fiat_trade(Some(&executed.trade_no), None)
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(trade.status, FiatTradeStatus::Success);
+    assert!(trade.status.is_final());
+    assert_eq!(trade.to_amount, "108");
+}
+
+#[tokio::test]
+async fn test_market_price_limit_clamp() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/market/price-limit"))
+        .and(query_param("category", "linear"))
+        .and(query_param("symbol", "BTCUSDT"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1750302285376_i64,
+            "result": {
+                "symbol": "BTCUSDT",
+                "buyLmt": "105878.10",
+                "sellLmt": "103781.60",
+                "ts": "1750302284491"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(&server);
+    let limit = client
+        .get_price_limit(Some(Category::Linear), "BTCUSDT")
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(limit.clamp(Side::Buy, 120000.0), 105878.10);
+    assert_eq!(limit.clamp(Side::Sell, 90000.0), 103781.60);
+}
+
+#[tokio::test]
+async fn test_order_maintenance_guard() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/system/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1751858399649_i64,
+            "result": {
+                "list": [
+                    {
+                        "id": "done",
+                        "title": "past upgrade",
+                        "state": "completed",
+                        "begin": "1751596902000",
+                        "end": "1751597011000",
+                        "href": "",
+                        "serviceTypes": [1],
+                        "product": [1],
+                        "uidSuffix": [],
+                        "maintainType": 1,
+                        "env": 1
+                    },
+                    {
+                        "id": "now",
+                        "title": "matching engine upgrade",
+                        "state": "ongoing",
+                        "begin": "0",
+                        "end": "9999999999999",
+                        "href": "",
+                        "serviceTypes": [1, 2],
+                        "product": [1, 2],
+                        "uidSuffix": [],
+                        "maintainType": 1,
+                        "env": 1
+                    },
+                    {
+                        "id": "demo-options",
+                        "title": "demo options upgrade",
+                        "state": "ongoing",
+                        "begin": "0",
+                        "end": "9999999999999",
+                        "href": "",
+                        "serviceTypes": [1],
+                        "product": [3],
+                        "uidSuffix": [],
+                        "maintainType": 1,
+                        "env": 2
+                    }
+                ]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/order/create"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let rest = mock_rest_client(&server);
+    let guard = MaintenanceGuard::new();
+    guard
+        .refresh(&AnnouncementsClient::new(rest.clone()))
+        .await
+        .unwrap();
+    assert_eq!(guard.upcoming().len(), 2);
+    assert_eq!(guard.active(None).unwrap().id, "now");
+    // The demo-only options window does not apply to a mainnet guard
+    assert!(guard.active(Some(Category::Option)).is_none());
+
+    let orders = OrderClient::new(rest).with_maintenance_guard(guard);
+    let err = orders
+        .place_order(PlaceOrderRequest {
+            category: Category::Linear,
+            symbol: "BTCUSDT".to_string(),
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            qty: "0.001".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, BybitError::Rejected { ref reason } if reason.contains("matching engine upgrade"))
+    );
+
+    Mock::given(method("POST"))
+        .and(path("v5/order/amend"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let err = orders
+        .amend_order(
+            serde_json::from_value(json!({
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "orderId": "1321003749386327552",
+                "qty": "0.002"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BybitError::Rejected { .. }));
+}
+
+#[tokio::test]
+async fn test_order_imr_guard() {
+    let server = MockServer::start().await;
+
+    let order = |qty: &str| PlaceOrderRequest {
+        category: Category::Linear,
+        symbol: "BTCUSDT".to_string(),
+        side: Side::Buy,
+        order_type: OrderType::Limit,
+        qty: qty.to_string(),
+        price: Some("100000".to_string()),
+        ..Default::default()
+    };
+    let pre_check = |qty: &str, post_imr_e4: i64| {
+        Mock::given(method("POST"))
+            .and(path("v5/order/pre-check"))
+            .and(body_json(serde_json::to_value(order(qty)).unwrap()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "retCode": 0,
+                "retMsg": "OK",
+                "retExtInfo": {},
+                "time": 1749541599589_i64,
+                "result": {
+                    "orderId": "",
+                    "orderLinkId": "",
+                    "preImrE4": 1000,
+                    "preMmrE4": 500,
+                    "postImrE4": post_imr_e4,
+                    "postMmrE4": 2000
+                }
+            })))
+    };
+    pre_check("0.01", 1500).mount(&server).await;
+    pre_check("1", 6000).mount(&server).await;
+    Mock::given(method("POST"))
+        .and(path("v5/order/create"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1749541599589_i64,
+            "result": { "orderId": "1321003749386327552", "orderLinkId": "" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let orders = OrderClient::new(mock_rest_client(&server)).with_max_imr(0.5);
+
+    let placed = orders.place_order(order("0.01")).await.unwrap();
+    assert_eq!(placed.result.order_id, "1321003749386327552");
+
+    let err = orders.place_order(order("1")).await.unwrap_err();
+    assert!(matches!(err, BybitError::Rejected { ref reason } if reason.contains("60.00%")));
+
+    Mock::given(method("POST"))
+        .and(path("v5/order/create-batch"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let err = orders
+        .batch_place_orders(Category::Linear, vec![order("0.01"), order("1")])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BybitError::Rejected { .. }));
+}
+
+#[tokio::test]
+async fn test_p2p_upload_and_share_file() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("v5/p2p/oss/upload_file"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/form-data; boundary=",
+        ))
+        .and(body_string_contains(
+            "name=\"upload_file\"; filename=\"receipt.pdf\"",
+        ))
+        .and(body_string_contains("%PDF-1.4"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ret_code": 0,
+            "ret_msg": "SUCCESS",
+            "ext_code": "",
+            "ext_info": {},
+            "result": {
+                "url": "/fiat/p2p/oss/show/receipt.pdf",
+                "type": "pdf"
+            },
+            "time_now": "1741773200.123"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/p2p/order/message/send"))
+        .and(body_json(json!({
+            "message": "/fiat/p2p/oss/show/receipt.pdf",
+            "contentType": "pdf",
+            "orderId": "1899736326186143744",
+            "fileName": "receipt.pdf"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ret_code": 0,
+            "ret_msg": "SUCCESS",
+            "ext_code": "",
+            "ext_info": {},
+            "result": {},
+            "time_now": "1741773201.456"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let p2p = P2pClient::new(mock_rest_client(&server));
+    let upload = p2p
+        .upload_file("receipt.pdf", b"%PDF-1.4 receipt".to_vec())
+        .await
+        .unwrap();
+    assert_eq!(upload.time, 1741773200123);
+
+    let sent = p2p
+        .send_message(P2pSendMessageRequest::file(
+            "1899736326186143744",
+            &upload.result,
+            "receipt.pdf",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(sent.ret_msg, "SUCCESS");
+}
+
+#[tokio::test]
+async fn test_multipart_file_name_cannot_inject_headers() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("v5/p2p/oss/upload_file"))
+        .and(body_string_contains(
+            "filename=\"a%22b%0D%0AX-Injected: 1.png\"\r\nContent-Type: image/png\r\n",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ret_code": 0,
+            "ret_msg": "SUCCESS",
+            "ext_code": "",
+            "ext_info": {},
+            "result": { "url": "/fiat/p2p/oss/show/a.png", "type": "png" },
+            "time_now": "1741773200.123"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    P2pClient::new(mock_rest_client(&server))
+        .upload_file("a\"b\r\nX-Injected: 1.png", b"png".to_vec())
+        .await
+        .unwrap();
+
+    // A content type cannot be escaped, so one with CR/LF is refused unsent
+    let err = mock_rest_client(&server)
+        .post_multipart::<serde_json::Value>(
+            "v5/p2p/oss/upload_file",
+            "upload_file",
+            "a.png",
+            "image/png\r\nX-Injected: 1",
+            b"png".to_vec(),
+            SecType::Signed,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BybitError::Rejected { .. }));
+}
+
+#[tokio::test]
+async fn test_v5_envelope_requires_result() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/market/time"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1688639403423_i64
+        })))
+        .mount(&server)
+        .await;
+
+    let err = mock_client(&server).get_server_time().await.unwrap_err();
+    assert!(
+        matches!(err, BybitError::Json(ref e) if e.to_string().contains("missing field `result`")),
+        "unexpected error: {}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_position_typed_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("v5/position/list"))
+        .and(query_param("category", "linear"))
+        .and(query_param("symbol", "BTCUSDT"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1697684980172_i64,
+            "result": {
+                "category": "linear",
+                "nextPageCursor": "",
+                "list": [{
+                    "positionIdx": 1,
+                    "riskId": 1,
+                    "riskLimitValue": "2000000",
+                    "symbol": "BTCUSDT",
+                    "side": "Buy",
+                    "size": "0.01",
+                    "avgPrice": "60000",
+                    "positionValue": "600",
+                    "tradeMode": 0,
+                    "autoAddMargin": 0,
+                    "positionStatus": "Normal",
+                    "leverage": "10",
+                    "markPrice": "60100",
+                    "liqPrice": "",
+                    "bustPrice": "",
+                    "positionIM": "60",
+                    "positionMM": "3",
+                    "tpslMode": "Full",
+                    "takeProfit": "0",
+                    "stopLoss": "0",
+                    "trailingStop": "0",
+                    "unrealisedPnl": "1",
+                    "curRealisedPnl": "-0.36",
+                    "cumRealisedPnl": "5",
+                    "adlRankIndicator": 2,
+                    "createdTime": "1676538056258",
+                    "updatedTime": "1697673600012",
+                    "seq": 4688002127_i64,
+                    "isReduceOnly": false
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("v5/position/trading-stop"))
+        .and(body_json(json!({
+            "category": "linear",
+            "symbol": "BTCUSDT",
+            "tpslMode": "Full",
+            "positionIdx": 1,
+            "stopLoss": "58000"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "retExtInfo": {},
+            "time": 1697684980172_i64,
+            "result": {}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = PositionClient::new(mock_rest_client(&server));
+
+    let page = client
+        .get_position_info(GetPositionInfoParams {
+            category: Category::Linear,
+            symbol: Some("BTCUSDT".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .result;
+    let position = &page.list[0];
+    assert_eq!(position.position_idx, PositionIdx::BuySideHedge);
+    assert_eq!(position.position_im, "60");
+    assert!(!position.is_flat());
+
+    client
+        .set_trading_stop(SetTradingStopRequest {
+            category: Category::Linear,
+            symbol: "BTCUSDT".to_string(),
+            tpsl_mode: Some(TpSlMode::Full),
+            position_idx: position.position_idx,
+            stop_loss: Some("58000".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+}